@@ -0,0 +1,407 @@
+//! Core-local interruptor, providing the machine timer and the machine and
+//! supervisor software interrupts of every hart. Its registers can be laid
+//! out as a SiFive CLINT or as the three devices of the split ACLINT: MSWI
+//! and MTIMER, which side by side match the CLINT layout, and SSWI.
+
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{memory::Device, processor::riscv::csr};
+
+/// Size of the CLINT register block.
+pub const CLINT_SIZE: u64 = 0x1_0000;
+/// Size of the ACLINT MSWI register block, holding `msip`.
+pub const MSWI_SIZE: u64 = 0x4000;
+/// Size of the ACLINT MTIMER register block, holding `mtimecmp` and `mtime`.
+pub const MTIMER_SIZE: u64 = 0x8000;
+/// Size of the ACLINT SSWI register block, holding `setssip`.
+pub const SSWI_SIZE: u64 = 0x4000;
+/// Frequency at which `mtime` counts when it follows the host clock.
+pub const TIMEBASE_FREQ: u64 = 10_000_000;
+
+/// Offset of the MTIMER registers within the CLINT.
+const MTIMER_BASE: u64 = MSWI_SIZE;
+/// Offset of `mtime` within the MTIMER registers.
+const MTIME: u64 = 0x7ff8;
+
+/// Register block of the CLINT that an access addresses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Region {
+    /// The whole CLINT, MSWI followed by MTIMER.
+    Clint,
+    Mswi,
+    Mtimer,
+    Sswi,
+}
+
+impl Region {
+    /// Size of the register block.
+    pub const fn size(self) -> u64 {
+        match self {
+            Self::Clint => CLINT_SIZE,
+            Self::Mswi => MSWI_SIZE,
+            Self::Mtimer => MTIMER_SIZE,
+            Self::Sswi => SSWI_SIZE,
+        }
+    }
+}
+
+/// What `mtime` counts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeSource {
+    /// The host's monotonic clock, at [`TIMEBASE_FREQ`].
+    #[default]
+    Host,
+    /// Instructions executed, so that runs are reproducible. A hart waiting
+    /// for an interrupt skips ahead to its timer deadline.
+    Instructions,
+}
+
+impl TimeSource {
+    pub const ALL: [Self; 2] = [Self::Host, Self::Instructions];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Host => "host",
+            Self::Instructions => "instructions",
+        }
+    }
+}
+
+impl FromStr for TimeSource {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|source| source.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| anyhow::anyhow!("unknown time source '{s}'"))
+    }
+}
+
+#[derive(Debug)]
+pub struct Clint {
+    source: TimeSource,
+    epoch: Instant,
+    instructions: u64,
+    /// What software wrote to `mtime`, relative to the time source.
+    delta: u64,
+    msip: Vec<bool>,
+    mtimecmp: Vec<u64>,
+    /// Supervisor software interrupts raised through `setssip` and not yet
+    /// delivered to the hart.
+    ssip: Vec<bool>,
+}
+
+impl Clint {
+    pub fn new(harts: usize, source: TimeSource) -> Self {
+        Self {
+            source,
+            epoch: Instant::now(),
+            instructions: 0,
+            delta: 0,
+            msip: vec![false; harts],
+            // A timer that is never due until software sets it.
+            mtimecmp: vec![u64::MAX; harts],
+            ssip: vec![false; harts],
+        }
+    }
+
+    pub fn mtime(&self) -> u64 {
+        let ticks = match self.source {
+            TimeSource::Host => {
+                let nanos = self.epoch.elapsed().as_nanos();
+                (nanos / u128::from(1_000_000_000 / TIMEBASE_FREQ)) as u64
+            }
+            TimeSource::Instructions => self.instructions,
+        };
+        ticks.wrapping_add(self.delta)
+    }
+
+    pub fn set_mtime(&mut self, mtime: u64) {
+        self.delta = self.delta.wrapping_add(mtime.wrapping_sub(self.mtime()));
+    }
+
+    /// Counts an executed instruction.
+    #[inline(always)]
+    pub fn tick(&mut self) {
+        self.instructions = self.instructions.wrapping_add(1);
+    }
+
+    /// The `mip` bits the CLINT drives for `hart`: MSIP and MTIP.
+    pub fn pending(&self, hart: usize) -> u64 {
+        let mut mip = 0;
+        if self.msip[hart] {
+            mip |= csr::MIP_MSIP;
+        }
+        if self.mtime() >= self.mtimecmp[hart] {
+            mip |= csr::MIP_MTIP;
+        }
+        mip
+    }
+
+    /// Consumes a supervisor software interrupt raised for `hart`. Unlike
+    /// the other interrupts, it is only set in `mip`, where software then
+    /// clears it.
+    pub fn take_ssip(&mut self, hart: usize) -> bool {
+        std::mem::take(&mut self.ssip[hart])
+    }
+
+    /// Lets time pass while `hart` waits for an interrupt, returning how long
+    /// the host may sleep before its timer is due, if it has a deadline. An
+    /// instruction-counting clock jumps straight to the deadline instead.
+    pub fn idle(&mut self, hart: usize) -> Option<Duration> {
+        let mtimecmp = self.mtimecmp[hart];
+        if mtimecmp == u64::MAX {
+            return None;
+        }
+        let left = mtimecmp.saturating_sub(self.mtime());
+        match self.source {
+            TimeSource::Host => Some(Duration::from_nanos(
+                left.saturating_mul(1_000_000_000 / TIMEBASE_FREQ),
+            )),
+            TimeSource::Instructions => {
+                self.delta = self.delta.wrapping_add(left);
+                Some(Duration::ZERO)
+            }
+        }
+    }
+
+    /// Accesses the registers of `region` as its [`Device`] does. The
+    /// registers of harts that do not exist read as zero and ignore writes.
+    pub fn read(&mut self, region: Region, offset: u64, size: u64) -> Option<u64> {
+        match region {
+            Region::Clint if offset < MTIMER_BASE => self.read(Region::Mswi, offset, size),
+            Region::Clint => self.read(Region::Mtimer, offset - MTIMER_BASE, size),
+            Region::Mswi if offset < MSWI_SIZE && size == 4 && offset.is_multiple_of(4) => {
+                let msip = self.msip.get((offset / 4) as usize);
+                Some(msip.is_some_and(|&msip| msip).into())
+            }
+            Region::Mtimer if offset < MTIMER_SIZE => {
+                let reg = if offset & !0x7 == MTIME {
+                    self.mtime()
+                } else {
+                    let mtimecmp = self.mtimecmp.get((offset / 8) as usize);
+                    mtimecmp.copied().unwrap_or(0)
+                };
+                read_part(reg, offset, size)
+            }
+            // `setssip` always reads as zero.
+            Region::Sswi if offset < SSWI_SIZE && size == 4 && offset.is_multiple_of(4) => Some(0),
+            _ => None,
+        }
+    }
+
+    pub fn write(&mut self, region: Region, offset: u64, size: u64, val: u64) -> Option<()> {
+        match region {
+            Region::Clint if offset < MTIMER_BASE => self.write(Region::Mswi, offset, size, val),
+            Region::Clint => self.write(Region::Mtimer, offset - MTIMER_BASE, size, val),
+            Region::Mswi if offset < MSWI_SIZE && size == 4 && offset.is_multiple_of(4) => {
+                if let Some(msip) = self.msip.get_mut((offset / 4) as usize) {
+                    *msip = val & 1 != 0;
+                }
+                Some(())
+            }
+            Region::Mtimer if offset < MTIMER_SIZE && offset & !0x7 == MTIME => {
+                let mtime = write_part(self.mtime(), offset, size, val)?;
+                self.set_mtime(mtime);
+                Some(())
+            }
+            Region::Mtimer if offset < MTIMER_SIZE => {
+                let mut missing = 0;
+                let mtimecmp = self
+                    .mtimecmp
+                    .get_mut((offset / 8) as usize)
+                    .unwrap_or(&mut missing);
+                *mtimecmp = write_part(*mtimecmp, offset, size, val)?;
+                Some(())
+            }
+            Region::Sswi if offset < SSWI_SIZE && size == 4 && offset.is_multiple_of(4) => {
+                if let Some(ssip) = self.ssip.get_mut((offset / 4) as usize) {
+                    *ssip |= val & 1 != 0;
+                }
+                Some(())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// One register block of a CLINT that the machine shares, as mapped on the
+/// bus.
+#[derive(Debug)]
+pub struct ClintRegion {
+    clint: Rc<RefCell<Clint>>,
+    region: Region,
+}
+
+impl ClintRegion {
+    pub fn new(clint: Rc<RefCell<Clint>>, region: Region) -> Self {
+        Self { clint, region }
+    }
+}
+
+impl Device for ClintRegion {
+    fn read(&mut self, offset: u64, size: u64) -> Option<u64> {
+        self.clint.borrow_mut().read(self.region, offset, size)
+    }
+
+    fn write(&mut self, offset: u64, size: u64, val: u64) -> Option<()> {
+        self.clint.borrow_mut().write(self.region, offset, size, val)
+    }
+}
+
+/// Reads the aligned 4 or 8 bytes at `offset` within a 64-bit register.
+fn read_part(reg: u64, offset: u64, size: u64) -> Option<u64> {
+    match size {
+        8 if offset.is_multiple_of(8) => Some(reg),
+        4 if offset.is_multiple_of(4) => Some((reg >> ((offset & 0x4) * 8)) & 0xffff_ffff),
+        _ => None,
+    }
+}
+
+/// Writes the aligned 4 or 8 bytes at `offset` within a 64-bit register.
+fn write_part(reg: u64, offset: u64, size: u64, val: u64) -> Option<u64> {
+    match size {
+        8 if offset.is_multiple_of(8) => Some(val),
+        4 if offset.is_multiple_of(4) => {
+            let shift = (offset & 0x4) * 8;
+            Some((reg & !(0xffff_ffff << shift)) | ((val & 0xffff_ffff) << shift))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Clint {
+        Clint::new(2, TimeSource::Instructions)
+    }
+
+    #[test]
+    fn clint_msip_drives_software_interrupt() {
+        let mut clint = setup();
+
+        clint.write(Region::Clint, 0x4, 4, 0xffff_ffff).unwrap();
+
+        assert_eq!(clint.read(Region::Clint, 0x4, 4), Some(1));
+        assert_eq!(clint.pending(1), csr::MIP_MSIP);
+        assert_eq!(clint.pending(0), 0);
+
+        clint.write(Region::Mswi, 0x4, 4, 0).unwrap();
+        assert_eq!(clint.pending(1), 0);
+    }
+
+    #[test]
+    fn clint_timer_fires_at_mtimecmp() {
+        let mut clint = setup();
+        clint.write(Region::Clint, 0x4000, 8, 3).unwrap();
+
+        clint.tick();
+        clint.tick();
+        assert_eq!(clint.pending(0), 0);
+        clint.tick();
+        assert_eq!(clint.pending(0), csr::MIP_MTIP);
+        assert_eq!(clint.read(Region::Clint, 0xbff8, 8), Some(3));
+
+        // Moving the deadline clears the interrupt.
+        clint.write(Region::Mtimer, 0x0, 8, 10).unwrap();
+        assert_eq!(clint.pending(0), 0);
+    }
+
+    #[test]
+    fn clint_mtimecmp_halves() {
+        let mut clint = setup();
+
+        clint.write(Region::Mtimer, 0x8, 4, 0x1234_5678).unwrap();
+        clint.write(Region::Mtimer, 0xc, 4, 0x9).unwrap();
+
+        assert_eq!(clint.read(Region::Mtimer, 0x8, 8), Some(0x9_1234_5678));
+        assert_eq!(clint.read(Region::Mtimer, 0xc, 4), Some(0x9));
+    }
+
+    #[test]
+    fn clint_mtime_writable() {
+        let mut clint = setup();
+
+        clint.write(Region::Mtimer, MTIME, 8, 100).unwrap();
+        clint.tick();
+
+        assert_eq!(clint.mtime(), 101);
+        assert_eq!(clint.read(Region::Mtimer, MTIME + 4, 4), Some(0));
+    }
+
+    #[test]
+    fn clint_sswi_raises_supervisor_software_interrupt_once() {
+        let mut clint = setup();
+
+        clint.write(Region::Sswi, 0x4, 4, 1).unwrap();
+
+        assert_eq!(clint.read(Region::Sswi, 0x4, 4), Some(0));
+        assert!(!clint.take_ssip(0));
+        assert!(clint.take_ssip(1));
+        assert!(!clint.take_ssip(1));
+    }
+
+    #[test]
+    fn clint_missing_harts_read_zero() {
+        let mut clint = setup();
+
+        clint.write(Region::Mswi, 0x8, 4, 1).unwrap();
+        clint.write(Region::Mtimer, 0x10, 8, 1).unwrap();
+
+        assert_eq!(clint.read(Region::Mswi, 0x8, 4), Some(0));
+        assert_eq!(clint.read(Region::Mtimer, 0x10, 8), Some(0));
+    }
+
+    #[test]
+    fn clint_rejects_bad_accesses() {
+        let mut clint = setup();
+
+        assert_eq!(clint.read(Region::Mswi, 0x0, 8), None);
+        assert_eq!(clint.read(Region::Mtimer, 0x2, 4), None);
+        assert_eq!(clint.write(Region::Mtimer, 0x0, 2, 0), None);
+        assert_eq!(clint.read(Region::Sswi, SSWI_SIZE, 4), None);
+        assert_eq!(clint.read(Region::Clint, CLINT_SIZE, 8), None);
+    }
+
+    #[test]
+    fn clint_idle_skips_to_deadline() {
+        let mut clint = setup();
+        assert_eq!(clint.idle(0), None);
+
+        clint.write(Region::Mtimer, 0x0, 8, 1000).unwrap();
+
+        assert_eq!(clint.idle(0), Some(Duration::ZERO));
+        assert_eq!(clint.mtime(), 1000);
+        assert_eq!(clint.pending(0), csr::MIP_MTIP);
+    }
+
+    #[test]
+    fn clint_host_time_advances() {
+        let mut clint = Clint::new(1, TimeSource::Host);
+        clint.write(Region::Mtimer, 0x0, 8, u64::MAX - 1).unwrap();
+
+        let before = clint.mtime();
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert!(clint.mtime() > before);
+        assert!(clint.idle(0).is_some_and(|wait| wait > Duration::ZERO));
+    }
+
+    #[test]
+    fn time_source_parses_by_name() {
+        assert_eq!("host".parse::<TimeSource>().unwrap(), TimeSource::Host);
+        assert_eq!(
+            "Instructions".parse::<TimeSource>().unwrap(),
+            TimeSource::Instructions
+        );
+        assert!("cycles".parse::<TimeSource>().is_err());
+    }
+}
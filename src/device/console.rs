@@ -0,0 +1,112 @@
+//! Host side of the serial console: puts the terminal on stdin into raw
+//! mode, so that keys reach the guest unprocessed, and forwards stdin to a
+//! device over a channel without ever blocking the machine.
+//!
+//! As raw mode also passes Ctrl-C through to the guest, the console asks the
+//! machine to quit on Ctrl-A followed by `x`; Ctrl-A twice sends one Ctrl-A.
+
+use std::{
+    io::{IsTerminal, Read},
+    process::{Command, Stdio},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+    },
+};
+
+const ESCAPE: u8 = 0x01;
+const QUIT: u8 = b'x';
+
+/// The terminal on stdin, kept in raw mode until dropped.
+#[derive(Debug)]
+pub struct Console {
+    /// Terminal settings to restore, if raw mode is on.
+    saved: Option<String>,
+    /// Set by the reader thread once the user asks to quit.
+    quit: Arc<AtomicBool>,
+}
+
+impl Console {
+    /// Starts forwarding stdin, switching the terminal to raw mode if stdin
+    /// is one. Input from a file or pipe is forwarded as is.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the thread reading stdin cannot be spawned.
+    pub fn open() -> std::io::Result<(Self, Receiver<u8>)> {
+        let saved = std::io::stdin()
+            .is_terminal()
+            .then(|| stty(&["-g"]))
+            .flatten()
+            .map(|saved| saved.trim().to_owned())
+            .filter(|_| stty(&["raw", "-echo"]).is_some());
+        // Restores the terminal should spawning fail.
+        let console = Self {
+            saved,
+            quit: Arc::default(),
+        };
+
+        let (tx, rx) = mpsc::channel();
+        let escape = console.saved.is_some();
+        let quit = console.quit.clone();
+        std::thread::Builder::new()
+            .name("console".into())
+            .spawn(move || {
+                let mut stdin = std::io::stdin().lock();
+                let mut buf = [0; 64];
+                let mut escaped = false;
+                while let Ok(len @ 1..) = stdin.read(&mut buf) {
+                    for &byte in &buf[..len] {
+                        let sent = match (escape, std::mem::take(&mut escaped), byte) {
+                            (true, false, ESCAPE) => {
+                                escaped = true;
+                                Ok(())
+                            }
+                            (true, true, QUIT) => {
+                                quit.store(true, Ordering::Relaxed);
+                                return;
+                            }
+                            (true, true, other) if other != ESCAPE => {
+                                tx.send(ESCAPE).and_then(|()| tx.send(byte))
+                            }
+                            _ => tx.send(byte),
+                        };
+                        if sent.is_err() {
+                            return;
+                        }
+                    }
+                }
+            })?;
+
+        Ok((console, rx))
+    }
+
+    /// Whether the user has asked to quit, which the machine should check
+    /// between steps.
+    pub fn quit_requested(&self) -> bool {
+        self.quit.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for Console {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            stty(&[saved]);
+        }
+    }
+}
+
+/// Runs `stty` on the terminal on stdin, returning its output on success.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -0,0 +1,85 @@
+//! Interrupt lines from devices to an interrupt controller. A device only
+//! holds the [`IrqLine`] it drives, and the controller samples the levels of
+//! all its lines through [`IrqLines`].
+
+use std::{cell::Cell, rc::Rc};
+
+/// The lines into an interrupt controller, numbered from zero.
+#[derive(Clone, Debug)]
+pub struct IrqLines(Rc<[Cell<bool>]>);
+
+impl IrqLines {
+    pub fn new(count: usize) -> Self {
+        Self((0..count).map(|_| Cell::new(false)).collect())
+    }
+
+    /// The handle a device drives line `index` through, or `None` when the
+    /// controller has no such line.
+    pub fn line(&self, index: usize) -> Option<IrqLine> {
+        (index < self.0.len()).then(|| IrqLine {
+            lines: self.clone(),
+            index,
+        })
+    }
+
+    /// Whether line `index` is asserted. Missing lines never are.
+    #[inline(always)]
+    pub fn level(&self, index: usize) -> bool {
+        self.0.get(index).is_some_and(Cell::get)
+    }
+}
+
+/// One level-triggered interrupt line, driven by a device.
+#[derive(Clone, Debug)]
+pub struct IrqLine {
+    lines: IrqLines,
+    index: usize,
+}
+
+impl IrqLine {
+    #[inline(always)]
+    pub fn set(&self, level: bool) {
+        self.lines.0[self.index].set(level);
+    }
+
+    #[inline(always)]
+    pub fn raise(&self) {
+        self.set(true);
+    }
+
+    #[inline(always)]
+    pub fn lower(&self) {
+        self.set(false);
+    }
+
+    #[inline(always)]
+    pub fn level(&self) -> bool {
+        self.lines.level(self.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn irq_line_drives_controller_level() {
+        let lines = IrqLines::new(4);
+        let line = lines.line(2).unwrap();
+
+        line.raise();
+        assert!(lines.level(2));
+        assert!(!lines.level(1));
+
+        line.lower();
+        assert!(!lines.level(2));
+    }
+
+    #[test]
+    fn irq_missing_line() {
+        let lines = IrqLines::new(4);
+
+        assert!(lines.line(4).is_none());
+        assert!(!lines.level(4));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod clint;
+pub mod console;
+pub mod irq;
+pub mod plic;
+pub mod uart;
@@ -0,0 +1,369 @@
+//! Platform-level interrupt controller, routing the interrupt lines of
+//! devices to the external interrupts of hart contexts. Every source has a
+//! level-triggered gateway, which forwards one request at a time: the source
+//! only becomes pending again once its previous request has been claimed and
+//! completed.
+
+use crate::{
+    device::irq::{IrqLine, IrqLines},
+    memory::Device,
+};
+
+/// Size of the PLIC register block.
+pub const PLIC_SIZE: u64 = 0x400_0000;
+/// Most interrupt sources a PLIC has, numbered from 1.
+pub const MAX_SOURCES: usize = 1023;
+/// Most contexts a PLIC has.
+pub const MAX_CONTEXTS: usize = 15872;
+/// Implemented priority bits; priority 0 never interrupts.
+pub const PRIORITY_MASK: u32 = 0x7;
+
+const PRIORITY: u64 = 0x0;
+const PENDING: u64 = 0x1000;
+const ENABLE: u64 = 0x2000;
+const ENABLE_STRIDE: u64 = 0x80;
+const CONTEXT: u64 = 0x20_0000;
+const CONTEXT_STRIDE: u64 = 0x1000;
+
+#[derive(Debug)]
+pub struct Plic {
+    lines: IrqLines,
+    /// Priority of each source, indexed by source ID; source 0 does not
+    /// exist and stays at zero.
+    priority: Vec<u32>,
+    /// Pending bits, 32 sources per word.
+    pending: Vec<u32>,
+    /// Sources whose gateway forwarded a request that is not completed yet.
+    forwarded: Vec<bool>,
+    /// Enable bits of each context, 32 sources per word.
+    enable: Vec<Vec<u32>>,
+    threshold: Vec<u32>,
+}
+
+impl Plic {
+    /// Creates a PLIC with sources 1 to `sources` and `contexts` contexts,
+    /// both capped to what the register layout has room for.
+    pub fn new(sources: usize, contexts: usize) -> Self {
+        let sources = sources.min(MAX_SOURCES) + 1;
+        let contexts = contexts.min(MAX_CONTEXTS);
+        let words = sources.div_ceil(32);
+        Self {
+            lines: IrqLines::new(sources),
+            priority: vec![0; sources],
+            pending: vec![0; words],
+            forwarded: vec![false; sources],
+            enable: vec![vec![0; words]; contexts],
+            threshold: vec![0; contexts],
+        }
+    }
+
+    /// The line that a device raises to request interrupt `source`, or `None`
+    /// when there is no such source.
+    pub fn line(&self, source: usize) -> Option<IrqLine> {
+        (source != 0).then(|| self.lines.line(source)).flatten()
+    }
+
+    /// Whether `context` has an enabled source pending above its threshold,
+    /// which is what drives its external interrupt.
+    pub fn interrupt(&mut self, context: usize) -> bool {
+        self.best(context) != 0
+    }
+
+    /// Claims the best interrupt of `context`, clearing its pending bit, and
+    /// returns its source ID, or 0 when there is none.
+    fn claim(&mut self, context: usize) -> u32 {
+        let source = self.best(context);
+        self.pending[source / 32] &= !(1 << (source % 32));
+        source as u32
+    }
+
+    /// Completes the handling of `source`, letting its gateway forward the
+    /// next request. Completions of sources the context has not enabled are
+    /// ignored.
+    fn complete(&mut self, context: usize, source: usize) {
+        let enabled = self
+            .enable
+            .get(context)
+            .and_then(|words| words.get(source / 32))
+            .is_some_and(|&word| (word >> (source % 32)) & 1 != 0);
+        if enabled {
+            self.forwarded[source] = false;
+        }
+    }
+
+    /// The enabled source pending in `context` with the highest priority above
+    /// its threshold, the lowest ID breaking ties, or 0 when there is none.
+    fn best(&mut self, context: usize) -> usize {
+        self.sample();
+        let (Some(enable), Some(&threshold)) =
+            (self.enable.get(context), self.threshold.get(context))
+        else {
+            return 0;
+        };
+
+        let mut best = (0, threshold);
+        for (word, (&pending, &enable)) in self.pending.iter().zip(enable).enumerate() {
+            let mut bits = pending & enable;
+            while bits != 0 {
+                let source = word * 32 + bits.trailing_zeros() as usize;
+                if self.priority[source] > best.1 {
+                    best = (source, self.priority[source]);
+                }
+                bits &= bits - 1;
+            }
+        }
+        best.0
+    }
+
+    /// Lets the gateways forward the requests of asserted lines.
+    fn sample(&mut self) {
+        for source in 1..self.forwarded.len() {
+            if !self.forwarded[source] && self.lines.level(source) {
+                self.forwarded[source] = true;
+                self.pending[source / 32] |= 1 << (source % 32);
+            }
+        }
+    }
+
+    /// Sources 1 to the last one in enable word `word`.
+    fn valid_sources(&self, word: usize) -> u32 {
+        (0..32)
+            .filter(|bit| {
+                let source = word * 32 + bit;
+                source != 0 && source < self.priority.len()
+            })
+            .fold(0, |mask, bit| mask | (1 << bit))
+    }
+}
+
+impl Device for Plic {
+    /// Only aligned 4-byte accesses are supported. Registers of missing
+    /// sources and contexts, and reserved ones, read as zero and ignore
+    /// writes.
+    fn read(&mut self, offset: u64, size: u64) -> Option<u64> {
+        if offset >= PLIC_SIZE || size != 4 || !offset.is_multiple_of(4) {
+            return None;
+        }
+        let val = match offset {
+            PRIORITY..PENDING => self.priority.get(index(offset, 4)).copied(),
+            PENDING..ENABLE => {
+                self.sample();
+                self.pending.get(index(offset - PENDING, 4)).copied()
+            }
+            ENABLE..CONTEXT => {
+                let context = index(offset - ENABLE, ENABLE_STRIDE);
+                let word = index(offset % ENABLE_STRIDE, 4);
+                self.enable
+                    .get(context)
+                    .and_then(|words| words.get(word))
+                    .copied()
+            }
+            _ => {
+                let context = index(offset - CONTEXT, CONTEXT_STRIDE);
+                match offset % CONTEXT_STRIDE {
+                    0 => self.threshold.get(context).copied(),
+                    4 if context < self.threshold.len() => Some(self.claim(context)),
+                    _ => None,
+                }
+            }
+        };
+        Some(val.map_or(0, u64::from))
+    }
+
+    fn write(&mut self, offset: u64, size: u64, val: u64) -> Option<()> {
+        if offset >= PLIC_SIZE || size != 4 || !offset.is_multiple_of(4) {
+            return None;
+        }
+        let val = val as u32;
+        match offset {
+            PRIORITY..PENDING => {
+                let source = index(offset, 4);
+                if source != 0
+                    && let Some(priority) = self.priority.get_mut(source)
+                {
+                    *priority = val & PRIORITY_MASK;
+                }
+            }
+            // Pending bits are read-only.
+            PENDING..ENABLE => {}
+            ENABLE..CONTEXT => {
+                let context = index(offset - ENABLE, ENABLE_STRIDE);
+                let word = index(offset % ENABLE_STRIDE, 4);
+                let valid = self.valid_sources(word);
+                if let Some(enable) = self
+                    .enable
+                    .get_mut(context)
+                    .and_then(|words| words.get_mut(word))
+                {
+                    *enable = val & valid;
+                }
+            }
+            _ => {
+                let context = index(offset - CONTEXT, CONTEXT_STRIDE);
+                match offset % CONTEXT_STRIDE {
+                    0 => {
+                        if let Some(threshold) = self.threshold.get_mut(context) {
+                            *threshold = val & PRIORITY_MASK;
+                        }
+                    }
+                    4 => self.complete(context, val as usize),
+                    _ => {}
+                }
+            }
+        }
+        Some(())
+    }
+}
+
+/// Index of the register at `offset` in an array with `stride` bytes apart.
+const fn index(offset: u64, stride: u64) -> usize {
+    (offset / stride) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLAIM: u64 = CONTEXT + 4;
+
+    fn setup() -> Plic {
+        let mut plic = Plic::new(40, 2);
+        for source in [1, 2, 33] {
+            plic.write(4 * source, 4, 1).unwrap();
+        }
+        plic
+    }
+
+    fn enable(plic: &mut Plic, context: u64, source: u64) {
+        let offset = ENABLE + ENABLE_STRIDE * context + 4 * (source / 32);
+        let word = plic.read(offset, 4).unwrap();
+        plic.write(offset, 4, word | (1 << (source % 32))).unwrap();
+    }
+
+    #[test]
+    fn plic_raised_line_becomes_pending() {
+        let mut plic = setup();
+        let line = plic.line(33).unwrap();
+
+        line.raise();
+
+        assert_eq!(plic.read(PENDING + 4, 4), Some(1 << 1));
+        assert!(!plic.interrupt(0));
+        enable(&mut plic, 0, 33);
+        assert!(plic.interrupt(0));
+        assert!(!plic.interrupt(1));
+    }
+
+    #[test]
+    fn plic_claim_and_complete() {
+        let mut plic = setup();
+        let line = plic.line(2).unwrap();
+        enable(&mut plic, 0, 2);
+        line.raise();
+
+        assert_eq!(plic.read(CLAIM, 4), Some(2));
+        assert!(!plic.interrupt(0));
+        assert_eq!(plic.read(CLAIM, 4), Some(0));
+
+        // Still asserted, but not forwarded again until completed.
+        plic.write(CLAIM, 4, 2).unwrap();
+        assert!(plic.interrupt(0));
+
+        assert_eq!(plic.read(CLAIM, 4), Some(2));
+        line.lower();
+        plic.write(CLAIM, 4, 2).unwrap();
+        assert!(!plic.interrupt(0));
+    }
+
+    #[test]
+    fn plic_claims_highest_priority_then_lowest_id() {
+        let mut plic = setup();
+        plic.write(4 * 33, 4, 5).unwrap();
+        for source in [1, 2, 33] {
+            enable(&mut plic, 0, source);
+            plic.line(source as usize).unwrap().raise();
+        }
+
+        assert_eq!(plic.read(CLAIM, 4), Some(33));
+        assert_eq!(plic.read(CLAIM, 4), Some(1));
+        assert_eq!(plic.read(CLAIM, 4), Some(2));
+    }
+
+    #[test]
+    fn plic_threshold_masks_lower_priorities() {
+        let mut plic = setup();
+        enable(&mut plic, 1, 1);
+        plic.line(1).unwrap().raise();
+        plic.write(CONTEXT + CONTEXT_STRIDE, 4, 1).unwrap();
+
+        assert!(!plic.interrupt(1));
+        assert_eq!(plic.read(CLAIM + CONTEXT_STRIDE, 4), Some(0));
+
+        plic.write(4, 4, 2).unwrap();
+        assert!(plic.interrupt(1));
+    }
+
+    #[test]
+    fn plic_zero_priority_never_interrupts() {
+        let mut plic = setup();
+        enable(&mut plic, 0, 3);
+        plic.line(3).unwrap().raise();
+
+        assert!(!plic.interrupt(0));
+    }
+
+    #[test]
+    fn plic_complete_ignored_when_not_enabled() {
+        let mut plic = setup();
+        enable(&mut plic, 0, 1);
+        let line = plic.line(1).unwrap();
+        line.raise();
+        assert_eq!(plic.read(CLAIM, 4), Some(1));
+
+        plic.write(CLAIM + CONTEXT_STRIDE, 4, 1).unwrap();
+        assert!(!plic.interrupt(0));
+
+        plic.write(CLAIM, 4, 1).unwrap();
+        assert!(plic.interrupt(0));
+    }
+
+    #[test]
+    fn plic_warl_fields() {
+        let mut plic = setup();
+
+        plic.write(0, 4, 7).unwrap();
+        plic.write(4, 4, 0xff).unwrap();
+        plic.write(ENABLE, 4, u64::MAX).unwrap();
+        plic.write(ENABLE + 4, 4, u64::MAX).unwrap();
+        plic.write(CONTEXT, 4, 0xff).unwrap();
+        plic.write(PENDING, 4, u64::MAX).unwrap();
+
+        assert_eq!(plic.read(0, 4), Some(0));
+        assert_eq!(plic.read(4, 4), Some(u64::from(PRIORITY_MASK)));
+        assert_eq!(plic.read(ENABLE, 4), Some(0xffff_fffe));
+        assert_eq!(plic.read(ENABLE + 4, 4), Some(0x1ff));
+        assert_eq!(plic.read(CONTEXT, 4), Some(u64::from(PRIORITY_MASK)));
+        assert_eq!(plic.read(PENDING, 4), Some(0));
+    }
+
+    #[test]
+    fn plic_missing_sources_and_contexts() {
+        let mut plic = setup();
+
+        assert!(plic.line(0).is_none());
+        assert!(plic.line(41).is_none());
+        assert_eq!(plic.read(4 * 41, 4), Some(0));
+        assert_eq!(plic.read(CLAIM + 2 * CONTEXT_STRIDE, 4), Some(0));
+        assert_eq!(plic.write(CONTEXT + 2 * CONTEXT_STRIDE, 4, 1), Some(()));
+        assert!(!plic.interrupt(2));
+    }
+
+    #[test]
+    fn plic_rejects_bad_accesses() {
+        let mut plic = setup();
+
+        assert_eq!(plic.read(0x4, 8), None);
+        assert_eq!(plic.read(0x6, 4), None);
+        assert_eq!(plic.write(PLIC_SIZE, 4, 0), None);
+    }
+}
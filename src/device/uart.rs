@@ -0,0 +1,377 @@
+//! NS16550A-compatible UART with byte-wide registers. Transmitted bytes go
+//! straight to the host output, so the transmitter is always empty, and
+//! received bytes arrive over a channel from the host input. The modem
+//! control lines are not modelled: MSR reports a peer that is always ready.
+
+use std::{collections::VecDeque, io::Write, sync::mpsc::Receiver};
+
+use crate::{device::irq::IrqLine, memory::Device};
+
+/// Size of the UART register block.
+pub const UART_SIZE: u64 = 0x100;
+/// Depth of the receive FIFO.
+pub const FIFO_DEPTH: usize = 16;
+
+const RBR_THR_DLL: u64 = 0;
+const IER_DLM: u64 = 1;
+const IIR_FCR: u64 = 2;
+const LCR: u64 = 3;
+const MCR: u64 = 4;
+const LSR: u64 = 5;
+const MSR: u64 = 6;
+const SCR: u64 = 7;
+
+const IER_ERBFI: u8 = 1 << 0;
+const IER_ETBEI: u8 = 1 << 1;
+const IER_MASK: u8 = 0xf;
+
+const IIR_NONE: u8 = 0x1;
+const IIR_THRE: u8 = 0x2;
+const IIR_RDA: u8 = 0x4;
+const IIR_FIFO: u8 = 0xc0;
+
+const FCR_ENABLE: u8 = 1 << 0;
+const FCR_CLEAR_RX: u8 = 1 << 1;
+
+const LCR_DLAB: u8 = 1 << 7;
+const MCR_MASK: u8 = 0x1f;
+
+const LSR_DR: u8 = 1 << 0;
+const LSR_THRE: u8 = 1 << 5;
+const LSR_TEMT: u8 = 1 << 6;
+
+const MSR_CTS: u8 = 1 << 4;
+const MSR_DSR: u8 = 1 << 5;
+const MSR_DCD: u8 = 1 << 7;
+
+pub struct Uart {
+    output: Box<dyn Write>,
+    input: Option<Receiver<u8>>,
+    irq: Option<IrqLine>,
+    rx: VecDeque<u8>,
+    ier: u8,
+    fcr: u8,
+    lcr: u8,
+    mcr: u8,
+    scr: u8,
+    dll: u8,
+    dlm: u8,
+    /// Whether the transmitter-empty interrupt is pending. Reading it from
+    /// IIR or writing THR clears it.
+    thre: bool,
+}
+
+impl Uart {
+    /// Creates a UART transmitting to `output`, with nothing to receive.
+    pub fn new(output: Box<dyn Write>) -> Self {
+        Self {
+            output,
+            input: None,
+            irq: None,
+            rx: VecDeque::with_capacity(FIFO_DEPTH),
+            ier: 0,
+            fcr: 0,
+            lcr: 0,
+            mcr: 0,
+            scr: 0,
+            dll: 0,
+            dlm: 0,
+            thre: false,
+        }
+    }
+
+    /// Receives the bytes sent over `input`.
+    pub fn with_input(mut self, input: Receiver<u8>) -> Self {
+        self.input = Some(input);
+        self
+    }
+
+    /// Signals interrupts on `irq`.
+    pub fn with_irq(mut self, irq: IrqLine) -> Self {
+        self.irq = Some(irq);
+        self
+    }
+
+    /// Moves the bytes that arrived from the host into the receiver, as many
+    /// as it has room for, and updates the interrupt line.
+    pub fn poll(&mut self) {
+        if let Some(input) = &self.input {
+            while self.rx.len() < self.rx_depth()
+                && let Ok(byte) = input.try_recv()
+            {
+                self.rx.push_back(byte);
+            }
+        }
+        self.update_irq();
+    }
+
+    /// How many received bytes the UART holds: a FIFO's worth, or a single
+    /// holding register when the FIFOs are disabled.
+    fn rx_depth(&self) -> usize {
+        if self.fcr & FCR_ENABLE != 0 {
+            FIFO_DEPTH
+        } else {
+            1
+        }
+    }
+
+    /// The highest-priority interrupt that is pending and enabled.
+    fn iir(&self) -> u8 {
+        if self.ier & IER_ERBFI != 0 && !self.rx.is_empty() {
+            IIR_RDA
+        } else if self.ier & IER_ETBEI != 0 && self.thre {
+            IIR_THRE
+        } else {
+            IIR_NONE
+        }
+    }
+
+    fn update_irq(&self) {
+        if let Some(irq) = &self.irq {
+            irq.set(self.iir() != IIR_NONE);
+        }
+    }
+}
+
+impl Device for Uart {
+    /// Only byte accesses are supported. The registers past the eighth read
+    /// as zero and ignore writes.
+    fn read(&mut self, offset: u64, size: u64) -> Option<u64> {
+        if offset >= UART_SIZE || size != 1 {
+            return None;
+        }
+        let dlab = self.lcr & LCR_DLAB != 0;
+        let val = match offset {
+            RBR_THR_DLL if dlab => self.dll,
+            RBR_THR_DLL => {
+                let byte = self.rx.pop_front().unwrap_or(0);
+                self.poll();
+                byte
+            }
+            IER_DLM if dlab => self.dlm,
+            IER_DLM => self.ier,
+            IIR_FCR => {
+                let iir = self.iir();
+                if iir == IIR_THRE {
+                    self.thre = false;
+                }
+                let fifo = if self.fcr & FCR_ENABLE != 0 {
+                    IIR_FIFO
+                } else {
+                    0
+                };
+                iir | fifo
+            }
+            LCR => self.lcr,
+            MCR => self.mcr,
+            // Bytes only leave the host once there is room for them, so the
+            // receiver never overruns.
+            LSR if self.rx.is_empty() => LSR_THRE | LSR_TEMT,
+            LSR => LSR_THRE | LSR_TEMT | LSR_DR,
+            MSR => MSR_CTS | MSR_DSR | MSR_DCD,
+            SCR => self.scr,
+            _ => 0,
+        };
+        self.update_irq();
+        Some(val.into())
+    }
+
+    fn write(&mut self, offset: u64, size: u64, val: u64) -> Option<()> {
+        if offset >= UART_SIZE || size != 1 {
+            return None;
+        }
+        let val = val as u8;
+        let dlab = self.lcr & LCR_DLAB != 0;
+        match offset {
+            RBR_THR_DLL if dlab => self.dll = val,
+            RBR_THR_DLL => {
+                // A host output that fails just loses the byte, as a
+                // disconnected line would.
+                let _ = self
+                    .output
+                    .write_all(&[val])
+                    .and_then(|()| self.output.flush());
+                self.thre = true;
+            }
+            IER_DLM if dlab => self.dlm = val,
+            IER_DLM => {
+                // Enabling the interrupt while the transmitter is empty
+                // raises it at once.
+                if val & IER_ETBEI != 0 && self.ier & IER_ETBEI == 0 {
+                    self.thre = true;
+                }
+                self.ier = val & IER_MASK;
+            }
+            IIR_FCR => {
+                if val & FCR_CLEAR_RX != 0 || (val ^ self.fcr) & FCR_ENABLE != 0 {
+                    self.rx.clear();
+                }
+                self.fcr = val & FCR_ENABLE;
+                self.poll();
+            }
+            LCR => self.lcr = val,
+            MCR => self.mcr = val & MCR_MASK,
+            SCR => self.scr = val,
+            // LSR and MSR are read-only.
+            _ => {}
+        }
+        self.update_irq();
+        Some(())
+    }
+}
+
+impl std::fmt::Debug for Uart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Uart")
+            .field("rx", &self.rx)
+            .field("ier", &self.ier)
+            .field("fcr", &self.fcr)
+            .field("lcr", &self.lcr)
+            .field("thre", &self.thre)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, sync::mpsc};
+
+    use super::*;
+    use crate::device::irq::IrqLines;
+
+    /// Host output that the test can inspect.
+    #[derive(Clone, Default)]
+    struct Sink(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn setup() -> (Uart, Sink, mpsc::Sender<u8>, IrqLines) {
+        let sink = Sink::default();
+        let (tx, rx) = mpsc::channel();
+        let lines = IrqLines::new(1);
+        let uart = Uart::new(Box::new(sink.clone()))
+            .with_input(rx)
+            .with_irq(lines.line(0).unwrap());
+        (uart, sink, tx, lines)
+    }
+
+    #[test]
+    fn uart_transmits_to_output() {
+        let (mut uart, sink, _, _) = setup();
+
+        for byte in b"hi\n" {
+            assert_eq!(uart.read(LSR, 1), Some((LSR_THRE | LSR_TEMT).into()));
+            uart.write(RBR_THR_DLL, 1, (*byte).into()).unwrap();
+        }
+
+        assert_eq!(sink.0.borrow().as_slice(), b"hi\n");
+    }
+
+    #[test]
+    fn uart_receives_from_input() {
+        let (mut uart, _, tx, _) = setup();
+        uart.write(IIR_FCR, 1, FCR_ENABLE.into()).unwrap();
+        tx.send(b'a').unwrap();
+        tx.send(b'b').unwrap();
+
+        assert_eq!(uart.read(LSR, 1).unwrap() as u8 & LSR_DR, 0);
+        uart.poll();
+        assert_eq!(uart.read(LSR, 1).unwrap() as u8 & LSR_DR, LSR_DR);
+        assert_eq!(uart.read(RBR_THR_DLL, 1), Some(b'a'.into()));
+        assert_eq!(uart.read(RBR_THR_DLL, 1), Some(b'b'.into()));
+        assert_eq!(uart.read(LSR, 1).unwrap() as u8 & LSR_DR, 0);
+    }
+
+    #[test]
+    fn uart_holds_one_byte_without_fifo() {
+        let (mut uart, _, tx, _) = setup();
+        tx.send(b'a').unwrap();
+        tx.send(b'b').unwrap();
+
+        uart.poll();
+        uart.poll();
+
+        // The second byte waits on the host side until the first is read.
+        assert_eq!(uart.read(RBR_THR_DLL, 1), Some(b'a'.into()));
+        assert_eq!(uart.read(RBR_THR_DLL, 1), Some(b'b'.into()));
+    }
+
+    #[test]
+    fn uart_divisor_latch() {
+        let (mut uart, sink, _, _) = setup();
+
+        uart.write(LCR, 1, LCR_DLAB.into()).unwrap();
+        uart.write(RBR_THR_DLL, 1, 0x12).unwrap();
+        uart.write(IER_DLM, 1, 0x34).unwrap();
+        assert_eq!(uart.read(RBR_THR_DLL, 1), Some(0x12));
+        assert_eq!(uart.read(IER_DLM, 1), Some(0x34));
+
+        uart.write(LCR, 1, 0x3).unwrap();
+        assert_eq!(uart.read(IER_DLM, 1), Some(0));
+        assert!(sink.0.borrow().is_empty());
+    }
+
+    #[test]
+    fn uart_receive_interrupt() {
+        let (mut uart, _, tx, lines) = setup();
+        uart.write(IER_DLM, 1, IER_ERBFI.into()).unwrap();
+        assert_eq!(uart.read(IIR_FCR, 1), Some(IIR_NONE.into()));
+        assert!(!lines.level(0));
+
+        tx.send(b'x').unwrap();
+        uart.poll();
+        assert!(lines.level(0));
+        assert_eq!(uart.read(IIR_FCR, 1), Some(IIR_RDA.into()));
+
+        uart.read(RBR_THR_DLL, 1).unwrap();
+        assert!(!lines.level(0));
+    }
+
+    #[test]
+    fn uart_transmit_interrupt() {
+        let (mut uart, _, _, lines) = setup();
+        uart.write(IIR_FCR, 1, FCR_ENABLE.into()).unwrap();
+
+        uart.write(IER_DLM, 1, IER_ETBEI.into()).unwrap();
+        assert!(lines.level(0));
+        assert_eq!(uart.read(IIR_FCR, 1), Some((IIR_THRE | IIR_FIFO).into()));
+        assert!(!lines.level(0));
+        assert_eq!(uart.read(IIR_FCR, 1), Some((IIR_NONE | IIR_FIFO).into()));
+
+        uart.write(RBR_THR_DLL, 1, b'z'.into()).unwrap();
+        assert!(lines.level(0));
+    }
+
+    #[test]
+    fn uart_scratch_and_modem_status() {
+        let (mut uart, _, _, _) = setup();
+
+        uart.write(SCR, 1, 0x5a).unwrap();
+        uart.write(MSR, 1, 0).unwrap();
+
+        assert_eq!(uart.read(SCR, 1), Some(0x5a));
+        assert_eq!(
+            uart.read(MSR, 1),
+            Some((MSR_CTS | MSR_DSR | MSR_DCD).into())
+        );
+    }
+
+    #[test]
+    fn uart_rejects_bad_accesses() {
+        let (mut uart, _, _, _) = setup();
+
+        assert_eq!(uart.read(LSR, 4), None);
+        assert_eq!(uart.write(RBR_THR_DLL, 2, 0), None);
+        assert_eq!(uart.read(UART_SIZE, 1), None);
+        assert_eq!(uart.read(0x10, 1), Some(0));
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg_attr(test, allow(clippy::identity_op))]
+
 pub mod device;
 pub mod processor;
 pub mod memory;
@@ -1,9 +1,23 @@
-use crate::{memory::Bus, processor::Cpu};
+use std::{
+    cell::{RefCell, RefMut},
+    rc::Rc,
+    time::Duration,
+};
+
+use crate::{
+    device::{clint::Clint, plic::Plic, uart::Uart},
+    memory::Bus,
+    processor::{Cpu, riscv::csr},
+};
 
 #[derive(Debug)]
 pub struct Machine<C, B> {
     cpu: C,
     bus: B,
+    // The devices are shared with the bus, which maps their registers.
+    clint: Option<Rc<RefCell<Clint>>>,
+    plic: Option<Rc<RefCell<Plic>>>,
+    uart: Option<Rc<RefCell<Uart>>>,
 }
 
 impl<C, B> Machine<C, B>
@@ -11,14 +25,108 @@ where
     C: Cpu,
     B: Bus,
 {
+    /// How long an idle CPU sleeps before checking for interrupts again.
+    const IDLE_SLEEP: Duration = Duration::from_micros(100);
+
+    /// Index of the machine's hart among those the CLINT serves.
+    const HART: usize = 0;
+    /// PLIC contexts of the hart's M-mode and S-mode external interrupts.
+    const M_CONTEXT: usize = 2 * Self::HART;
+    const S_CONTEXT: usize = 2 * Self::HART + 1;
+
     pub fn new(cpu: C, bus: B) -> Self {
-        Self { cpu, bus }
+        Self {
+            cpu,
+            bus,
+            clint: None,
+            plic: None,
+            uart: None,
+        }
     }
 
-    pub fn start(&mut self) -> anyhow::Result<()> {
-        loop {
-            self.cpu.step(&mut self.bus)?;
+    /// Attaches a CLINT, which drives the timer and software interrupts of
+    /// the hart and the time it reads.
+    pub fn with_clint(mut self, clint: Rc<RefCell<Clint>>) -> Self {
+        self.clint = Some(clint);
+        self
+    }
+
+    /// Attaches a PLIC, whose first two contexts drive the M-mode and
+    /// S-mode external interrupts of the hart.
+    pub fn with_plic(mut self, plic: Rc<RefCell<Plic>>) -> Self {
+        self.plic = Some(plic);
+        self
+    }
+
+    /// Attaches a UART, which receives the input that arrived from the host
+    /// at every step.
+    pub fn with_uart(mut self, uart: Rc<RefCell<Uart>>) -> Self {
+        self.uart = Some(uart);
+        self
+    }
+
+    /// Runs the machine until `stop` returns true, which it checks before
+    /// every step.
+    pub fn start(&mut self, stop: impl Fn() -> bool) -> anyhow::Result<()> {
+        while !stop() {
+            self.step()?;
         }
+        Ok(())
+    }
+
+    /// Runs one step of the CPU and brings the devices up to date with it.
+    /// The CLINT counts the instructions retired, and an idle CPU sleeps
+    /// until its next timer deadline, or for `IDLE_SLEEP` at most.
+    pub fn step(&mut self) -> anyhow::Result<()> {
+        let retired = self.cpu.step(&mut self.bus)?;
+
+        let idle = self.cpu.idle();
+        let mut sleep = Self::IDLE_SLEEP;
+        if let Some(clint) = &self.clint {
+            let mut clint = clint.borrow_mut();
+            if idle {
+                sleep = clint.idle(Self::HART).map_or(sleep, |left| left.min(sleep));
+            } else if retired {
+                clint.tick();
+            }
+            self.cpu.set_interrupt(csr::MIP_MSIP | csr::MIP_MTIP, false);
+            self.cpu.set_interrupt(clint.pending(Self::HART), true);
+            if clint.take_ssip(Self::HART) {
+                self.cpu.post_interrupt(csr::MIP_SSIP);
+            }
+            self.cpu.set_time(clint.mtime());
+        }
+        if let Some(uart) = &self.uart {
+            uart.borrow_mut().poll();
+        }
+        if let Some(plic) = &self.plic {
+            let mut plic = plic.borrow_mut();
+            self.cpu
+                .set_interrupt(csr::MIP_MEIP, plic.interrupt(Self::M_CONTEXT));
+            self.cpu
+                .set_interrupt(csr::MIP_SEIP, plic.interrupt(Self::S_CONTEXT));
+        }
+        if idle && !sleep.is_zero() {
+            std::thread::sleep(sleep);
+        }
+
+        Ok(())
+    }
+
+    pub fn cpu(&self) -> &C {
+        &self.cpu
+    }
+
+    pub fn clint_mut(&self) -> Option<RefMut<'_, Clint>> {
+        self.clint.as_ref().map(|clint| clint.borrow_mut())
+    }
+
+    pub fn plic_mut(&self) -> Option<RefMut<'_, Plic>> {
+        self.plic.as_ref().map(|plic| plic.borrow_mut())
+    }
+
+    pub fn uart_mut(&self) -> Option<RefMut<'_, Uart>> {
+        self.uart.as_ref().map(|uart| uart.borrow_mut())
     }
 }
 
@@ -30,3 +138,172 @@ where
         write!(f, "{}", self.cpu)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        device::clint::{ClintRegion, Region, TimeSource},
+        memory::mmap::Mmap,
+        processor::riscv::hart::Hart,
+    };
+
+    // wfi; j .
+    const PROGRAM: [u32; 2] = [0x1050_0073, 0x0000_006f];
+
+    const CLINT_BASE: u64 = 0x200_0000;
+    const PLIC_BASE: u64 = 0xc00_0000;
+    const UART_BASE: u64 = 0x1000_0000;
+
+    fn setup() -> Machine<Hart, Mmap> {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        for (i, inst) in PROGRAM.into_iter().enumerate() {
+            bus.write32(0x1000 + 4 * i as u64, inst).unwrap();
+        }
+        let clint = Rc::new(RefCell::new(Clint::new(1, TimeSource::Instructions)));
+        bus.add_device(
+            CLINT_BASE,
+            Region::Clint.size(),
+            ClintRegion::new(clint.clone(), Region::Clint),
+        )
+        .unwrap();
+        let mut hart = Hart::new(0x1000);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_MIE);
+        hart.csrs_mut().set(csr::MIE, csr::MIP_MTIP | csr::MIP_MSIP);
+
+        Machine::new(hart, bus).with_clint(clint)
+    }
+
+    /// Maps a PLIC with `source` enabled at priority 1 in the M-mode context.
+    fn with_plic(mut machine: Machine<Hart, Mmap>, source: u64) -> Machine<Hart, Mmap> {
+        machine.cpu.csrs_mut().set(csr::MIE, csr::MIP_MEIP);
+        let plic = Rc::new(RefCell::new(Plic::new(16, 2)));
+        machine
+            .bus
+            .add_device(PLIC_BASE, crate::device::plic::PLIC_SIZE, plic.clone())
+            .unwrap();
+        machine.bus.write32(PLIC_BASE + 4 * source, 1).unwrap();
+        machine
+            .bus
+            .write32(PLIC_BASE + 0x2000, 1 << source)
+            .unwrap();
+        machine.with_plic(plic)
+    }
+
+    #[test]
+    fn machine_timer_interrupt_wakes_wfi() {
+        let mut machine = setup();
+        machine.bus.write64(CLINT_BASE + 0x4000, 1000).unwrap();
+
+        machine.step().unwrap();
+        assert!(machine.cpu().idle());
+        machine.step().unwrap();
+
+        assert!(!machine.cpu().idle());
+        assert_eq!(machine.cpu().pc(), 0x2000);
+        assert_eq!(machine.cpu().csrs().get(csr::MCAUSE), (1 << 63) | 7);
+        assert!(machine.cpu().csrs().get(csr::TIME) >= 1000);
+    }
+
+    #[test]
+    fn machine_start_runs_until_stopped() {
+        let mut machine = setup();
+        let steps = std::cell::Cell::new(0);
+
+        machine
+            .start(|| {
+                steps.set(steps.get() + 1);
+                steps.get() > 1
+            })
+            .unwrap();
+
+        assert!(machine.cpu().idle());
+        assert_eq!(machine.cpu().csrs().get(csr::MINSTRET), 1);
+    }
+
+    #[test]
+    fn machine_time_counts_retired_instructions() {
+        let mut machine = setup();
+        // An illegal instruction, then a nop at the trap vector.
+        machine.bus.write32(0x1000, 0).unwrap();
+        machine.bus.write32(0x2000, 0x13).unwrap();
+
+        machine.step().unwrap();
+        assert_eq!(machine.cpu().pc(), 0x2000);
+        assert_eq!(machine.clint_mut().unwrap().mtime(), 0);
+        machine.step().unwrap();
+
+        assert_eq!(machine.clint_mut().unwrap().mtime(), 1);
+    }
+
+    #[test]
+    fn machine_software_interrupt() {
+        let mut machine = setup();
+        machine.bus.write32(CLINT_BASE, 1).unwrap();
+
+        machine.step().unwrap();
+        machine.step().unwrap();
+
+        assert_eq!(machine.cpu().pc(), 0x2000);
+        assert_eq!(machine.cpu().csrs().get(csr::MCAUSE), (1 << 63) | 3);
+    }
+
+    #[test]
+    fn machine_external_interrupt_from_plic() {
+        let mut machine = with_plic(setup(), 5);
+        let line = machine.plic_mut().unwrap().line(5).unwrap();
+
+        machine.step().unwrap();
+        assert!(machine.cpu().idle());
+        line.raise();
+        machine.step().unwrap();
+        machine.step().unwrap();
+
+        assert_eq!(machine.cpu().pc(), 0x2000);
+        assert_eq!(machine.cpu().csrs().get(csr::MCAUSE), (1 << 63) | 11);
+        assert_eq!(machine.bus.read32(PLIC_BASE + 0x20_0004).unwrap(), 5);
+    }
+
+    #[test]
+    fn machine_uart_input_interrupts_through_plic() {
+        let machine = with_plic(setup(), 10);
+        let (tx, rx) = std::sync::mpsc::channel();
+        let irq = machine.plic_mut().unwrap().line(10).unwrap();
+        let uart = Uart::new(Box::new(std::io::sink()))
+            .with_input(rx)
+            .with_irq(irq);
+        let uart = Rc::new(RefCell::new(uart));
+        let mut machine = machine.with_uart(uart.clone());
+        machine
+            .bus
+            .add_device(UART_BASE, crate::device::uart::UART_SIZE, uart)
+            .unwrap();
+        // IER.ERBFI
+        machine.bus.write8(UART_BASE + 1, 0x1).unwrap();
+
+        machine.step().unwrap();
+        assert!(machine.cpu().idle());
+        tx.send(b'k').unwrap();
+        machine.step().unwrap();
+        machine.step().unwrap();
+
+        assert_eq!(machine.cpu().csrs().get(csr::MCAUSE), (1 << 63) | 11);
+        assert_eq!(machine.bus.read32(PLIC_BASE + 0x20_0004).unwrap(), 10);
+        assert_eq!(machine.bus.read8(UART_BASE).unwrap(), b'k');
+    }
+
+    #[test]
+    fn machine_time_counts_instructions() {
+        let mut machine = setup();
+        machine.cpu.csrs_mut().set(csr::MIE, 0);
+        machine.cpu.set_pc(0x1004);
+
+        for _ in 0..5 {
+            machine.step().unwrap();
+        }
+
+        assert_eq!(machine.cpu().csrs().get(csr::TIME), 5);
+        assert_eq!(machine.bus.read64(CLINT_BASE + 0xbff8).unwrap(), 5);
+    }
+}
@@ -24,10 +24,24 @@
 #![warn(clippy::must_use_candidate)]
 #![warn(clippy::missing_errors_doc)]
 
-use std::path::PathBuf;
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
 
 use clap::Parser;
-use priest::{machine::Machine, memory::mmap::Mmap, processor::riscv::hart::Hart};
+use priest::{
+    device::{
+        clint::{Clint, ClintRegion, Region, TimeSource},
+        console::Console,
+        plic::{PLIC_SIZE, Plic},
+        uart::{UART_SIZE, Uart},
+    },
+    machine::Machine,
+    memory::mmap::Mmap,
+    processor::riscv::{
+        hart::{Extension, Extensions, Hart, HartConfig, Misaligned},
+        profile::Profile,
+        vector::VectorRegs,
+    },
+};
 use tracing::{error, info};
 use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -35,6 +49,81 @@ use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberI
 struct Args {
     #[arg()]
     kernel: PathBuf,
+
+    /// Number of implemented PMP entries.
+    #[arg(long, default_value_t = 16, value_parser = clap::value_parser!(u8).range(0..=64))]
+    pmp_entries: u8,
+
+    /// Leave out the C extension, requiring 4-byte instruction alignment.
+    #[arg(long)]
+    no_compressed: bool,
+
+    /// Leave out the V extension.
+    #[arg(long)]
+    no_vector: bool,
+
+    /// Leave out the H extension.
+    #[arg(long)]
+    no_hypervisor: bool,
+
+    /// Vector register width in bits.
+    #[arg(long, default_value_t = 128, value_parser = parse_vlen)]
+    vlen: u32,
+
+    /// Widest vector element in bits.
+    #[arg(long, default_value_t = 64, value_parser = parse_elen)]
+    elen: u32,
+
+    /// Leave out extensions that have no misa bit, e.g. `--disable zbc,zbs`.
+    #[arg(long, value_delimiter = ',')]
+    disable: Vec<Extension>,
+
+    /// What to do with misaligned loads, stores and AMOs: `trap`, handle them
+    /// `transparent`ly, or handle only those within a `page`.
+    #[arg(long, default_value = "trap")]
+    misaligned: Misaligned,
+
+    /// What the machine timer counts: `host` time, or `instructions`
+    /// executed for reproducible runs.
+    #[arg(long, default_value = "host")]
+    time_source: TimeSource,
+
+    /// Interrupt sources of the PLIC, numbered from 1.
+    #[arg(long, default_value_t = 32)]
+    plic_sources: usize,
+
+    /// Where the UART transmits to, e.g. a file or a PTY such as
+    /// `/dev/pts/3`, instead of stdout.
+    #[arg(long)]
+    uart_output: Option<PathBuf>,
+
+    /// Implement exactly the mandatory extensions of a profile, e.g.
+    /// `--profile rva22u64`.
+    #[arg(long, conflicts_with_all = ["no_compressed", "no_vector", "no_hypervisor", "disable"])]
+    profile: Option<Profile>,
+}
+
+// Address map and UART interrupt source of QEMU's `virt` machine.
+const CLINT_BASE: u64 = 0x200_0000;
+const SSWI_BASE: u64 = 0x2f0_0000;
+const PLIC_BASE: u64 = 0xc00_0000;
+const UART_BASE: u64 = 0x1000_0000;
+const UART_IRQ: usize = 10;
+
+fn parse_vlen(s: &str) -> anyhow::Result<u32> {
+    let vlen = s.parse::<u32>()?;
+    anyhow::ensure!(
+        vlen.is_power_of_two() && (32..=VectorRegs::MAX_VLEN).contains(&vlen),
+        "VLEN must be a power of two between 32 and {}",
+        VectorRegs::MAX_VLEN
+    );
+    Ok(vlen)
+}
+
+fn parse_elen(s: &str) -> anyhow::Result<u32> {
+    let elen = s.parse::<u32>()?;
+    anyhow::ensure!(matches!(elen, 32 | 64), "ELEN must be 32 or 64");
+    Ok(elen)
 }
 
 fn main() -> anyhow::Result<()> {
@@ -50,13 +139,18 @@ fn main() -> anyhow::Result<()> {
         .init();
 
     let args = Args::parse();
+    anyhow::ensure!(args.vlen >= args.elen, "VLEN must be at least ELEN");
     let mut bus = Mmap::new(0x8000_0000, 0x800_0000);
 
     let mut kernel_entry = 0;
+    let mut xlen = 64;
     let kernel = std::fs::read(args.kernel)?;
     if let Ok(goblin::Object::Elf(elf)) = goblin::Object::parse(&kernel) {
         info!("entry point paddr={:#018x}", elf.entry);
         kernel_entry = elf.entry;
+        if !elf.is_64 {
+            xlen = 32;
+        }
 
         for ph in elf
             .program_headers
@@ -72,14 +166,69 @@ fn main() -> anyhow::Result<()> {
                 ph.p_paddr,
                 ph.p_memsz,
                 ph.p_filesz,
-            );
+            )?;
         }
     }
 
-    let cpu = Hart::new(kernel_entry);
-    let mut machine = Machine::new(cpu, bus);
-    if let Err(trap) = machine.start() {
-        error!(%trap, %machine, "machine trapped");
+    let mut config = HartConfig {
+        xlen,
+        pmp_entries: usize::from(args.pmp_entries),
+        compressed: !args.no_compressed,
+        extensions: args
+            .disable
+            .iter()
+            .fold(Extensions::all(), |set, &ext| set.without(ext)),
+        vector: !args.no_vector,
+        hypervisor: !args.no_hypervisor,
+        vlen: args.vlen,
+        elen: args.elen,
+        misaligned: args.misaligned,
+    };
+    if let Some(profile) = args.profile {
+        config = profile.config(config)?;
+        info!("profile {}", profile.name());
+    }
+    let cpu = Hart::with_config(kernel_entry, config);
+    info!("isa {}", cpu.isa_string());
+    let clint = Rc::new(RefCell::new(Clint::new(1, args.time_source)));
+    bus.add_device(
+        CLINT_BASE,
+        Region::Clint.size(),
+        ClintRegion::new(clint.clone(), Region::Clint),
+    )?;
+    bus.add_device(
+        SSWI_BASE,
+        Region::Sswi.size(),
+        ClintRegion::new(clint.clone(), Region::Sswi),
+    )?;
+    let plic = Rc::new(RefCell::new(Plic::new(args.plic_sources, 2)));
+    bus.add_device(PLIC_BASE, PLIC_SIZE, plic.clone())?;
+    let output: Box<dyn std::io::Write> = match &args.uart_output {
+        Some(path) => Box::new(
+            std::fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(path)?,
+        ),
+        None => Box::new(std::io::stdout()),
+    };
+    let (console, input) = Console::open()?;
+    let uart = Uart::new(output).with_input(input);
+    let uart = match plic.borrow().line(UART_IRQ) {
+        Some(irq) => uart.with_irq(irq),
+        None => uart,
+    };
+    let uart = Rc::new(RefCell::new(uart));
+    bus.add_device(UART_BASE, UART_SIZE, uart.clone())?;
+
+    let mut machine = Machine::new(cpu, bus)
+        .with_clint(clint)
+        .with_plic(plic)
+        .with_uart(uart);
+    let result = machine.start(|| console.quit_requested());
+    drop(console);
+    if let Err(err) = result {
+        error!(%err, %machine, "machine stopped");
     }
 
     Ok(())
@@ -34,6 +34,10 @@ impl MemoryBuffer {
         }
     }
 
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
     pub const fn as_mut_ptr(&mut self) -> *mut u8 {
         self.data_ptr
     }
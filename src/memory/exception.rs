@@ -20,3 +20,29 @@ pub enum Trap {
     #[error("store address misaligned at address 0x{addr:016x} (align {align})")]
     MisalignedStore { addr: u64, align: usize },
 }
+
+impl Trap {
+    /// Architectural exception code reported in `mcause`/`scause`.
+    pub const fn cause(&self) -> u64 {
+        match self {
+            Self::MisalignedFetch { .. } => 0,
+            Self::FetchAccessFault { .. } => 1,
+            Self::MisalignedLoad { .. } => 4,
+            Self::LoadAccessFault { .. } => 5,
+            Self::MisalignedStore { .. } => 6,
+            Self::StoreAccessFault { .. } => 7,
+        }
+    }
+
+    /// Faulting address reported in `mtval`/`stval`.
+    pub const fn tval(&self) -> u64 {
+        match *self {
+            Self::MisalignedFetch { addr }
+            | Self::FetchAccessFault { addr }
+            | Self::LoadAccessFault { addr }
+            | Self::StoreAccessFault { addr }
+            | Self::MisalignedLoad { addr, .. }
+            | Self::MisalignedStore { addr, .. } => addr,
+        }
+    }
+}
@@ -1,9 +1,35 @@
-use crate::memory::{Bus, buffer::MemoryBuffer, exception::Trap};
+//! Physical address map: the main RAM, which accesses reach on a fast path,
+//! plus further RAM, ROM and device regions. Addresses that no region covers
+//! are holes, and accessing them is an access fault.
+
+use std::cell::RefCell;
+
+use crate::memory::{Bus, Device, buffer::MemoryBuffer, exception::Trap};
 
 #[derive(Debug)]
 pub struct Mmap {
     ram: MemoryBuffer,
     ram_start: u64,
+    /// Regions besides the main RAM, sorted by base and never overlapping
+    /// each other or the main RAM.
+    regions: Vec<Region>,
+}
+
+#[derive(Debug)]
+struct Region {
+    base: u64,
+    size: u64,
+    target: Target,
+}
+
+#[derive(Debug)]
+enum Target {
+    Ram(MemoryBuffer),
+    /// Memory that software can read and execute but not write.
+    Rom(MemoryBuffer),
+    /// Reads can have side effects, so a device is borrowed mutably even by
+    /// the bus's shared reads.
+    Device(RefCell<Box<dyn Device>>),
 }
 
 impl Mmap {
@@ -11,61 +37,222 @@ impl Mmap {
         Self {
             ram: MemoryBuffer::new(length),
             ram_start: origin,
+            regions: Vec::new(),
         }
     }
 
-    pub fn load_segment(&mut self, src: &[u8], paddr: u64, memsz: u64, filesz: u64) {
+    /// Maps `size` bytes of zeroed RAM at `base`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the range is empty or overlaps one already mapped.
+    pub fn add_ram(&mut self, base: u64, size: usize) -> anyhow::Result<()> {
+        self.add(base, size as u64, Target::Ram(MemoryBuffer::new(size)))
+    }
+
+    /// Maps `size` bytes of ROM at `base`, holding `contents` followed by
+    /// zeros.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the range is empty or overlaps one already mapped, or if
+    /// `contents` does not fit.
+    pub fn add_rom(&mut self, base: u64, size: usize, contents: &[u8]) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            contents.len() <= size,
+            "ROM contents of {} bytes exceed its size of {size} bytes",
+            contents.len()
+        );
+        let mut rom = MemoryBuffer::new(size);
+        // SAFETY: `contents` fits in the buffer, which does not alias it.
+        unsafe {
+            std::ptr::copy_nonoverlapping(contents.as_ptr(), rom.as_mut_ptr(), contents.len());
+        }
+        self.add(base, size as u64, Target::Rom(rom))
+    }
+
+    /// Maps `device` at `base`, answering the accesses to the `size` bytes
+    /// from there.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the range is empty or overlaps one already mapped.
+    pub fn add_device(
+        &mut self,
+        base: u64,
+        size: u64,
+        device: impl Device + 'static,
+    ) -> anyhow::Result<()> {
+        self.add(base, size, Target::Device(RefCell::new(Box::new(device))))
+    }
+
+    /// Copies a program segment into the RAM or ROM that holds it, zeroing
+    /// its `memsz - filesz` bytes past the end of `src`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no single RAM or ROM region holds the whole segment, or if
+    /// `src` is shorter than `filesz`.
+    pub fn load_segment(
+        &mut self,
+        src: &[u8],
+        paddr: u64,
+        memsz: u64,
+        filesz: u64,
+    ) -> anyhow::Result<()> {
+        let filesz = filesz.min(memsz);
+        anyhow::ensure!(
+            src.len() as u64 >= filesz,
+            "segment at {paddr:#x} is truncated"
+        );
+        let Some(dst) = self.memory_mut(paddr, memsz) else {
+            anyhow::bail!("segment at {paddr:#x} of {memsz:#x} bytes is not in memory");
+        };
+        // SAFETY: `memory_mut` checked that the segment is inside the buffer,
+        // and `src` holds at least `filesz` bytes.
         unsafe {
-            let dst = self.ram.as_mut_ptr().add((paddr - self.ram_start) as usize);
             std::ptr::copy_nonoverlapping(src.as_ptr(), dst, filesz as usize);
+            std::ptr::write_bytes(dst.add(filesz as usize), 0, (memsz - filesz) as usize);
+        }
+        Ok(())
+    }
 
-            if memsz > filesz {
-                std::ptr::write_bytes(dst.add(filesz as usize), 0, (memsz - filesz) as usize);
+    fn add(&mut self, base: u64, size: u64, target: Target) -> anyhow::Result<()> {
+        let end = base.checked_add(size).filter(|_| size != 0);
+        let Some(end) = end else {
+            anyhow::bail!("invalid region at {base:#x} of {size:#x} bytes");
+        };
+        let ram_end = self.ram_start + self.ram.size() as u64;
+        let overlaps = |start: u64, stop: u64| base < stop && start < end;
+        anyhow::ensure!(
+            !overlaps(self.ram_start, ram_end)
+                && !self
+                    .regions
+                    .iter()
+                    .any(|region| overlaps(region.base, region.base + region.size)),
+            "region {base:#x}..{end:#x} overlaps one already mapped"
+        );
+
+        let index = self.regions.partition_point(|region| region.base < base);
+        self.regions.insert(index, Region { base, size, target });
+        Ok(())
+    }
+
+    /// Offset into RAM of a `size`-byte access at `paddr`, if it lies wholly
+    /// inside.
+    #[inline(always)]
+    fn offset(&self, paddr: u64, size: usize) -> Option<u64> {
+        paddr
+            .checked_sub(self.ram_start)
+            .filter(|offset| offset.saturating_add(size as u64) <= self.ram.size() as u64)
+    }
+
+    /// Index of the region other than the main RAM that a `size`-byte access
+    /// at `paddr` lies wholly inside, and the offset of the access in it.
+    fn region(&self, paddr: u64, size: u64) -> Option<(usize, u64)> {
+        let index = self
+            .regions
+            .partition_point(|region| region.base <= paddr)
+            .checked_sub(1)?;
+        let region = &self.regions[index];
+        let offset = paddr - region.base;
+        (offset.saturating_add(size) <= region.size).then_some((index, offset))
+    }
+
+    /// Pointer to `size` bytes of RAM or ROM at `paddr`, if they lie wholly
+    /// inside one buffer.
+    fn memory_mut(&mut self, paddr: u64, size: u64) -> Option<*mut u8> {
+        if let Some(offset) = self.offset(paddr, size as usize) {
+            // SAFETY: `offset` is inside the main RAM.
+            return Some(unsafe { self.ram.as_mut_ptr().add(offset as usize) });
+        }
+        let (index, offset) = self.region(paddr, size)?;
+        match &mut self.regions[index].target {
+            // SAFETY: `offset` is inside the region's buffer.
+            Target::Ram(buf) | Target::Rom(buf) => {
+                Some(unsafe { buf.as_mut_ptr().add(offset as usize) })
             }
+            Target::Device(_) => None,
         }
     }
 
     #[inline(always)]
     fn load<T>(&self, paddr: u64) -> anyhow::Result<T>
     where
-        T: Copy,
+        T: Copy + TryFrom<u64>,
     {
-        if !paddr.is_multiple_of(std::mem::size_of::<T>() as u64) {
+        let size = std::mem::size_of::<T>();
+        if !paddr.is_multiple_of(size as u64) {
             return Err(Trap::MisalignedLoad {
                 addr: paddr,
-                align: std::mem::size_of::<T>(),
+                align: size,
             }
             .into());
         }
-        if paddr >= self.ram_start {
-            Ok(self.ram.load(paddr - self.ram_start))
-        } else {
-            Err(Trap::LoadAccessFault { addr: paddr }.into())
+        if let Some(offset) = self.offset(paddr, size) {
+            return Ok(self.ram.load(offset));
         }
+        let val = match self.region(paddr, size as u64) {
+            Some((index, offset)) => match &self.regions[index].target {
+                Target::Ram(buf) | Target::Rom(buf) => Some(buf.load(offset)),
+                Target::Device(device) => device
+                    .borrow_mut()
+                    .read(offset, size as u64)
+                    .and_then(|val| T::try_from(val & (u64::MAX >> (64 - 8 * size))).ok()),
+            },
+            None => None,
+        };
+        val.ok_or_else(|| Trap::LoadAccessFault { addr: paddr }.into())
     }
 
     #[inline(always)]
-    fn store<T>(&mut self, paddr: u64, val: T) -> anyhow::Result<()> {
-        if !paddr.is_multiple_of(std::mem::size_of::<T>() as u64) {
+    fn store<T>(&mut self, paddr: u64, val: T) -> anyhow::Result<()>
+    where
+        T: Copy + Into<u64>,
+    {
+        let size = std::mem::size_of::<T>();
+        if !paddr.is_multiple_of(size as u64) {
             return Err(Trap::MisalignedStore {
                 addr: paddr,
-                align: std::mem::size_of::<T>(),
+                align: size,
             }
             .into());
         }
-        if paddr >= self.ram_start {
-            self.ram.store(paddr - self.ram_start, val);
-        } else {
-            return Err(Trap::StoreAccessFault { addr: paddr }.into());
+        if let Some(offset) = self.offset(paddr, size) {
+            self.ram.store(offset, val);
+            return Ok(());
         }
-        Ok(())
+        let stored = match self.region(paddr, size as u64) {
+            Some((index, offset)) => match &mut self.regions[index].target {
+                Target::Ram(buf) => {
+                    buf.store(offset, val);
+                    Some(())
+                }
+                Target::Rom(_) => None,
+                Target::Device(device) => {
+                    device.borrow_mut().write(offset, size as u64, val.into())
+                }
+            },
+            None => None,
+        };
+        stored.ok_or_else(|| Trap::StoreAccessFault { addr: paddr }.into())
     }
 }
 
 impl Bus for Mmap {
     #[inline(always)]
-    fn fetch(&self, paddr: u64) -> u32 {
-        self.ram.load::<u32>(paddr - self.ram_start)
+    fn fetch(&self, paddr: u64) -> anyhow::Result<u16> {
+        if let Some(offset) = self.offset(paddr, 2) {
+            return Ok(self.ram.load(offset));
+        }
+        // Devices cannot be executed from.
+        match self.region(paddr, 2) {
+            Some((index, offset)) => match &self.regions[index].target {
+                Target::Ram(buf) | Target::Rom(buf) => Ok(buf.load(offset)),
+                Target::Device(_) => Err(Trap::FetchAccessFault { addr: paddr }.into()),
+            },
+            None => Err(Trap::FetchAccessFault { addr: paddr }.into()),
+        }
     }
 
     fn read8(&self, paddr: u64) -> anyhow::Result<u8> {
@@ -100,3 +287,170 @@ impl Bus for Mmap {
         self.store(paddr, val)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    /// Device with one 4-byte register at offset 0x4, recording the last
+    /// access it answered.
+    #[derive(Debug, Default)]
+    struct Register {
+        val: u64,
+        last: Option<(u64, u64)>,
+    }
+
+    impl Device for Register {
+        fn read(&mut self, offset: u64, size: u64) -> Option<u64> {
+            self.last = Some((offset, size));
+            (offset == 0x4 && size == 4).then_some(self.val)
+        }
+
+        fn write(&mut self, offset: u64, size: u64, val: u64) -> Option<()> {
+            self.last = Some((offset, size));
+            (offset == 0x4 && size == 4).then(|| self.val = val)
+        }
+    }
+
+    fn setup() -> Mmap {
+        Mmap::new(0x8000_0000, 0x1000)
+    }
+
+    fn trap(err: anyhow::Error) -> Trap {
+        err.downcast::<Trap>().unwrap()
+    }
+
+    #[test]
+    fn mmap_holes_around_ram_fault() {
+        let mut bus = setup();
+
+        for addr in [0x7fff_fff8, 0x8000_1000, 0xffff_fff8] {
+            assert_eq!(
+                trap(bus.read64(addr).unwrap_err()),
+                Trap::LoadAccessFault { addr }
+            );
+            assert_eq!(
+                trap(bus.write64(addr, 0).unwrap_err()),
+                Trap::StoreAccessFault { addr }
+            );
+            assert_eq!(
+                trap(bus.fetch(addr).unwrap_err()),
+                Trap::FetchAccessFault { addr }
+            );
+        }
+        bus.write64(0x8000_0ff8, 1).unwrap();
+        assert_eq!(bus.read64(0x8000_0ff8).unwrap(), 1);
+    }
+
+    #[test]
+    fn mmap_additional_ram() {
+        let mut bus = setup();
+        bus.add_ram(0x1000, 0x1000).unwrap();
+
+        bus.write32(0x1ffc, 0xdead_beef).unwrap();
+        bus.write16(0x1000, 0x0013).unwrap();
+
+        assert_eq!(bus.read32(0x1ffc).unwrap(), 0xdead_beef);
+        assert_eq!(bus.fetch(0x1000).unwrap(), 0x0013);
+        assert_eq!(
+            trap(bus.read8(0x2000).unwrap_err()),
+            Trap::LoadAccessFault { addr: 0x2000 }
+        );
+    }
+
+    #[test]
+    fn mmap_rom_is_read_only() {
+        let mut bus = setup();
+        bus.add_rom(0x1000, 0x100, &[0x13, 0x00, 0x00, 0x00])
+            .unwrap();
+
+        assert_eq!(bus.read32(0x1000).unwrap(), 0x13);
+        assert_eq!(bus.fetch(0x1000).unwrap(), 0x13);
+        assert_eq!(bus.read32(0x10fc).unwrap(), 0);
+        assert_eq!(
+            trap(bus.write32(0x1000, 0).unwrap_err()),
+            Trap::StoreAccessFault { addr: 0x1000 }
+        );
+        assert!(bus.add_rom(0x2000, 2, &[0; 4]).is_err());
+    }
+
+    #[test]
+    fn mmap_routes_device_accesses() {
+        let mut bus = setup();
+        let device = Rc::new(RefCell::new(Register::default()));
+        bus.add_device(0x1000_0000, 0x100, device.clone()).unwrap();
+
+        bus.write32(0x1000_0004, 0x1234_5678).unwrap();
+        assert_eq!(device.borrow().val, 0x1234_5678);
+        assert_eq!(bus.read32(0x1000_0004).unwrap(), 0x1234_5678);
+        assert_eq!(device.borrow().last, Some((0x4, 4)));
+
+        // Accesses the device does not answer, and instruction fetches.
+        assert_eq!(
+            trap(bus.read8(0x1000_0004).unwrap_err()),
+            Trap::LoadAccessFault { addr: 0x1000_0004 }
+        );
+        assert_eq!(
+            trap(bus.write64(0x1000_0008, 0).unwrap_err()),
+            Trap::StoreAccessFault { addr: 0x1000_0008 }
+        );
+        assert_eq!(
+            trap(bus.fetch(0x1000_0004).unwrap_err()),
+            Trap::FetchAccessFault { addr: 0x1000_0004 }
+        );
+        assert_eq!(
+            trap(bus.read32(0x1000_0100).unwrap_err()),
+            Trap::LoadAccessFault { addr: 0x1000_0100 }
+        );
+    }
+
+    #[test]
+    fn mmap_finds_regions_in_any_order() {
+        let mut bus = setup();
+        bus.add_ram(0x3000, 0x1000).unwrap();
+        bus.add_ram(0x1000, 0x1000).unwrap();
+        bus.add_ram(0x9000_0000, 0x1000).unwrap();
+
+        for addr in [0x1000, 0x3000, 0x9000_0000] {
+            bus.write64(addr + 8, addr).unwrap();
+        }
+        for addr in [0x1000, 0x3000, 0x9000_0000] {
+            assert_eq!(bus.read64(addr + 8).unwrap(), addr);
+        }
+        assert!(bus.read8(0x2000).is_err());
+    }
+
+    #[test]
+    fn mmap_rejects_overlapping_regions() {
+        let mut bus = setup();
+        bus.add_ram(0x1000, 0x1000).unwrap();
+
+        assert!(bus.add_ram(0x1800, 0x1000).is_err());
+        assert!(bus.add_ram(0x800, 0x1000).is_err());
+        assert!(bus.add_ram(0x7fff_f000, 0x1001).is_err());
+        assert!(bus.add_ram(0x3000, 0).is_err());
+        assert!(bus.add_device(u64::MAX, 2, Register::default()).is_err());
+
+        bus.add_ram(0x2000, 0x1000).unwrap();
+        bus.add_ram(0x7fff_f000, 0x1000).unwrap();
+    }
+
+    #[test]
+    fn mmap_loads_segments_into_memory() {
+        let mut bus = setup();
+        bus.add_rom(0x1000, 0x100, &[]).unwrap();
+        bus.add_device(0x2000, 0x100, Register::default()).unwrap();
+
+        bus.load_segment(&[1, 2, 3, 4], 0x1000, 8, 4).unwrap();
+        bus.write64(0x8000_0000, u64::MAX).unwrap();
+        bus.load_segment(&[5, 6], 0x8000_0000, 4, 2).unwrap();
+
+        assert_eq!(bus.read64(0x1000).unwrap(), 0x0403_0201);
+        assert_eq!(bus.read64(0x8000_0000).unwrap(), 0xffff_ffff_0000_0605);
+        assert!(bus.load_segment(&[0; 8], 0x8000_0ffc, 8, 8).is_err());
+        assert!(bus.load_segment(&[0; 8], 0x2000, 8, 8).is_err());
+        assert!(bus.load_segment(&[0; 4], 0x1000, 8, 8).is_err());
+    }
+}
@@ -1,9 +1,12 @@
+use std::{cell::RefCell, rc::Rc};
+
 pub mod buffer;
 pub mod exception;
 pub mod mmap;
 
 pub trait Bus {
-    fn fetch(&self, paddr: u64) -> u32;
+    /// Fetches one 16-bit instruction parcel.
+    fn fetch(&self, paddr: u64) -> anyhow::Result<u16>;
 
     fn read8(&self, paddr: u64) -> anyhow::Result<u8>;
     fn read16(&self, paddr: u64) -> anyhow::Result<u16>;
@@ -15,3 +18,30 @@ pub trait Bus {
     fn write32(&mut self, paddr: u64, val: u32) -> anyhow::Result<()>;
     fn write64(&mut self, paddr: u64, val: u64) -> anyhow::Result<()>;
 }
+
+/// A memory-mapped device, answering the naturally aligned accesses of 1, 2,
+/// 4 or 8 bytes that fall within its range, at offsets from its base.
+pub trait Device: std::fmt::Debug {
+    /// Reads `size` bytes at `offset`, returning `None` for accesses that no
+    /// register answers, which the bus reports as access faults.
+    fn read(&mut self, offset: u64, size: u64) -> Option<u64>;
+
+    /// Writes the low `size` bytes of `val` at `offset`, returning `None` for
+    /// accesses that no register answers.
+    fn write(&mut self, offset: u64, size: u64, val: u64) -> Option<()>;
+}
+
+/// A device shared between the bus and its owner, e.g. one the machine also
+/// updates every step.
+impl<D> Device for Rc<RefCell<D>>
+where
+    D: Device + ?Sized,
+{
+    fn read(&mut self, offset: u64, size: u64) -> Option<u64> {
+        self.borrow_mut().read(offset, size)
+    }
+
+    fn write(&mut self, offset: u64, size: u64, val: u64) -> Option<()> {
+        self.borrow_mut().write(offset, size, val)
+    }
+}
@@ -3,7 +3,23 @@ use crate::memory::Bus;
 pub mod riscv;
 
 pub trait Cpu {
-    fn step<B>(&mut self, bus: &mut B) -> anyhow::Result<()>
+    /// Runs one step, returning whether it retired an instruction rather
+    /// than waiting or taking a trap.
+    fn step<B>(&mut self, bus: &mut B) -> anyhow::Result<bool>
     where
         B: Bus;
+
+    /// Raises or lowers the interrupt lines in `mask`, as an interrupt
+    /// controller does.
+    fn set_interrupt(&mut self, mask: u64, pending: bool);
+
+    /// Makes the interrupts in `mask` pending until software clears them.
+    fn post_interrupt(&mut self, mask: u64);
+
+    /// Sets the platform time that the CPU reads from its time counter.
+    fn set_time(&mut self, time: u64);
+
+    /// Whether the CPU is waiting for an interrupt, with nothing to execute
+    /// until one arrives.
+    fn idle(&self) -> bool;
 }
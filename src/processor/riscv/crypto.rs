@@ -0,0 +1,338 @@
+//! Scalar cryptography primitives behind the Zkn and Zks instructions, in
+//! terms of RV64 register operands. A 128-bit AES state is split across two
+//! registers, the low eight bytes in `rs1` and the high eight in `rs2`, with
+//! byte `4 * c + r` holding row `r` of column `c`.
+
+/// FIPS-197 S-box.
+const AES_SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// Inverse of [`AES_SBOX`].
+const AES_INV_SBOX: [u8; 256] = [
+    0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3, 0xd7, 0xfb,
+    0x7c, 0xe3, 0x39, 0x82, 0x9b, 0x2f, 0xff, 0x87, 0x34, 0x8e, 0x43, 0x44, 0xc4, 0xde, 0xe9, 0xcb,
+    0x54, 0x7b, 0x94, 0x32, 0xa6, 0xc2, 0x23, 0x3d, 0xee, 0x4c, 0x95, 0x0b, 0x42, 0xfa, 0xc3, 0x4e,
+    0x08, 0x2e, 0xa1, 0x66, 0x28, 0xd9, 0x24, 0xb2, 0x76, 0x5b, 0xa2, 0x49, 0x6d, 0x8b, 0xd1, 0x25,
+    0x72, 0xf8, 0xf6, 0x64, 0x86, 0x68, 0x98, 0x16, 0xd4, 0xa4, 0x5c, 0xcc, 0x5d, 0x65, 0xb6, 0x92,
+    0x6c, 0x70, 0x48, 0x50, 0xfd, 0xed, 0xb9, 0xda, 0x5e, 0x15, 0x46, 0x57, 0xa7, 0x8d, 0x9d, 0x84,
+    0x90, 0xd8, 0xab, 0x00, 0x8c, 0xbc, 0xd3, 0x0a, 0xf7, 0xe4, 0x58, 0x05, 0xb8, 0xb3, 0x45, 0x06,
+    0xd0, 0x2c, 0x1e, 0x8f, 0xca, 0x3f, 0x0f, 0x02, 0xc1, 0xaf, 0xbd, 0x03, 0x01, 0x13, 0x8a, 0x6b,
+    0x3a, 0x91, 0x11, 0x41, 0x4f, 0x67, 0xdc, 0xea, 0x97, 0xf2, 0xcf, 0xce, 0xf0, 0xb4, 0xe6, 0x73,
+    0x96, 0xac, 0x74, 0x22, 0xe7, 0xad, 0x35, 0x85, 0xe2, 0xf9, 0x37, 0xe8, 0x1c, 0x75, 0xdf, 0x6e,
+    0x47, 0xf1, 0x1a, 0x71, 0x1d, 0x29, 0xc5, 0x89, 0x6f, 0xb7, 0x62, 0x0e, 0xaa, 0x18, 0xbe, 0x1b,
+    0xfc, 0x56, 0x3e, 0x4b, 0xc6, 0xd2, 0x79, 0x20, 0x9a, 0xdb, 0xc0, 0xfe, 0x78, 0xcd, 0x5a, 0xf4,
+    0x1f, 0xdd, 0xa8, 0x33, 0x88, 0x07, 0xc7, 0x31, 0xb1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xec, 0x5f,
+    0x60, 0x51, 0x7f, 0xa9, 0x19, 0xb5, 0x4a, 0x0d, 0x2d, 0xe5, 0x7a, 0x9f, 0x93, 0xc9, 0x9c, 0xef,
+    0xa0, 0xe0, 0x3b, 0x4d, 0xae, 0x2a, 0xf5, 0xb0, 0xc8, 0xeb, 0xbb, 0x3c, 0x83, 0x53, 0x99, 0x61,
+    0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0c, 0x7d,
+];
+
+/// GB/T 32907-2016 S-box.
+const SM4_SBOX: [u8; 256] = [
+    0xd6, 0x90, 0xe9, 0xfe, 0xcc, 0xe1, 0x3d, 0xb7, 0x16, 0xb6, 0x14, 0xc2, 0x28, 0xfb, 0x2c, 0x05,
+    0x2b, 0x67, 0x9a, 0x76, 0x2a, 0xbe, 0x04, 0xc3, 0xaa, 0x44, 0x13, 0x26, 0x49, 0x86, 0x06, 0x99,
+    0x9c, 0x42, 0x50, 0xf4, 0x91, 0xef, 0x98, 0x7a, 0x33, 0x54, 0x0b, 0x43, 0xed, 0xcf, 0xac, 0x62,
+    0xe4, 0xb3, 0x1c, 0xa9, 0xc9, 0x08, 0xe8, 0x95, 0x80, 0xdf, 0x94, 0xfa, 0x75, 0x8f, 0x3f, 0xa6,
+    0x47, 0x07, 0xa7, 0xfc, 0xf3, 0x73, 0x17, 0xba, 0x83, 0x59, 0x3c, 0x19, 0xe6, 0x85, 0x4f, 0xa8,
+    0x68, 0x6b, 0x81, 0xb2, 0x71, 0x64, 0xda, 0x8b, 0xf8, 0xeb, 0x0f, 0x4b, 0x70, 0x56, 0x9d, 0x35,
+    0x1e, 0x24, 0x0e, 0x5e, 0x63, 0x58, 0xd1, 0xa2, 0x25, 0x22, 0x7c, 0x3b, 0x01, 0x21, 0x78, 0x87,
+    0xd4, 0x00, 0x46, 0x57, 0x9f, 0xd3, 0x27, 0x52, 0x4c, 0x36, 0x02, 0xe7, 0xa0, 0xc4, 0xc8, 0x9e,
+    0xea, 0xbf, 0x8a, 0xd2, 0x40, 0xc7, 0x38, 0xb5, 0xa3, 0xf7, 0xf2, 0xce, 0xf9, 0x61, 0x15, 0xa1,
+    0xe0, 0xae, 0x5d, 0xa4, 0x9b, 0x34, 0x1a, 0x55, 0xad, 0x93, 0x32, 0x30, 0xf5, 0x8c, 0xb1, 0xe3,
+    0x1d, 0xf6, 0xe2, 0x2e, 0x82, 0x66, 0xca, 0x60, 0xc0, 0x29, 0x23, 0xab, 0x0d, 0x53, 0x4e, 0x6f,
+    0xd5, 0xdb, 0x37, 0x45, 0xde, 0xfd, 0x8e, 0x2f, 0x03, 0xff, 0x6a, 0x72, 0x6d, 0x6c, 0x5b, 0x51,
+    0x8d, 0x1b, 0xaf, 0x92, 0xbb, 0xdd, 0xbc, 0x7f, 0x11, 0xd9, 0x5c, 0x41, 0x1f, 0x10, 0x5a, 0xd8,
+    0x0a, 0xc1, 0x31, 0x88, 0xa5, 0xcd, 0x7b, 0xbd, 0x2d, 0x74, 0xd0, 0x12, 0xb8, 0xe5, 0xb4, 0xb0,
+    0x89, 0x69, 0x97, 0x4a, 0x0c, 0x96, 0x77, 0x7e, 0x65, 0xb9, 0xf1, 0x09, 0xc5, 0x6e, 0xc6, 0x84,
+    0x18, 0xf0, 0x7d, 0xec, 0x3a, 0xdc, 0x4d, 0x20, 0x79, 0xee, 0x5f, 0x3e, 0xd7, 0xcb, 0x39, 0x48,
+];
+
+/// Round constants for [`aes64_ks1i`], indexed by round number. Round 10 is
+/// the extra step of the AES-256 schedule that applies no constant.
+const AES_RCON: [u8; 11] = [
+    0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36, 0x00,
+];
+
+/// Multiplies in GF(2^8) modulo the AES polynomial x^8 + x^4 + x^3 + x + 1.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut acc = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            acc ^= a;
+        }
+        a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 };
+        b >>= 1;
+    }
+    acc
+}
+
+/// Applies the circulant matrix whose first row is `coeffs` to one column.
+fn mix_column(col: u32, coeffs: [u8; 4]) -> u32 {
+    let bytes = col.to_le_bytes();
+    let mut out = [0u8; 4];
+    for (r, out) in out.iter_mut().enumerate() {
+        for (c, &byte) in bytes.iter().enumerate() {
+            *out ^= gf_mul(coeffs[(c + 4 - r) % 4], byte);
+        }
+    }
+    u32::from_le_bytes(out)
+}
+
+/// MixColumns, or its inverse, on both columns of a register.
+fn mix_columns(val: u64, coeffs: [u8; 4]) -> u64 {
+    let lo = mix_column(val as u32, coeffs);
+    let hi = mix_column((val >> 32) as u32, coeffs);
+    (u64::from(hi) << 32) | u64::from(lo)
+}
+
+fn sub_bytes(val: u64, sbox: &[u8; 256]) -> u64 {
+    u64::from_le_bytes(val.to_le_bytes().map(|b| sbox[usize::from(b)]))
+}
+
+/// The two columns of ShiftRows, or of InvShiftRows, that land in `rs1`.
+fn shift_rows(rs1: u64, rs2: u64, inverse: bool) -> u64 {
+    let mut state = [0u8; 16];
+    state[..8].copy_from_slice(&rs1.to_le_bytes());
+    state[8..].copy_from_slice(&rs2.to_le_bytes());
+
+    let mut out = [0u8; 8];
+    for (i, out) in out.iter_mut().enumerate() {
+        let (c, r) = (i / 4, i % 4);
+        let src = if inverse {
+            (c + 4 - r) % 4
+        } else {
+            (c + r) % 4
+        };
+        *out = state[4 * src + r];
+    }
+    u64::from_le_bytes(out)
+}
+
+const MIX_FWD: [u8; 4] = [0x02, 0x03, 0x01, 0x01];
+const MIX_INV: [u8; 4] = [0x0e, 0x0b, 0x0d, 0x09];
+
+/// ShiftRows and SubBytes, as in the final encryption round.
+pub fn aes64_es(rs1: u64, rs2: u64) -> u64 {
+    sub_bytes(shift_rows(rs1, rs2, false), &AES_SBOX)
+}
+
+/// ShiftRows, SubBytes and MixColumns, as in a middle encryption round.
+pub fn aes64_esm(rs1: u64, rs2: u64) -> u64 {
+    mix_columns(aes64_es(rs1, rs2), MIX_FWD)
+}
+
+/// InvShiftRows and InvSubBytes, as in the final decryption round.
+pub fn aes64_ds(rs1: u64, rs2: u64) -> u64 {
+    sub_bytes(shift_rows(rs1, rs2, true), &AES_INV_SBOX)
+}
+
+/// InvShiftRows, InvSubBytes and InvMixColumns, as in a middle round of the
+/// equivalent inverse cipher.
+pub fn aes64_dsm(rs1: u64, rs2: u64) -> u64 {
+    mix_columns(aes64_ds(rs1, rs2), MIX_INV)
+}
+
+/// InvMixColumns, which turns encryption round keys into decryption ones.
+pub fn aes64_im(rs1: u64) -> u64 {
+    mix_columns(rs1, MIX_INV)
+}
+
+/// RotWord, SubWord and the round constant applied to the high word of
+/// `rs1`, replicated into both halves. `rnum` must be at most 10.
+pub fn aes64_ks1i(rs1: u64, rnum: u32) -> u64 {
+    let mut word = (rs1 >> 32) as u32;
+    if rnum != 10 {
+        word = word.rotate_right(8);
+    }
+    let word = u32::from_le_bytes(word.to_le_bytes().map(|b| AES_SBOX[usize::from(b)]))
+        ^ u32::from(AES_RCON[rnum as usize]);
+    (u64::from(word) << 32) | u64::from(word)
+}
+
+/// Chains the next two round key words from `rs1` and the previous ones.
+pub fn aes64_ks2(rs1: u64, rs2: u64) -> u64 {
+    let w0 = (rs1 >> 32) as u32 ^ rs2 as u32;
+    let w1 = w0 ^ (rs2 >> 32) as u32;
+    (u64::from(w1) << 32) | u64::from(w0)
+}
+
+/// One byte lane of the SM4 round function T, XORed into `rs1`.
+pub fn sm4_ed(rs1: u64, rs2: u64, bs: u32) -> u64 {
+    let x = u32::from(SM4_SBOX[((rs2 >> (8 * bs)) & 0xff) as usize]);
+    let y = x ^ (x << 2) ^ (x << 10) ^ (x << 18) ^ (x << 24);
+    (y.rotate_left(8 * bs) ^ rs1 as u32) as i32 as i64 as u64
+}
+
+/// One byte lane of the SM4 key schedule function T', XORed into `rs1`.
+pub fn sm4_ks(rs1: u64, rs2: u64, bs: u32) -> u64 {
+    let x = u32::from(SM4_SBOX[((rs2 >> (8 * bs)) & 0xff) as usize]);
+    let y = x ^ (x << 13) ^ (x << 23);
+    (y.rotate_left(8 * bs) ^ rs1 as u32) as i32 as i64 as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn halves(bytes: [u8; 16]) -> (u64, u64) {
+        let lo = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        let hi = u64::from_le_bytes(bytes[8..].try_into().unwrap());
+        (lo, hi)
+    }
+
+    fn join(lo: u64, hi: u64) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&lo.to_le_bytes());
+        bytes[8..].copy_from_slice(&hi.to_le_bytes());
+        bytes
+    }
+
+    fn hex(s: &str) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    fn aes128_round_keys(key: [u8; 16]) -> Vec<(u64, u64)> {
+        let (mut k0, mut k1) = halves(key);
+        let mut keys = vec![(k0, k1)];
+        for rnum in 0..10 {
+            let t = aes64_ks1i(k1, rnum);
+            k0 = aes64_ks2(t, k0);
+            k1 = aes64_ks2(k0, k1);
+            keys.push((k0, k1));
+        }
+        keys
+    }
+
+    fn aes_encrypt(keys: &[(u64, u64)], block: [u8; 16]) -> [u8; 16] {
+        let (mut s0, mut s1) = halves(block);
+        s0 ^= keys[0].0;
+        s1 ^= keys[0].1;
+        let last = keys.len() - 1;
+        for &(k0, k1) in &keys[1..last] {
+            (s0, s1) = (aes64_esm(s0, s1) ^ k0, aes64_esm(s1, s0) ^ k1);
+        }
+        let (k0, k1) = keys[last];
+        join(aes64_es(s0, s1) ^ k0, aes64_es(s1, s0) ^ k1)
+    }
+
+    fn aes_decrypt(keys: &[(u64, u64)], block: [u8; 16]) -> [u8; 16] {
+        let (mut s0, mut s1) = halves(block);
+        let last = keys.len() - 1;
+        s0 ^= keys[last].0;
+        s1 ^= keys[last].1;
+        for &(k0, k1) in keys[1..last].iter().rev() {
+            (s0, s1) = (
+                aes64_dsm(s0, s1) ^ aes64_im(k0),
+                aes64_dsm(s1, s0) ^ aes64_im(k1),
+            );
+        }
+        let (k0, k1) = keys[0];
+        join(aes64_ds(s0, s1) ^ k0, aes64_ds(s1, s0) ^ k1)
+    }
+
+    #[test]
+    fn aes_sboxes_are_inverses() {
+        for i in 0..=255u8 {
+            assert_eq!(AES_INV_SBOX[usize::from(AES_SBOX[usize::from(i)])], i);
+        }
+    }
+
+    #[test]
+    fn aes_key_expansion_fips197_a1() {
+        let keys = aes128_round_keys(hex("2b7e151628aed2a6abf7158809cf4f3c"));
+
+        assert_eq!(
+            join(keys[1].0, keys[1].1),
+            hex("a0fafe1788542cb123a339392a6c7605")
+        );
+        assert_eq!(
+            join(keys[10].0, keys[10].1),
+            hex("d014f9a8c9ee2589e13f0cc8b6630ca6")
+        );
+    }
+
+    #[test]
+    fn aes128_fips197_c1() {
+        let keys = aes128_round_keys(hex("000102030405060708090a0b0c0d0e0f"));
+        let plain = hex("00112233445566778899aabbccddeeff");
+        let cipher = hex("69c4e0d86a7b0430d8cdb78070b4c55a");
+
+        assert_eq!(aes_encrypt(&keys, plain), cipher);
+        assert_eq!(aes_decrypt(&keys, cipher), plain);
+    }
+
+    #[test]
+    fn aes256_fips197_c3() {
+        let (k0, k1) = halves(hex("000102030405060708090a0b0c0d0e0f"));
+        let (k2, k3) = halves(hex("101112131415161718191a1b1c1d1e1f"));
+        let mut k = [k0, k1, k2, k3];
+        let mut keys = vec![(k[0], k[1]), (k[2], k[3])];
+        for rnum in 0..7 {
+            let t = aes64_ks1i(k[3], rnum);
+            k[0] = aes64_ks2(t, k[0]);
+            k[1] = aes64_ks2(k[0], k[1]);
+            keys.push((k[0], k[1]));
+            if rnum < 6 {
+                // The in-between step applies SubWord alone.
+                let t = aes64_ks1i(k[1], 10);
+                k[2] = aes64_ks2(t, k[2]);
+                k[3] = aes64_ks2(k[2], k[3]);
+                keys.push((k[2], k[3]));
+            }
+        }
+        let plain = hex("00112233445566778899aabbccddeeff");
+        let cipher = hex("8ea2b7ca516745bfeafc49904b496089");
+
+        assert_eq!(aes_encrypt(&keys, plain), cipher);
+        assert_eq!(aes_decrypt(&keys, cipher), plain);
+    }
+
+    /// The example in GB/T 32907-2016, appendix A.1.
+    #[test]
+    fn sm4_gbt32907_a1() {
+        const FK: [u32; 4] = [0xa3b1_bac6, 0x56aa_3350, 0x677d_9197, 0xb270_22dc];
+        let words = |bytes: [u8; 16]| -> [u32; 4] {
+            std::array::from_fn(|i| u32::from_be_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+        };
+        let t = |f: fn(u64, u64, u32) -> u64, x: u32, rs2: u32| -> u32 {
+            (0..4).fold(u64::from(x), |acc, bs| f(acc, u64::from(rs2), bs)) as u32
+        };
+
+        let key = hex("0123456789abcdeffedcba9876543210");
+        let mut k: Vec<u32> = words(key).iter().zip(FK).map(|(k, fk)| k ^ fk).collect();
+        for i in 0..32 {
+            let ck = u32::from_be_bytes(std::array::from_fn(|j| ((4 * i + j) * 7) as u8));
+            let next = t(sm4_ks, k[i], k[i + 1] ^ k[i + 2] ^ k[i + 3] ^ ck);
+            k.push(next);
+        }
+
+        let mut x = words(key).to_vec();
+        for i in 0..32 {
+            let next = t(sm4_ed, x[i], x[i + 1] ^ x[i + 2] ^ x[i + 3] ^ k[i + 4]);
+            x.push(next);
+        }
+        let cipher: Vec<u8> = x[32..].iter().rev().flat_map(|w| w.to_be_bytes()).collect();
+
+        assert_eq!(cipher, hex("681edf34d206965e86b3e94f536e4246"));
+    }
+}
@@ -0,0 +1,1326 @@
+use crate::processor::riscv::{
+    hart::Privilege,
+    mmu::{SATP_MODE_BARE, SATP_MODE_SV39, SATP_MODE_SV48, SATP_MODE_SV57},
+    pmp::{self, PMPADDR_MASK, Pmp},
+    vector::VTYPE_VILL,
+};
+
+pub const FFLAGS: u16 = 0x001;
+pub const FRM: u16 = 0x002;
+pub const FCSR: u16 = 0x003;
+pub const VSTART: u16 = 0x008;
+pub const VXSAT: u16 = 0x009;
+pub const VXRM: u16 = 0x00a;
+pub const VCSR: u16 = 0x00f;
+
+pub const CYCLE: u16 = 0xc00;
+pub const TIME: u16 = 0xc01;
+pub const INSTRET: u16 = 0xc02;
+pub const HPMCOUNTER3: u16 = 0xc03;
+pub const HPMCOUNTER31: u16 = 0xc1f;
+pub const CYCLEH: u16 = 0xc80;
+pub const INSTRETH: u16 = 0xc82;
+pub const HPMCOUNTER3H: u16 = 0xc83;
+pub const HPMCOUNTER31H: u16 = 0xc9f;
+pub const VL: u16 = 0xc20;
+pub const VTYPE: u16 = 0xc21;
+pub const VLENB: u16 = 0xc22;
+
+pub const SSTATUS: u16 = 0x100;
+pub const SIE: u16 = 0x104;
+pub const SCOUNTEREN: u16 = 0x106;
+pub const STVEC: u16 = 0x105;
+pub const SENVCFG: u16 = 0x10a;
+pub const SSCRATCH: u16 = 0x140;
+pub const SEPC: u16 = 0x141;
+pub const SCAUSE: u16 = 0x142;
+pub const STVAL: u16 = 0x143;
+pub const SIP: u16 = 0x144;
+pub const SATP: u16 = 0x180;
+
+pub const VSSTATUS: u16 = 0x200;
+pub const VSIE: u16 = 0x204;
+pub const VSTVEC: u16 = 0x205;
+pub const VSSCRATCH: u16 = 0x240;
+pub const VSEPC: u16 = 0x241;
+pub const VSCAUSE: u16 = 0x242;
+pub const VSTVAL: u16 = 0x243;
+pub const VSIP: u16 = 0x244;
+pub const VSATP: u16 = 0x280;
+
+pub const HSTATUS: u16 = 0x600;
+pub const HEDELEG: u16 = 0x602;
+pub const HIDELEG: u16 = 0x603;
+pub const HIE: u16 = 0x604;
+pub const HTIMEDELTA: u16 = 0x605;
+pub const HCOUNTEREN: u16 = 0x606;
+pub const HGEIE: u16 = 0x607;
+pub const HENVCFG: u16 = 0x60a;
+pub const HTVAL: u16 = 0x643;
+pub const HIP: u16 = 0x644;
+pub const HVIP: u16 = 0x645;
+pub const HTINST: u16 = 0x64a;
+pub const HGATP: u16 = 0x680;
+pub const HGEIP: u16 = 0xe12;
+
+pub const MVENDORID: u16 = 0xf11;
+pub const MARCHID: u16 = 0xf12;
+pub const MIMPID: u16 = 0xf13;
+pub const MHARTID: u16 = 0xf14;
+pub const MCONFIGPTR: u16 = 0xf15;
+
+pub const MSTATUS: u16 = 0x300;
+pub const MISA: u16 = 0x301;
+pub const MEDELEG: u16 = 0x302;
+pub const MIDELEG: u16 = 0x303;
+pub const MIE: u16 = 0x304;
+pub const MTVEC: u16 = 0x305;
+pub const MCOUNTEREN: u16 = 0x306;
+pub const MCOUNTINHIBIT: u16 = 0x320;
+pub const MHPMEVENT3: u16 = 0x323;
+pub const MHPMEVENT31: u16 = 0x33f;
+pub const MSCRATCH: u16 = 0x340;
+pub const MEPC: u16 = 0x341;
+pub const MCAUSE: u16 = 0x342;
+pub const MTVAL: u16 = 0x343;
+pub const MIP: u16 = 0x344;
+pub const MTINST: u16 = 0x34a;
+pub const MTVAL2: u16 = 0x34b;
+pub const PMPCFG0: u16 = 0x3a0;
+pub const PMPCFG15: u16 = 0x3af;
+pub const PMPADDR0: u16 = 0x3b0;
+pub const PMPADDR63: u16 = 0x3ef;
+pub const MENVCFG: u16 = 0x30a;
+pub const MSTATUSH: u16 = 0x310;
+pub const MEDELEGH: u16 = 0x312;
+pub const MENVCFGH: u16 = 0x31a;
+pub const MCYCLE: u16 = 0xb00;
+pub const MINSTRET: u16 = 0xb02;
+pub const MHPMCOUNTER3: u16 = 0xb03;
+pub const MHPMCOUNTER31: u16 = 0xb1f;
+pub const MCYCLEH: u16 = 0xb80;
+pub const MINSTRETH: u16 = 0xb82;
+pub const MHPMCOUNTER3H: u16 = 0xb83;
+pub const MHPMCOUNTER31H: u16 = 0xb9f;
+
+pub const MSTATUS_SIE: u64 = 1 << 1;
+pub const MSTATUS_MIE: u64 = 1 << 3;
+pub const MSTATUS_SPIE: u64 = 1 << 5;
+pub const MSTATUS_MPIE: u64 = 1 << 7;
+pub const MSTATUS_SPP: u64 = 1 << 8;
+pub const MSTATUS_VS: u64 = 0b11 << 9;
+pub const MSTATUS_MPP: u64 = 0b11 << 11;
+pub const MSTATUS_FS: u64 = 0b11 << 13;
+pub const MSTATUS_XS: u64 = 0b11 << 15;
+pub const MSTATUS_MPRV: u64 = 1 << 17;
+pub const MSTATUS_SUM: u64 = 1 << 18;
+pub const MSTATUS_MXR: u64 = 1 << 19;
+pub const MSTATUS_TVM: u64 = 1 << 20;
+pub const MSTATUS_TW: u64 = 1 << 21;
+pub const MSTATUS_TSR: u64 = 1 << 22;
+pub const MSTATUS_UXL: u64 = 0b11 << 32;
+pub const MSTATUS_SXL: u64 = 0b11 << 34;
+pub const MSTATUS_GVA: u64 = 1 << 38;
+pub const MSTATUS_MPV: u64 = 1 << 39;
+pub const MSTATUS_SD: u64 = 1 << 63;
+
+pub const SSTATUS_MASK: u64 = MSTATUS_SIE
+    | MSTATUS_SPIE
+    | MSTATUS_SPP
+    | MSTATUS_VS
+    | MSTATUS_FS
+    | MSTATUS_XS
+    | MSTATUS_SUM
+    | MSTATUS_MXR
+    | MSTATUS_UXL
+    | MSTATUS_SD;
+
+pub const MSTATUS_FS_OFF: u64 = 0;
+pub const MSTATUS_FS_INITIAL: u64 = 1 << 13;
+pub const MSTATUS_FS_CLEAN: u64 = 2 << 13;
+pub const MSTATUS_FS_DIRTY: u64 = 3 << 13;
+
+pub const MSTATUS_VS_OFF: u64 = 0;
+pub const MSTATUS_VS_INITIAL: u64 = 1 << 9;
+pub const MSTATUS_VS_CLEAN: u64 = 2 << 9;
+pub const MSTATUS_VS_DIRTY: u64 = 3 << 9;
+
+pub const MSTATUS_UXL_64: u64 = 2 << 32;
+pub const MSTATUS_SXL_64: u64 = 2 << 34;
+
+pub const MISA_MXL_32: u64 = 1 << 30;
+pub const MISA_MXL_64: u64 = 2 << 62;
+
+pub const HSTATUS_VSBE: u64 = 1 << 5;
+pub const HSTATUS_GVA: u64 = 1 << 6;
+pub const HSTATUS_SPV: u64 = 1 << 7;
+pub const HSTATUS_SPVP: u64 = 1 << 8;
+pub const HSTATUS_HU: u64 = 1 << 9;
+pub const HSTATUS_VGEIN: u64 = 0x3f << 12;
+pub const HSTATUS_VTVM: u64 = 1 << 20;
+pub const HSTATUS_VTW: u64 = 1 << 21;
+pub const HSTATUS_VTSR: u64 = 1 << 22;
+pub const HSTATUS_VSXL: u64 = 0b11 << 32;
+pub const HSTATUS_VSXL_64: u64 = 2 << 32;
+
+pub const HGATP_MODE_BARE: u64 = 0;
+pub const HGATP_MODE_SV39X4: u64 = 8;
+pub const HGATP_MODE_SV48X4: u64 = 9;
+pub const HGATP_MODE_SV57X4: u64 = 10;
+/// VMID field of `hgatp`; all 14 bits are implemented.
+pub const HGATP_VMID: u64 = 0x3fff << 44;
+/// Root page-table PPN in `hgatp`. The x4 root tables are 16 KiB aligned, so
+/// the low two bits are read-only zero.
+pub const HGATP_PPN: u64 = ((1 << 44) - 1) & !0b11;
+
+/// Cache-block invalidate enable in `menvcfg`/`senvcfg`; zero makes CBO.INVAL
+/// illegal below M-mode.
+pub const ENVCFG_CBIE: u64 = 0b11 << 4;
+/// Cache-block clean and flush enable.
+pub const ENVCFG_CBCFE: u64 = 1 << 6;
+/// Cache-block zero enable.
+pub const ENVCFG_CBZE: u64 = 1 << 7;
+
+pub const COUNTER_CY: u64 = 1 << 0;
+pub const COUNTER_TM: u64 = 1 << 1;
+pub const COUNTER_IR: u64 = 1 << 2;
+
+pub const MIP_SSIP: u64 = 1 << 1;
+pub const MIP_VSSIP: u64 = 1 << 2;
+pub const MIP_MSIP: u64 = 1 << 3;
+pub const MIP_STIP: u64 = 1 << 5;
+pub const MIP_VSTIP: u64 = 1 << 6;
+pub const MIP_MTIP: u64 = 1 << 7;
+pub const MIP_SEIP: u64 = 1 << 9;
+pub const MIP_VSEIP: u64 = 1 << 10;
+pub const MIP_MEIP: u64 = 1 << 11;
+pub const MIP_SGEIP: u64 = 1 << 12;
+
+/// The software, timer and external interrupts of M- and S-mode.
+pub const MIP_MASK: u64 = MIP_SSIP | MIP_MSIP | MIP_STIP | MIP_MTIP | MIP_SEIP | MIP_MEIP;
+
+/// Exceptions that may be delegated to S-mode: everything but M-mode ECALL and
+/// the causes of extensions that are not implemented.
+pub const MEDELEG_MASK: u64 = 0xb3ff;
+/// Interrupts that may be delegated to S-mode: SSI, STI and SEI.
+pub const MIDELEG_MASK: u64 = 0x222;
+/// Exceptions the H extension adds to `medeleg`: VS-mode ECALL, the
+/// guest-page faults and virtual instruction.
+pub const MEDELEG_MASK_H: u64 = (1 << 10) | (0xf << 20);
+/// VS-level interrupts (VSSI, VSTI, VSEI) and SGEI, which the H extension
+/// always delegates past M-mode.
+pub const MIDELEG_H: u64 = 0x1444;
+/// Exceptions that may be further delegated to VS-mode: those of `medeleg`
+/// but the ECALLs from S, VS and M mode and the causes only HS-mode can
+/// handle.
+pub const HEDELEG_MASK: u64 = 0xb1ff;
+/// Interrupts that may be further delegated to VS-mode: VSSI, VSTI and VSEI.
+pub const HIDELEG_MASK: u64 = 0x444;
+
+/// Returns the `misa` bit for an extension letter.
+pub const fn misa_ext(letter: u8) -> u64 {
+    1 << (letter - b'A')
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CsrSpec {
+    /// Register that actually holds the state, so that views such as `cycle`
+    /// share storage with the CSR they shadow.
+    reg: u16,
+    /// Position of the view within `reg`, for views of a field such as `frm`.
+    shift: u32,
+    /// Bits that read back; everything else reads as zero.
+    rmask: u64,
+    /// Bits a CSR instruction may change; everything else keeps its value.
+    wmask: u64,
+}
+
+#[derive(Debug)]
+pub struct CsrFile {
+    regs: Box<[u64; 4096]>,
+    specs: Box<[Option<CsrSpec>; 4096]>,
+    /// Width of every CSR, which places `mstatus.SD` in the top bit.
+    xlen: u32,
+    /// Interrupt lines that devices drive. They read as set in `mip` on top
+    /// of what software wrote there, which is how `mip.SEIP` works.
+    lines: u64,
+}
+
+impl CsrFile {
+    pub fn new(hartid: u64) -> Self {
+        Self::with_pmp_entries(hartid, Pmp::DEFAULT_ENTRIES)
+    }
+
+    /// Creates the CSR file with `pmp_entries` implemented PMP entries; the
+    /// remaining `pmpaddr` registers and `pmpcfg` bytes are read-only zero.
+    pub fn with_pmp_entries(hartid: u64, pmp_entries: usize) -> Self {
+        let mut csrs = Self {
+            regs: Box::new([0u64; 4096]),
+            specs: Box::new([None; 4096]),
+            xlen: 64,
+            lines: 0,
+        };
+
+        csrs.define(MVENDORID, u64::MAX, 0);
+        csrs.define(MARCHID, u64::MAX, 0);
+        csrs.define(MIMPID, u64::MAX, 0);
+        csrs.define(MHARTID, u64::MAX, 0);
+        csrs.define(MCONFIGPTR, u64::MAX, 0);
+
+        csrs.define(
+            MSTATUS,
+            u64::MAX,
+            MSTATUS_SIE
+                | MSTATUS_MIE
+                | MSTATUS_SPIE
+                | MSTATUS_MPIE
+                | MSTATUS_SPP
+                | MSTATUS_MPP
+                | MSTATUS_FS
+                | MSTATUS_MPRV
+                | MSTATUS_SUM
+                | MSTATUS_MXR
+                | MSTATUS_TVM
+                | MSTATUS_TW
+                | MSTATUS_TSR,
+        );
+        csrs.define(MISA, u64::MAX, 0);
+        csrs.define(MEDELEG, u64::MAX, MEDELEG_MASK);
+        csrs.define(MIDELEG, u64::MAX, MIDELEG_MASK);
+        csrs.define(MENVCFG, u64::MAX, 0);
+        csrs.define(MIE, MIP_MASK, MIP_MASK);
+        // The M-level bits follow the interrupt sources; M-mode software may
+        // only raise or clear the S-level ones.
+        csrs.define(MIP, MIP_MASK, MIP_SSIP | MIP_STIP | MIP_SEIP);
+        csrs.define(MTVEC, u64::MAX, u64::MAX);
+        csrs.define(MCOUNTEREN, u64::MAX, COUNTER_CY | COUNTER_TM | COUNTER_IR);
+        csrs.define(MCOUNTINHIBIT, u64::MAX, COUNTER_CY | COUNTER_IR);
+        csrs.define(MSCRATCH, u64::MAX, u64::MAX);
+        csrs.define(MEPC, u64::MAX, u64::MAX);
+        csrs.define(MCAUSE, u64::MAX, u64::MAX);
+        csrs.define(MTVAL, u64::MAX, u64::MAX);
+        for i in 0..Pmp::MAX_ENTRIES as u16 {
+            let implemented = usize::from(i) < pmp_entries;
+            let mask = if implemented { PMPADDR_MASK } else { 0 };
+            csrs.define(PMPADDR0 + i, mask, mask);
+        }
+        for i in (0..=PMPCFG15 - PMPCFG0).step_by(2) {
+            let implemented = pmp_entries.saturating_sub(usize::from(i) * 4).min(8);
+            let mask = u64::MAX
+                .checked_shr(64 - 8 * implemented as u32)
+                .unwrap_or(0);
+            csrs.define(PMPCFG0 + i, mask, mask);
+        }
+
+        csrs.alias(
+            SSTATUS,
+            MSTATUS,
+            SSTATUS_MASK,
+            MSTATUS_SIE | MSTATUS_SPIE | MSTATUS_SPP | MSTATUS_FS | MSTATUS_SUM | MSTATUS_MXR,
+        );
+        // Only delegated interrupts show up in `sie` and `sip`, see
+        // [`CsrFile::accessible`].
+        csrs.alias(SIE, MIE, MIDELEG_MASK, MIDELEG_MASK);
+        csrs.alias(SIP, MIP, MIDELEG_MASK, MIP_SSIP);
+        csrs.define(STVEC, u64::MAX, u64::MAX);
+        csrs.define(SCOUNTEREN, u64::MAX, COUNTER_CY | COUNTER_TM | COUNTER_IR);
+        csrs.define(SENVCFG, u64::MAX, 0);
+        csrs.define(SSCRATCH, u64::MAX, u64::MAX);
+        csrs.define(SEPC, u64::MAX, u64::MAX);
+        csrs.define(SCAUSE, u64::MAX, u64::MAX);
+        csrs.define(STVAL, u64::MAX, u64::MAX);
+        csrs.define(SATP, u64::MAX, u64::MAX);
+
+        csrs.define(FCSR, 0xff, 0xff);
+        csrs.field(FFLAGS, FCSR, 0, 0x1f);
+        csrs.field(FRM, FCSR, 5, 0x7);
+
+        csrs.define(MCYCLE, u64::MAX, u64::MAX);
+        csrs.define(MINSTRET, u64::MAX, u64::MAX);
+        csrs.alias(CYCLE, MCYCLE, u64::MAX, 0);
+        // The platform timer keeps `time` in step with its `mtime`.
+        csrs.define(TIME, u64::MAX, 0);
+        csrs.alias(INSTRET, MINSTRET, u64::MAX, 0);
+        for i in 0..=(MHPMCOUNTER31 - MHPMCOUNTER3) {
+            csrs.define(MHPMCOUNTER3 + i, 0, 0);
+            csrs.define(MHPMEVENT3 + i, 0, 0);
+            csrs.alias(HPMCOUNTER3 + i, MHPMCOUNTER3 + i, 0, 0);
+        }
+
+        csrs.set(MHARTID, hartid);
+        csrs.set(MSTATUS, MSTATUS_MPP | MSTATUS_UXL_64 | MSTATUS_SXL_64);
+        csrs.set(
+            MISA,
+            MISA_MXL_64
+                | misa_ext(b'I')
+                | misa_ext(b'M')
+                | misa_ext(b'A')
+                | misa_ext(b'F')
+                | misa_ext(b'D')
+                | misa_ext(b'C')
+                | misa_ext(b'S')
+                | misa_ext(b'U'),
+        );
+
+        csrs
+    }
+
+    /// Defines the vector CSRs of a `vlen`-bit vector unit, makes
+    /// `mstatus.VS` writable and reports V in `misa`. `vtype` starts out
+    /// with `vill` set and `vl` at zero.
+    pub fn enable_vector(&mut self, vlen: u32) {
+        let vstart = u64::from(vlen - 1);
+        self.define(VSTART, vstart, vstart);
+        self.define(VCSR, 0b111, 0b111);
+        self.field(VXSAT, VCSR, 0, 0b1);
+        self.field(VXRM, VCSR, 1, 0b11);
+        self.define(VL, u64::MAX, 0);
+        self.define(VTYPE, u64::MAX, 0);
+        self.define(VLENB, u64::MAX, 0);
+
+        for addr in [MSTATUS, SSTATUS] {
+            if let Some(spec) = &mut self.specs[addr as usize] {
+                spec.wmask |= MSTATUS_VS;
+            }
+        }
+
+        self.set(VTYPE, VTYPE_VILL);
+        self.set(VLENB, u64::from(vlen / 8));
+        self.set(MISA, self.get(MISA) | misa_ext(b'V'));
+    }
+
+    /// Defines the hypervisor and VS CSRs, makes `mstatus.MPV`/`GVA` writable,
+    /// lets `medeleg` delegate the H exceptions and reports H in `misa`.
+    /// Defining the VS CSRs after [`CsrFile::enable_vector`] has run keeps
+    /// `vsstatus.VS` writable along with `sstatus.VS`.
+    pub fn enable_hypervisor(&mut self) {
+        let sstatus_wmask = self.specs[SSTATUS as usize].map_or(0, |spec| spec.wmask);
+        self.define(VSSTATUS, SSTATUS_MASK, sstatus_wmask);
+        self.specs[VSIE as usize] = Some(CsrSpec {
+            reg: MIE,
+            shift: 1,
+            rmask: HIDELEG_MASK >> 1,
+            wmask: HIDELEG_MASK >> 1,
+        });
+        self.define(VSTVEC, u64::MAX, u64::MAX);
+        self.define(VSSCRATCH, u64::MAX, u64::MAX);
+        self.define(VSEPC, u64::MAX, u64::MAX);
+        self.define(VSCAUSE, u64::MAX, u64::MAX);
+        self.define(VSTVAL, u64::MAX, u64::MAX);
+        // Only VSSIP is software-writable; all three bits live in `hvip`
+        // while there is no other source of VS-level interrupts.
+        self.specs[VSIP as usize] = Some(CsrSpec {
+            reg: HVIP,
+            shift: 1,
+            rmask: HIDELEG_MASK >> 1,
+            wmask: 0b10,
+        });
+        self.define(VSATP, u64::MAX, u64::MAX);
+
+        self.define(
+            HSTATUS,
+            HSTATUS_VSBE
+                | HSTATUS_GVA
+                | HSTATUS_SPV
+                | HSTATUS_SPVP
+                | HSTATUS_HU
+                | HSTATUS_VGEIN
+                | HSTATUS_VTVM
+                | HSTATUS_VTW
+                | HSTATUS_VTSR
+                | HSTATUS_VSXL,
+            HSTATUS_GVA
+                | HSTATUS_SPV
+                | HSTATUS_SPVP
+                | HSTATUS_HU
+                | HSTATUS_VTVM
+                | HSTATUS_VTW
+                | HSTATUS_VTSR,
+        );
+        self.define(HEDELEG, u64::MAX, HEDELEG_MASK);
+        self.define(HIDELEG, u64::MAX, HIDELEG_MASK);
+        self.alias(HIE, MIE, MIDELEG_H, HIDELEG_MASK);
+        self.alias(HIP, HVIP, HIDELEG_MASK, 0b100);
+        self.define(HVIP, u64::MAX, HIDELEG_MASK);
+        self.define(HTIMEDELTA, u64::MAX, u64::MAX);
+        self.define(HCOUNTEREN, u64::MAX, COUNTER_CY | COUNTER_TM | COUNTER_IR);
+        // No guest external interrupt lines are implemented (GEILEN is zero).
+        self.define(HGEIE, u64::MAX, 0);
+        self.define(HGEIP, u64::MAX, 0);
+        self.define(HENVCFG, u64::MAX, 0);
+        self.define(HTVAL, u64::MAX, u64::MAX);
+        self.define(HTINST, u64::MAX, u64::MAX);
+        self.define(HGATP, u64::MAX, (0xf << 60) | HGATP_VMID | HGATP_PPN);
+        self.define(MTVAL2, u64::MAX, u64::MAX);
+        self.define(MTINST, u64::MAX, u64::MAX);
+
+        if let Some(spec) = &mut self.specs[MSTATUS as usize] {
+            spec.wmask |= MSTATUS_MPV | MSTATUS_GVA;
+        }
+        if let Some(spec) = &mut self.specs[MEDELEG as usize] {
+            spec.wmask |= MEDELEG_MASK_H;
+        }
+        if let Some(spec) = &mut self.specs[MIE as usize] {
+            spec.rmask |= MIDELEG_H;
+            spec.wmask |= MIDELEG_H;
+        }
+        if let Some(spec) = &mut self.specs[MIP as usize] {
+            spec.rmask |= MIDELEG_H;
+        }
+        self.set(MIDELEG, self.get(MIDELEG) | MIDELEG_H);
+        self.set(VSSTATUS, MSTATUS_UXL_64);
+        self.set(HSTATUS, HSTATUS_VSXL_64);
+        self.set(MISA, self.get(MISA) | misa_ext(b'H'));
+    }
+
+    /// Makes `bits` of `menvcfg`, `senvcfg` and, with the H extension,
+    /// `henvcfg` writable, for the extensions whose lower-privilege enables
+    /// live there.
+    pub fn enable_envcfg(&mut self, bits: u64) {
+        for addr in [MENVCFG, SENVCFG, HENVCFG] {
+            if let Some(spec) = &mut self.specs[addr as usize] {
+                spec.wmask |= bits;
+            }
+        }
+    }
+
+    /// Narrows every CSR to the 32 bits of RV32. The upper half of a 64-bit
+    /// CSR becomes a CSR of its own where RV32 has one, such as `mstatush`
+    /// or `cycleh`, and `misa.MXL` reports XLEN=32. Run it last, once every
+    /// other CSR is defined.
+    pub fn enable_rv32(&mut self) {
+        let mut halves = vec![
+            (MSTATUSH, MSTATUS),
+            (MEDELEGH, MEDELEG),
+            (MENVCFGH, MENVCFG),
+        ];
+        halves.extend((0..=HPMCOUNTER31 - CYCLE).map(|i| (CYCLEH + i, CYCLE + i)));
+        halves.extend((0..=MHPMCOUNTER31 - MCYCLE).map(|i| (MCYCLEH + i, MCYCLE + i)));
+        // Odd pmpcfg registers hold entries four to seven of the even one.
+        halves.extend(
+            (0..=PMPCFG15 - PMPCFG0)
+                .step_by(2)
+                .map(|i| (PMPCFG0 + i + 1, PMPCFG0 + i)),
+        );
+        let halves: Vec<_> = halves
+            .into_iter()
+            .filter_map(|(high, low)| {
+                let spec = self.specs[low as usize]?;
+                Some((
+                    high,
+                    CsrSpec {
+                        shift: spec.shift + 32,
+                        rmask: spec.rmask >> 32,
+                        wmask: spec.wmask >> 32,
+                        ..spec
+                    },
+                ))
+            })
+            .collect();
+
+        for spec in self.specs.iter_mut().flatten() {
+            spec.rmask &= u64::from(u32::MAX);
+            spec.wmask &= u64::from(u32::MAX);
+        }
+        for (high, spec) in halves {
+            self.specs[high as usize] = Some(spec);
+        }
+
+        self.set(MSTATUS, self.get(MSTATUS) & !(MSTATUS_UXL | MSTATUS_SXL));
+        self.set(MISA, (self.get(MISA) & !MISA_MXL_64) | MISA_MXL_32);
+        self.xlen = 32;
+    }
+
+    fn define(&mut self, addr: u16, rmask: u64, wmask: u64) {
+        self.alias(addr, addr, rmask, wmask);
+    }
+
+    fn alias(&mut self, addr: u16, reg: u16, rmask: u64, wmask: u64) {
+        self.specs[addr as usize] = Some(CsrSpec {
+            reg,
+            shift: 0,
+            rmask,
+            wmask,
+        });
+    }
+
+    /// Defines `addr` as a read-write view of the `mask` wide field of `reg`
+    /// starting at bit `shift`.
+    fn field(&mut self, addr: u16, reg: u16, shift: u32, mask: u64) {
+        self.specs[addr as usize] = Some(CsrSpec {
+            reg,
+            shift,
+            rmask: mask,
+            wmask: mask,
+        });
+    }
+
+    /// Returns the raw register contents, bypassing masks and privilege checks.
+    #[inline(always)]
+    pub fn get(&self, addr: u16) -> u64 {
+        self.regs[addr as usize & 0xfff]
+    }
+
+    /// Sets the raw register contents, bypassing masks and privilege checks.
+    #[inline(always)]
+    pub fn set(&mut self, addr: u16, val: u64) {
+        self.regs[addr as usize & 0xfff] = val;
+    }
+
+    /// Reads a CSR as a CSR instruction would, returning `None` when the access
+    /// must raise an illegal-instruction exception.
+    pub fn read(&self, addr: u16, privilege: Privilege) -> Option<u64> {
+        let spec = self.accessible(addr, privilege)?;
+        let reg = if spec.reg == MIP {
+            self.pending()
+        } else {
+            self.get(spec.reg)
+        };
+        let val = (reg >> spec.shift) & spec.rmask;
+
+        if matches!(spec.reg, MSTATUS | VSSTATUS) && spec.shift == 0 && Self::dirty(val) {
+            Some(val | (1 << (self.xlen - 1)))
+        } else {
+            Some(val)
+        }
+    }
+
+    /// Writes a CSR as a CSR instruction would, returning `None` when the access
+    /// must raise an illegal-instruction exception.
+    pub fn write(&mut self, addr: u16, val: u64, privilege: Privilege) -> Option<()> {
+        if addr >> 10 == 0b11 {
+            return None;
+        }
+        let spec = self.accessible(addr, privilege)?;
+
+        let wmask = spec.wmask << spec.shift;
+        let old = self.get(spec.reg);
+        let new = (old & !wmask) | ((val << spec.shift) & wmask);
+        let val = self.legalize(spec.reg, old, new);
+        self.set(spec.reg, val);
+        if spec.reg == FCSR {
+            self.set(MSTATUS, self.get(MSTATUS) | MSTATUS_FS_DIRTY);
+        }
+        if matches!(spec.reg, VSTART | VCSR) {
+            self.set(MSTATUS, self.get(MSTATUS) | MSTATUS_VS_DIRTY);
+        }
+
+        Some(())
+    }
+
+    /// Interrupts pending at the hart: `mip` and the interrupt lines, along
+    /// with the VS-level interrupts that `hvip` injects.
+    #[inline(always)]
+    pub fn pending(&self) -> u64 {
+        self.get(MIP) | self.lines | (self.get(HVIP) & HIDELEG_MASK)
+    }
+
+    /// Raises or lowers the interrupt lines in `mask`.
+    #[inline(always)]
+    pub fn set_lines(&mut self, mask: u64, level: bool) {
+        if level {
+            self.lines |= mask;
+        } else {
+            self.lines &= !mask;
+        }
+    }
+
+    /// Advances the free-running counters by one cycle, and `minstret` when an
+    /// instruction retired, unless inhibited through `mcountinhibit`.
+    #[inline(always)]
+    pub fn tick(&mut self, retired: bool) {
+        let inhibit = self.get(MCOUNTINHIBIT);
+        if inhibit & COUNTER_CY == 0 {
+            self.set(MCYCLE, self.get(MCYCLE).wrapping_add(1));
+        }
+        if retired && inhibit & COUNTER_IR == 0 {
+            self.set(MINSTRET, self.get(MINSTRET).wrapping_add(1));
+        }
+    }
+
+    fn accessible(&self, addr: u16, privilege: Privilege) -> Option<CsrSpec> {
+        let mut spec = (*self.specs.get(addr as usize)?)?;
+        // Hypervisor and VS CSRs belong to HS-mode.
+        let level = match (addr >> 8) & 0b11 {
+            0b10 => Privilege::Supervisor as u16,
+            level => level,
+        };
+        if (privilege as u16) < level {
+            return None;
+        }
+        if (FFLAGS..=FCSR).contains(&addr) && self.get(MSTATUS) & MSTATUS_FS == MSTATUS_FS_OFF {
+            return None;
+        }
+        if (matches!(addr, VSTART..=VCSR) || matches!(addr, VL..=VLENB))
+            && self.get(MSTATUS) & MSTATUS_VS == MSTATUS_VS_OFF
+        {
+            return None;
+        }
+        if matches!(addr, SATP | HGATP)
+            && privilege == Privilege::Supervisor
+            && self.get(MSTATUS) & MSTATUS_TVM != 0
+        {
+            return None;
+        }
+        // Interrupts that are not delegated read as zero and ignore writes in
+        // the supervisor and VS views of `mie` and `mip`.
+        let deleg = match addr {
+            SIE | SIP => self.get(MIDELEG),
+            VSIE | VSIP => self.get(HIDELEG) >> 1,
+            _ => u64::MAX,
+        };
+        spec.rmask &= deleg;
+        spec.wmask &= deleg;
+        if (CYCLE..=HPMCOUNTER31).contains(&addr) || (CYCLEH..=HPMCOUNTER31H).contains(&addr) {
+            let bit = 1 << ((addr - CYCLE) & 0x1f);
+            if privilege < Privilege::Machine && self.get(MCOUNTEREN) & bit == 0 {
+                return None;
+            }
+            if privilege < Privilege::Supervisor && self.get(SCOUNTEREN) & bit == 0 {
+                return None;
+            }
+        }
+        Some(spec)
+    }
+
+    const fn dirty(mstatus: u64) -> bool {
+        mstatus & MSTATUS_FS == MSTATUS_FS
+            || mstatus & MSTATUS_VS == MSTATUS_VS
+            || mstatus & MSTATUS_XS == MSTATUS_XS
+    }
+
+    /// Maps values written to WARL fields back onto legal ones.
+    fn legalize(&self, reg: u16, old: u64, new: u64) -> u64 {
+        match reg {
+            PMPCFG0..=PMPCFG15 => pmp::legalize_cfg(old, new),
+            PMPADDR0..=PMPADDR63 if pmp::addr_locked(self, usize::from(reg - PMPADDR0)) => old,
+            MSTATUS if new & MSTATUS_MPP == 2 << 11 => (new & !MSTATUS_MPP) | (old & MSTATUS_MPP),
+            MTVEC | STVEC | VSTVEC if new & 0b11 >= 2 => (new & !0b11) | (old & 0b11),
+            MEPC | SEPC | VSEPC if self.get(MISA) & misa_ext(b'C') != 0 => new & !0b1,
+            MEPC | SEPC | VSEPC => new & !0b11,
+            SATP | VSATP
+                if !matches!(
+                    new >> 60,
+                    SATP_MODE_BARE | SATP_MODE_SV39 | SATP_MODE_SV48 | SATP_MODE_SV57
+                ) =>
+            {
+                old
+            }
+            HGATP
+                if !matches!(
+                    new >> 60,
+                    HGATP_MODE_BARE | HGATP_MODE_SV39X4 | HGATP_MODE_SV48X4 | HGATP_MODE_SV57X4
+                ) =>
+            {
+                old
+            }
+            _ => new,
+        }
+    }
+}
+
+impl Default for CsrFile {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csr_read_only_machine_info() {
+        let csrs = CsrFile::new(3);
+
+        assert_eq!(csrs.read(MHARTID, Privilege::Machine), Some(3));
+        assert_eq!(csrs.read(MVENDORID, Privilege::Machine), Some(0));
+    }
+
+    #[test]
+    fn csr_write_to_read_only_is_illegal() {
+        let mut csrs = CsrFile::new(0);
+
+        assert_eq!(csrs.write(MHARTID, 1, Privilege::Machine), None);
+        assert_eq!(csrs.write(CYCLE, 1, Privilege::Machine), None);
+        assert_eq!(csrs.get(MHARTID), 0);
+    }
+
+    #[test]
+    fn csr_unimplemented_is_illegal() {
+        let mut csrs = CsrFile::new(0);
+
+        assert_eq!(csrs.read(0x7c0, Privilege::Machine), None);
+        assert_eq!(csrs.write(0x7c0, 1, Privilege::Machine), None);
+    }
+
+    #[test]
+    fn csr_misa_reports_extensions() {
+        let csrs = CsrFile::new(0);
+        let misa = csrs.read(MISA, Privilege::Machine).unwrap();
+
+        assert_eq!(misa >> 62, 2);
+        assert_ne!(misa & misa_ext(b'I'), 0);
+        assert_ne!(misa & misa_ext(b'M'), 0);
+        assert_ne!(misa & misa_ext(b'A'), 0);
+        assert_ne!(misa & misa_ext(b'F'), 0);
+        assert_ne!(misa & misa_ext(b'D'), 0);
+        assert_ne!(misa & misa_ext(b'C'), 0);
+        assert_ne!(misa & misa_ext(b'S'), 0);
+        assert_ne!(misa & misa_ext(b'U'), 0);
+    }
+
+    #[test]
+    fn csr_misa_writes_ignored() {
+        let mut csrs = CsrFile::new(0);
+        let misa = csrs.get(MISA);
+
+        assert_eq!(csrs.write(MISA, 0, Privilege::Machine), Some(()));
+        assert_eq!(csrs.get(MISA), misa);
+    }
+
+    #[test]
+    fn csr_mstatus_write_mask() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MSTATUS, 0, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MSTATUS), MSTATUS_UXL_64 | MSTATUS_SXL_64);
+
+        csrs.write(MSTATUS, u64::MAX, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_FS, MSTATUS_FS_DIRTY);
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_SD, 0);
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_TSR, MSTATUS_TSR);
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_MPP, MSTATUS_MPP);
+    }
+
+    #[test]
+    fn csr_mstatus_reserved_mpp_keeps_previous_mode() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MSTATUS, 1 << 11, Privilege::Machine).unwrap();
+        csrs.write(MSTATUS, 2 << 11, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_MPP, 1 << 11);
+    }
+
+    #[test]
+    fn csr_sstatus_is_restricted_view_of_mstatus() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(SSTATUS, u64::MAX, Privilege::Supervisor)
+            .unwrap();
+
+        let mstatus = csrs.get(MSTATUS);
+        assert_ne!(mstatus & MSTATUS_SIE, 0);
+        assert_ne!(mstatus & MSTATUS_SUM, 0);
+        assert_eq!(mstatus & MSTATUS_MIE, 0);
+        assert_eq!(mstatus & MSTATUS_TSR, 0);
+
+        csrs.set(MSTATUS, mstatus | MSTATUS_MIE | MSTATUS_MPP);
+        let sstatus = csrs.read(SSTATUS, Privilege::Supervisor).unwrap();
+        assert_eq!(sstatus & (MSTATUS_MIE | MSTATUS_MPP), 0);
+    }
+
+    #[test]
+    fn csr_medeleg_cannot_delegate_machine_ecall() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MEDELEG, u64::MAX, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MEDELEG), MEDELEG_MASK);
+        assert_eq!(csrs.get(MEDELEG) & (1 << 11), 0);
+    }
+
+    #[test]
+    fn csr_supervisor_csr_needs_supervisor_mode() {
+        let csrs = CsrFile::new(0);
+
+        assert_eq!(csrs.read(SSCRATCH, Privilege::User), None);
+        assert_eq!(csrs.read(SSCRATCH, Privilege::Supervisor), Some(0));
+        assert_eq!(csrs.read(SSCRATCH, Privilege::Machine), Some(0));
+    }
+
+    #[test]
+    fn csr_mstatus_sd_summarises_dirty_state() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.set(MSTATUS, MSTATUS_FS);
+
+        assert_eq!(
+            csrs.read(MSTATUS, Privilege::Machine),
+            Some(MSTATUS_FS | MSTATUS_SD)
+        );
+    }
+
+    #[test]
+    fn csr_fflags_and_frm_are_views_of_fcsr() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MSTATUS, MSTATUS_FS_INITIAL);
+
+        csrs.write(FCSR, 0xfff, Privilege::User).unwrap();
+        assert_eq!(csrs.get(FCSR), 0xff);
+
+        csrs.write(FRM, 0b010, Privilege::User).unwrap();
+        csrs.write(FFLAGS, 0b00001, Privilege::User).unwrap();
+
+        assert_eq!(csrs.read(FCSR, Privilege::User), Some(0b010_00001));
+        assert_eq!(csrs.read(FRM, Privilege::User), Some(0b010));
+        assert_eq!(csrs.read(FFLAGS, Privilege::User), Some(0b00001));
+    }
+
+    #[test]
+    fn csr_fcsr_write_marks_fs_dirty() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MSTATUS, MSTATUS_FS_CLEAN);
+
+        csrs.write(FFLAGS, 0, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_FS, MSTATUS_FS_DIRTY);
+    }
+
+    #[test]
+    fn csr_fcsr_illegal_when_fs_off() {
+        let mut csrs = CsrFile::new(0);
+
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_FS, MSTATUS_FS_OFF);
+        assert_eq!(csrs.read(FCSR, Privilege::Machine), None);
+        assert_eq!(csrs.write(FRM, 0, Privilege::Machine), None);
+    }
+
+    #[test]
+    fn csr_vector_csrs_need_vector_unit() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MSTATUS, MSTATUS_VS_INITIAL);
+
+        assert_eq!(csrs.read(VLENB, Privilege::User), None);
+        assert_eq!(csrs.get(MISA) & misa_ext(b'V'), 0);
+
+        csrs.enable_vector(256);
+
+        assert_eq!(csrs.read(VLENB, Privilege::User), Some(32));
+        assert_eq!(csrs.read(VTYPE, Privilege::User), Some(VTYPE_VILL));
+        assert_eq!(csrs.read(VL, Privilege::User), Some(0));
+        assert_ne!(csrs.get(MISA) & misa_ext(b'V'), 0);
+    }
+
+    #[test]
+    fn csr_vector_csrs_illegal_when_vs_off() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_vector(128);
+
+        assert_eq!(csrs.read(VL, Privilege::Machine), None);
+        assert_eq!(csrs.write(VSTART, 0, Privilege::Machine), None);
+
+        csrs.write(MSTATUS, MSTATUS_VS_INITIAL, Privilege::Machine)
+            .unwrap();
+
+        assert_eq!(csrs.read(VL, Privilege::Machine), Some(0));
+    }
+
+    #[test]
+    fn csr_vl_and_vtype_are_read_only() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_vector(128);
+        csrs.set(MSTATUS, MSTATUS_VS_INITIAL);
+
+        assert_eq!(csrs.write(VL, 1, Privilege::Machine), None);
+        assert_eq!(csrs.write(VTYPE, 0, Privilege::Machine), None);
+        assert_eq!(csrs.write(VLENB, 0, Privilege::Machine), None);
+    }
+
+    #[test]
+    fn csr_vxrm_and_vxsat_are_views_of_vcsr() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_vector(128);
+        csrs.set(MSTATUS, MSTATUS_VS_CLEAN);
+
+        csrs.write(VXRM, 0b10, Privilege::User).unwrap();
+        csrs.write(VXSAT, 1, Privilege::User).unwrap();
+
+        assert_eq!(csrs.read(VCSR, Privilege::User), Some(0b101));
+        assert_eq!(csrs.get(MSTATUS) & MSTATUS_VS, MSTATUS_VS_DIRTY);
+    }
+
+    #[test]
+    fn csr_vstart_holds_element_indices_below_vlen() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_vector(128);
+        csrs.set(MSTATUS, MSTATUS_VS_INITIAL);
+
+        csrs.write(VSTART, 0x1ff, Privilege::User).unwrap();
+
+        assert_eq!(csrs.get(VSTART), 0x7f);
+    }
+
+    #[test]
+    fn csr_mtvec_reserved_mode_keeps_previous_mode() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MTVEC, 0x1001, Privilege::Machine).unwrap();
+        csrs.write(MTVEC, 0x2002, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MTVEC), 0x2001);
+    }
+
+    #[test]
+    fn csr_mepc_is_aligned() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MEPC, 0x1003, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MEPC), 0x1002);
+    }
+
+    #[test]
+    fn csr_mepc_is_word_aligned_without_compressed() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MISA, csrs.get(MISA) & !misa_ext(b'C'));
+
+        csrs.write(MEPC, 0x1003, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MEPC), 0x1000);
+    }
+
+    #[test]
+    fn csr_machine_csr_needs_machine_mode() {
+        let mut csrs = CsrFile::new(0);
+
+        assert_eq!(csrs.read(MSCRATCH, Privilege::Supervisor), None);
+        assert_eq!(csrs.write(MSCRATCH, 1, Privilege::User), None);
+    }
+
+    #[test]
+    fn csr_satp_unsupported_mode_ignored() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(SATP, (8 << 60) | 0x1234, Privilege::Supervisor)
+            .unwrap();
+        csrs.write(SATP, (5 << 60) | 0x5678, Privilege::Supervisor)
+            .unwrap();
+
+        assert_eq!(csrs.get(SATP), (8 << 60) | 0x1234);
+    }
+
+    #[test]
+    fn csr_satp_trapped_by_tvm() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MSTATUS, MSTATUS_TVM);
+
+        assert_eq!(csrs.read(SATP, Privilege::Supervisor), None);
+        assert_eq!(csrs.read(SATP, Privilege::Machine), Some(0));
+    }
+
+    #[test]
+    fn csr_counter_gated_by_mcounteren() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MCYCLE, 42);
+
+        assert_eq!(csrs.read(CYCLE, Privilege::User), None);
+
+        csrs.write(MCOUNTEREN, COUNTER_CY, Privilege::Machine)
+            .unwrap();
+
+        assert_eq!(csrs.read(CYCLE, Privilege::Supervisor), Some(42));
+        assert_eq!(csrs.read(INSTRET, Privilege::Supervisor), None);
+    }
+
+    #[test]
+    fn csr_time_is_read_only_and_gated_by_tm() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(TIME, 7);
+
+        assert_eq!(csrs.read(TIME, Privilege::Machine), Some(7));
+        assert_eq!(csrs.write(TIME, 0, Privilege::Machine), None);
+        assert_eq!(csrs.read(TIME, Privilege::Supervisor), None);
+
+        csrs.write(MCOUNTEREN, COUNTER_TM, Privilege::Machine)
+            .unwrap();
+
+        assert_eq!(csrs.read(TIME, Privilege::Supervisor), Some(7));
+    }
+
+    #[test]
+    fn csr_user_counter_also_gated_by_scounteren() {
+        let mut csrs = CsrFile::new(0);
+        csrs.write(MCOUNTEREN, COUNTER_CY, Privilege::Machine)
+            .unwrap();
+
+        assert_eq!(csrs.read(CYCLE, Privilege::User), None);
+
+        csrs.write(SCOUNTEREN, COUNTER_CY, Privilege::Supervisor)
+            .unwrap();
+
+        assert_eq!(csrs.read(CYCLE, Privilege::User), Some(0));
+    }
+
+    #[test]
+    fn csr_tick_counts_cycles_and_retired_instructions() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.tick(true);
+        csrs.tick(false);
+
+        assert_eq!(csrs.read(CYCLE, Privilege::Machine), Some(2));
+        assert_eq!(csrs.read(INSTRET, Privilege::Machine), Some(1));
+    }
+
+    #[test]
+    fn csr_tick_respects_mcountinhibit() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MCOUNTINHIBIT, COUNTER_IR, Privilege::Machine)
+            .unwrap();
+        csrs.tick(true);
+
+        assert_eq!(csrs.get(MCYCLE), 1);
+        assert_eq!(csrs.get(MINSTRET), 0);
+    }
+
+    #[test]
+    fn csr_hpm_counters_hardwired_to_zero() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MHPMCOUNTER3, 7, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.read(MHPMCOUNTER3, Privilege::Machine), Some(0));
+        assert_eq!(csrs.read(HPMCOUNTER3 + 4, Privilege::Machine), Some(0));
+    }
+
+    #[test]
+    fn csr_hypervisor_csrs_need_h() {
+        let mut csrs = CsrFile::new(0);
+
+        assert_eq!(csrs.read(HSTATUS, Privilege::Machine), None);
+        assert_eq!(csrs.read(VSATP, Privilege::Machine), None);
+
+        csrs.enable_hypervisor();
+
+        assert_eq!(
+            csrs.read(HSTATUS, Privilege::Supervisor),
+            Some(HSTATUS_VSXL_64)
+        );
+        assert_eq!(
+            csrs.read(VSSTATUS, Privilege::Supervisor),
+            Some(MSTATUS_UXL_64)
+        );
+        assert_eq!(csrs.read(HGATP, Privilege::User), None);
+        assert_ne!(csrs.get(MISA) & misa_ext(b'H'), 0);
+    }
+
+    #[test]
+    fn csr_hstatus_write_mask() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_hypervisor();
+
+        csrs.write(HSTATUS, u64::MAX, Privilege::Supervisor)
+            .unwrap();
+
+        let hstatus = csrs.get(HSTATUS);
+        assert_ne!(hstatus & (HSTATUS_SPV | HSTATUS_SPVP | HSTATUS_VTSR), 0);
+        assert_eq!(hstatus & HSTATUS_VSXL, HSTATUS_VSXL_64);
+        assert_eq!(hstatus & HSTATUS_VGEIN, 0);
+    }
+
+    #[test]
+    fn csr_hedeleg_cannot_delegate_hypervisor_exceptions() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_hypervisor();
+
+        csrs.write(HEDELEG, u64::MAX, Privilege::Supervisor)
+            .unwrap();
+        csrs.write(MEDELEG, u64::MAX, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(HEDELEG), HEDELEG_MASK);
+        assert_eq!(csrs.get(HEDELEG) & ((1 << 10) | (0xf << 20)), 0);
+        assert_ne!(csrs.get(MEDELEG) & (1 << 22), 0);
+    }
+
+    #[test]
+    fn csr_hgatp_unsupported_mode_ignored() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_hypervisor();
+
+        let hgatp = (HGATP_MODE_SV39X4 << 60) | (5 << 44) | 0x1234;
+        csrs.write(HGATP, hgatp, Privilege::Supervisor).unwrap();
+        csrs.write(HGATP, 3 << 60, Privilege::Supervisor).unwrap();
+
+        // The two low PPN bits are zero, as the root table is 16 KiB aligned.
+        assert_eq!(csrs.get(HGATP), hgatp & !0b11);
+    }
+
+    #[test]
+    fn csr_hgatp_trapped_by_tvm() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_hypervisor();
+        csrs.set(MSTATUS, MSTATUS_TVM);
+
+        assert_eq!(csrs.read(HGATP, Privilege::Supervisor), None);
+        assert_eq!(csrs.read(HGATP, Privilege::Machine), Some(0));
+    }
+
+    #[test]
+    fn csr_vsip_is_view_of_hvip() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_hypervisor();
+
+        csrs.write(HVIP, 0x444, Privilege::Supervisor).unwrap();
+        assert_eq!(csrs.read(VSIP, Privilege::Supervisor), Some(0));
+
+        csrs.write(HIDELEG, 0x444, Privilege::Supervisor).unwrap();
+        assert_eq!(csrs.read(VSIP, Privilege::Supervisor), Some(0x222));
+
+        csrs.write(VSIP, 0, Privilege::Supervisor).unwrap();
+        assert_eq!(csrs.get(HVIP), 0x440);
+    }
+
+    #[test]
+    fn csr_mie_write_mask() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.write(MIE, u64::MAX, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MIE), MIP_MASK);
+    }
+
+    #[test]
+    fn csr_mip_only_supervisor_bits_writable() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MIP, MIP_MTIP);
+
+        csrs.write(MIP, MIP_SSIP | MIP_STIP | MIP_MSIP, Privilege::Machine)
+            .unwrap();
+
+        assert_eq!(csrs.get(MIP), MIP_MTIP | MIP_SSIP | MIP_STIP);
+    }
+
+    #[test]
+    fn csr_sie_and_sip_show_delegated_interrupts() {
+        let mut csrs = CsrFile::new(0);
+        csrs.set(MIE, MIP_MASK);
+        csrs.set(MIP, MIP_MASK);
+
+        assert_eq!(csrs.read(SIE, Privilege::Supervisor), Some(0));
+        assert_eq!(csrs.read(SIP, Privilege::Supervisor), Some(0));
+
+        csrs.write(MIDELEG, MIP_SSIP | MIP_STIP, Privilege::Machine)
+            .unwrap();
+        assert_eq!(
+            csrs.read(SIE, Privilege::Supervisor),
+            Some(MIP_SSIP | MIP_STIP)
+        );
+        assert_eq!(
+            csrs.read(SIP, Privilege::Supervisor),
+            Some(MIP_SSIP | MIP_STIP)
+        );
+
+        csrs.write(SIE, 0, Privilege::Supervisor).unwrap();
+        csrs.write(SIP, 0, Privilege::Supervisor).unwrap();
+        assert_eq!(csrs.get(MIE), MIP_MASK & !(MIP_SSIP | MIP_STIP));
+        // STIP is driven by the timer, not by S-mode software.
+        assert_eq!(csrs.get(MIP), MIP_MASK & !MIP_SSIP);
+    }
+
+    #[test]
+    fn csr_mip_seip_is_software_bit_or_line() {
+        let mut csrs = CsrFile::new(0);
+
+        csrs.set_lines(MIP_SEIP | MIP_MTIP, true);
+        csrs.write(MIP, 0, Privilege::Machine).unwrap();
+        assert_eq!(
+            csrs.read(MIP, Privilege::Machine),
+            Some(MIP_SEIP | MIP_MTIP)
+        );
+
+        csrs.set_lines(MIP_SEIP, false);
+        assert_eq!(csrs.read(MIP, Privilege::Machine), Some(MIP_MTIP));
+        csrs.write(MIP, MIP_SEIP, Privilege::Machine).unwrap();
+        assert_eq!(
+            csrs.read(MIP, Privilege::Machine),
+            Some(MIP_SEIP | MIP_MTIP)
+        );
+    }
+
+    #[test]
+    fn csr_mip_shows_interrupts_injected_through_hvip() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_hypervisor();
+
+        csrs.write(HVIP, MIP_VSTIP, Privilege::Supervisor).unwrap();
+
+        assert_eq!(csrs.read(MIP, Privilege::Machine), Some(MIP_VSTIP));
+        assert_eq!(csrs.get(MIP), 0);
+    }
+
+    #[test]
+    fn csr_rv32_misa_reports_mxl_32() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_rv32();
+        let misa = csrs.read(MISA, Privilege::Machine).unwrap();
+
+        assert_eq!(misa >> 30, 1);
+        assert_ne!(misa & misa_ext(b'I'), 0);
+        assert_eq!(csrs.read(MSTATUS, Privilege::Machine).unwrap() >> 32, 0);
+    }
+
+    #[test]
+    fn csr_rv32_sd_is_bit_31() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_rv32();
+        csrs.set(MSTATUS, MSTATUS_FS_DIRTY);
+
+        assert_eq!(
+            csrs.read(MSTATUS, Privilege::Machine),
+            Some(MSTATUS_FS_DIRTY | (1 << 31))
+        );
+        assert_eq!(csrs.read(MSTATUSH, Privilege::Machine), Some(0));
+    }
+
+    #[test]
+    fn csr_rv32_counters_split_in_halves() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_rv32();
+        csrs.set(MCYCLE, 0x1234_5678_9abc_def0);
+
+        assert_eq!(csrs.read(MCYCLE, Privilege::Machine), Some(0x9abc_def0));
+        assert_eq!(csrs.read(MCYCLEH, Privilege::Machine), Some(0x1234_5678));
+        assert_eq!(csrs.read(CYCLEH, Privilege::Machine), Some(0x1234_5678));
+
+        csrs.write(MCYCLEH, 0x42, Privilege::Machine).unwrap();
+        assert_eq!(csrs.get(MCYCLE), 0x42_9abc_def0);
+    }
+
+    #[test]
+    fn csr_rv32_cycleh_gated_by_mcounteren() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_rv32();
+
+        assert_eq!(csrs.read(CYCLEH, Privilege::Supervisor), None);
+        csrs.set(MCOUNTEREN, COUNTER_CY);
+        assert_eq!(csrs.read(CYCLEH, Privilege::Supervisor), Some(0));
+    }
+
+    #[test]
+    fn csr_rv32_odd_pmpcfg_holds_upper_entries() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_rv32();
+
+        csrs.write(PMPCFG0 + 1, 0x1f, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(PMPCFG0), 0x1f << 32);
+        assert_eq!(csrs.read(PMPCFG0, Privilege::Machine), Some(0));
+        assert_eq!(csrs.read(PMPCFG0 + 1, Privilege::Machine), Some(0x1f));
+    }
+
+    #[test]
+    fn csr_rv32_writes_truncated() {
+        let mut csrs = CsrFile::new(0);
+        csrs.enable_rv32();
+
+        csrs.write(MSCRATCH, u64::MAX, Privilege::Machine).unwrap();
+
+        assert_eq!(csrs.get(MSCRATCH), 0xffff_ffff);
+        assert_eq!(csrs.read(MSTATUSH + 1, Privilege::Machine), None);
+    }
+}
@@ -38,18 +38,187 @@ pub enum Trap {
     #[error("hardware error fault")]
     HardwareErrorFault,
 
-    #[error("instruction guest page fault at 0x{addr:016x}")]
-    FetchGuestPageFault { addr: u64 },
+    #[error("instruction guest page fault at 0x{addr:016x} (guest physical 0x{gpa:016x})")]
+    FetchGuestPageFault { addr: u64, gpa: u64 },
 
-    #[error("load guest page fault at 0x{addr:016x}")]
-    LoadGuestPageFault { addr: u64 },
+    #[error("load guest page fault at 0x{addr:016x} (guest physical 0x{gpa:016x})")]
+    LoadGuestPageFault { addr: u64, gpa: u64 },
 
-    #[error("virtual instruction")]
-    VirtualInstruction,
+    #[error("virtual instruction 0x{inst:08x}")]
+    VirtualInstruction { inst: u32 },
 
-    #[error("store guest page fault at 0x{addr:016x}")]
-    StoreGuestPageFault { addr: u64 },
+    #[error("store guest page fault at 0x{addr:016x} (guest physical 0x{gpa:016x})")]
+    StoreGuestPageFault { addr: u64, gpa: u64 },
 
     #[error(transparent)]
     Memory(#[from] crate::memory::exception::Trap),
 }
+
+impl Trap {
+    /// Architectural exception code reported in `mcause`/`scause`.
+    pub const fn cause(&self) -> u64 {
+        match self {
+            Self::IllegalInstruction { .. } => 2,
+            Self::Breakpoint { .. } => 3,
+            Self::UserEcall => 8,
+            Self::SupervisorEcall => 9,
+            Self::VirtualSupervisorEcall => 10,
+            Self::MachineEcall => 11,
+            Self::FetchPageFault { .. } => 12,
+            Self::LoadPageFault { .. } => 13,
+            Self::StorePageFault { .. } => 15,
+            Self::DoubleTrap => 16,
+            Self::SoftwareCheckFault => 18,
+            Self::HardwareErrorFault => 19,
+            Self::FetchGuestPageFault { .. } => 20,
+            Self::LoadGuestPageFault { .. } => 21,
+            Self::VirtualInstruction { .. } => 22,
+            Self::StoreGuestPageFault { .. } => 23,
+            Self::Memory(trap) => trap.cause(),
+        }
+    }
+
+    /// Trap value reported in `mtval`/`stval`: the faulting address or
+    /// instruction bits, and zero when the trap carries neither.
+    pub const fn tval(&self) -> u64 {
+        match *self {
+            Self::IllegalInstruction { inst } | Self::VirtualInstruction { inst } => inst as u64,
+            Self::Breakpoint { addr }
+            | Self::FetchPageFault { addr }
+            | Self::LoadPageFault { addr }
+            | Self::StorePageFault { addr }
+            | Self::FetchGuestPageFault { addr, .. }
+            | Self::LoadGuestPageFault { addr, .. }
+            | Self::StoreGuestPageFault { addr, .. } => addr,
+            Self::Memory(trap) => trap.tval(),
+            Self::UserEcall
+            | Self::SupervisorEcall
+            | Self::VirtualSupervisorEcall
+            | Self::MachineEcall
+            | Self::DoubleTrap
+            | Self::SoftwareCheckFault
+            | Self::HardwareErrorFault => 0,
+        }
+    }
+
+    /// Second trap value reported in `htval`/`mtval2`: the guest physical
+    /// address of a guest-page fault shifted right by two, and zero otherwise.
+    pub const fn tval2(&self) -> u64 {
+        match *self {
+            Self::FetchGuestPageFault { gpa, .. }
+            | Self::LoadGuestPageFault { gpa, .. }
+            | Self::StoreGuestPageFault { gpa, .. } => gpa >> 2,
+            _ => 0,
+        }
+    }
+
+    /// Whether `tval` holds a virtual address, which is a guest virtual
+    /// address when the trap comes from a guest.
+    pub const fn has_vaddr(&self) -> bool {
+        match self {
+            Self::Breakpoint { .. }
+            | Self::FetchPageFault { .. }
+            | Self::LoadPageFault { .. }
+            | Self::StorePageFault { .. }
+            | Self::FetchGuestPageFault { .. }
+            | Self::LoadGuestPageFault { .. }
+            | Self::StoreGuestPageFault { .. }
+            | Self::Memory(_) => true,
+            Self::IllegalInstruction { .. }
+            | Self::UserEcall
+            | Self::SupervisorEcall
+            | Self::VirtualSupervisorEcall
+            | Self::MachineEcall
+            | Self::DoubleTrap
+            | Self::SoftwareCheckFault
+            | Self::HardwareErrorFault
+            | Self::VirtualInstruction { .. } => false,
+        }
+    }
+
+    /// Recovers the architectural trap carried by an instruction error. Anything
+    /// else is a host-side failure that the guest cannot handle.
+    pub fn from_error(err: &anyhow::Error) -> Option<Self> {
+        if let Some(trap) = err.downcast_ref::<Self>() {
+            Some(*trap)
+        } else {
+            err.downcast_ref::<crate::memory::exception::Trap>()
+                .map(|trap| Self::Memory(*trap))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::exception::Trap as MemoryTrap;
+
+    #[test]
+    fn trap_cause_codes() {
+        assert_eq!(MemoryTrap::MisalignedFetch { addr: 0 }.cause(), 0);
+        assert_eq!(MemoryTrap::FetchAccessFault { addr: 0 }.cause(), 1);
+        assert_eq!(Trap::IllegalInstruction { inst: 0 }.cause(), 2);
+        assert_eq!(Trap::Breakpoint { addr: 0 }.cause(), 3);
+        assert_eq!(MemoryTrap::MisalignedLoad { addr: 0, align: 8 }.cause(), 4);
+        assert_eq!(MemoryTrap::LoadAccessFault { addr: 0 }.cause(), 5);
+        assert_eq!(MemoryTrap::MisalignedStore { addr: 0, align: 8 }.cause(), 6);
+        assert_eq!(MemoryTrap::StoreAccessFault { addr: 0 }.cause(), 7);
+        assert_eq!(Trap::UserEcall.cause(), 8);
+        assert_eq!(Trap::SupervisorEcall.cause(), 9);
+        assert_eq!(Trap::VirtualSupervisorEcall.cause(), 10);
+        assert_eq!(Trap::MachineEcall.cause(), 11);
+        assert_eq!(Trap::FetchPageFault { addr: 0 }.cause(), 12);
+        assert_eq!(Trap::LoadPageFault { addr: 0 }.cause(), 13);
+        assert_eq!(Trap::StorePageFault { addr: 0 }.cause(), 15);
+        assert_eq!(Trap::DoubleTrap.cause(), 16);
+        assert_eq!(Trap::SoftwareCheckFault.cause(), 18);
+        assert_eq!(Trap::HardwareErrorFault.cause(), 19);
+        assert_eq!(Trap::FetchGuestPageFault { addr: 0, gpa: 0 }.cause(), 20);
+        assert_eq!(Trap::LoadGuestPageFault { addr: 0, gpa: 0 }.cause(), 21);
+        assert_eq!(Trap::VirtualInstruction { inst: 0 }.cause(), 22);
+        assert_eq!(Trap::StoreGuestPageFault { addr: 0, gpa: 0 }.cause(), 23);
+    }
+
+    #[test]
+    fn trap_memory_variant_delegates() {
+        let trap = Trap::Memory(MemoryTrap::StoreAccessFault { addr: 0x40 });
+
+        assert_eq!(trap.cause(), 7);
+        assert_eq!(trap.tval(), 0x40);
+    }
+
+    #[test]
+    fn trap_tval() {
+        assert_eq!(Trap::IllegalInstruction { inst: 0xdead }.tval(), 0xdead);
+        assert_eq!(Trap::Breakpoint { addr: 0x80 }.tval(), 0x80);
+        assert_eq!(Trap::MachineEcall.tval(), 0);
+        assert_eq!(Trap::VirtualInstruction { inst: 0xbeef }.tval(), 0xbeef);
+    }
+
+    #[test]
+    fn trap_tval2_reports_guest_physical_address() {
+        let trap = Trap::LoadGuestPageFault {
+            addr: 0x1234,
+            gpa: 0x8000_1230,
+        };
+
+        assert_eq!(trap.tval(), 0x1234);
+        assert_eq!(trap.tval2(), 0x2000_048c);
+        assert_eq!(Trap::LoadPageFault { addr: 0x1234 }.tval2(), 0);
+    }
+
+    #[test]
+    fn trap_from_error() {
+        let err: anyhow::Error = MemoryTrap::LoadAccessFault { addr: 0x10 }.into();
+        assert_eq!(
+            Trap::from_error(&err),
+            Some(Trap::Memory(MemoryTrap::LoadAccessFault { addr: 0x10 }))
+        );
+
+        let err: anyhow::Error = Trap::MachineEcall.into();
+        assert_eq!(Trap::from_error(&err), Some(Trap::MachineEcall));
+
+        let err = anyhow::anyhow!("host failure");
+        assert_eq!(Trap::from_error(&err), None);
+    }
+}
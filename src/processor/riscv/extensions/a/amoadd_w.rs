@@ -0,0 +1,174 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct AmoaddW;
+
+impl InstrExec for AmoaddW {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xf800_707f == 0x0000_202f
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "AMOADD.W", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let addr = hart.xreg(rs1);
+        let src = hart.xreg(rs2);
+        let old = hart.amo32(bus, addr, |old| old.wrapping_add(src as u32))?;
+
+        hart.set_xreg(rd, old as i32 as i64 as u64);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{exception::Trap, mmap::Mmap},
+        processor::riscv::{
+            hart::{Hart, HartConfig, Misaligned},
+            instruction::InstrExec,
+        },
+    };
+
+    fn encode_amoadd_w(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b00000 << 27) | (rs2 << 20) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b0101111
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        AmoaddW.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn amoadd_w_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        hart.set_xreg(2, 2);
+        bus.write32(0x100, 40).unwrap();
+
+        exec(encode_amoadd_w(3, 1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), 40);
+        assert_eq!(bus.read32(0x100).unwrap(), 42);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn amoadd_w_wraps() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        hart.set_xreg(2, 1);
+        bus.write32(0x100, u32::MAX).unwrap();
+
+        exec(encode_amoadd_w(3, 1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), u64::MAX);
+        assert_eq!(bus.read32(0x100).unwrap(), 0);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn amoadd_w_misaligned() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x102);
+
+        let err = exec(encode_amoadd_w(3, 1, 2), &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::MisalignedStore {
+                addr: 0x102,
+                align: 4
+            }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn amoadd_w_misaligned_transparent() {
+        let config = HartConfig {
+            misaligned: Misaligned::Transparent,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0xffe);
+        hart.set_xreg(2, 2);
+        bus.write16(0xffe, 40).unwrap();
+
+        exec(encode_amoadd_w(3, 1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), 40);
+        assert_eq!(bus.read16(0xffe).unwrap(), 42);
+        assert_eq!(bus.read16(0x1000).unwrap(), 0);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn amoadd_w_misaligned_across_pages_within_page() {
+        let config = HartConfig {
+            misaligned: Misaligned::WithinPage,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0xffe);
+
+        let err = exec(encode_amoadd_w(3, 1, 2), &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::MisalignedStore {
+                addr: 0xffe,
+                align: 4
+            }
+        );
+    }
+
+    #[test]
+    fn amoadd_w_access_fault_reported_as_store() {
+        let mut hart = Hart::new(0);
+        let mut bus = Mmap::new(0x8000_0000, 0x1000);
+        hart.set_xreg(1, 0x100);
+
+        let err = exec(encode_amoadd_w(3, 1, 2), &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::StoreAccessFault { addr: 0x100 }
+        );
+    }
+
+    #[test]
+    fn amoadd_w_invalidates_reservation() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        hart.reserve(0x100);
+
+        exec(encode_amoadd_w(3, 1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.reservation(), None);
+    }
+}
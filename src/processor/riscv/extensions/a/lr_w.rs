@@ -0,0 +1,115 @@
+use crate::{
+    memory::{Bus, exception::Trap},
+    processor::riscv::{hart::Hart, instruction::InstrExec, mmu::Access},
+};
+
+#[derive(Debug)]
+pub struct LrW;
+
+impl InstrExec for LrW {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xf9f0_707f == 0x1000_202f
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "LR.W", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        let addr = hart.xreg(rs1);
+        if !addr.is_multiple_of(4) {
+            return Err(Trap::MisalignedLoad { addr, align: 4 }.into());
+        }
+
+        let paddr = hart.translate(bus, addr, 4, Access::Load)?;
+        let val = bus.read32(paddr)?;
+        hart.reserve(paddr);
+
+        hart.set_xreg(rd, val as i32 as i64 as u64);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_lr_w(rd: u32, rs1: u32) -> u32 {
+        (0b00010 << 27) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b0101111
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        LrW.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn lr_w_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        bus.write32(0x100, 0x1234).unwrap();
+
+        exec(encode_lr_w(2, 1), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 0x1234);
+        assert_eq!(hart.reservation(), Some(0x100));
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn lr_w_sign_extends() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        bus.write32(0x100, 0x8000_0000).unwrap();
+
+        exec(encode_lr_w(2, 1), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 0xffff_ffff_8000_0000);
+    }
+
+    #[test]
+    fn lr_w_replaces_previous_reservation() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x200);
+        hart.reserve(0x100);
+
+        exec(encode_lr_w(2, 1), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.reservation(), Some(0x200));
+    }
+
+    #[test]
+    fn lr_w_misaligned() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x102);
+
+        let err = exec(encode_lr_w(2, 1), &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::MisalignedLoad {
+                addr: 0x102,
+                align: 4
+            }
+        );
+        assert_eq!(hart.reservation(), None);
+    }
+}
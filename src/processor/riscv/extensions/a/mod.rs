@@ -0,0 +1,22 @@
+pub mod amoadd_d;
+pub mod amoadd_w;
+pub mod amoand_d;
+pub mod amoand_w;
+pub mod amomax_d;
+pub mod amomax_w;
+pub mod amomaxu_d;
+pub mod amomaxu_w;
+pub mod amomin_d;
+pub mod amomin_w;
+pub mod amominu_d;
+pub mod amominu_w;
+pub mod amoor_d;
+pub mod amoor_w;
+pub mod amoswap_d;
+pub mod amoswap_w;
+pub mod amoxor_d;
+pub mod amoxor_w;
+pub mod lr_d;
+pub mod lr_w;
+pub mod sc_d;
+pub mod sc_w;
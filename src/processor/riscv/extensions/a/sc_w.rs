@@ -0,0 +1,126 @@
+use crate::{
+    memory::{Bus, exception::Trap},
+    processor::riscv::{hart::Hart, instruction::InstrExec, mmu::Access},
+};
+
+#[derive(Debug)]
+pub struct ScW;
+
+impl InstrExec for ScW {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xf800_707f == 0x1800_202f
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "SC.W", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let addr = hart.xreg(rs1);
+        if !addr.is_multiple_of(4) {
+            return Err(Trap::MisalignedStore { addr, align: 4 }.into());
+        }
+
+        let paddr = hart.translate(bus, addr, 4, Access::Store)?;
+        if hart.take_reservation(paddr) {
+            bus.write32(paddr, hart.xreg(rs2) as u32)?;
+            hart.set_xreg(rd, 0);
+        } else {
+            hart.set_xreg(rd, 1);
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_sc_w(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b00011 << 27) | (rs2 << 20) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b0101111
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        ScW.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn sc_w_succeeds_with_reservation() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        hart.set_xreg(2, 0x5678);
+        hart.reserve(0x100);
+
+        exec(encode_sc_w(3, 1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(bus.read32(0x100).unwrap(), 0x5678);
+        assert_eq!(hart.reservation(), None);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn sc_w_fails_without_reservation() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        hart.set_xreg(2, 0x5678);
+
+        exec(encode_sc_w(3, 1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), 1);
+        assert_eq!(bus.read32(0x100).unwrap(), 0);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn sc_w_fails_on_other_address() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        hart.set_xreg(2, 0x5678);
+        hart.reserve(0x200);
+
+        exec(encode_sc_w(3, 1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), 1);
+        assert_eq!(bus.read32(0x100).unwrap(), 0);
+        assert_eq!(hart.reservation(), None);
+    }
+
+    #[test]
+    fn sc_w_misaligned() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x102);
+        hart.reserve(0x100);
+
+        let err = exec(encode_sc_w(3, 1, 2), &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::MisalignedStore {
+                addr: 0x102,
+                align: 4
+            }
+        );
+    }
+}
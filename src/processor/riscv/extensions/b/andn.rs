@@ -0,0 +1,128 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Andn;
+
+impl InstrExec for Andn {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x4000_7033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "ANDN", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_any_ext(&[Extension::Zbb, Extension::Zbkb], inst)?;
+        let val = hart.xreg(rs1) & !hart.xreg(rs2);
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_andn(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0100000 << 25) | (rs2 << 20) | (rs1 << 15) | (0b111 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Andn.call(inst, hart, bus)
+            .expect("ANDN execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn andn_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xff00_ff00_ff00_ff00);
+        hart.set_xreg(2, 0xf0f_0f0f_0f0f_0f0f);
+
+        exec(encode_andn(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xf000_f000_f000_f000);
+    }
+
+    #[test]
+    fn andn_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678_9abc_def0);
+        hart.set_xreg(2, 0);
+
+        exec(encode_andn(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1234_5678_9abc_def0);
+    }
+
+    #[test]
+    fn andn_all_ones() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678_9abc_def0);
+        hart.set_xreg(2, 0xffff_ffff_ffff_ffff);
+
+        exec(encode_andn(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn andn_same_register() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xa5a5);
+        hart.set_xreg(2, 0xa5a5);
+
+        exec(encode_andn(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn andn_illegal_without_zbb_or_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbb)
+                .without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_andn(3, 1, 2);
+
+        let err = Andn.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Bexti;
+
+impl InstrExec for Bexti {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfc00_707f == 0x4800_5013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "BEXTI", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, shamt = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let shamt = (inst >> 20) & 0x3f;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("shamt", shamt);
+        }
+
+        hart.check_ext(Extension::Zbs, inst)?;
+        hart.check_shamt(inst, shamt)?;
+        let val = (hart.xreg(rs1) >> shamt) & 1;
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_bexti(rd: u32, rs1: u32, shamt: u32) -> u32 {
+        (0b010010 << 26) | (shamt << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Bexti
+            .call(inst, hart, bus)
+            .expect("BEXTI execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn bexti_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xf0f0);
+
+        exec(encode_bexti(3, 1, 4), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn bexti_bit_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_fffe);
+
+        exec(encode_bexti(3, 1, 0), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn bexti_top_bit() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0001);
+
+        exec(encode_bexti(3, 1, 63), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn bexti_bit_32() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1_0000_0001);
+
+        exec(encode_bexti(3, 1, 32), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn bexti_illegal_without_zbs() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbs),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_bexti(3, 1, 1);
+
+        let err = Bexti.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Binv;
+
+impl InstrExec for Binv {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x6800_1033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "BINV", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zbs, inst)?;
+        let val = hart.xreg(rs1) ^ (1 << hart.shamt(hart.xreg(rs2)));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_binv(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0110100 << 25) | (rs2 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Binv.call(inst, hart, bus)
+            .expect("BINV execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn binv_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xf0f0);
+        hart.set_xreg(2, 4);
+
+        exec(encode_binv(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xf0e0);
+    }
+
+    #[test]
+    fn binv_bit_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_fffe);
+        hart.set_xreg(2, 0);
+
+        exec(encode_binv(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ffff_ffff);
+    }
+
+    #[test]
+    fn binv_top_bit() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0001);
+        hart.set_xreg(2, 0x3f);
+
+        exec(encode_binv(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn binv_uses_low_six_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x5555);
+        hart.set_xreg(2, 0x41);
+
+        exec(encode_binv(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x5557);
+    }
+
+    #[test]
+    fn binv_illegal_without_zbs() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbs),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_binv(3, 1, 2);
+
+        let err = Binv.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
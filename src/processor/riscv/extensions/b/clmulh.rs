@@ -0,0 +1,152 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Clmulh;
+
+impl InstrExec for Clmulh {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0a00_3033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CLMULH", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_any_ext(&[Extension::Zbc, Extension::Zbkc], inst)?;
+        let (a, b) = (hart.uxreg(rs1), hart.uxreg(rs2));
+        let prod = (0..hart.xlen())
+            .filter(|i| (b >> i) & 1 != 0)
+            .fold(0u128, |acc, i| acc ^ (u128::from(a) << i));
+        let val = (prod >> hart.xlen()) as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_clmulh(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000101 << 25) | (rs2 << 20) | (rs1 << 15) | (0b011 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Clmulh
+            .call(inst, hart, bus)
+            .expect("CLMULH execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn clmulh_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xb);
+        hart.set_xreg(2, 6);
+
+        exec(encode_clmulh(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn clmulh_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678_9abc_def0);
+        hart.set_xreg(2, 0);
+
+        exec(encode_clmulh(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn clmulh_all_ones() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+        hart.set_xreg(2, 0xffff_ffff_ffff_ffff);
+
+        exec(encode_clmulh(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x5555_5555_5555_5555);
+    }
+
+    #[test]
+    fn clmulh_high_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0001);
+        hart.set_xreg(2, 0x8000_0000_0000_0003);
+
+        exec(encode_clmulh(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x4000_0000_0000_0001);
+    }
+
+    #[test]
+    fn clmulh_illegal_without_zbc_or_zbkc() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbc)
+                .without(Extension::Zbkc),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_clmulh(3, 1, 2);
+
+        let err = Clmulh.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn clmulh_rv32_returns_upper_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0xffff_ffff_8000_0000);
+        hart.set_xreg(2, 0x2);
+
+        exec(encode_clmulh(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 1);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Clz;
+
+impl InstrExec for Clz {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x6000_1013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CLZ", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zbb, inst)?;
+        let val = u64::from(hart.uxreg(rs1).leading_zeros() - (64 - hart.xlen()));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_clz(rd: u32, rs1: u32) -> u32 {
+        (0x600 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Clz.call(inst, hart, bus)
+            .expect("CLZ execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn clz_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100_0000_0000);
+
+        exec(encode_clz(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x17);
+    }
+
+    #[test]
+    fn clz_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_clz(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x40);
+    }
+
+    #[test]
+    fn clz_top_bit() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0000);
+
+        exec(encode_clz(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn clz_one() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1);
+
+        exec(encode_clz(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x3f);
+    }
+
+    #[test]
+    fn clz_illegal_without_zbb() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_clz(3, 1);
+
+        let err = Clz.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn clz_rv32_counts_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0x1);
+
+        exec(encode_clz(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 31);
+
+        hart.set_xreg(1, 0);
+        exec(encode_clz(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 32);
+    }
+}
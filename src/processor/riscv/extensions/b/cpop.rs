@@ -0,0 +1,111 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Cpop;
+
+impl InstrExec for Cpop {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x6020_1013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CPOP", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zbb, inst)?;
+        let val = u64::from(hart.uxreg(rs1).count_ones());
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_cpop(rd: u32, rs1: u32) -> u32 {
+        (0x602 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Cpop.call(inst, hart, bus)
+            .expect("CPOP execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn cpop_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xf0f0);
+
+        exec(encode_cpop(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 8);
+    }
+
+    #[test]
+    fn cpop_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_cpop(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn cpop_all_ones() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+
+        exec(encode_cpop(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x40);
+    }
+
+    #[test]
+    fn cpop_illegal_without_zbb() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_cpop(3, 1);
+
+        let err = Cpop.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Maxu;
+
+impl InstrExec for Maxu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0a00_7033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MAXU", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zbb, inst)?;
+        let val = hart.xreg(rs1).max(hart.xreg(rs2));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_maxu(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000101 << 25) | (rs2 << 20) | (rs1 << 15) | (0b111 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Maxu.call(inst, hart, bus)
+            .expect("MAXU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn maxu_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 3);
+        hart.set_xreg(2, 5);
+
+        exec(encode_maxu(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 5);
+    }
+
+    #[test]
+    fn maxu_negative() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+        hart.set_xreg(2, 1);
+
+        exec(encode_maxu(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ffff_ffff);
+    }
+
+    #[test]
+    fn maxu_extremes() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0000);
+        hart.set_xreg(2, 0x7fff_ffff_ffff_ffff);
+
+        exec(encode_maxu(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x8000_0000_0000_0000);
+    }
+
+    #[test]
+    fn maxu_equal() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x2a);
+        hart.set_xreg(2, 0x2a);
+
+        exec(encode_maxu(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x2a);
+    }
+
+    #[test]
+    fn maxu_illegal_without_zbb() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_maxu(3, 1, 2);
+
+        let err = Maxu.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
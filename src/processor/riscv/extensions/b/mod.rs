@@ -0,0 +1,43 @@
+pub mod add_uw;
+pub mod andn;
+pub mod bclr;
+pub mod bclri;
+pub mod bext;
+pub mod bexti;
+pub mod binv;
+pub mod binvi;
+pub mod bset;
+pub mod bseti;
+pub mod clmul;
+pub mod clmulh;
+pub mod clmulr;
+pub mod clz;
+pub mod clzw;
+pub mod cpop;
+pub mod cpopw;
+pub mod ctz;
+pub mod ctzw;
+pub mod max;
+pub mod maxu;
+pub mod min;
+pub mod minu;
+pub mod orc_b;
+pub mod orn;
+pub mod rev8;
+pub mod rol;
+pub mod rolw;
+pub mod ror;
+pub mod rori;
+pub mod roriw;
+pub mod rorw;
+pub mod sext_b;
+pub mod sext_h;
+pub mod sh1add;
+pub mod sh1add_uw;
+pub mod sh2add;
+pub mod sh2add_uw;
+pub mod sh3add;
+pub mod sh3add_uw;
+pub mod slli_uw;
+pub mod xnor;
+pub mod zext_h;
@@ -0,0 +1,147 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Rev8;
+
+impl InstrExec for Rev8 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x6b80_5013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "REV8", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_any_ext(&[Extension::Zbb, Extension::Zbkb], inst)?;
+        let val = hart.uxreg(rs1).swap_bytes() >> (64 - hart.xlen());
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+/// The RV32 encoding of REV8, whose shift amount field reverses 32 bits
+/// rather than 64.
+#[derive(Debug)]
+pub struct Rev8Rv32;
+
+impl InstrExec for Rev8Rv32 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x6980_5013
+    }
+
+    #[inline(always)]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        Rev8.call(inst, hart, bus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_rev8(rd: u32, rs1: u32) -> u32 {
+        (0x6b8 << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Rev8.call(inst, hart, bus)
+            .expect("REV8 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn rev8_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x102_0304_0506_0708);
+
+        exec(encode_rev8(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x807_0605_0403_0201);
+    }
+
+    #[test]
+    fn rev8_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_rev8(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn rev8_low_byte() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xff);
+
+        exec(encode_rev8(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xff00_0000_0000_0000);
+    }
+
+    #[test]
+    fn rev8_rv32_reverses_word() {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x0102_0380);
+        let inst = (0x698 << 20) | (1 << 15) | (0b101 << 12) | (3 << 7) | 0b0010011;
+
+        assert!(Rev8Rv32.matches(inst));
+        assert!(!Rev8.matches(inst));
+        Rev8Rv32.call(inst, &mut hart, &mut bus).unwrap();
+        assert_eq!(hart.xreg(3), 0xffff_ffff_8003_0201);
+    }
+
+    #[test]
+    fn rev8_illegal_without_zbb_or_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbb)
+                .without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_rev8(3, 1);
+
+        let err = Rev8.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Rol;
+
+impl InstrExec for Rol {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x6000_1033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "ROL", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_any_ext(&[Extension::Zbb, Extension::Zbkb], inst)?;
+        let shamt = hart.shamt(hart.xreg(rs2));
+        let val = if hart.xlen() == 32 {
+            u64::from((hart.xreg(rs1) as u32).rotate_left(shamt))
+        } else {
+            hart.xreg(rs1).rotate_left(shamt)
+        };
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_rol(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0110000 << 25) | (rs2 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Rol.call(inst, hart, bus)
+            .expect("ROL execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn rol_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0001);
+        hart.set_xreg(2, 1);
+
+        exec(encode_rol(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 3);
+    }
+
+    #[test]
+    fn rol_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234);
+        hart.set_xreg(2, 0);
+
+        exec(encode_rol(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1234);
+    }
+
+    #[test]
+    fn rol_uses_low_six_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1);
+        hart.set_xreg(2, 0x44);
+
+        exec(encode_rol(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x10);
+    }
+
+    #[test]
+    fn rol_by_32() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678_9abc_def0);
+        hart.set_xreg(2, 0x20);
+
+        exec(encode_rol(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x9abc_def0_1234_5678);
+    }
+
+    #[test]
+    fn rol_illegal_without_zbb_or_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbb)
+                .without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_rol(3, 1, 2);
+
+        let err = Rol.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Ror;
+
+impl InstrExec for Ror {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x6000_5033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "ROR", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_any_ext(&[Extension::Zbb, Extension::Zbkb], inst)?;
+        let shamt = hart.shamt(hart.xreg(rs2));
+        let val = if hart.xlen() == 32 {
+            u64::from((hart.xreg(rs1) as u32).rotate_right(shamt))
+        } else {
+            hart.xreg(rs1).rotate_right(shamt)
+        };
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_ror(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0110000 << 25) | (rs2 << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Ror.call(inst, hart, bus)
+            .expect("ROR execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn ror_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0001);
+        hart.set_xreg(2, 1);
+
+        exec(encode_ror(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xc000_0000_0000_0000);
+    }
+
+    #[test]
+    fn ror_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234);
+        hart.set_xreg(2, 0);
+
+        exec(encode_ror(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1234);
+    }
+
+    #[test]
+    fn ror_uses_low_six_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1);
+        hart.set_xreg(2, 0x41);
+
+        exec(encode_ror(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x8000_0000_0000_0000);
+    }
+
+    #[test]
+    fn ror_by_8() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678_9abc_def0);
+        hart.set_xreg(2, 8);
+
+        exec(encode_ror(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xf012_3456_789a_bcde);
+    }
+
+    #[test]
+    fn ror_illegal_without_zbb_or_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbb)
+                .without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_ror(3, 1, 2);
+
+        let err = Ror.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn ror_rv32_rotates_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0x1);
+        hart.set_xreg(2, 33);
+
+        exec(encode_ror(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_8000_0000);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Rori;
+
+impl InstrExec for Rori {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfc00_707f == 0x6000_5013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "RORI", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, shamt = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let shamt = (inst >> 20) & 0x3f;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("shamt", shamt);
+        }
+
+        hart.check_any_ext(&[Extension::Zbb, Extension::Zbkb], inst)?;
+        hart.check_shamt(inst, shamt)?;
+        let val = if hart.xlen() == 32 {
+            u64::from((hart.xreg(rs1) as u32).rotate_right(shamt))
+        } else {
+            hart.xreg(rs1).rotate_right(shamt)
+        };
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_rori(rd: u32, rs1: u32, shamt: u32) -> u32 {
+        (0b011000 << 26) | (shamt << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Rori.call(inst, hart, bus)
+            .expect("RORI execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn rori_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0001);
+
+        exec(encode_rori(3, 1, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xc000_0000_0000_0000);
+    }
+
+    #[test]
+    fn rori_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234);
+
+        exec(encode_rori(3, 1, 0), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1234);
+    }
+
+    #[test]
+    fn rori_max() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000_0000_0001);
+
+        exec(encode_rori(3, 1, 63), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 3);
+    }
+
+    #[test]
+    fn rori_by_16() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678_9abc_def0);
+
+        exec(encode_rori(3, 1, 16), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xdef0_1234_5678_9abc);
+    }
+
+    #[test]
+    fn rori_illegal_without_zbb_or_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbb)
+                .without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_rori(3, 1, 1);
+
+        let err = Rori.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn rori_rv32_rotates_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0x1);
+
+        exec(encode_rori(3, 1, 4), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1000_0000);
+    }
+}
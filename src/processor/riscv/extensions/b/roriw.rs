@@ -0,0 +1,125 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Roriw;
+
+impl InstrExec for Roriw {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x6000_501b
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "RORIW", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, shamt = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let shamt = (inst >> 20) & 0x1f;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("shamt", shamt);
+        }
+
+        hart.check_any_ext(&[Extension::Zbb, Extension::Zbkb], inst)?;
+        let val = (hart.xreg(rs1) as u32).rotate_right(shamt) as i32 as i64 as u64;
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_roriw(rd: u32, rs1: u32, shamt: u32) -> u32 {
+        (0b0110000 << 25) | (shamt << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0011011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Roriw
+            .call(inst, hart, bus)
+            .expect("RORIW execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn roriw_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0002);
+
+        exec(encode_roriw(3, 1, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x4000_0001);
+    }
+
+    #[test]
+    fn roriw_sign_extends() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1);
+
+        exec(encode_roriw(3, 1, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_8000_0000);
+    }
+
+    #[test]
+    fn roriw_ignores_upper_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_0000_1234);
+
+        exec(encode_roriw(3, 1, 4), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x4000_0123);
+    }
+
+    #[test]
+    fn roriw_max() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1);
+
+        exec(encode_roriw(3, 1, 31), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 2);
+    }
+
+    #[test]
+    fn roriw_illegal_without_zbb_or_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbb)
+                .without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_roriw(3, 1, 1);
+
+        let err = Roriw.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
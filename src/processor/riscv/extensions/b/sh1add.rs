@@ -0,0 +1,127 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Sh1add;
+
+impl InstrExec for Sh1add {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x2000_2033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "SH1ADD", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zba, inst)?;
+        let val = (hart.xreg(rs1) << 1).wrapping_add(hart.xreg(rs2));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_sh1add(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0010000 << 25) | (rs2 << 20) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Sh1add
+            .call(inst, hart, bus)
+            .expect("SH1ADD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn sh1add_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 3);
+        hart.set_xreg(2, 0x100);
+
+        exec(encode_sh1add(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x106);
+    }
+
+    #[test]
+    fn sh1add_index_into_array() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xa);
+        hart.set_xreg(2, 0x8000_0000);
+
+        exec(encode_sh1add(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x8000_0014);
+    }
+
+    #[test]
+    fn sh1add_discards_shifted_out_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xf000_0000_0000_0001);
+        hart.set_xreg(2, 0);
+
+        exec(encode_sh1add(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xe000_0000_0000_0002);
+    }
+
+    #[test]
+    fn sh1add_wraps() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+        hart.set_xreg(2, 2);
+
+        exec(encode_sh1add(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn sh1add_illegal_without_zba() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zba),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_sh1add(3, 1, 2);
+
+        let err = Sh1add.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
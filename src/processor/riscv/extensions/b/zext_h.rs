@@ -0,0 +1,147 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct ZextH;
+
+impl InstrExec for ZextH {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x0800_403b
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "ZEXT.H", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_any_ext(&[Extension::Zbb, Extension::Zbkb], inst)?;
+        let val = u64::from(hart.xreg(rs1) as u16);
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+/// The RV32 encoding of ZEXT.H, which is PACK with `rs2 = x0` where RV64
+/// uses PACKW.
+#[derive(Debug)]
+pub struct ZextHRv32;
+
+impl InstrExec for ZextHRv32 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x0800_4033
+    }
+
+    #[inline(always)]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        ZextH.call(inst, hart, bus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_zext_h(rd: u32, rs1: u32) -> u32 {
+        (0x080 << 20) | (rs1 << 15) | (0b100 << 12) | (rd << 7) | 0b0111011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        ZextH
+            .call(inst, hart, bus)
+            .expect("ZEXT.H execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn zext_h_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678);
+
+        exec(encode_zext_h(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x5678);
+    }
+
+    #[test]
+    fn zext_h_negative() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+
+        exec(encode_zext_h(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff);
+    }
+
+    #[test]
+    fn zext_h_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_0000);
+
+        exec(encode_zext_h(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn zext_h_rv32_encoding() {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0xdead_beef);
+        let inst = (0x080 << 20) | (1 << 15) | (0b100 << 12) | (3 << 7) | 0b0110011;
+
+        assert!(ZextHRv32.matches(inst));
+        ZextHRv32.call(inst, &mut hart, &mut bus).unwrap();
+        assert_eq!(hart.xreg(3), 0xbeef);
+    }
+
+    #[test]
+    fn zext_h_illegal_without_zbb_or_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zbb)
+                .without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_zext_h(3, 1);
+
+        let err = ZextH.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::processor::riscv::{
+    extensions::c::{OP, r_type, rd, rs2},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAdd;
+
+impl InstrExpand for CAdd {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xf003 == 0x9002 && rs2(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        r_type(0, rs2(inst), rd, 0b000, rd, OP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_add_expands() {
+        // c.add a0, a1 -> add a0, a0, a1
+        assert_eq!(CAdd.expand(0x952e), 0x00b5_0533);
+        // c.add t6, s0 -> add t6, t6, s0
+        assert_eq!(CAdd.expand(0x9fa2), 0x008f_8fb3);
+    }
+
+    #[test]
+    fn c_add_adds_registers() {
+        // c.add a0, a1
+        let (mut hart, mut bus) = setup(0x952e);
+        hart.set_xreg(10, 40);
+        hart.set_xreg(11, 2);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 42);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, ci_imm, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAddi;
+
+impl InstrExpand for CAddi {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x0001 && rd(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        i_type(ci_imm(inst), rd, 0b000, rd, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_addi_expands() {
+        // c.addi a0, 1 -> addi a0, a0, 1
+        assert_eq!(CAddi.expand(0x0505), 0x0015_0513);
+        // c.addi t6, -32 -> addi t6, t6, -32
+        assert_eq!(CAddi.expand(0x1f81), 0xfe0f_8f93);
+    }
+
+    #[test]
+    fn c_addi_adds_negative_immediate() {
+        // c.addi a0, -32
+        let (mut hart, mut bus) = setup(0x1501);
+        hart.set_xreg(10, 100);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 68);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, bits, i_type, sext},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAddi16sp;
+
+impl InstrExpand for CAddi16sp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xef83 == 0x6101 && inst & 0x107c != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let imm = (bits(inst, 12, 12) << 9)
+            | (bits(inst, 6, 6) << 4)
+            | (bits(inst, 5, 5) << 6)
+            | (bits(inst, 4, 3) << 7)
+            | (bits(inst, 2, 2) << 5);
+        i_type(sext(imm, 10), 2, 0b000, 2, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_addi16sp_expands() {
+        // c.addi16sp sp, 16 -> addi sp, sp, 16
+        assert_eq!(CAddi16sp.expand(0x6141), 0x0101_0113);
+        // c.addi16sp sp, -512 -> addi sp, sp, -512
+        assert_eq!(CAddi16sp.expand(0x7101), 0xe001_0113);
+        // c.addi16sp sp, 496 -> addi sp, sp, 496
+        assert_eq!(CAddi16sp.expand(0x617d), 0x1f01_0113);
+    }
+
+    #[test]
+    fn c_addi16sp_zero_immediate_is_reserved() {
+        assert!(!CAddi16sp.matches(0x6101));
+    }
+
+    #[test]
+    fn c_addi16sp_adjusts_sp() {
+        // c.addi16sp sp, -512
+        let (mut hart, mut bus) = setup(0x7101);
+        hart.set_xreg(2, 0x8000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 0x8000 - 512);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
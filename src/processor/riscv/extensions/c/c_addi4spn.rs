@@ -0,0 +1,64 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, bits, creg, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAddi4spn;
+
+impl InstrExpand for CAddi4spn {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x0000 && inst & 0x1fe0 != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 2);
+        let imm = (bits(inst, 12, 11) << 4)
+            | (bits(inst, 10, 7) << 6)
+            | (bits(inst, 6, 6) << 2)
+            | (bits(inst, 5, 5) << 3);
+        i_type(imm as i32, 2, 0b000, rd, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_addi4spn_expands() {
+        // c.addi4spn s0, sp, 4 -> addi s0, sp, 4
+        assert_eq!(CAddi4spn.expand(0x0040), 0x0041_0413);
+        // c.addi4spn a5, sp, 1020 -> addi a5, sp, 1020
+        assert_eq!(CAddi4spn.expand(0x1ffc), 0x3fc1_0793);
+    }
+
+    #[test]
+    fn c_addi4spn_zero_immediate_is_reserved() {
+        assert!(!CAddi4spn.matches(0x0000));
+    }
+
+    #[test]
+    fn c_addi4spn_adds_to_sp() {
+        // c.addi4spn a0, sp, 1020
+        let (mut hart, mut bus) = setup(0x1fe8);
+        hart.set_xreg(2, 0x8000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0x8000 + 1020);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
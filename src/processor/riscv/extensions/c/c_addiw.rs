@@ -0,0 +1,60 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM_32, ci_imm, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAddiw;
+
+impl InstrExpand for CAddiw {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x2001 && rd(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        i_type(ci_imm(inst), rd, 0b000, rd, OP_IMM_32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_addiw_expands() {
+        // c.addiw a0, 1 -> addiw a0, a0, 1
+        assert_eq!(CAddiw.expand(0x2505), 0x0015_051b);
+        // c.addiw t6, -32 -> addiw t6, t6, -32
+        assert_eq!(CAddiw.expand(0x3f81), 0xfe0f_8f9b);
+    }
+
+    #[test]
+    fn c_addiw_rd_zero_is_reserved() {
+        assert!(!CAddiw.matches(0x2005));
+    }
+
+    #[test]
+    fn c_addiw_sign_extends_result() {
+        // c.addiw a0, 1
+        let (mut hart, mut bus) = setup(0x2505);
+        hart.set_xreg(10, 0x7fff_ffff);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0xffff_ffff_8000_0000);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
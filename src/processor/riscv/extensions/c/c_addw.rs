@@ -0,0 +1,57 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_32, creg, r_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAddw;
+
+impl InstrExpand for CAddw {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xfc63 == 0x9c21
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 7);
+        let rs2 = creg(inst, 2);
+        r_type(0, rs2, rd, 0b000, rd, OP_32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_addw_expands() {
+        // c.addw a0, a1 -> addw a0, a0, a1
+        assert_eq!(CAddw.expand(0x9d2d), 0x00b5_053b);
+        // c.addw s1, a5 -> addw s1, s1, a5
+        assert_eq!(CAddw.expand(0x9cbd), 0x00f4_84bb);
+    }
+
+    #[test]
+    fn c_addw_sign_extends_result() {
+        // c.addw a0, a1
+        let (mut hart, mut bus) = setup(0x9d2d);
+        hart.set_xreg(10, 0x7fff_ffff);
+        hart.set_xreg(11, 1);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0xffff_ffff_8000_0000);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::processor::riscv::{
+    extensions::c::{OP, creg, r_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAnd;
+
+impl InstrExpand for CAnd {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xfc63 == 0x8c61
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 7);
+        let rs2 = creg(inst, 2);
+        r_type(0, rs2, rd, 0b111, rd, OP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_and_expands() {
+        // c.and a0, a1 -> and a0, a0, a1
+        assert_eq!(CAnd.expand(0x8d6d), 0x00b5_7533);
+        // c.and s1, a5 -> and s1, s1, a5
+        assert_eq!(CAnd.expand(0x8cfd), 0x00f4_f4b3);
+    }
+
+    #[test]
+    fn c_and_ands() {
+        // c.and a0, a1
+        let (mut hart, mut bus) = setup(0x8d6d);
+        hart.set_xreg(10, 0b1100);
+        hart.set_xreg(11, 0b1010);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0b1000);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, ci_imm, creg, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CAndi;
+
+impl InstrExpand for CAndi {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xec03 == 0x8801
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 7);
+        i_type(ci_imm(inst), rd, 0b111, rd, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_andi_expands() {
+        // c.andi a0, 15 -> andi a0, a0, 15
+        assert_eq!(CAndi.expand(0x893d), 0x00f5_7513);
+        // c.andi s1, -32 -> andi s1, s1, -32
+        assert_eq!(CAndi.expand(0x9881), 0xfe04_f493);
+    }
+
+    #[test]
+    fn c_andi_masks_with_sign_extended_immediate() {
+        // c.andi a0, -16
+        let (mut hart, mut bus) = setup(0x9941);
+        hart.set_xreg(10, 0x1234_5678);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0x1234_5670);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
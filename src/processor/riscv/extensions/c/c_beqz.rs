@@ -0,0 +1,61 @@
+use crate::processor::riscv::{
+    extensions::c::{b_type, bits, creg, sext},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CBeqz;
+
+impl InstrExpand for CBeqz {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xc001
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 12) << 8)
+            | (bits(inst, 11, 10) << 3)
+            | (bits(inst, 6, 5) << 6)
+            | (bits(inst, 4, 3) << 1)
+            | (bits(inst, 2, 2) << 5);
+        b_type(sext(imm, 9), 0, rs1, 0b000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_beqz_expands() {
+        // c.beqz s0, 254 -> beq s0, zero, 254
+        assert_eq!(CBeqz.expand(0xcc7d), 0x0e04_0f63);
+        // c.beqz a5, -256 -> beq a5, zero, -256
+        assert_eq!(CBeqz.expand(0xd381), 0xf007_80e3);
+        // c.beqz a0, 170 -> beq a0, zero, 170
+        assert_eq!(CBeqz.expand(0xc54d), 0x0a05_0563);
+    }
+
+    #[test]
+    fn c_beqz_taken_when_zero() {
+        // c.beqz s1, -256
+        let (mut hart, mut bus) = setup(0xd081);
+        hart.set_xreg(9, 0);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x1000 - 256);
+    }
+}
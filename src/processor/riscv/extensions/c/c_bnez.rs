@@ -0,0 +1,61 @@
+use crate::processor::riscv::{
+    extensions::c::{b_type, bits, creg, sext},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CBnez;
+
+impl InstrExpand for CBnez {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xe001
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 12) << 8)
+            | (bits(inst, 11, 10) << 3)
+            | (bits(inst, 6, 5) << 6)
+            | (bits(inst, 4, 3) << 1)
+            | (bits(inst, 2, 2) << 5);
+        b_type(sext(imm, 9), 0, rs1, 0b001)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_bnez_expands() {
+        // c.bnez s0, 254 -> bne s0, zero, 254
+        assert_eq!(CBnez.expand(0xec7d), 0x0e04_1f63);
+        // c.bnez a5, -256 -> bne a5, zero, -256
+        assert_eq!(CBnez.expand(0xf381), 0xf007_90e3);
+        // c.bnez a0, 170 -> bne a0, zero, 170
+        assert_eq!(CBnez.expand(0xe54d), 0x0a05_1563);
+    }
+
+    #[test]
+    fn c_bnez_taken_when_non_zero() {
+        // c.bnez s1, -256
+        let (mut hart, mut bus) = setup(0xf081);
+        hart.set_xreg(9, 5);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x1000 - 256);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::processor::riscv::instruction::InstrExpand;
+
+#[derive(Debug)]
+pub struct CEbreak;
+
+impl InstrExpand for CEbreak {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst == 0x9002
+    }
+
+    #[inline(always)]
+    fn expand(&self, _inst: u16) -> u32 {
+        0x0010_0073
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::{csr, hart::Hart},
+        },
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_ebreak_expands() {
+        // c.ebreak -> ebreak
+        assert_eq!(CEbreak.expand(0x9002), 0x0010_0073);
+    }
+
+    #[test]
+    fn c_ebreak_raises_breakpoint() {
+        let (mut hart, mut bus) = setup(0x9002);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 3);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
+    }
+}
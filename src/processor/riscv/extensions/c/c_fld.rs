@@ -0,0 +1,71 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD_FP, bits, creg, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CFld;
+
+impl InstrExpand for CFld {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x2000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 5) << 6);
+        i_type(imm as i32, rs1, 0b011, rd, OP_LOAD_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::{csr, hart::Hart},
+        },
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_fld_expands() {
+        // c.fld fs0, 0(a0) -> fld fs0, 0(a0)
+        assert_eq!(CFld.expand(0x2100), 0x0005_3407);
+        // c.fld fa5, 248(s1) -> fld fa5, 248(s1)
+        assert_eq!(CFld.expand(0x3cfc), 0x0f84_b787);
+    }
+
+    #[test]
+    fn c_fld_loads_double() {
+        let (mut hart, mut bus) = setup(0x2100);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_FS_INITIAL);
+        hart.set_xreg(10, 0x200);
+        bus.write64(0x200, 1.5f64.to_bits()).unwrap();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.freg(8), 1.5f64.to_bits());
+        assert_eq!(hart.pc(), 0x1002);
+    }
+
+    #[test]
+    fn c_fld_illegal_when_fs_off_reports_compressed_bits() {
+        let (mut hart, mut bus) = setup(0x2100);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x2100);
+    }
+}
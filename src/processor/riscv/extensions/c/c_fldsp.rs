@@ -0,0 +1,70 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD_FP, bits, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CFldsp;
+
+impl InstrExpand for CFldsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x2002
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        let imm = (bits(inst, 12, 12) << 5) | (bits(inst, 6, 5) << 3) | (bits(inst, 4, 2) << 6);
+        i_type(imm as i32, 2, 0b011, rd, OP_LOAD_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::{csr, hart::Hart},
+        },
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_fldsp_expands() {
+        // c.fldsp fs0, 0(sp) -> fld fs0, 0(sp)
+        assert_eq!(CFldsp.expand(0x2402), 0x0001_3407);
+        // c.fldsp ft11, 504(sp) -> fld ft11, 504(sp)
+        assert_eq!(CFldsp.expand(0x3ffe), 0x1f81_3f87);
+    }
+
+    #[test]
+    fn c_fldsp_loads_double() {
+        let (mut hart, mut bus) = setup(0x2402);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_FS_INITIAL);
+        hart.set_xreg(2, 0x200);
+        bus.write64(0x200, 1.5f64.to_bits()).unwrap();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.freg(8), 1.5f64.to_bits());
+        assert_eq!(hart.pc(), 0x1002);
+    }
+
+    #[test]
+    fn c_fldsp_illegal_when_fs_off_reports_compressed_bits() {
+        let (mut hart, mut bus) = setup(0x2402);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x2402);
+    }
+}
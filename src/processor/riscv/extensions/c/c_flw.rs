@@ -0,0 +1,73 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD_FP, bits, creg, i_type},
+    instruction::InstrExpand,
+};
+
+/// RV32-only; RV64 reuses the encoding for C.LD.
+#[derive(Debug)]
+pub struct CFlw;
+
+impl InstrExpand for CFlw {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x6000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 6) << 2) | (bits(inst, 5, 5) << 6);
+        i_type(imm as i32, rs1, 0b010, rd, OP_LOAD_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::{
+                csr,
+                hart::{Hart, HartConfig},
+            },
+        },
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0x1000, config), bus)
+    }
+
+    #[test]
+    fn c_flw_expands() {
+        // c.flw fa0, 4(s0) -> flw fa0, 4(s0)
+        assert_eq!(CFlw.expand(0x6048), 0x0044_2507);
+        // c.flw fa5, 124(a4) -> flw fa5, 124(a4)
+        assert_eq!(CFlw.expand(0x7f7c), 0x07c7_2787);
+    }
+
+    #[test]
+    fn c_flw_loads_single() {
+        // c.flw fa0, 124(s1)
+        let (mut hart, mut bus) = setup(0x7ce8);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_FS_INITIAL);
+        hart.set_xreg(9, 0x2000);
+        bus.write32(0x2000 + 124, 1.5f32.to_bits()).unwrap();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(
+            hart.freg(10),
+            0xffff_ffff_0000_0000 | u64::from(1.5f32.to_bits())
+        );
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD_FP, bits, i_type, rd},
+    instruction::InstrExpand,
+};
+
+/// RV32-only; RV64 reuses the encoding for C.LDSP.
+#[derive(Debug)]
+pub struct CFlwsp;
+
+impl InstrExpand for CFlwsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x6002
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        let imm = (bits(inst, 12, 12) << 5) | (bits(inst, 6, 4) << 2) | (bits(inst, 3, 2) << 6);
+        i_type(imm as i32, 2, 0b010, rd, OP_LOAD_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_flwsp_expands() {
+        // c.flwsp fs0, 0(sp) -> flw fs0, 0(sp)
+        assert_eq!(CFlwsp.expand(0x6402), 0x0001_2407);
+        // c.flwsp ft11, 252(sp) -> flw ft11, 252(sp)
+        assert_eq!(CFlwsp.expand(0x7ffe), 0x0fc1_2f87);
+    }
+}
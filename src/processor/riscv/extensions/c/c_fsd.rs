@@ -0,0 +1,71 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE_FP, bits, creg, s_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CFsd;
+
+impl InstrExpand for CFsd {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xa000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rs2 = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 5) << 6);
+        s_type(imm as i32, rs2, rs1, 0b011, OP_STORE_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::{csr, hart::Hart},
+        },
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_fsd_expands() {
+        // c.fsd fs0, 0(a0) -> fsd fs0, 0(a0)
+        assert_eq!(CFsd.expand(0xa100), 0x0085_3027);
+        // c.fsd fa5, 248(s1) -> fsd fa5, 248(s1)
+        assert_eq!(CFsd.expand(0xbcfc), 0x0ef4_bc27);
+    }
+
+    #[test]
+    fn c_fsd_stores_double() {
+        let (mut hart, mut bus) = setup(0xa100);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_FS_INITIAL);
+        hart.set_xreg(10, 0x200);
+        hart.set_freg(8, 1.5f64.to_bits());
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(bus.read64(0x200).unwrap(), 1.5f64.to_bits());
+        assert_eq!(hart.pc(), 0x1002);
+    }
+
+    #[test]
+    fn c_fsd_illegal_when_fs_off_reports_compressed_bits() {
+        let (mut hart, mut bus) = setup(0xa100);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0xa100);
+    }
+}
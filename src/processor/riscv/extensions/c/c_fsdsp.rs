@@ -0,0 +1,69 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE_FP, bits, rs2, s_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CFsdsp;
+
+impl InstrExpand for CFsdsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xa002
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 9, 7) << 6);
+        s_type(imm as i32, rs2(inst), 2, 0b011, OP_STORE_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::{csr, hart::Hart},
+        },
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_fsdsp_expands() {
+        // c.fsdsp fs0, 0(sp) -> fsd fs0, 0(sp)
+        assert_eq!(CFsdsp.expand(0xa022), 0x0081_3027);
+        // c.fsdsp ft11, 504(sp) -> fsd ft11, 504(sp)
+        assert_eq!(CFsdsp.expand(0xbffe), 0x1ff1_3c27);
+    }
+
+    #[test]
+    fn c_fsdsp_stores_double() {
+        let (mut hart, mut bus) = setup(0xa022);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_FS_INITIAL);
+        hart.set_xreg(2, 0x200);
+        hart.set_freg(8, 1.5f64.to_bits());
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(bus.read64(0x200).unwrap(), 1.5f64.to_bits());
+        assert_eq!(hart.pc(), 0x1002);
+    }
+
+    #[test]
+    fn c_fsdsp_illegal_when_fs_off_reports_compressed_bits() {
+        let (mut hart, mut bus) = setup(0xa022);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0xa022);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE_FP, bits, creg, s_type},
+    instruction::InstrExpand,
+};
+
+/// RV32-only; RV64 reuses the encoding for C.SD.
+#[derive(Debug)]
+pub struct CFsw;
+
+impl InstrExpand for CFsw {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xe000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rs2 = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 6) << 2) | (bits(inst, 5, 5) << 6);
+        s_type(imm as i32, rs2, rs1, 0b010, OP_STORE_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_fsw_expands() {
+        // c.fsw fa0, 4(s0) -> fsw fa0, 4(s0)
+        assert_eq!(CFsw.expand(0xe048), 0x00a4_2227);
+        // c.fsw fa5, 124(a4) -> fsw fa5, 124(a4)
+        assert_eq!(CFsw.expand(0xff7c), 0x06f7_2e27);
+    }
+}
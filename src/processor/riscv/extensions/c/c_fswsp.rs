@@ -0,0 +1,34 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE_FP, bits, rs2, s_type},
+    instruction::InstrExpand,
+};
+
+/// RV32-only; RV64 reuses the encoding for C.SDSP.
+#[derive(Debug)]
+pub struct CFswsp;
+
+impl InstrExpand for CFswsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xe002
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let imm = (bits(inst, 12, 9) << 2) | (bits(inst, 8, 7) << 6);
+        s_type(imm as i32, rs2(inst), 2, 0b010, OP_STORE_FP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_fswsp_expands() {
+        // c.fswsp fs0, 0(sp) -> fsw fs0, 0(sp)
+        assert_eq!(CFswsp.expand(0xe022), 0x0081_2027);
+        // c.fswsp ft11, 252(sp) -> fsw ft11, 252(sp)
+        assert_eq!(CFswsp.expand(0xfffe), 0x0ff1_2e27);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::processor::riscv::{
+    extensions::c::{bits, j_type, sext},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CJ;
+
+impl InstrExpand for CJ {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xa001
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let imm = (bits(inst, 12, 12) << 11)
+            | (bits(inst, 11, 11) << 4)
+            | (bits(inst, 10, 9) << 8)
+            | (bits(inst, 8, 8) << 10)
+            | (bits(inst, 7, 7) << 6)
+            | (bits(inst, 6, 6) << 7)
+            | (bits(inst, 5, 3) << 1)
+            | (bits(inst, 2, 2) << 5);
+        j_type(sext(imm, 12), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_j_expands() {
+        // c.j 2046 -> jal zero, 2046
+        assert_eq!(CJ.expand(0xaffd), 0x7fe0_006f);
+        // c.j -2048 -> jal zero, -2048
+        assert_eq!(CJ.expand(0xb001), 0x801f_f06f);
+        // c.j 1366 -> jal zero, 1366
+        assert_eq!(CJ.expand(0xab99), 0x5560_006f);
+    }
+
+    #[test]
+    fn c_j_jumps_relative_to_pc() {
+        // c.j -2048
+        let (mut hart, mut bus) = setup(0xb001);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x1000 - 2048);
+    }
+}
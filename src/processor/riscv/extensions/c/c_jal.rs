@@ -0,0 +1,69 @@
+use crate::processor::riscv::{
+    extensions::c::{bits, j_type, sext},
+    instruction::InstrExpand,
+};
+
+/// RV32-only; RV64 reuses the encoding for C.ADDIW.
+#[derive(Debug)]
+pub struct CJal;
+
+impl InstrExpand for CJal {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x2001
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let imm = (bits(inst, 12, 12) << 11)
+            | (bits(inst, 11, 11) << 4)
+            | (bits(inst, 10, 9) << 8)
+            | (bits(inst, 8, 8) << 10)
+            | (bits(inst, 7, 7) << 6)
+            | (bits(inst, 6, 6) << 7)
+            | (bits(inst, 5, 3) << 1)
+            | (bits(inst, 2, 2) << 5);
+        j_type(sext(imm, 12), 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::hart::{Hart, HartConfig},
+        },
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0x1000, config), bus)
+    }
+
+    #[test]
+    fn c_jal_expands() {
+        // c.jal 2046 -> jal ra, 2046
+        assert_eq!(CJal.expand(0x2ffd), 0x7fe0_00ef);
+        // c.jal -2048 -> jal ra, -2048
+        assert_eq!(CJal.expand(0x3001), 0x801f_f0ef);
+    }
+
+    #[test]
+    fn c_jal_links_next_halfword() {
+        // c.jal -2048
+        let (mut hart, mut bus) = setup(0x3001);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x1000 - 2048);
+        assert_eq!(hart.xreg(1), 0x1002);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_JALR, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CJalr;
+
+impl InstrExpand for CJalr {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xf07f == 0x9002 && rd(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        i_type(0, rd(inst), 0b000, 1, OP_JALR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_jalr_expands() {
+        // c.jalr a0 -> jalr ra, 0(a0)
+        assert_eq!(CJalr.expand(0x9502), 0x0005_00e7);
+        // c.jalr t6 -> jalr ra, 0(t6)
+        assert_eq!(CJalr.expand(0x9f82), 0x000f_80e7);
+    }
+
+    #[test]
+    fn c_jalr_links_past_compressed_instruction() {
+        // c.jalr a0
+        let (mut hart, mut bus) = setup(0x9502);
+        hart.set_xreg(10, 0x4000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(1), 0x1002);
+        assert_eq!(hart.pc(), 0x4000);
+    }
+}
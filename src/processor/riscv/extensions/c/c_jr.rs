@@ -0,0 +1,58 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_JALR, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CJr;
+
+impl InstrExpand for CJr {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xf07f == 0x8002 && rd(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        i_type(0, rd(inst), 0b000, 0, OP_JALR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_jr_expands() {
+        // c.jr ra -> jalr zero, 0(ra)
+        assert_eq!(CJr.expand(0x8082), 0x0000_8067);
+        // c.jr t6 -> jalr zero, 0(t6)
+        assert_eq!(CJr.expand(0x8f82), 0x000f_8067);
+    }
+
+    #[test]
+    fn c_jr_rs1_zero_is_reserved() {
+        assert!(!CJr.matches(0x8002));
+    }
+
+    #[test]
+    fn c_jr_jumps_to_register() {
+        // c.jr a0
+        let (mut hart, mut bus) = setup(0x8502);
+        hart.set_xreg(10, 0x4002);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x4002);
+    }
+}
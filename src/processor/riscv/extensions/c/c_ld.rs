@@ -0,0 +1,58 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD, bits, creg, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CLd;
+
+impl InstrExpand for CLd {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x6000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 5) << 6);
+        i_type(imm as i32, rs1, 0b011, rd, OP_LOAD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_ld_expands() {
+        // c.ld a0, 8(s0) -> ld a0, 8(s0)
+        assert_eq!(CLd.expand(0x6408), 0x0084_3503);
+        // c.ld a5, 248(a4) -> ld a5, 248(a4)
+        assert_eq!(CLd.expand(0x7f7c), 0x0f87_3783);
+    }
+
+    #[test]
+    fn c_ld_loads_doubleword() {
+        // c.ld a0, 248(s1)
+        let (mut hart, mut bus) = setup(0x7ce8);
+        hart.set_xreg(9, 0x2000);
+        bus.write64(0x2000 + 248, 0x1122_3344_5566_7788).unwrap();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0x1122_3344_5566_7788);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD, bits, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CLdsp;
+
+impl InstrExpand for CLdsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x6002 && rd(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        let imm = (bits(inst, 12, 12) << 5) | (bits(inst, 6, 5) << 3) | (bits(inst, 4, 2) << 6);
+        i_type(imm as i32, 2, 0b011, rd, OP_LOAD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_ldsp_expands() {
+        // c.ldsp a0, 0(sp) -> ld a0, 0(sp)
+        assert_eq!(CLdsp.expand(0x6502), 0x0001_3503);
+        // c.ldsp t6, 504(sp) -> ld t6, 504(sp)
+        assert_eq!(CLdsp.expand(0x7ffe), 0x1f81_3f83);
+    }
+
+    #[test]
+    fn c_ldsp_rd_zero_is_reserved() {
+        assert!(!CLdsp.matches(0x6002));
+    }
+
+    #[test]
+    fn c_ldsp_loads_relative_to_sp() {
+        // c.ldsp a0, 504(sp)
+        let (mut hart, mut bus) = setup(0x757e);
+        hart.set_xreg(2, 0x2000);
+        bus.write64(0x2000 + 504, 0x1122_3344_5566_7788).unwrap();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0x1122_3344_5566_7788);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, ci_imm, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CLi;
+
+impl InstrExpand for CLi {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x4001
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        i_type(ci_imm(inst), 0, 0b000, rd, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_li_expands() {
+        // c.li a0, 0 -> addi a0, zero, 0
+        assert_eq!(CLi.expand(0x4501), 0x0000_0513);
+        // c.li t6, -32 -> addi t6, zero, -32
+        assert_eq!(CLi.expand(0x5f81), 0xfe00_0f93);
+    }
+
+    #[test]
+    fn c_li_loads_sign_extended_immediate() {
+        // c.li a0, -1
+        let (mut hart, mut bus) = setup(0x557d);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), u64::MAX);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LUI, bits, rd, sext, u_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CLui;
+
+impl InstrExpand for CLui {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x6001 && rd(inst) != 2 && inst & 0x107c != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        let imm = (bits(inst, 12, 12) << 17) | (bits(inst, 6, 2) << 12);
+        u_type(sext(imm, 18), rd, OP_LUI)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_lui_expands() {
+        // c.lui a0, 1 -> lui a0, 1
+        assert_eq!(CLui.expand(0x6505), 0x0000_1537);
+        // c.lui t6, 0xfffe0 -> lui t6, 0xfffe0
+        assert_eq!(CLui.expand(0x7f81), 0xfffe_0fb7);
+        // c.lui s0, 31 -> lui s0, 31
+        assert_eq!(CLui.expand(0x647d), 0x0001_f437);
+    }
+
+    #[test]
+    fn c_lui_zero_immediate_is_reserved() {
+        assert!(!CLui.matches(0x6501));
+    }
+
+    #[test]
+    fn c_lui_sign_extends_upper_immediate() {
+        // c.lui a0, 0xfffe0
+        let (mut hart, mut bus) = setup(0x7501);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0xffff_ffff_fffe_0000);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD, bits, creg, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CLw;
+
+impl InstrExpand for CLw {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x4000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 6) << 2) | (bits(inst, 5, 5) << 6);
+        i_type(imm as i32, rs1, 0b010, rd, OP_LOAD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_lw_expands() {
+        // c.lw a0, 4(s0) -> lw a0, 4(s0)
+        assert_eq!(CLw.expand(0x4048), 0x0044_2503);
+        // c.lw a5, 124(a4) -> lw a5, 124(a4)
+        assert_eq!(CLw.expand(0x5f7c), 0x07c7_2783);
+    }
+
+    #[test]
+    fn c_lw_loads_sign_extended_word() {
+        // c.lw a0, 124(s1)
+        let (mut hart, mut bus) = setup(0x5ce8);
+        hart.set_xreg(9, 0x2000);
+        bus.write32(0x2000 + 124, 0x8000_0000).unwrap();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0xffff_ffff_8000_0000);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_LOAD, bits, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CLwsp;
+
+impl InstrExpand for CLwsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x4002 && rd(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        let imm = (bits(inst, 12, 12) << 5) | (bits(inst, 6, 4) << 2) | (bits(inst, 3, 2) << 6);
+        i_type(imm as i32, 2, 0b010, rd, OP_LOAD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_lwsp_expands() {
+        // c.lwsp a0, 0(sp) -> lw a0, 0(sp)
+        assert_eq!(CLwsp.expand(0x4502), 0x0001_2503);
+        // c.lwsp t6, 252(sp) -> lw t6, 252(sp)
+        assert_eq!(CLwsp.expand(0x5ffe), 0x0fc1_2f83);
+    }
+
+    #[test]
+    fn c_lwsp_rd_zero_is_reserved() {
+        assert!(!CLwsp.matches(0x4002));
+    }
+
+    #[test]
+    fn c_lwsp_loads_relative_to_sp() {
+        // c.lwsp a0, 252(sp)
+        let (mut hart, mut bus) = setup(0x557e);
+        hart.set_xreg(2, 0x2000);
+        bus.write32(0x2000 + 252, 0x8000_0000).unwrap();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0xffff_ffff_8000_0000);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
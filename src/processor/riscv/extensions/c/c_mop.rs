@@ -0,0 +1,91 @@
+use crate::processor::riscv::{extensions::c::rd, instruction::InstrExpand};
+
+#[derive(Debug)]
+pub struct CMop;
+
+impl InstrExpand for CMop {
+    /// The reserved C.LUI encodings with a zero immediate and an odd `rd` of
+    /// x1-x15.
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xf8ff == 0x6081
+    }
+
+    /// Expands to MOP.R.n x0, xn, which writes no register either.
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let n = rd(inst);
+        (1 << 31)
+            | ((n & 0b1100) << 24)
+            | (0b0111 << 22)
+            | ((n & 0b11) << 20)
+            | (n << 15)
+            | (0b100 << 12)
+            | 0b111_0011
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{
+            Cpu,
+            riscv::{
+                csr,
+                hart::{Extension, Extensions, Hart, HartConfig},
+            },
+        },
+    };
+
+    fn setup(inst: u16, config: HartConfig) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::with_config(0x1000, config), bus)
+    }
+
+    #[test]
+    fn c_mop_expands() {
+        // c.mop.1 -> mop.r.1 zero, ra
+        assert_eq!(CMop.expand(0x6081), 0x81d0_c073);
+        // c.mop.15 -> mop.r.15 zero, a5
+        assert_eq!(CMop.expand(0x6781), 0x8df7_c073);
+    }
+
+    #[test]
+    fn c_mop_excludes_c_lui_and_even_registers() {
+        // c.lui ra, 1
+        assert!(!CMop.matches(0x6085));
+        // c.lui sp, 0 is reserved, not a c.mop
+        assert!(!CMop.matches(0x6101));
+        // c.mop.1 with bit 11 set would name x17
+        assert!(!CMop.matches(0x6881));
+    }
+
+    #[test]
+    fn c_mop_writes_nothing() {
+        // c.mop.3
+        let (mut hart, mut bus) = setup(0x6181, HartConfig::default());
+        hart.set_xreg(3, 0x1234);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), 0x1234);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+
+    #[test]
+    fn c_mop_illegal_without_zcmop() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zcmop),
+            ..HartConfig::default()
+        };
+        let (mut hart, mut bus) = setup(0x6181, config);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x6181);
+    }
+}
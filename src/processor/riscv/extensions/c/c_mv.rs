@@ -0,0 +1,54 @@
+use crate::processor::riscv::{
+    extensions::c::{OP, r_type, rd, rs2},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CMv;
+
+impl InstrExpand for CMv {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xf003 == 0x8002 && rs2(inst) != 0
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        r_type(0, rs2(inst), 0, 0b000, rd(inst), OP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_mv_expands() {
+        // c.mv a0, a1 -> add a0, zero, a1
+        assert_eq!(CMv.expand(0x852e), 0x00b0_0533);
+        // c.mv t6, s0 -> add t6, zero, s0
+        assert_eq!(CMv.expand(0x8fa2), 0x0080_0fb3);
+    }
+
+    #[test]
+    fn c_mv_copies_register() {
+        // c.mv a0, a1
+        let (mut hart, mut bus) = setup(0x852e);
+        hart.set_xreg(11, 42);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 42);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, ci_imm, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CNop;
+
+impl InstrExpand for CNop {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xef83 == 0x0001
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        i_type(ci_imm(inst), 0, 0b000, 0, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_nop_expands() {
+        // c.nop -> addi zero, zero, 0
+        assert_eq!(CNop.expand(0x0001), 0x0000_0013);
+    }
+
+    #[test]
+    fn c_nop_only_advances_pc() {
+        // c.nop
+        let (mut hart, mut bus) = setup(0x0001);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
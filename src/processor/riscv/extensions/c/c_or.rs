@@ -0,0 +1,57 @@
+use crate::processor::riscv::{
+    extensions::c::{OP, creg, r_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct COr;
+
+impl InstrExpand for COr {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xfc63 == 0x8c41
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 7);
+        let rs2 = creg(inst, 2);
+        r_type(0, rs2, rd, 0b110, rd, OP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_or_expands() {
+        // c.or a0, a1 -> or a0, a0, a1
+        assert_eq!(COr.expand(0x8d4d), 0x00b5_6533);
+        // c.or s1, a5 -> or s1, s1, a5
+        assert_eq!(COr.expand(0x8cdd), 0x00f4_e4b3);
+    }
+
+    #[test]
+    fn c_or_ors() {
+        // c.or a0, a1
+        let (mut hart, mut bus) = setup(0x8d4d);
+        hart.set_xreg(10, 0b1100);
+        hart.set_xreg(11, 0b1010);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 0b1110);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE, bits, creg, s_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSd;
+
+impl InstrExpand for CSd {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xe000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rs2 = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 5) << 6);
+        s_type(imm as i32, rs2, rs1, 0b011, OP_STORE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_sd_expands() {
+        // c.sd a0, 8(s0) -> sd a0, 8(s0)
+        assert_eq!(CSd.expand(0xe408), 0x00a4_3423);
+        // c.sd a5, 248(a4) -> sd a5, 248(a4)
+        assert_eq!(CSd.expand(0xff7c), 0x0ef7_3c23);
+    }
+
+    #[test]
+    fn c_sd_stores_doubleword() {
+        // c.sd a0, 248(s1)
+        let (mut hart, mut bus) = setup(0xfce8);
+        hart.set_xreg(9, 0x2000);
+        hart.set_xreg(10, 0x1122_3344_5566_7788);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(bus.read64(0x2000 + 248).unwrap(), 0x1122_3344_5566_7788);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
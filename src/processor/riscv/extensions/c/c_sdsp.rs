@@ -0,0 +1,56 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE, bits, rs2, s_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSdsp;
+
+impl InstrExpand for CSdsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xe002
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 9, 7) << 6);
+        s_type(imm as i32, rs2(inst), 2, 0b011, OP_STORE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_sdsp_expands() {
+        // c.sdsp a0, 0(sp) -> sd a0, 0(sp)
+        assert_eq!(CSdsp.expand(0xe02a), 0x00a1_3023);
+        // c.sdsp t6, 504(sp) -> sd t6, 504(sp)
+        assert_eq!(CSdsp.expand(0xfffe), 0x1ff1_3c23);
+    }
+
+    #[test]
+    fn c_sdsp_stores_relative_to_sp() {
+        // c.sdsp a0, 504(sp)
+        let (mut hart, mut bus) = setup(0xffaa);
+        hart.set_xreg(2, 0x2000);
+        hart.set_xreg(10, 0x1122_3344_5566_7788);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(bus.read64(0x2000 + 504).unwrap(), 0x1122_3344_5566_7788);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
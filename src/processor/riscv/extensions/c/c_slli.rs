@@ -0,0 +1,56 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, bits, i_type, rd},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSlli;
+
+impl InstrExpand for CSlli {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0x0002
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = rd(inst);
+        let shamt = (bits(inst, 12, 12) << 5) | bits(inst, 6, 2);
+        i_type(shamt as i32, rd, 0b001, rd, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_slli_expands() {
+        // c.slli a0, 1 -> slli a0, a0, 1
+        assert_eq!(CSlli.expand(0x0506), 0x0015_1513);
+        // c.slli t6, 63 -> slli t6, t6, 63
+        assert_eq!(CSlli.expand(0x1ffe), 0x03ff_9f93);
+    }
+
+    #[test]
+    fn c_slli_shifts_left() {
+        // c.slli a0, 63
+        let (mut hart, mut bus) = setup(0x157e);
+        hart.set_xreg(10, 3);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 1 << 63);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, bits, creg, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSrai;
+
+impl InstrExpand for CSrai {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xec03 == 0x8401
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 7);
+        let shamt = (bits(inst, 12, 12) << 5) | bits(inst, 6, 2);
+        i_type((0x400 | shamt) as i32, rd, 0b101, rd, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_srai_expands() {
+        // c.srai a0, 1 -> srai a0, a0, 1
+        assert_eq!(CSrai.expand(0x8505), 0x4015_5513);
+        // c.srai s1, 63 -> srai s1, s1, 63
+        assert_eq!(CSrai.expand(0x94fd), 0x43f4_d493);
+    }
+
+    #[test]
+    fn c_srai_shifts_arithmetically() {
+        // c.srai a0, 62
+        let (mut hart, mut bus) = setup(0x9579);
+        hart.set_xreg(10, 1 << 63);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), u64::MAX << 1);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_IMM, bits, creg, i_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSrli;
+
+impl InstrExpand for CSrli {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xec03 == 0x8001
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 7);
+        let shamt = (bits(inst, 12, 12) << 5) | bits(inst, 6, 2);
+        i_type(shamt as i32, rd, 0b101, rd, OP_IMM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_srli_expands() {
+        // c.srli a0, 1 -> srli a0, a0, 1
+        assert_eq!(CSrli.expand(0x8105), 0x0015_5513);
+        // c.srli s1, 63 -> srli s1, s1, 63
+        assert_eq!(CSrli.expand(0x90fd), 0x03f4_d493);
+    }
+
+    #[test]
+    fn c_srli_shifts_logically() {
+        // c.srli a0, 63
+        let (mut hart, mut bus) = setup(0x917d);
+        hart.set_xreg(10, 1 << 63);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 1);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_32, creg, r_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSubw;
+
+impl InstrExpand for CSubw {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xfc63 == 0x9c01
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rd = creg(inst, 7);
+        let rs2 = creg(inst, 2);
+        r_type(0b010_0000, rs2, rd, 0b000, rd, OP_32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_subw_expands() {
+        // c.subw a0, a1 -> subw a0, a0, a1
+        assert_eq!(CSubw.expand(0x9d0d), 0x40b5_053b);
+        // c.subw s1, a5 -> subw s1, s1, a5
+        assert_eq!(CSubw.expand(0x9c9d), 0x40f4_84bb);
+    }
+
+    #[test]
+    fn c_subw_sign_extends_result() {
+        // c.subw a0, a1
+        let (mut hart, mut bus) = setup(0x9d0d);
+        hart.set_xreg(10, 0);
+        hart.set_xreg(11, 1);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), u64::MAX);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE, bits, creg, s_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSw;
+
+impl InstrExpand for CSw {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xc000
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let rs2 = creg(inst, 2);
+        let rs1 = creg(inst, 7);
+        let imm = (bits(inst, 12, 10) << 3) | (bits(inst, 6, 6) << 2) | (bits(inst, 5, 5) << 6);
+        s_type(imm as i32, rs2, rs1, 0b010, OP_STORE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_sw_expands() {
+        // c.sw a0, 4(s0) -> sw a0, 4(s0)
+        assert_eq!(CSw.expand(0xc048), 0x00a4_2223);
+        // c.sw a5, 124(a4) -> sw a5, 124(a4)
+        assert_eq!(CSw.expand(0xdf7c), 0x06f7_2e23);
+    }
+
+    #[test]
+    fn c_sw_stores_word() {
+        // c.sw a0, 124(s1)
+        let (mut hart, mut bus) = setup(0xdce8);
+        hart.set_xreg(9, 0x2000);
+        hart.set_xreg(10, 0x1122_3344_5566_7788);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(bus.read64(0x2000 + 124 - 4).unwrap(), 0x5566_7788_0000_0000);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
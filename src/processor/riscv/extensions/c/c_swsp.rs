@@ -0,0 +1,56 @@
+use crate::processor::riscv::{
+    extensions::c::{OP_STORE, bits, rs2, s_type},
+    instruction::InstrExpand,
+};
+
+#[derive(Debug)]
+pub struct CSwsp;
+
+impl InstrExpand for CSwsp {
+    #[inline(always)]
+    fn matches(&self, inst: u16) -> bool {
+        inst & 0xe003 == 0xc002
+    }
+
+    #[inline(always)]
+    fn expand(&self, inst: u16) -> u32 {
+        let imm = (bits(inst, 12, 9) << 2) | (bits(inst, 8, 7) << 6);
+        s_type(imm as i32, rs2(inst), 2, 0b010, OP_STORE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::hart::Hart},
+    };
+
+    fn setup(inst: u16) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write16(0x1000, inst).unwrap();
+        (Hart::new(0x1000), bus)
+    }
+
+    #[test]
+    fn c_swsp_expands() {
+        // c.swsp a0, 0(sp) -> sw a0, 0(sp)
+        assert_eq!(CSwsp.expand(0xc02a), 0x00a1_2023);
+        // c.swsp t6, 252(sp) -> sw t6, 252(sp)
+        assert_eq!(CSwsp.expand(0xdffe), 0x0ff1_2e23);
+    }
+
+    #[test]
+    fn c_swsp_stores_relative_to_sp() {
+        // c.swsp a0, 252(sp)
+        let (mut hart, mut bus) = setup(0xdfaa);
+        hart.set_xreg(2, 0x2000);
+        hart.set_xreg(10, 0x1122_3344_5566_7788);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(bus.read32(0x2000 + 252).unwrap(), 0x5566_7788);
+        assert_eq!(hart.pc(), 0x1002);
+    }
+}
@@ -0,0 +1,150 @@
+pub mod c_add;
+pub mod c_addi;
+pub mod c_addi16sp;
+pub mod c_addi4spn;
+pub mod c_addiw;
+pub mod c_addw;
+pub mod c_and;
+pub mod c_andi;
+pub mod c_beqz;
+pub mod c_bnez;
+pub mod c_ebreak;
+pub mod c_fld;
+pub mod c_fldsp;
+pub mod c_flw;
+pub mod c_flwsp;
+pub mod c_fsd;
+pub mod c_fsdsp;
+pub mod c_fsw;
+pub mod c_fswsp;
+pub mod c_j;
+pub mod c_jal;
+pub mod c_jalr;
+pub mod c_jr;
+pub mod c_ld;
+pub mod c_ldsp;
+pub mod c_li;
+pub mod c_lui;
+pub mod c_lw;
+pub mod c_lwsp;
+pub mod c_mop;
+pub mod c_mv;
+pub mod c_nop;
+pub mod c_or;
+pub mod c_sd;
+pub mod c_sdsp;
+pub mod c_slli;
+pub mod c_srai;
+pub mod c_srli;
+pub mod c_sub;
+pub mod c_subw;
+pub mod c_sw;
+pub mod c_swsp;
+pub mod c_xor;
+
+pub(crate) const OP_LOAD: u32 = 0b000_0011;
+pub(crate) const OP_LOAD_FP: u32 = 0b000_0111;
+pub(crate) const OP_IMM: u32 = 0b001_0011;
+pub(crate) const OP_IMM_32: u32 = 0b001_1011;
+pub(crate) const OP_STORE: u32 = 0b010_0011;
+pub(crate) const OP_STORE_FP: u32 = 0b010_0111;
+pub(crate) const OP: u32 = 0b011_0011;
+pub(crate) const OP_LUI: u32 = 0b011_0111;
+pub(crate) const OP_32: u32 = 0b011_1011;
+pub(crate) const OP_BRANCH: u32 = 0b110_0011;
+pub(crate) const OP_JALR: u32 = 0b110_0111;
+pub(crate) const OP_JAL: u32 = 0b110_1111;
+
+/// Extracts bits `hi..=lo` of a compressed instruction.
+#[inline(always)]
+pub(crate) const fn bits(inst: u16, hi: u32, lo: u32) -> u32 {
+    (inst as u32 >> lo) & ((1 << (hi - lo + 1)) - 1)
+}
+
+/// Sign-extends the low `width` bits of `val`.
+#[inline(always)]
+pub(crate) const fn sext(val: u32, width: u32) -> i32 {
+    ((val << (32 - width)) as i32) >> (32 - width)
+}
+
+/// The full register field at bits 11:7, `rd` or `rs1`.
+#[inline(always)]
+pub(crate) const fn rd(inst: u16) -> u32 {
+    bits(inst, 11, 7)
+}
+
+/// The full register field at bits 6:2.
+#[inline(always)]
+pub(crate) const fn rs2(inst: u16) -> u32 {
+    bits(inst, 6, 2)
+}
+
+/// Maps the three-bit register field starting at bit `lo` onto x8-x15.
+#[inline(always)]
+pub(crate) const fn creg(inst: u16, lo: u32) -> u32 {
+    8 + bits(inst, lo + 2, lo)
+}
+
+/// The sign-extended six-bit immediate of the CI format, split across bit 12
+/// and bits 6:2.
+#[inline(always)]
+pub(crate) const fn ci_imm(inst: u16) -> i32 {
+    sext((bits(inst, 12, 12) << 5) | bits(inst, 6, 2), 6)
+}
+
+#[inline(always)]
+pub(crate) const fn r_type(
+    funct7: u32,
+    rs2: u32,
+    rs1: u32,
+    funct3: u32,
+    rd: u32,
+    opcode: u32,
+) -> u32 {
+    (funct7 << 25) | (rs2 << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+#[inline(always)]
+pub(crate) const fn i_type(imm: i32, rs1: u32, funct3: u32, rd: u32, opcode: u32) -> u32 {
+    ((imm as u32 & 0xfff) << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+#[inline(always)]
+pub(crate) const fn s_type(imm: i32, rs2: u32, rs1: u32, funct3: u32, opcode: u32) -> u32 {
+    let imm = imm as u32;
+    (((imm >> 5) & 0x7f) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | ((imm & 0x1f) << 7)
+        | opcode
+}
+
+#[inline(always)]
+pub(crate) const fn b_type(imm: i32, rs2: u32, rs1: u32, funct3: u32) -> u32 {
+    let imm = imm as u32;
+    (((imm >> 12) & 0x1) << 31)
+        | (((imm >> 5) & 0x3f) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (funct3 << 12)
+        | (((imm >> 1) & 0xf) << 8)
+        | (((imm >> 11) & 0x1) << 7)
+        | OP_BRANCH
+}
+
+#[inline(always)]
+pub(crate) const fn u_type(imm: i32, rd: u32, opcode: u32) -> u32 {
+    (imm as u32 & 0xffff_f000) | (rd << 7) | opcode
+}
+
+#[inline(always)]
+pub(crate) const fn j_type(imm: i32, rd: u32) -> u32 {
+    let imm = imm as u32;
+    (((imm >> 20) & 0x1) << 31)
+        | (((imm >> 1) & 0x3ff) << 21)
+        | (((imm >> 11) & 0x1) << 20)
+        | (((imm >> 12) & 0xff) << 12)
+        | (rd << 7)
+        | OP_JAL
+}
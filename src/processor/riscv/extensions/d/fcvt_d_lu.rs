@@ -0,0 +1,109 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F64, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FcvtDLu;
+
+impl InstrExec for FcvtDLu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_007f == 0xd230_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCVT.@S.LU", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.from_int(F64, hart.xreg(rs1), 64, false);
+
+        hart.write_freg(F64, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::FLAG_NX},
+    };
+
+    fn encode_fcvt_d_lu(rd: u32, rs1: u32, rm: u32) -> u32 {
+        (0b1101001 << 25) | (0b00011 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f64 {
+        f64::from_bits(hart.read_freg(F64, i))
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FcvtDLu
+            .call(inst, hart, bus)
+            .expect("FCVT.@S.LU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fcvt_d_lu_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1 << 40);
+
+        exec(encode_fcvt_d_lu(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 1_099_511_627_776.0);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_d_lu_max() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, u64::MAX);
+
+        exec(encode_fcvt_d_lu(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 18_446_744_073_709_551_616.0);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+
+    #[test]
+    fn fcvt_d_lu_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fcvt_d_lu(2, 1, 0b000);
+
+        let err = FcvtDLu.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
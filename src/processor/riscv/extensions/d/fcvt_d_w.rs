@@ -0,0 +1,109 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F64, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FcvtDW;
+
+impl InstrExec for FcvtDW {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_007f == 0xd200_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCVT.@S.W", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.from_int(F64, hart.xreg(rs1), 32, true);
+
+        hart.write_freg(F64, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_fcvt_d_w(rd: u32, rs1: u32, rm: u32) -> u32 {
+        (0b1101001 << 25) | (0b00000 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f64 {
+        f64::from_bits(hart.read_freg(F64, i))
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FcvtDW
+            .call(inst, hart, bus)
+            .expect("FCVT.@S.W execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fcvt_d_w_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, -7i64 as u64);
+
+        exec(encode_fcvt_d_w(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), -7.0);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_d_w_ignores_upper_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_0000_0005);
+
+        exec(encode_fcvt_d_w(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 5.0);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_d_w_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fcvt_d_w(2, 1, 0b000);
+
+        let err = FcvtDW.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F32, F64, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FcvtSD;
+
+impl InstrExec for FcvtSD {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_007f == 0x4010_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCVT.S.D", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.convert(F64, F32, hart.read_freg(F64, rs1));
+
+        hart.write_freg(F32, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            softfloat::{FLAG_NV, FLAG_NX},
+        },
+    };
+
+    fn encode_fcvt_s_d(rd: u32, rs1: u32, rm: u32) -> u32 {
+        (0b0100000 << 25) | (0b00001 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FcvtSD
+            .call(inst, hart, bus)
+            .expect("FCVT.S.D execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fcvt_s_d_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 1.5f64.to_bits());
+
+        exec(encode_fcvt_s_d(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(2), 0xffff_ffff_3fc0_0000);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_s_d_inexact() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0.1f64.to_bits());
+
+        exec(encode_fcvt_s_d(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.read_freg(F32, 2), u64::from(0.1f32.to_bits()));
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+
+    #[test]
+    fn fcvt_s_d_nan_is_canonical() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0xfff0_0000_0000_0001);
+
+        exec(encode_fcvt_s_d(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.read_freg(F32, 2), F32.canonical_nan());
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn fcvt_s_d_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fcvt_s_d(2, 1, 0b000);
+
+        let err = FcvtSD.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F64, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FltD;
+
+impl InstrExec for FltD {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0xa200_1053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FLT.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::default();
+        let val = sf.lt(F64, hart.read_freg(F64, rs1), hart.read_freg(F64, rs2));
+
+        hart.set_xreg(rd, u64::from(val));
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::FLAG_NV},
+    };
+
+    fn encode_flt_d(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b1010001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f64) {
+        hart.write_freg(F64, i, val.to_bits());
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FltD.call(inst, hart, bus)
+            .expect("FLT.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn flt_d_true() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, -1.0);
+        set_f(&mut hart, 2, 1.0);
+
+        exec(encode_flt_d(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn flt_d_false() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(3, 7);
+        set_f(&mut hart, 1, 1.0);
+        set_f(&mut hart, 2, 1.0);
+
+        exec(encode_flt_d(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn flt_d_quiet_nan_is_invalid() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f64::NAN);
+        set_f(&mut hart, 2, 1.0);
+
+        exec(encode_flt_d(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn flt_d_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_flt_d(3, 1, 2);
+
+        let err = FltD.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F64, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FminD;
+
+impl InstrExec for FminD {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x2a00_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FMIN.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::default();
+        let val = sf.min(F64, hart.read_freg(F64, rs1), hart.read_freg(F64, rs2));
+
+        hart.write_freg(F64, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::FLAG_NV},
+    };
+
+    fn encode_fmin_d(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0010101 << 25) | (rs2 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f64) {
+        hart.write_freg(F64, i, val.to_bits());
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f64 {
+        f64::from_bits(hart.read_freg(F64, i))
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FminD
+            .call(inst, hart, bus)
+            .expect("FMIN.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fmin_d_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 1.0);
+        set_f(&mut hart, 2, -2.0);
+
+        exec(encode_fmin_d(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), -2.0);
+    }
+
+    #[test]
+    fn fmin_d_signed_zeros() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0.0);
+        set_f(&mut hart, 2, -0.0);
+
+        exec(encode_fmin_d(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.read_freg(F64, 3), F64.sign());
+    }
+
+    #[test]
+    fn fmin_d_quiet_nan_operand_ignored() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f64::NAN);
+        set_f(&mut hart, 2, 5.0);
+
+        exec(encode_fmin_d(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 5.0);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fmin_d_signaling_nan_is_invalid() {
+        let (mut hart, mut bus) = setup();
+        hart.write_freg(F64, 1, 0x7ff0_0000_0000_0001);
+        hart.write_freg(F64, 2, F64.canonical_nan());
+
+        exec(encode_fmin_d(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.read_freg(F64, 3), F64.canonical_nan());
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn fmin_d_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fmin_d(3, 1, 2);
+
+        let err = FminD.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F64, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FmsubD;
+
+impl InstrExec for FmsubD {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x0600_007f == 0x0200_0047
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FMSUB.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty, rs3 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+        let rs3 = ((inst >> 27) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+            span.record("rs3", Hart::FABI[rs3]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        // a * b - c
+        let val = sf.mul_add(
+            F64,
+            hart.read_freg(F64, rs1),
+            hart.read_freg(F64, rs2),
+            hart.read_freg(F64, rs3) ^ F64.sign(),
+        );
+
+        hart.write_freg(F64, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_fmsub_d(rd: u32, rs1: u32, rs2: u32, rs3: u32, rm: u32) -> u32 {
+        (rs3 << 27) | (0b01 << 25) | (rs2 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1000111
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f64) {
+        hart.write_freg(F64, i, val.to_bits());
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f64 {
+        f64::from_bits(hart.read_freg(F64, i))
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FmsubD
+            .call(inst, hart, bus)
+            .expect("FMSUB.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fmsub_d_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 2.0);
+        set_f(&mut hart, 2, 3.0);
+        set_f(&mut hart, 3, 1.0);
+
+        exec(encode_fmsub_d(4, 1, 2, 3, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 4), 5.0);
+    }
+
+    #[test]
+    fn fmsub_d_rounds_once() {
+        let (mut hart, mut bus) = setup();
+        let a = 1.0 + 2f64.powi(-27);
+        set_f(&mut hart, 1, a);
+        set_f(&mut hart, 2, a);
+        set_f(&mut hart, 3, 1.0 + 2f64.powi(-26));
+
+        exec(encode_fmsub_d(4, 1, 2, 3, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 4), 2f64.powi(-54));
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fmsub_d_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fmsub_d(4, 1, 2, 3, 0b000);
+
+        let err = FmsubD.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Fsd;
+
+impl InstrExec for Fsd {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x707f == 0x0000_3027
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FSD", skip_all, fields(rs1 = tracing::field::Empty, rs2 = tracing::field::Empty, imm = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+        let imm = ((((inst >> 7) & 0x1f) | (((inst >> 25) & 0x7f) << 5)) as i32) << 20 >> 20;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+            span.record("imm", imm);
+        }
+
+        hart.check_fp(inst)?;
+        let addr = hart.xreg(rs1).wrapping_add_signed(imm as i64);
+        hart.write64(bus, addr, hart.freg(rs2))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::F64},
+    };
+
+    fn encode_fsd(rs2: u32, rs1: u32, imm: i16) -> u32 {
+        let imm = imm as u32;
+        ((imm >> 5) & 0x7f) << 25
+            | (rs2 << 20)
+            | (rs1 << 15)
+            | (0b011 << 12)
+            | (imm & 0x1f) << 7
+            | 0b0100111
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f64) {
+        hart.write_freg(F64, i, val.to_bits());
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Fsd.call(inst, hart, bus)
+            .expect("FSD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fsd_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        set_f(&mut hart, 2, -2.5);
+
+        exec(encode_fsd(2, 1, 8), &mut hart, &mut bus);
+
+        assert_eq!(bus.read64(0x108).unwrap(), (-2.5f64).to_bits());
+    }
+
+    #[test]
+    fn fsd_negative_offset() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x208);
+        hart.set_freg(2, 0xdead_beef_cafe_f00d);
+
+        exec(encode_fsd(2, 1, -8), &mut hart, &mut bus);
+
+        assert_eq!(bus.read64(0x200).unwrap(), 0xdead_beef_cafe_f00d);
+    }
+
+    #[test]
+    fn fsd_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fsd(2, 1, 0);
+
+        let err = Fsd.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
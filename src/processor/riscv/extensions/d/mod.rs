@@ -0,0 +1,32 @@
+pub mod fadd_d;
+pub mod fclass_d;
+pub mod fcvt_d_l;
+pub mod fcvt_d_lu;
+pub mod fcvt_d_s;
+pub mod fcvt_d_w;
+pub mod fcvt_d_wu;
+pub mod fcvt_l_d;
+pub mod fcvt_lu_d;
+pub mod fcvt_s_d;
+pub mod fcvt_w_d;
+pub mod fcvt_wu_d;
+pub mod fdiv_d;
+pub mod feq_d;
+pub mod fld;
+pub mod fle_d;
+pub mod flt_d;
+pub mod fmadd_d;
+pub mod fmax_d;
+pub mod fmin_d;
+pub mod fmsub_d;
+pub mod fmul_d;
+pub mod fmv_d_x;
+pub mod fmv_x_d;
+pub mod fnmadd_d;
+pub mod fnmsub_d;
+pub mod fsd;
+pub mod fsgnj_d;
+pub mod fsgnjn_d;
+pub mod fsgnjx_d;
+pub mod fsqrt_d;
+pub mod fsub_d;
@@ -0,0 +1,189 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F32, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FaddS;
+
+impl InstrExec for FaddS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_007f == 0x0000_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FADD.S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.add(F32, hart.read_freg(F32, rs1), hart.read_freg(F32, rs2));
+
+        hart.write_freg(F32, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::FLAG_NX},
+    };
+
+    fn encode_fadd_s(rd: u32, rs1: u32, rs2: u32, rm: u32) -> u32 {
+        (0b0000000 << 25) | (rs2 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f32 {
+        f32::from_bits(hart.read_freg(F32, i) as u32)
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FaddS
+            .call(inst, hart, bus)
+            .expect("FADD.S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fadd_s_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 1.5);
+        set_f(&mut hart, 2, 2.25);
+
+        exec(encode_fadd_s(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 3.75);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fadd_s_inexact_sets_fflags() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 1.0);
+        set_f(&mut hart, 2, f32::EPSILON / 4.0);
+
+        exec(encode_fadd_s(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 1.0);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+
+    #[test]
+    fn fadd_s_static_rounding_mode() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 1.0);
+        set_f(&mut hart, 2, f32::EPSILON / 4.0);
+
+        exec(encode_fadd_s(3, 1, 2, 0b011), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 1.0 + f32::EPSILON);
+    }
+
+    #[test]
+    fn fadd_s_dynamic_rounding_mode_uses_frm() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::FCSR, 0b011 << 5);
+        set_f(&mut hart, 1, 1.0);
+        set_f(&mut hart, 2, f32::EPSILON / 4.0);
+
+        exec(encode_fadd_s(3, 1, 2, 0b111), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 1.0 + f32::EPSILON);
+        assert_eq!(
+            hart.csrs().get(csr::FCSR),
+            (0b011 << 5) | u64::from(FLAG_NX)
+        );
+    }
+
+    #[test]
+    fn fadd_s_reserved_rounding_mode_is_illegal() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_fadd_s(3, 1, 2, 0b101);
+
+        let err = FaddS.call(inst, &mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+
+        hart.csrs_mut().set(csr::FCSR, 0b110 << 5);
+        let inst = encode_fadd_s(3, 1, 2, 0b111);
+
+        let err = FaddS.call(inst, &mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn fadd_s_marks_fs_dirty() {
+        let (mut hart, mut bus) = setup();
+
+        exec(encode_fadd_s(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(
+            hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_FS,
+            csr::MSTATUS_FS_DIRTY
+        );
+    }
+
+    #[test]
+    fn fadd_s_unboxed_operand_is_canonical_nan() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0x3f80_0000);
+        set_f(&mut hart, 2, 1.0);
+
+        exec(encode_fadd_s(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(3), 0xffff_ffff_7fc0_0000);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fadd_s_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fadd_s(3, 1, 2, 0b000);
+
+        let err = FaddS.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
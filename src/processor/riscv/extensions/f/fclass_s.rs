@@ -0,0 +1,125 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F32, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FclassS;
+
+impl InstrExec for FclassS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0xe000_1053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCLASS.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_fp(inst)?;
+        let val = SoftFloat::classify(F32, hart.read_freg(F32, rs1));
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_fclass_s(rd: u32, rs1: u32) -> u32 {
+        (0b1110000 << 25) | (0b00000 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FclassS
+            .call(inst, hart, bus)
+            .expect("FCLASS.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fclass_s_negative_infinity() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f32::NEG_INFINITY);
+
+        exec(encode_fclass_s(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 1 << 0);
+    }
+
+    #[test]
+    fn fclass_s_positive_subnormal() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f32::MIN_POSITIVE / 2.0);
+
+        exec(encode_fclass_s(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 1 << 5);
+    }
+
+    #[test]
+    fn fclass_s_quiet_nan() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f32::NAN);
+
+        exec(encode_fclass_s(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 1 << 9);
+    }
+
+    #[test]
+    fn fclass_s_unboxed_is_quiet_nan() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0xff80_0000);
+
+        exec(encode_fclass_s(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 1 << 9);
+    }
+
+    #[test]
+    fn fclass_s_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fclass_s(2, 1);
+
+        let err = FclassS.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
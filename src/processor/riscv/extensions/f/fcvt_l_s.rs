@@ -0,0 +1,124 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F32, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FcvtLS;
+
+impl InstrExec for FcvtLS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_007f == 0xc020_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCVT.L.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.to_int(F32, hart.read_freg(F32, rs1), 64, true);
+
+        hart.set_xreg(rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            softfloat::{FLAG_NV, FLAG_NX},
+        },
+    };
+
+    fn encode_fcvt_l_s(rd: u32, rs1: u32, rm: u32) -> u32 {
+        (0b1100000 << 25) | (0b00010 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FcvtLS
+            .call(inst, hart, bus)
+            .expect("FCVT.L.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fcvt_l_s_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 1e10);
+
+        exec(encode_fcvt_l_s(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 10_000_000_000);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_l_s_negative_infinity_saturates() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f32::NEG_INFINITY);
+
+        exec(encode_fcvt_l_s(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), i64::MIN as u64);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn fcvt_l_s_rounds_down() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, -2.5);
+
+        exec(encode_fcvt_l_s(2, 1, 0b010), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), -3i64 as u64);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+
+    #[test]
+    fn fcvt_l_s_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fcvt_l_s(2, 1, 0b000);
+
+        let err = FcvtLS.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F32, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FcvtLuS;
+
+impl InstrExec for FcvtLuS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_007f == 0xc030_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCVT.LU.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.to_int(F32, hart.read_freg(F32, rs1), 64, false);
+
+        hart.set_xreg(rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::FLAG_NV},
+    };
+
+    fn encode_fcvt_lu_s(rd: u32, rs1: u32, rm: u32) -> u32 {
+        (0b1100000 << 25) | (0b00011 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FcvtLuS
+            .call(inst, hart, bus)
+            .expect("FCVT.LU.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fcvt_lu_s_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 9223372036854775808.0);
+
+        exec(encode_fcvt_lu_s(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 1 << 63);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_lu_s_infinity_saturates() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f32::INFINITY);
+
+        exec(encode_fcvt_lu_s(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), u64::MAX);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn fcvt_lu_s_negative_is_invalid() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, -1.0);
+
+        exec(encode_fcvt_lu_s(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 0);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn fcvt_lu_s_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fcvt_lu_s(2, 1, 0b000);
+
+        let err = FcvtLuS.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
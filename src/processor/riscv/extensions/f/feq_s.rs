@@ -0,0 +1,134 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F32, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FeqS;
+
+impl InstrExec for FeqS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0xa000_2053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FEQ.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::default();
+        let val = sf.eq(F32, hart.read_freg(F32, rs1), hart.read_freg(F32, rs2));
+
+        hart.set_xreg(rd, u64::from(val));
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_feq_s(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b1010000 << 25) | (rs2 << 20) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FeqS.call(inst, hart, bus)
+            .expect("FEQ.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn feq_s_true() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 1.5);
+        set_f(&mut hart, 2, 1.5);
+
+        exec(encode_feq_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn feq_s_false() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(3, 7);
+        set_f(&mut hart, 1, 1.0);
+        set_f(&mut hart, 2, 2.0);
+
+        exec(encode_feq_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn feq_s_quiet_nan_is_quiet() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f32::NAN);
+        set_f(&mut hart, 2, 1.0);
+
+        exec(encode_feq_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn feq_s_signed_zeros_equal() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, -0.0);
+        set_f(&mut hart, 2, 0.0);
+
+        exec(encode_feq_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn feq_s_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_feq_s(3, 1, 2);
+
+        let err = FeqS.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
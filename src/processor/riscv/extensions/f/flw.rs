@@ -0,0 +1,106 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec, softfloat::F32},
+};
+
+#[derive(Debug)]
+pub struct Flw;
+
+impl InstrExec for Flw {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x707f == 0x0000_2007
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FLW", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, imm = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let imm = ((inst as i32) >> 20) as i64;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("imm", imm);
+        }
+
+        hart.check_fp(inst)?;
+        let addr = hart.xreg(rs1).wrapping_add_signed(imm);
+        let val = hart.read32(bus, addr)?;
+
+        hart.write_freg(F32, rd, u64::from(val));
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_flw(rd: u32, rs1: u32, imm: i16) -> u32 {
+        let imm12 = (imm as u32) & 0xfff;
+        (imm12 << 20) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b0000111
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f32 {
+        f32::from_bits(hart.read_freg(F32, i) as u32)
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Flw.call(inst, hart, bus)
+            .expect("FLW execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn flw_nan_boxes() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        bus.write32(0x104, 0x3fc0_0000).unwrap();
+
+        exec(encode_flw(2, 1, 4), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(2), 0xffff_ffff_3fc0_0000);
+    }
+
+    #[test]
+    fn flw_negative_offset() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x208);
+        bus.write32(0x200, 0xbf80_0000).unwrap();
+
+        exec(encode_flw(3, 1, -8), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), -1.0);
+    }
+
+    #[test]
+    fn flw_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_flw(2, 1, 0);
+
+        let err = Flw.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
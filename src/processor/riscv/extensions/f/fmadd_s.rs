@@ -0,0 +1,139 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::{F32, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FmaddS;
+
+impl InstrExec for FmaddS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x0600_007f == 0x0000_0043
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FMADD.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty, rs3 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+        let rs3 = ((inst >> 27) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+            span.record("rs3", Hart::FABI[rs3]);
+        }
+
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.mul_add(
+            F32,
+            hart.read_freg(F32, rs1),
+            hart.read_freg(F32, rs2),
+            hart.read_freg(F32, rs3),
+        );
+
+        hart.write_freg(F32, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::FLAG_NV},
+    };
+
+    fn encode_fmadd_s(rd: u32, rs1: u32, rs2: u32, rs3: u32, rm: u32) -> u32 {
+        (rs3 << 27) | (0b00 << 25) | (rs2 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1000011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f32 {
+        f32::from_bits(hart.read_freg(F32, i) as u32)
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FmaddS
+            .call(inst, hart, bus)
+            .expect("FMADD.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fmadd_s_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 2.0);
+        set_f(&mut hart, 2, 3.0);
+        set_f(&mut hart, 3, 1.0);
+
+        exec(encode_fmadd_s(4, 1, 2, 3, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 4), 7.0);
+    }
+
+    #[test]
+    fn fmadd_s_rounds_once() {
+        let (mut hart, mut bus) = setup();
+        let a = 1.0 + 2f32.powi(-12);
+        set_f(&mut hart, 1, a);
+        set_f(&mut hart, 2, a);
+        set_f(&mut hart, 3, -(1.0 + 2f32.powi(-11)));
+
+        exec(encode_fmadd_s(4, 1, 2, 3, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 4), 2f32.powi(-24));
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fmadd_s_infinity_times_zero_is_invalid_with_quiet_nan_addend() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, f32::INFINITY);
+        set_f(&mut hart, 2, 0.0);
+        set_f(&mut hart, 3, f32::NAN);
+
+        exec(encode_fmadd_s(4, 1, 2, 3, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.read_freg(F32, 4), F32.canonical_nan());
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn fmadd_s_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fmadd_s(4, 1, 2, 3, 0b000);
+
+        let err = FmaddS.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
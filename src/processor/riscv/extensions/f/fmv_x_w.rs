@@ -0,0 +1,101 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct FmvXW;
+
+impl InstrExec for FmvXW {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0xe000_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FMV.X.W", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_fp(inst)?;
+        let val = hart.freg(rs1) as i32 as i64 as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::F32},
+    };
+
+    fn encode_fmv_x_w(rd: u32, rs1: u32) -> u32 {
+        (0b1110000 << 25) | (0b00000 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FmvXW
+            .call(inst, hart, bus)
+            .expect("FMV.X.W execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fmv_x_w_sign_extends() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, -1.0);
+
+        exec(encode_fmv_x_w(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 0xffff_ffff_bf80_0000);
+    }
+
+    #[test]
+    fn fmv_x_w_ignores_nan_boxing() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0x1234_5678_3f80_0000);
+
+        exec(encode_fmv_x_w(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 0x3f80_0000);
+    }
+
+    #[test]
+    fn fmv_x_w_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fmv_x_w(2, 1);
+
+        let err = FmvXW.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
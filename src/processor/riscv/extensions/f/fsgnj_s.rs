@@ -0,0 +1,136 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec, softfloat::F32},
+};
+
+#[derive(Debug)]
+pub struct FsgnjS;
+
+impl InstrExec for FsgnjS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x2000_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FSGNJ.@S", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+        }
+
+        hart.check_fp(inst)?;
+        let a = hart.read_freg(F32, rs1);
+        let b = hart.read_freg(F32, rs2);
+        let val = (a & !F32.sign()) | (b & F32.sign());
+
+        hart.write_freg(F32, rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_fsgnj_s(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0010000 << 25) | (rs2 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: f32) {
+        hart.write_freg(F32, i, u64::from(val.to_bits()));
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> f32 {
+        f32::from_bits(hart.read_freg(F32, i) as u32)
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FsgnjS
+            .call(inst, hart, bus)
+            .expect("FSGNJ.@S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fsgnj_s_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 3.0);
+        set_f(&mut hart, 2, -1.0);
+
+        exec(encode_fsgnj_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), -3.0);
+    }
+
+    #[test]
+    fn fsgnj_s_positive_sign() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, -3.0);
+        set_f(&mut hart, 2, 1.0);
+
+        exec(encode_fsgnj_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 3.0);
+    }
+
+    #[test]
+    fn fsgnj_s_nan_payload_preserved() {
+        let (mut hart, mut bus) = setup();
+        hart.write_freg(F32, 1, F32.canonical_nan() | 1);
+        set_f(&mut hart, 2, -1.0);
+
+        exec(encode_fsgnj_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(
+            hart.read_freg(F32, 3) & !F32.sign(),
+            F32.canonical_nan() | 1
+        );
+    }
+
+    #[test]
+    fn fsgnj_s_unboxed_operand_is_canonical_nan() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0x3f80_0000);
+        set_f(&mut hart, 2, 1.0);
+
+        exec(encode_fsgnj_s(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(3), 0xffff_ffff_7fc0_0000);
+    }
+
+    #[test]
+    fn fsgnj_s_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fsgnj_s(3, 1, 2);
+
+        let err = FsgnjS.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,30 @@
+pub mod fadd_s;
+pub mod fclass_s;
+pub mod fcvt_l_s;
+pub mod fcvt_lu_s;
+pub mod fcvt_s_l;
+pub mod fcvt_s_lu;
+pub mod fcvt_s_w;
+pub mod fcvt_s_wu;
+pub mod fcvt_w_s;
+pub mod fcvt_wu_s;
+pub mod fdiv_s;
+pub mod feq_s;
+pub mod fle_s;
+pub mod flt_s;
+pub mod flw;
+pub mod fmadd_s;
+pub mod fmax_s;
+pub mod fmin_s;
+pub mod fmsub_s;
+pub mod fmul_s;
+pub mod fmv_w_x;
+pub mod fmv_x_w;
+pub mod fnmadd_s;
+pub mod fnmsub_s;
+pub mod fsgnj_s;
+pub mod fsgnjn_s;
+pub mod fsgnjx_s;
+pub mod fsqrt_s;
+pub mod fsub_s;
+pub mod fsw;
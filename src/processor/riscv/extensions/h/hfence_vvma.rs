@@ -0,0 +1,158 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct HfenceVvma;
+
+impl InstrExec for HfenceVvma {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_7fff == 0x2200_0073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "HFENCE.VVMA", skip_all, fields(rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_hypervisor(inst, false)?;
+
+        let vaddr = (rs1 != 0).then(|| hart.xreg(rs1));
+        let asid = (rs2 != 0).then(|| hart.xreg(rs2) as u16);
+        hart.hfence_vvma(vaddr, asid);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            hart::Privilege,
+            mmu::{PTE_A, PTE_D, PTE_R, PTE_V, PTE_W, SATP_MODE_SV39},
+            pmp::{PMP_A_NAPOT, PMP_R, PMP_W, PMP_X, PMPADDR_MASK},
+        },
+    };
+
+    fn encode_hfence_vvma(rs1: u32, rs2: u32) -> u32 {
+        (0b0010001 << 25) | (rs2 << 20) | (rs1 << 15) | 0b1110011
+    }
+
+    const ROOT: u64 = 0x1000;
+
+    /// Runs in HS-mode with a guest whose VS-stage maps its page at 0x5000
+    /// to 0x8000 through Sv39 tables at 0x1000, 0x2000 and 0x3000, under VMID
+    /// 1 and a Bare G-stage.
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W | PMP_X));
+        hart.csrs_mut()
+            .set(csr::VSATP, (SATP_MODE_SV39 << 60) | (ROOT >> 12));
+        hart.csrs_mut().set(csr::HGATP, 1 << 44);
+        hart.csrs_mut().set(csr::HSTATUS, csr::HSTATUS_SPVP);
+        bus.write64(ROOT, (0x2000 >> 12 << 10) | PTE_V).unwrap();
+        bus.write64(0x2000, (0x3000 >> 12 << 10) | PTE_V).unwrap();
+        map(&mut bus, 0x8000);
+        bus.write64(0x8000, 0xaa).unwrap();
+        bus.write64(0x9000, 0xbb).unwrap();
+        (hart, bus)
+    }
+
+    fn map(bus: &mut Mmap, paddr: u64) {
+        let pte = (paddr >> 12 << 10) | PTE_R | PTE_W | PTE_A | PTE_D | PTE_V;
+        bus.write64(0x3000 + 5 * 8, pte).unwrap();
+    }
+
+    fn exec(hart: &mut Hart, bus: &mut Mmap, inst: u32) -> anyhow::Result<()> {
+        HfenceVvma.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn hfence_vvma_stale_translation_until_flushed() {
+        let (mut hart, mut bus) = setup();
+
+        assert_eq!(hart.read_guest(&mut bus, 0x5000, 8, false).unwrap(), 0xaa);
+        map(&mut bus, 0x9000);
+        assert_eq!(hart.read_guest(&mut bus, 0x5000, 8, false).unwrap(), 0xaa);
+
+        exec(&mut hart, &mut bus, encode_hfence_vvma(0, 0)).unwrap();
+
+        assert_eq!(hart.read_guest(&mut bus, 0x5000, 8, false).unwrap(), 0xbb);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn hfence_vvma_leaves_other_vmids_alone() {
+        let (mut hart, mut bus) = setup();
+        hart.read_guest(&mut bus, 0x5000, 8, false).unwrap();
+        map(&mut bus, 0x9000);
+
+        hart.csrs_mut().set(csr::HGATP, 2 << 44);
+        exec(&mut hart, &mut bus, encode_hfence_vvma(0, 0)).unwrap();
+        hart.csrs_mut().set(csr::HGATP, 1 << 44);
+
+        assert_eq!(hart.read_guest(&mut bus, 0x5000, 8, false).unwrap(), 0xaa);
+    }
+
+    #[test]
+    fn hfence_vvma_leaves_host_translations_alone() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut()
+            .set(csr::SATP, (SATP_MODE_SV39 << 60) | (ROOT >> 12));
+        assert_eq!(hart.read64(&mut bus, 0x5000).unwrap(), 0xaa);
+        map(&mut bus, 0x9000);
+
+        exec(&mut hart, &mut bus, encode_hfence_vvma(0, 0)).unwrap();
+
+        assert_eq!(hart.read64(&mut bus, 0x5000).unwrap(), 0xaa);
+    }
+
+    #[test]
+    fn hfence_vvma_virtual_instruction_in_guest() {
+        let (mut hart, mut bus) = setup();
+        hart.set_virt(true);
+        let inst = encode_hfence_vvma(0, 0);
+
+        let err = exec(&mut hart, &mut bus, inst).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn hfence_vvma_illegal_in_user_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::User);
+        hart.csrs_mut().set(csr::HSTATUS, csr::HSTATUS_HU);
+        let inst = encode_hfence_vvma(0, 0);
+
+        let err = exec(&mut hart, &mut bus, inst).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
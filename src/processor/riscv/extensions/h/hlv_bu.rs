@@ -0,0 +1,92 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct HlvBu;
+
+impl InstrExec for HlvBu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x6010_4073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "HLV.BU", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_hypervisor(inst, true)?;
+        let addr = hart.xreg(rs1);
+        let val = hart.read_guest(bus, addr, 1, false)?;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            hart::Privilege,
+            pmp::{PMP_A_NAPOT, PMP_R, PMP_W, PMP_X, PMPADDR_MASK},
+        },
+    };
+
+    fn encode_hlv_bu(rd: u32, rs1: u32) -> u32 {
+        0x6010_4073 | (rs1 << 15) | (rd << 7)
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W | PMP_X));
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    #[test]
+    fn hlv_bu_reads_guest_memory() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        bus.write8(0x100, 0x80).unwrap();
+
+        HlvBu
+            .call(encode_hlv_bu(2, 1), &mut hart, &mut bus)
+            .unwrap();
+
+        assert_eq!(hart.xreg(2), 0x80);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn hlv_bu_virtual_instruction_in_guest() {
+        let (mut hart, mut bus) = setup();
+        hart.set_virt(true);
+        let inst = encode_hlv_bu(2, 1);
+
+        let err = HlvBu.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct HsvW;
+
+impl InstrExec for HsvW {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_7fff == 0x6a00_4073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "HSV.W", skip_all, fields(rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_hypervisor(inst, true)?;
+        let addr = hart.xreg(rs1);
+        hart.write_guest(bus, addr, 4, hart.xreg(rs2))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            hart::Privilege,
+            mmu::{PTE_A, PTE_R, PTE_U, PTE_V},
+            pmp::{PMP_A_NAPOT, PMP_R, PMP_W, PMP_X, PMPADDR_MASK},
+        },
+    };
+
+    fn encode_hsv_w(rs1: u32, rs2: u32) -> u32 {
+        0x6a00_4073 | (rs2 << 20) | (rs1 << 15)
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W | PMP_X));
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    #[test]
+    fn hsv_w_writes_guest_memory() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        hart.set_xreg(2, 0x1234_5678_9abc_def0);
+
+        HsvW.call(encode_hsv_w(1, 2), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(bus.read64(0x100).unwrap(), 0x9abc_def0);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn hsv_w_virtual_instruction_in_guest() {
+        let (mut hart, mut bus) = setup();
+        hart.set_virt(true);
+        let inst = encode_hsv_w(1, 2);
+
+        let err = HsvW.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn hsv_w_store_guest_page_fault() {
+        let (mut hart, mut bus) = setup();
+        // A read-only Sv39x4 gigapage over the first GiB of guest physical
+        // memory.
+        hart.csrs_mut()
+            .set(csr::HGATP, (csr::HGATP_MODE_SV39X4 << 60) | (0x4000 >> 12));
+        bus.write64(0x4000, PTE_V | PTE_R | PTE_U | PTE_A).unwrap();
+        hart.set_xreg(1, 0x100);
+        let inst = encode_hsv_w(1, 2);
+
+        let err = HsvW.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::StoreGuestPageFault {
+                addr: 0x100,
+                gpa: 0x100
+            }
+        );
+    }
+}
@@ -0,0 +1,15 @@
+pub mod hfence_gvma;
+pub mod hfence_vvma;
+pub mod hlv_b;
+pub mod hlv_bu;
+pub mod hlv_d;
+pub mod hlv_h;
+pub mod hlv_hu;
+pub mod hlv_w;
+pub mod hlv_wu;
+pub mod hlvx_hu;
+pub mod hlvx_wu;
+pub mod hsv_b;
+pub mod hsv_d;
+pub mod hsv_h;
+pub mod hsv_w;
@@ -16,7 +16,7 @@ impl InstrExec for Auipc {
     #[cfg_attr(feature = "trace", tracing::instrument(name = "AUIPC", skip_all, fields(rd = tracing::field::Empty, imm = tracing::field::Empty)))]
     fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
         let rd = ((inst >> 7) & 0x1f) as usize;
-        let imm = (inst & 0xfffff000) as u64;
+        let imm = (inst & 0xfffff000) as i32 as u64;
 
         #[cfg(feature = "trace")]
         {
@@ -86,7 +86,7 @@ mod tests {
         let (mut hart, mut bus) = setup();
         hart.set_pc(0);
         exec(encode_auipc(3, 0xfffff), &mut hart, &mut bus);
-        assert_eq!(hart.xreg(3), 0xfffff000);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ffff_f000);
         assert_eq!(hart.pc(), 0x4);
     }
 
@@ -95,7 +95,7 @@ mod tests {
         let (mut hart, mut bus) = setup();
         hart.set_pc(0x1000);
         exec(encode_auipc(4, 0xfffff), &mut hart, &mut bus);
-        assert_eq!(hart.xreg(4), 0xfffff000 + 0x1000);
+        assert_eq!(hart.xreg(4), 0x0);
         assert_eq!(hart.pc(), 0x1004);
     }
 }
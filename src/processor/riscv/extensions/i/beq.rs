@@ -35,7 +35,7 @@ impl InstrExec for Beq {
 
         if hart.xreg(rs1) == hart.xreg(rs2) {
             let target = hart.pc().wrapping_add_signed(imm);
-            if target & 0b11 != 0 {
+            if target & (hart.ialign() - 1) != 0 {
                 return Err(
                     crate::memory::exception::Trap::MisalignedFetch { addr: target }.into(),
                 );
@@ -53,8 +53,11 @@ impl InstrExec for Beq {
 mod tests {
     use super::*;
     use crate::{
-        memory::mmap::Mmap,
-        processor::riscv::{hart::Hart, instruction::InstrExec},
+        memory::{exception::Trap, mmap::Mmap},
+        processor::riscv::{
+            hart::{Hart, HartConfig},
+            instruction::InstrExec,
+        },
     };
 
     fn encode_beq(rs1: u32, rs2: u32, imm: i16) -> u32 {
@@ -124,4 +127,40 @@ mod tests {
         exec(encode_beq(1, 2, -4), &mut hart, &mut bus);
         assert_eq!(hart.pc(), 0x5000 - 4);
     }
+
+    #[test]
+    fn beq_misaligned_target_without_compressed() {
+        let config = HartConfig {
+            compressed: false,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_pc(0x1000);
+
+        let err = Beq
+            .call(encode_beq(1, 2, 6), &mut hart, &mut bus)
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::MisalignedFetch { addr: 0x1006 }
+        );
+    }
+
+    #[test]
+    fn beq_misaligned_target_not_taken_without_compressed() {
+        let config = HartConfig {
+            compressed: false,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_pc(0x1000);
+        hart.set_xreg(1, 1);
+
+        Beq.call(encode_beq(1, 2, 6), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x1004);
+    }
 }
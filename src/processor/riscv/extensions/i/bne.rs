@@ -34,7 +34,7 @@ impl InstrExec for Bne {
 
         if hart.xreg(rs1) != hart.xreg(rs2) {
             let target = hart.pc().wrapping_add_signed(imm);
-            if target & 0b11 != 0 {
+            if target & (hart.ialign() - 1) != 0 {
                 return Err(
                     crate::memory::exception::Trap::MisalignedFetch { addr: target }.into(),
                 );
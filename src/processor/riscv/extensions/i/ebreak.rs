@@ -1,12 +1,12 @@
 use crate::{
     memory::Bus,
-    processor::riscv::{hart::Hart, instruction::InstrExec},
+    processor::riscv::{exception::Trap, hart::Hart, instruction::InstrExec},
 };
 
 #[derive(Debug)]
-pub struct Ecall;
+pub struct Ebreak;
 
-impl InstrExec for Ecall {
+impl InstrExec for Ebreak {
     #[inline(always)]
     fn matches(&self, inst: u32) -> bool {
         inst == 0x0010_0073
@@ -15,7 +15,85 @@ impl InstrExec for Ecall {
     #[inline(always)]
     #[cfg_attr(feature = "trace", tracing::instrument(name = "EBREAK", skip_all))]
     fn call(&self, _inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
-        hart.next_pc();
-        Ok(())
+        Err(Trap::Breakpoint { addr: hart.pc() }.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::{
+            Cpu,
+            riscv::{
+                csr,
+                hart::{Hart, Privilege},
+                instruction::InstrExec,
+                pmp::{PMP_A_NAPOT, PMP_R, PMP_W, PMP_X, PMPADDR_MASK},
+            },
+        },
+    };
+
+    const EBREAK: u32 = 0x0010_0073;
+
+    fn setup() -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write32(0x1000, EBREAK).unwrap();
+
+        let mut hart = Hart::new(0x1000);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+        // Open all of memory to S and U modes, as firmware would.
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W | PMP_X));
+
+        (hart, bus)
+    }
+
+    #[test]
+    fn ebreak_raises_breakpoint() {
+        let (mut hart, mut bus) = setup();
+
+        let err = Ebreak.call(EBREAK, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::Breakpoint { addr: 0x1000 }
+        );
+        assert_eq!(hart.pc(), 0x1000);
+    }
+
+    #[test]
+    fn ebreak_traps_to_mtvec() {
+        let (mut hart, mut bus) = setup();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x2000);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 3);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1000);
+    }
+
+    #[test]
+    fn ebreak_does_not_retire() {
+        let (mut hart, mut bus) = setup();
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MINSTRET), 0);
+    }
+
+    #[test]
+    fn ebreak_from_user_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::User);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::Machine);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 3);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
     }
 }
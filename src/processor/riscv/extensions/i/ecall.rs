@@ -1,12 +1,16 @@
 use crate::{
     memory::Bus,
-    processor::riscv::{hart::Hart, instruction::InstrExec},
+    processor::riscv::{
+        exception::Trap,
+        hart::{Hart, Privilege},
+        instruction::InstrExec,
+    },
 };
 
 #[derive(Debug)]
-pub struct Ebreak;
+pub struct Ecall;
 
-impl InstrExec for Ebreak {
+impl InstrExec for Ecall {
     #[inline(always)]
     fn matches(&self, inst: u32) -> bool {
         inst == 0x73
@@ -15,7 +19,135 @@ impl InstrExec for Ebreak {
     #[inline(always)]
     #[cfg_attr(feature = "trace", tracing::instrument(name = "ECALL", skip_all))]
     fn call(&self, _inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
-        hart.next_pc();
-        Ok(())
+        let trap = match hart.privilege() {
+            Privilege::User => Trap::UserEcall,
+            Privilege::Supervisor if hart.virt() => Trap::VirtualSupervisorEcall,
+            Privilege::Supervisor => Trap::SupervisorEcall,
+            Privilege::Machine => Trap::MachineEcall,
+        };
+
+        Err(trap.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::{
+            Cpu,
+            riscv::{
+                csr,
+                hart::Hart,
+                instruction::InstrExec,
+                pmp::{PMP_A_NAPOT, PMP_R, PMP_W, PMP_X, PMPADDR_MASK},
+            },
+        },
+    };
+
+    const ECALL: u32 = 0x73;
+
+    fn setup(privilege: Privilege) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write32(0x1000, ECALL).unwrap();
+
+        let mut hart = Hart::new(0x1000);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+        // Open all of memory to S and U modes, as firmware would.
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W | PMP_X));
+        hart.set_privilege(privilege);
+
+        (hart, bus)
+    }
+
+    fn ecall_from(privilege: Privilege) -> Trap {
+        let (mut hart, mut bus) = setup(privilege);
+
+        let trap = Ecall
+            .call(ECALL, &mut hart, &mut bus)
+            .unwrap_err()
+            .downcast::<Trap>()
+            .unwrap();
+        assert_eq!(hart.pc(), 0x1000);
+
+        trap
+    }
+
+    #[test]
+    fn ecall_from_user() {
+        assert_eq!(ecall_from(Privilege::User), Trap::UserEcall);
+    }
+
+    #[test]
+    fn ecall_from_supervisor() {
+        assert_eq!(ecall_from(Privilege::Supervisor), Trap::SupervisorEcall);
+    }
+
+    #[test]
+    fn ecall_from_machine() {
+        assert_eq!(ecall_from(Privilege::Machine), Trap::MachineEcall);
+    }
+
+    #[test]
+    fn ecall_traps_to_mtvec() {
+        let (mut hart, mut bus) = setup(Privilege::Machine);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x2000);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 11);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0);
+        assert_eq!(hart.csrs().get(csr::MINSTRET), 0);
+    }
+
+    #[test]
+    fn ecall_from_user_cause() {
+        let (mut hart, mut bus) = setup(Privilege::User);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 8);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
+        assert_eq!(hart.privilege(), Privilege::Machine);
+    }
+
+    #[test]
+    fn ecall_from_user_delegated_to_supervisor() {
+        let (mut hart, mut bus) = setup(Privilege::User);
+        hart.csrs_mut().set(csr::MEDELEG, 1 << 8);
+        hart.csrs_mut().set(csr::STVEC, 0x3000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x3000);
+        assert_eq!(hart.csrs().get(csr::SCAUSE), 8);
+        assert_eq!(hart.csrs().get(csr::SEPC), 0x1000);
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+    }
+
+    #[test]
+    fn ecall_from_virtual_supervisor() {
+        let (mut hart, mut bus) = setup(Privilege::Supervisor);
+        hart.set_virt(true);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 10);
+        assert!(!hart.virt());
+        assert_ne!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MPV, 0);
+    }
+
+    #[test]
+    fn ecall_from_virtual_user_is_user_ecall() {
+        let (mut hart, mut bus) = setup(Privilege::User);
+        hart.set_virt(true);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 8);
     }
 }
@@ -3,6 +3,8 @@ use crate::{
     processor::riscv::{hart::Hart, instruction::InstrExec},
 };
 
+/// FENCE, which orders memory accesses. A single hart performs them in
+/// program order against memory without buffering, so it has no effect.
 #[derive(Debug)]
 pub struct Fence;
 
@@ -14,14 +16,7 @@ impl InstrExec for Fence {
 
     #[inline(always)]
     #[cfg_attr(feature = "trace", tracing::instrument(name = "FENCE", skip_all))]
-    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
-        let funct3 = (inst >> 12) & 0x7;
-        match funct3 {
-            0 => {}
-            1 => {}
-            _ => {}
-        }
-
+    fn call(&self, _inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
         hart.next_pc();
         Ok(())
     }
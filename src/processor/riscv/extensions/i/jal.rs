@@ -31,12 +31,12 @@ impl InstrExec for Jal {
         }
 
         let pc = hart.pc();
-        hart.set_xreg(rd, pc.wrapping_add(Hart::ILEN));
-
         let target = pc.wrapping_add_signed(imm);
-        if target & 0b11 != 0 {
+        if target & (hart.ialign() - 1) != 0 {
             return Err(crate::memory::exception::Trap::MisalignedFetch { addr: target }.into());
         }
+
+        hart.set_xreg(rd, pc.wrapping_add(hart.ilen()));
         hart.set_pc(target);
 
         Ok(())
@@ -47,8 +47,11 @@ impl InstrExec for Jal {
 mod tests {
     use super::*;
     use crate::{
-        memory::mmap::Mmap,
-        processor::riscv::{hart::Hart, instruction::InstrExec},
+        memory::{exception::Trap, mmap::Mmap},
+        processor::riscv::{
+            hart::{Hart, HartConfig},
+            instruction::InstrExec,
+        },
     };
 
     fn encode_jal(rd: u32, imm: i32) -> u32 {
@@ -113,4 +116,33 @@ mod tests {
         assert_eq!(hart.xreg(4), 0x1000 + 4);
         assert_eq!(hart.pc(), 0x1000 + 0xffff0);
     }
+
+    #[test]
+    fn jal_halfword_target_with_compressed() {
+        let (mut hart, mut bus) = setup();
+        hart.set_pc(0x1000);
+        exec(encode_jal(1, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(1), 0x1000 + 4);
+        assert_eq!(hart.pc(), 0x1000 + 6);
+    }
+
+    #[test]
+    fn jal_misaligned_target_without_compressed() {
+        let config = HartConfig {
+            compressed: false,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_pc(0x1000);
+
+        let err = Jal.call(encode_jal(1, 6), &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::MisalignedFetch { addr: 0x1006 }
+        );
+        assert_eq!(hart.xreg(1), 0);
+        assert_eq!(hart.pc(), 0x1000);
+    }
 }
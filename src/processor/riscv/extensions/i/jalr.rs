@@ -28,12 +28,12 @@ impl InstrExec for Jalr {
         }
 
         let target = hart.xreg(rs1).wrapping_add_signed(imm) & !1;
-        if target & 0b11 != 0 {
+        if target & (hart.ialign() - 1) != 0 {
             return Err(crate::memory::exception::Trap::MisalignedFetch { addr: target }.into());
         }
 
         let pc = hart.pc();
-        hart.set_xreg(rd, pc.wrapping_add(Hart::ILEN));
+        hart.set_xreg(rd, pc.wrapping_add(hart.ilen()));
 
         hart.set_pc(target);
 
@@ -45,8 +45,11 @@ impl InstrExec for Jalr {
 mod tests {
     use super::*;
     use crate::{
-        memory::mmap::Mmap,
-        processor::riscv::{hart::Hart, instruction::InstrExec},
+        memory::{exception::Trap, mmap::Mmap},
+        processor::riscv::{
+            hart::{Hart, HartConfig},
+            instruction::InstrExec,
+        },
     };
 
     fn encode_jalr(rd: u32, rs1: u32, imm: i16) -> u32 {
@@ -112,4 +115,26 @@ mod tests {
         assert_eq!(hart.xreg(1), 0x9000 + 4);
         assert_eq!(hart.pc(), 0x1000 + 20);
     }
+
+    #[test]
+    fn jalr_misaligned_target_without_compressed() {
+        let config = HartConfig {
+            compressed: false,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_pc(0x1000);
+        hart.set_xreg(2, 0x2002);
+
+        let err = Jalr
+            .call(encode_jalr(1, 2, 0), &mut hart, &mut bus)
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::MisalignedFetch { addr: 0x2002 }
+        );
+        assert_eq!(hart.xreg(1), 0);
+    }
 }
@@ -28,7 +28,7 @@ impl InstrExec for Lb {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm);
-        let val = (bus.read8(addr)? as i8 as i64) as u64;
+        let val = (hart.read8(bus, addr)? as i8 as i64) as u64;
 
         hart.set_xreg(rd, val);
         hart.next_pc();
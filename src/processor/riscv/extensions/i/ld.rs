@@ -28,7 +28,7 @@ impl InstrExec for Ld {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm);
-        let val = bus.read64(addr)?;
+        let val = hart.read64(bus, addr)?;
 
         hart.set_xreg(rd, val);
         hart.next_pc();
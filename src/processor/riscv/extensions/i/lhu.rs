@@ -28,7 +28,7 @@ impl InstrExec for Lhu {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm);
-        let val = bus.read16(addr)? as u64;
+        let val = hart.read16(bus, addr)? as u64;
 
         hart.set_xreg(rd, val);
         hart.next_pc();
@@ -16,7 +16,7 @@ impl InstrExec for Lui {
     #[cfg_attr(feature = "trace", tracing::instrument(name = "LUI", skip_all, fields(rd = tracing::field::Empty, imm = tracing::field::Empty)))]
     fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
         let rd = ((inst >> 7) & 0x1f) as usize;
-        let imm = (inst & 0xfffff000) as i64;
+        let imm = (inst & 0xfffff000) as i32 as i64;
 
         #[cfg(feature = "trace")]
         {
@@ -71,7 +71,7 @@ mod tests {
     fn lui_max_value() {
         let (mut hart, mut bus) = setup();
         exec(encode_lui(2, 0xfffff), &mut hart, &mut bus);
-        assert_eq!(hart.xreg(2), 0xfffff000);
+        assert_eq!(hart.xreg(2), 0xffff_ffff_ffff_f000);
     }
 
     #[test]
@@ -28,7 +28,7 @@ impl InstrExec for Lw {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm);
-        let val = (bus.read32(addr)? as i32 as i64) as u64;
+        let val = (hart.read32(bus, addr)? as i32 as i64) as u64;
 
         hart.set_xreg(rd, val);
         hart.next_pc();
@@ -28,7 +28,7 @@ impl InstrExec for Sb {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm as i64);
-        bus.write8(addr, hart.xreg(rs2) as u8)?;
+        hart.write8(bus, addr, hart.xreg(rs2) as u8)?;
         hart.next_pc();
 
         Ok(())
@@ -28,7 +28,7 @@ impl InstrExec for Sd {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm as i64);
-        bus.write64(addr, hart.xreg(rs2))?;
+        hart.write64(bus, addr, hart.xreg(rs2))?;
         hart.next_pc();
 
         Ok(())
@@ -98,4 +98,26 @@ mod tests {
         exec(encode_sd(1, 2, 0), &mut hart, &mut bus);
         assert_eq!(bus.read64(0x400).unwrap(), 0);
     }
+
+    #[test]
+    fn sd_invalidates_reservation() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x500);
+        hart.set_xreg(2, 1);
+        hart.reserve(0x500);
+
+        exec(encode_sd(1, 2, 0), &mut hart, &mut bus);
+        assert_eq!(hart.reservation(), None);
+    }
+
+    #[test]
+    fn sd_keeps_unrelated_reservation() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x500);
+        hart.set_xreg(2, 1);
+        hart.reserve(0x600);
+
+        exec(encode_sd(1, 2, 0), &mut hart, &mut bus);
+        assert_eq!(hart.reservation(), Some(0x600));
+    }
 }
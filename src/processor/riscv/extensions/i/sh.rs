@@ -28,7 +28,7 @@ impl InstrExec for Sh {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm as i64);
-        bus.write16(addr, hart.xreg(rs2) as u16)?;
+        hart.write16(bus, addr, hart.xreg(rs2) as u16)?;
         hart.next_pc();
 
         Ok(())
@@ -27,7 +27,7 @@ impl InstrExec for Sll {
             span.record("rs2", Hart::IABI[rs2]);
         }
 
-        let shamt = (hart.xreg(rs2) & 0x3f) as u32;
+        let shamt = hart.shamt(hart.xreg(rs2));
         let val = hart.xreg(rs1).wrapping_shl(shamt);
         hart.set_xreg(rd, val);
         hart.next_pc();
@@ -27,6 +27,7 @@ impl InstrExec for Slli {
             span.record("shamt", shamt);
         }
 
+        hart.check_shamt(inst, shamt)?;
         let val = hart.xreg(rs1).wrapping_shl(shamt);
         hart.set_xreg(rd, val);
         hart.next_pc();
@@ -40,7 +41,11 @@ mod tests {
     use super::*;
     use crate::{
         memory::mmap::Mmap,
-        processor::riscv::{hart::Hart, instruction::InstrExec},
+        processor::riscv::{
+            exception::Trap,
+            hart::{Hart, HartConfig},
+            instruction::InstrExec,
+        },
     };
 
     fn encode_slli(rd: u32, rs1: u32, shamt: u32) -> u32 {
@@ -51,6 +56,14 @@ mod tests {
         (Hart::new(0), Mmap::new(0x0, 0x10_0000))
     }
 
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
     fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
         Slli.call(inst, hart, bus)
             .expect("SLLI execution unexpectedly trapped");
@@ -91,4 +104,26 @@ mod tests {
         exec(encode_slli(0, 1, 2), &mut hart, &mut bus);
         assert_eq!(hart.xreg(0), 0);
     }
+
+    #[test]
+    fn slli_rv32_wraps_into_sign_bit() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 1);
+
+        exec(encode_slli(3, 1, 31), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_8000_0000);
+    }
+
+    #[test]
+    fn slli_rv32_shamt_32_is_illegal() {
+        let (mut hart, mut bus) = setup_rv32();
+        let inst = encode_slli(3, 1, 32);
+
+        let err = Slli.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
 }
@@ -27,7 +27,7 @@ impl InstrExec for Sra {
             span.record("rs2", Hart::IABI[rs2]);
         }
 
-        let shamt = (hart.xreg(rs2) & 0x3f) as u32;
+        let shamt = hart.shamt(hart.xreg(rs2));
         let val = ((hart.xreg(rs1) as i64).wrapping_shr(shamt)) as u64;
 
         hart.set_xreg(rd, val);
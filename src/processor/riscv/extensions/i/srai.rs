@@ -27,6 +27,7 @@ impl InstrExec for Srai {
             span.record("shamt", shamt);
         }
 
+        hart.check_shamt(inst, shamt)?;
         let val = ((hart.xreg(rs1) as i64).wrapping_shr(shamt)) as u64;
 
         hart.set_xreg(rd, val);
@@ -40,12 +41,20 @@ impl InstrExec for Srai {
 mod tests {
     use super::*;
     use crate::memory::mmap::Mmap;
-    use crate::processor::riscv::hart::Hart;
+    use crate::processor::riscv::hart::{Hart, HartConfig};
 
     fn setup() -> (Hart, Mmap) {
         (Hart::new(0), Mmap::new(0x0, 0x10_0000))
     }
 
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
     fn encode_srai(rd: usize, rs1: usize, shamt: u32) -> u32 {
         (0b0100000 << 25)
             | ((shamt & 0x3f) << 20)
@@ -109,4 +118,13 @@ mod tests {
         exec(encode_srai(1, 1, 1), &mut hart, &mut bus);
         assert_eq!(hart.xreg(1), (-4i64) as u64);
     }
+
+    #[test]
+    fn srai_rv32_shifts_sign() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0x8000_0000);
+
+        exec(encode_srai(3, 1, 31), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), u64::MAX);
+    }
 }
@@ -27,8 +27,8 @@ impl InstrExec for Srl {
             span.record("rs2", Hart::IABI[rs2]);
         }
 
-        let shamt = (hart.xreg(rs2) & 0x3f) as u32;
-        let val = hart.xreg(rs1) >> shamt;
+        let shamt = hart.shamt(hart.xreg(rs2));
+        let val = hart.uxreg(rs1) >> shamt;
 
         hart.set_xreg(rd, val);
         hart.next_pc();
@@ -41,12 +41,20 @@ impl InstrExec for Srl {
 mod tests {
     use super::*;
     use crate::memory::mmap::Mmap;
-    use crate::processor::riscv::hart::Hart;
+    use crate::processor::riscv::hart::{Hart, HartConfig};
 
     fn setup() -> (Hart, Mmap) {
         (Hart::new(0), Mmap::new(0x0, 0x10_0000))
     }
 
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
     fn encode_srl(rd: usize, rs1: usize, rs2: usize) -> u32 {
         (0b0000000 << 25)
             | ((rs2 as u32) << 20)
@@ -107,4 +115,14 @@ mod tests {
         exec(encode_srl(3, 1, 2), &mut hart, &mut bus);
         assert_eq!(hart.xreg(3), 1);
     }
+
+    #[test]
+    fn srl_rv32_shifts_zero_extended_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0x8000_0000);
+        hart.set_xreg(2, 33);
+
+        exec(encode_srl(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x4000_0000);
+    }
 }
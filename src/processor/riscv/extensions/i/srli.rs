@@ -27,7 +27,8 @@ impl InstrExec for Srli {
             span.record("shamt", shamt);
         }
 
-        let val = hart.xreg(rs1) >> shamt;
+        hart.check_shamt(inst, shamt)?;
+        let val = hart.uxreg(rs1) >> shamt;
         hart.set_xreg(rd, val);
         hart.next_pc();
 
@@ -28,7 +28,7 @@ impl InstrExec for Sw {
         }
 
         let addr = hart.xreg(rs1).wrapping_add_signed(imm as i64);
-        bus.write32(addr, hart.xreg(rs2) as u32)?;
+        hart.write32(bus, addr, hart.xreg(rs2) as u32)?;
         hart.next_pc();
 
         Ok(())
@@ -0,0 +1,118 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        crypto,
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Aes64dsm;
+
+impl InstrExec for Aes64dsm {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x3e00_0033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "AES64DSM", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zknd, inst)?;
+        let val = crypto::aes64_dsm(hart.xreg(rs1), hart.xreg(rs2));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_aes64dsm(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0011111 << 25) | (rs2 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Aes64dsm
+            .call(inst, hart, bus)
+            .expect("AES64DSM execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn aes64dsm_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xae52_b4e0_305d_bfd4);
+        hart.set_xreg(2, 0xe598_271e_f111_41b8);
+
+        exec(encode_aes64dsm(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x6e61_9002_4d00_3c08);
+    }
+
+    #[test]
+    fn aes64dsm_high() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xe598_271e_f111_41b8);
+        hart.set_xreg(2, 0xae52_b4e0_305d_bfd4);
+
+        exec(encode_aes64dsm(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xd395_8196_aee6_af1c);
+    }
+
+    #[test]
+    fn aes64dsm_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+        hart.set_xreg(2, 0);
+
+        exec(encode_aes64dsm(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x5252_5252_5252_5252);
+    }
+
+    #[test]
+    fn aes64dsm_illegal_without_zknd() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zknd),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_aes64dsm(3, 1, 2);
+
+        let err = Aes64dsm.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
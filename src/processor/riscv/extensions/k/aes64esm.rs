@@ -0,0 +1,118 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        crypto,
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Aes64esm;
+
+impl InstrExec for Aes64esm {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x3600_0033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "AES64ESM", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zkne, inst)?;
+        let val = crypto::aes64_esm(hart.xreg(rs1), hart.xreg(rs2));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_aes64esm(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0011011 << 25) | (rs2 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Aes64esm
+            .call(inst, hart, bus)
+            .expect("AES64ESM execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn aes64esm_fips197_round_1_low() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x2be2_f4a0_bee3_3d19);
+        hart.set_xreg(2, 0x848_f8e9_2a8d_c69a);
+
+        exec(encode_aes64esm(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x9a19_cbe0_e581_6604);
+    }
+
+    #[test]
+    fn aes64esm_fips197_round_1_high() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x848_f8e9_2a8d_c69a);
+        hart.set_xreg(2, 0x2be2_f4a0_bee3_3d19);
+
+        exec(encode_aes64esm(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x4c26_0628_7ad3_f848);
+    }
+
+    #[test]
+    fn aes64esm_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+        hart.set_xreg(2, 0);
+
+        exec(encode_aes64esm(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x6363_6363_6363_6363);
+    }
+
+    #[test]
+    fn aes64esm_illegal_without_zkne() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zkne),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_aes64esm(3, 1, 2);
+
+        let err = Aes64esm.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        crypto,
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Aes64im;
+
+impl InstrExec for Aes64im {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x3000_1013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "AES64IM", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zknd, inst)?;
+        let val = crypto::aes64_im(hart.xreg(rs1));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_aes64im(rd: u32, rs1: u32) -> u32 {
+        (0x300 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Aes64im
+            .call(inst, hart, bus)
+            .expect("AES64IM execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn aes64im_inverts_fips197_mix_columns_low() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x9a19_cbe0_e581_6604);
+
+        exec(encode_aes64im(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xae52_b4e0_305d_bfd4);
+    }
+
+    #[test]
+    fn aes64im_inverts_fips197_mix_columns_high() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x4c26_0628_7ad3_f848);
+
+        exec(encode_aes64im(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xe598_271e_f111_41b8);
+    }
+
+    #[test]
+    fn aes64im_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_aes64im(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn aes64im_illegal_without_zknd() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zknd),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_aes64im(3, 1);
+
+        let err = Aes64im.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        crypto,
+        exception::Trap,
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Aes64ks1i;
+
+impl InstrExec for Aes64ks1i {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xff00_707f == 0x3100_1013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "AES64KS1I", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rnum = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rnum = (inst >> 20) & 0xf;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rnum", rnum);
+        }
+
+        hart.check_any_ext(&[Extension::Zknd, Extension::Zkne], inst)?;
+        if rnum > 0xa {
+            return Err(Trap::IllegalInstruction { inst }.into());
+        }
+        let val = crypto::aes64_ks1i(hart.xreg(rs1), rnum);
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_aes64ks1i(rd: u32, rs1: u32, rnum: u32) -> u32 {
+        (0b00110001 << 24) | (rnum << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Aes64ks1i
+            .call(inst, hart, bus)
+            .expect("AES64KS1I execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn aes64ks1i_fips197_first_round() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x3c4f_cf09_8815_f7ab);
+
+        exec(encode_aes64ks1i(3, 1, 0), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1eb_848b_01eb_848b);
+    }
+
+    #[test]
+    fn aes64ks1i_last_round_constant() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x3c4f_cf09_8815_f7ab);
+
+        exec(encode_aes64ks1i(3, 1, 9), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1eb_84bc_01eb_84bc);
+    }
+
+    #[test]
+    fn aes64ks1i_round_10_skips_rotation() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x3c4f_cf09_8815_f7ab);
+
+        exec(encode_aes64ks1i(3, 1, 10), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xeb84_8a01_eb84_8a01);
+    }
+
+    #[test]
+    fn aes64ks1i_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_aes64ks1i(3, 1, 0), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x6363_6362_6363_6362);
+    }
+
+    #[test]
+    fn aes64ks1i_reserved_rnum_is_illegal() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_aes64ks1i(3, 1, 0xb);
+
+        let err = Aes64ks1i.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn aes64ks1i_illegal_without_zknd_or_zkne() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zknd)
+                .without(Extension::Zkne),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_aes64ks1i(3, 1, 1);
+
+        let err = Aes64ks1i.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        crypto,
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Aes64ks2;
+
+impl InstrExec for Aes64ks2 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x7e00_0033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "AES64KS2", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_any_ext(&[Extension::Zknd, Extension::Zkne], inst)?;
+        let val = crypto::aes64_ks2(hart.xreg(rs1), hart.xreg(rs2));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_aes64ks2(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0111111 << 25) | (rs2 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Aes64ks2
+            .call(inst, hart, bus)
+            .expect("AES64KS2 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn aes64ks2_fips197_first_round_low() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1eb_848b_01eb_848b);
+        hart.set_xreg(2, 0xa6d2_ae28_1615_7e2b);
+
+        exec(encode_aes64ks2(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xb12c_5488_17fe_faa0);
+    }
+
+    #[test]
+    fn aes64ks2_fips197_first_round_high() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xb12c_5488_17fe_faa0);
+        hart.set_xreg(2, 0x3c4f_cf09_8815_f7ab);
+
+        exec(encode_aes64ks2(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x576_6c2a_3939_a323);
+    }
+
+    #[test]
+    fn aes64ks2_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+        hart.set_xreg(2, 0);
+
+        exec(encode_aes64ks2(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn aes64ks2_illegal_without_zknd_or_zkne() {
+        let config = HartConfig {
+            extensions: Extensions::all()
+                .without(Extension::Zknd)
+                .without(Extension::Zkne),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_aes64ks2(3, 1, 2);
+
+        let err = Aes64ks2.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
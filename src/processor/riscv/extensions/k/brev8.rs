@@ -0,0 +1,121 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Brev8;
+
+impl InstrExec for Brev8 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x6870_5013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "BREV8", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zbkb, inst)?;
+        let val = u64::from_le_bytes(hart.xreg(rs1).to_le_bytes().map(u8::reverse_bits));
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_brev8(rd: u32, rs1: u32) -> u32 {
+        (0x687 << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Brev8
+            .call(inst, hart, bus)
+            .expect("BREV8 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn brev8_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x102_0408_1020_4080);
+
+        exec(encode_brev8(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x8040_2010_0804_0201);
+    }
+
+    #[test]
+    fn brev8_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_brev8(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn brev8_all_ones() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+
+        exec(encode_brev8(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ffff_ffff);
+    }
+
+    #[test]
+    fn brev8_nibbles() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xf0f_f0f0_1234_5678);
+
+        exec(encode_brev8(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xf0f0_0f0f_482c_6a1e);
+    }
+
+    #[test]
+    fn brev8_illegal_without_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_brev8(3, 1);
+
+        let err = Brev8.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,25 @@
+pub mod aes64ds;
+pub mod aes64dsm;
+pub mod aes64es;
+pub mod aes64esm;
+pub mod aes64im;
+pub mod aes64ks1i;
+pub mod aes64ks2;
+pub mod brev8;
+pub mod pack;
+pub mod packh;
+pub mod packw;
+pub mod sha256sig0;
+pub mod sha256sig1;
+pub mod sha256sum0;
+pub mod sha256sum1;
+pub mod sha512sig0;
+pub mod sha512sig1;
+pub mod sha512sum0;
+pub mod sha512sum1;
+pub mod sm3p0;
+pub mod sm3p1;
+pub mod sm4ed;
+pub mod sm4ks;
+pub mod xperm4;
+pub mod xperm8;
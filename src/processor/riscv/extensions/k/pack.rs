@@ -0,0 +1,116 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Pack;
+
+impl InstrExec for Pack {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0800_4033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "PACK", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zbkb, inst)?;
+        let val = (hart.xreg(rs1) & 0xffff_ffff) | (hart.xreg(rs2) << 32);
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_pack(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000100 << 25) | (rs2 << 20) | (rs1 << 15) | (0b100 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Pack.call(inst, hart, bus)
+            .expect("PACK execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn pack_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1111_1111_2222_2222);
+        hart.set_xreg(2, 0x3333_3333_4444_4444);
+
+        exec(encode_pack(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x4444_4444_2222_2222);
+    }
+
+    #[test]
+    fn pack_zero_rs2() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+        hart.set_xreg(2, 0);
+
+        exec(encode_pack(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff);
+    }
+
+    #[test]
+    fn pack_zero_rs1() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+        hart.set_xreg(2, 0xdead_beef);
+
+        exec(encode_pack(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xdead_beef_0000_0000);
+    }
+
+    #[test]
+    fn pack_illegal_without_zbkb() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbkb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_pack(3, 1, 2);
+
+        let err = Pack.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Sha256sig0;
+
+impl InstrExec for Sha256sig0 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x1020_1013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "SHA256SIG0", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zknh, inst)?;
+        let x = hart.xreg(rs1) as u32;
+        let val = (x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3)) as i32 as i64 as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_sha256sig0(rd: u32, rs1: u32) -> u32 {
+        (0x102 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Sha256sig0
+            .call(inst, hart, bus)
+            .expect("SHA256SIG0 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn sha256sig0_initial_hash_a() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x6a09_e667);
+
+        exec(encode_sha256sig0(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ba0c_f582);
+    }
+
+    #[test]
+    fn sha256sig0_initial_hash_e() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x510e_527f);
+
+        exec(encode_sha256sig0(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x601c_02a8);
+    }
+
+    #[test]
+    fn sha256sig0_abc_schedule_word() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x6162_6380);
+
+        exec(encode_sha256sig0(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_940e_90ef);
+    }
+
+    #[test]
+    fn sha256sig0_ignores_upper_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_0000_0018);
+
+        exec(encode_sha256sig0(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x3006_0003);
+    }
+
+    #[test]
+    fn sha256sig0_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_sha256sig0(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn sha256sig0_illegal_without_zknh() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zknh),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_sha256sig0(3, 1);
+
+        let err = Sha256sig0.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Sm3p1;
+
+impl InstrExec for Sm3p1 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0x1090_1013
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "SM3P1", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zksh, inst)?;
+        let x = hart.xreg(rs1) as u32;
+        let val = (x ^ x.rotate_left(15) ^ x.rotate_left(23)) as i32 as i64 as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_sm3p1(rd: u32, rs1: u32) -> u32 {
+        (0x109 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0010011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Sm3p1
+            .call(inst, hart, bus)
+            .expect("SM3P1 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn sm3p1_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1);
+
+        exec(encode_sm3p1(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x80_8001);
+    }
+
+    #[test]
+    fn sm3p1_sign_extends() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8000_0000);
+
+        exec(encode_sm3p1(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_8040_4000);
+    }
+
+    #[test]
+    fn sm3p1_ignores_upper_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_1234_5678);
+
+        exec(encode_sm3p1(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x501_4549);
+    }
+
+    #[test]
+    fn sm3p1_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+
+        exec(encode_sm3p1(3, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn sm3p1_illegal_without_zksh() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zksh),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_sm3p1(3, 1);
+
+        let err = Sm3p1.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
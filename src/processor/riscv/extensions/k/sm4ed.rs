@@ -0,0 +1,136 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        crypto,
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Sm4ed;
+
+impl InstrExec for Sm4ed {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x3e00_707f == 0x3000_0033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "SM4ED", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty, bs = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+        let bs = inst >> 30;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+            span.record("bs", bs);
+        }
+
+        hart.check_ext(Extension::Zksed, inst)?;
+        let val = crypto::sm4_ed(hart.xreg(rs1), hart.xreg(rs2), bs);
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_sm4ed(rd: u32, rs1: u32, rs2: u32, bs: u32) -> u32 {
+        (bs << 30)
+            | (0b11000 << 25)
+            | (rs2 << 20)
+            | (rs1 << 15)
+            | (0b000 << 12)
+            | (rd << 7)
+            | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Sm4ed
+            .call(inst, hart, bus)
+            .expect("SM4ED execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn sm4ed_byte_0() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+        hart.set_xreg(2, 1);
+
+        exec(encode_sm4ed(3, 1, 2, 0), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_9242_42d0);
+    }
+
+    #[test]
+    fn sm4ed_byte_3() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0);
+        hart.set_xreg(2, 0x100_0000);
+
+        exec(encode_sm4ed(3, 1, 2, 3), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_d092_4242);
+    }
+
+    #[test]
+    fn sm4ed_xors_rs1() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234_5678);
+        hart.set_xreg(2, 0x9abc_def0);
+
+        exec(encode_sm4ed(3, 1, 2, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_c0e6_32ce);
+    }
+
+    #[test]
+    fn sm4ed_ignores_upper_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xffff_ffff_0000_0000);
+        hart.set_xreg(2, 0xffff_ffff_0000_0000);
+
+        exec(encode_sm4ed(3, 1, 2, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x5b8e_d55b);
+    }
+
+    #[test]
+    fn sm4ed_illegal_without_zksed() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zksed),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_sm4ed(3, 1, 2, 1);
+
+        let err = Sm4ed.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Xperm4;
+
+impl InstrExec for Xperm4 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x2800_2033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "XPERM4", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zbkx, inst)?;
+        let (table, indices) = (hart.xreg(rs1), hart.xreg(rs2));
+        let val = (0..16).fold(0, |acc, i| {
+            let index = (indices >> (4 * i)) & 0xf;
+            acc | (((table >> (4 * index)) & 0xf) << (4 * i))
+        });
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_xperm4(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0010100 << 25) | (rs2 << 20) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Xperm4
+            .call(inst, hart, bus)
+            .expect("XPERM4 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn xperm4_identity() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xfedc_ba98_7654_3210);
+        hart.set_xreg(2, 0xfedc_ba98_7654_3210);
+
+        exec(encode_xperm4(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xfedc_ba98_7654_3210);
+    }
+
+    #[test]
+    fn xperm4_sbox_lookup() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x123_4567_89ab_cdef);
+        hart.set_xreg(2, 0xf1e);
+
+        exec(encode_xperm4(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ffff_f0e1);
+    }
+
+    #[test]
+    fn xperm4_reverse() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xfedc_ba98_7654_3210);
+        hart.set_xreg(2, 0x123_4567_89ab_cdef);
+
+        exec(encode_xperm4(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x123_4567_89ab_cdef);
+    }
+
+    #[test]
+    fn xperm4_broadcast() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 7);
+        hart.set_xreg(2, 0);
+
+        exec(encode_xperm4(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x7777_7777_7777_7777);
+    }
+
+    #[test]
+    fn xperm4_illegal_without_zbkx() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbkx),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_xperm4(3, 1, 2);
+
+        let err = Xperm4.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
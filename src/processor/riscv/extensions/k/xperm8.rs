@@ -0,0 +1,130 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Xperm8;
+
+impl InstrExec for Xperm8 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x2800_4033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "XPERM8", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zbkx, inst)?;
+        let (table, indices) = (hart.xreg(rs1).to_le_bytes(), hart.xreg(rs2).to_le_bytes());
+        let val =
+            u64::from_le_bytes(indices.map(|i| table.get(usize::from(i)).copied().unwrap_or(0)));
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_xperm8(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0010100 << 25) | (rs2 << 20) | (rs1 << 15) | (0b100 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Xperm8
+            .call(inst, hart, bus)
+            .expect("XPERM8 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn xperm8_identity() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8877_6655_4433_2211);
+        hart.set_xreg(2, 0x706_0504_0302_0100);
+
+        exec(encode_xperm8(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x8877_6655_4433_2211);
+    }
+
+    #[test]
+    fn xperm8_reverse() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8877_6655_4433_2211);
+        hart.set_xreg(2, 0x1_0203_0405_0607);
+
+        exec(encode_xperm8(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1122_3344_5566_7788);
+    }
+
+    #[test]
+    fn xperm8_out_of_range_is_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x8877_6655_4433_2211);
+        hart.set_xreg(2, 0xff08_0910_0000_0000);
+
+        exec(encode_xperm8(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x1111_1111);
+    }
+
+    #[test]
+    fn xperm8_broadcast() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xab);
+        hart.set_xreg(2, 0);
+
+        exec(encode_xperm8(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xabab_abab_abab_abab);
+    }
+
+    #[test]
+    fn xperm8_illegal_without_zbkx() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbkx),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_xperm8(3, 1, 2);
+
+        let err = Xperm8.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(3), 0);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Divu;
+
+impl InstrExec for Divu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_5033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "DIVU", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let dividend = hart.uxreg(rs1);
+        let divisor = hart.uxreg(rs2);
+        let val = dividend.checked_div(divisor).unwrap_or(u64::MAX);
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            hart::{Hart, HartConfig},
+            instruction::InstrExec,
+        },
+    };
+
+    fn encode_divu(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Divu.call(inst, hart, bus)
+            .expect("DIVU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn divu_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 42);
+        hart.set_xreg(2, 6);
+
+        exec(encode_divu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 7);
+    }
+
+    #[test]
+    fn divu_large_dividend() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, u64::MAX);
+        hart.set_xreg(2, 2);
+
+        exec(encode_divu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0x7fff_ffff_ffff_ffff);
+    }
+
+    #[test]
+    fn divu_no_sign_interpretation() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, -7i64 as u64);
+        hart.set_xreg(2, 2);
+
+        exec(encode_divu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0x7fff_ffff_ffff_fffc);
+    }
+
+    #[test]
+    fn divu_by_zero() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 42);
+        hart.set_xreg(2, 0);
+
+        exec(encode_divu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), u64::MAX);
+    }
+
+    #[test]
+    fn divu_rv32_divides_zero_extended_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0xffff_ffff_ffff_fffe);
+        hart.set_xreg(2, 2);
+
+        exec(encode_divu(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0x7fff_ffff);
+    }
+}
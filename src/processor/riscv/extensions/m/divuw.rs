@@ -0,0 +1,110 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Divuw;
+
+impl InstrExec for Divuw {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_503b
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "DIVUW", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let dividend = hart.xreg(rs1) as u32;
+        let divisor = hart.xreg(rs2) as u32;
+        let val = dividend.checked_div(divisor).unwrap_or(u32::MAX) as i32 as i64 as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_divuw(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b101 << 12) | (rd << 7) | 0b0111011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Divuw
+            .call(inst, hart, bus)
+            .expect("DIVUW execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn divuw_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 42);
+        hart.set_xreg(2, 6);
+
+        exec(encode_divuw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 7);
+    }
+
+    #[test]
+    fn divuw_sign_extends_result() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0xffff_fffe);
+        hart.set_xreg(2, 1);
+
+        exec(encode_divuw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ffff_fffe);
+    }
+
+    #[test]
+    fn divuw_ignores_upper_32bits() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0xffff_ffff_0000_002a);
+        hart.set_xreg(2, 0x1234_5678_0000_0006);
+
+        exec(encode_divuw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 7);
+    }
+
+    #[test]
+    fn divuw_by_zero() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 42);
+        hart.set_xreg(2, 0);
+
+        exec(encode_divuw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), u64::MAX);
+    }
+}
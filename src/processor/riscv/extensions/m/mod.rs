@@ -0,0 +1,13 @@
+pub mod div;
+pub mod divu;
+pub mod divuw;
+pub mod divw;
+pub mod mul;
+pub mod mulh;
+pub mod mulhsu;
+pub mod mulhu;
+pub mod mulw;
+pub mod rem;
+pub mod remu;
+pub mod remuw;
+pub mod remw;
@@ -0,0 +1,119 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Mul;
+
+impl InstrExec for Mul {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_0033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MUL", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let val = hart.xreg(rs1).wrapping_mul(hart.xreg(rs2));
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_mul(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Mul.call(inst, hart, bus)
+            .expect("MUL execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn mul_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 6);
+        hart.set_xreg(2, 7);
+
+        exec(encode_mul(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 42);
+    }
+
+    #[test]
+    fn mul_negative() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, -3i64 as u64);
+        hart.set_xreg(2, 5);
+
+        exec(encode_mul(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), -15i64 as u64);
+    }
+
+    #[test]
+    fn mul_overflow_wrap() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, u64::MAX);
+        hart.set_xreg(2, 2);
+
+        exec(encode_mul(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), u64::MAX - 1);
+    }
+
+    #[test]
+    fn mul_low_bits_only() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0x1_0000_0000);
+        hart.set_xreg(2, 0x1_0000_0000);
+
+        exec(encode_mul(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn mul_x0_destination() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 6);
+        hart.set_xreg(2, 7);
+
+        exec(encode_mul(0, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(0), 0);
+    }
+}
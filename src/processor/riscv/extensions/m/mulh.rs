@@ -0,0 +1,141 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Mulh;
+
+impl InstrExec for Mulh {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_1033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MULH", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let prod = i128::from(hart.xreg(rs1) as i64) * i128::from(hart.xreg(rs2) as i64);
+        let val = (prod >> hart.xlen()) as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            hart::{Hart, HartConfig},
+            instruction::InstrExec,
+        },
+    };
+
+    fn encode_mulh(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Mulh.call(inst, hart, bus)
+            .expect("MULH execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn mulh_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0x1_0000_0000);
+        hart.set_xreg(2, 0x1_0000_0000);
+
+        exec(encode_mulh(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn mulh_small_positive() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 6);
+        hart.set_xreg(2, 7);
+
+        exec(encode_mulh(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn mulh_negative_times_positive() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, -1i64 as u64);
+        hart.set_xreg(2, 1);
+
+        exec(encode_mulh(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), u64::MAX);
+    }
+
+    #[test]
+    fn mulh_negative_times_negative() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, -1i64 as u64);
+        hart.set_xreg(2, -1i64 as u64);
+
+        exec(encode_mulh(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn mulh_min_times_min() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, i64::MIN as u64);
+        hart.set_xreg(2, i64::MIN as u64);
+
+        exec(encode_mulh(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0x4000_0000_0000_0000);
+    }
+
+    #[test]
+    fn mulh_rv32_returns_upper_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0xffff_ffff_8000_0000);
+        hart.set_xreg(2, 2);
+
+        exec(encode_mulh(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), u64::MAX);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Mulhu;
+
+impl InstrExec for Mulhu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_3033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MULHU", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let prod = u128::from(hart.uxreg(rs1)) * u128::from(hart.uxreg(rs2));
+        let val = (prod >> hart.xlen()) as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            hart::{Hart, HartConfig},
+            instruction::InstrExec,
+        },
+    };
+
+    fn encode_mulhu(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b011 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn setup_rv32() -> (Hart, Mmap) {
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Mulhu
+            .call(inst, hart, bus)
+            .expect("MULHU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn mulhu_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0x1_0000_0000);
+        hart.set_xreg(2, 0x1_0000_0000);
+
+        exec(encode_mulhu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn mulhu_small() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 6);
+        hart.set_xreg(2, 7);
+
+        exec(encode_mulhu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+
+    #[test]
+    fn mulhu_max_times_max() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, u64::MAX);
+        hart.set_xreg(2, u64::MAX);
+
+        exec(encode_mulhu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), u64::MAX - 1);
+    }
+
+    #[test]
+    fn mulhu_max_times_two() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, u64::MAX);
+        hart.set_xreg(2, 2);
+
+        exec(encode_mulhu(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn mulhu_rv32_returns_upper_word() {
+        let (mut hart, mut bus) = setup_rv32();
+        hart.set_xreg(1, 0xffff_ffff_ffff_ffff);
+        hart.set_xreg(2, 0xffff_ffff_ffff_ffff);
+
+        exec(encode_mulhu(3, 1, 2), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(3), 0xffff_ffff_ffff_fffe);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Mulw;
+
+impl InstrExec for Mulw {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_003b
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MULW", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let val = (hart.xreg(rs1) as i32).wrapping_mul(hart.xreg(rs2) as i32) as i64 as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_mulw(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b0111011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Mulw.call(inst, hart, bus)
+            .expect("MULW execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn mulw_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 6);
+        hart.set_xreg(2, 7);
+
+        exec(encode_mulw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 42);
+    }
+
+    #[test]
+    fn mulw_sign_extend_negative() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0x8000);
+        hart.set_xreg(2, 0x1_0000);
+
+        exec(encode_mulw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0xffff_ffff_8000_0000);
+    }
+
+    #[test]
+    fn mulw_ignores_upper_32bits() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0xffff_ffff_0000_0003);
+        hart.set_xreg(2, 0xdead_beef_0000_0005);
+
+        exec(encode_mulw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 15);
+    }
+
+    #[test]
+    fn mulw_overflow_wrap_32bit() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0x7fff_ffff);
+        hart.set_xreg(2, 2);
+
+        exec(encode_mulw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), -2i64 as u64);
+    }
+
+    #[test]
+    fn mulw_x0_destination() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 6);
+        hart.set_xreg(2, 7);
+
+        exec(encode_mulw(0, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(0), 0);
+    }
+}
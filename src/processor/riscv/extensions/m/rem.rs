@@ -0,0 +1,125 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Rem;
+
+impl InstrExec for Rem {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_6033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "REM", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let dividend = hart.xreg(rs1) as i64;
+        let divisor = hart.xreg(rs2) as i64;
+        let val = if divisor == 0 {
+            dividend as u64
+        } else {
+            dividend.wrapping_rem(divisor) as u64
+        };
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_rem(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b110 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Rem.call(inst, hart, bus)
+            .expect("REM execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn rem_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 43);
+        hart.set_xreg(2, 6);
+
+        exec(encode_rem(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn rem_sign_follows_dividend() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, -7i64 as u64);
+        hart.set_xreg(2, 2);
+
+        exec(encode_rem(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), -1i64 as u64);
+    }
+
+    #[test]
+    fn rem_negative_divisor() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 7);
+        hart.set_xreg(2, -2i64 as u64);
+
+        exec(encode_rem(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn rem_by_zero() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 42);
+        hart.set_xreg(2, 0);
+
+        exec(encode_rem(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 42);
+    }
+
+    #[test]
+    fn rem_signed_overflow() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, i64::MIN as u64);
+        hart.set_xreg(2, -1i64 as u64);
+
+        exec(encode_rem(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Remw;
+
+impl InstrExec for Remw {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0200_603b
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "REMW", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let dividend = hart.xreg(rs1) as i32;
+        let divisor = hart.xreg(rs2) as i32;
+        let val = if divisor == 0 {
+            dividend as i64 as u64
+        } else {
+            dividend.wrapping_rem(divisor) as i64 as u64
+        };
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_remw(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000001 << 25) | (rs2 << 20) | (rs1 << 15) | (0b110 << 12) | (rd << 7) | 0b0111011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Remw.call(inst, hart, bus)
+            .expect("REMW execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn remw_basic() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 43);
+        hart.set_xreg(2, 6);
+
+        exec(encode_remw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 1);
+    }
+
+    #[test]
+    fn remw_sign_follows_dividend() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, -7i64 as u64);
+        hart.set_xreg(2, 2);
+
+        exec(encode_remw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), -1i64 as u64);
+    }
+
+    #[test]
+    fn remw_by_zero_sign_extends_dividend() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, 0x8000_0000);
+        hart.set_xreg(2, 0);
+
+        exec(encode_remw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0xffff_ffff_8000_0000);
+    }
+
+    #[test]
+    fn remw_signed_overflow() {
+        let (mut hart, mut bus) = setup();
+
+        hart.set_xreg(1, i32::MIN as u64);
+        hart.set_xreg(2, -1i64 as u64);
+
+        exec(encode_remw(3, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(3), 0);
+    }
+}
@@ -1 +1,19 @@
+pub mod a;
+pub mod b;
+pub mod c;
+pub mod d;
+pub mod f;
+pub mod h;
 pub mod i;
+pub mod k;
+pub mod m;
+pub mod privileged;
+pub mod v;
+pub mod zawrs;
+pub mod zfh;
+pub mod zicbo;
+pub mod zicond;
+pub mod zicsr;
+pub mod zifencei;
+pub mod zihintpause;
+pub mod zimop;
@@ -0,0 +1,4 @@
+pub mod mret;
+pub mod sfence_vma;
+pub mod sret;
+pub mod wfi;
@@ -0,0 +1,121 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        exception::Trap,
+        hart::{Hart, Privilege},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Mret;
+
+impl InstrExec for Mret {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst == 0x3020_0073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MRET", skip_all))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        if hart.privilege() != Privilege::Machine {
+            return Err(Trap::IllegalInstruction { inst }.into());
+        }
+
+        hart.mret();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, hart::Hart, instruction::InstrExec},
+    };
+
+    const MRET: u32 = 0x3020_0073;
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        Mret.call(MRET, hart, bus)
+    }
+
+    fn set_mstatus(hart: &mut Hart, mstatus: u64) {
+        hart.csrs_mut().set(csr::MSTATUS, mstatus);
+    }
+
+    #[test]
+    fn mret_jumps_to_mepc() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MEPC, 0x4000);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x4000);
+    }
+
+    #[test]
+    fn mret_restores_privilege_from_mpp() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, 1 << 11);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MPP, 0);
+    }
+
+    #[test]
+    fn mret_restores_interrupt_enable() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, csr::MSTATUS_MPIE | csr::MSTATUS_MPP);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        assert_ne!(mstatus & csr::MSTATUS_MIE, 0);
+        assert_ne!(mstatus & csr::MSTATUS_MPIE, 0);
+        assert_eq!(hart.privilege(), Privilege::Machine);
+    }
+
+    #[test]
+    fn mret_clears_mie_when_mpie_clear() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, csr::MSTATUS_MIE | csr::MSTATUS_MPP);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MIE, 0);
+    }
+
+    #[test]
+    fn mret_to_lower_mode_clears_mprv() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, csr::MSTATUS_MPRV);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::User);
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MPRV, 0);
+    }
+
+    #[test]
+    fn mret_illegal_outside_machine_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::Supervisor);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst: MRET }
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        hart::{Hart, Privilege},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct SfenceVma;
+
+impl InstrExec for SfenceVma {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_7fff == 0x1200_0073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "SFENCE.VMA", skip_all, fields(rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        let tvm = hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_TVM != 0;
+        let vtvm = hart.csrs().get(csr::HSTATUS) & csr::HSTATUS_VTVM != 0;
+        match (hart.privilege(), hart.virt()) {
+            (Privilege::User, true) => return Err(Trap::VirtualInstruction { inst }.into()),
+            (Privilege::Supervisor, true) if vtvm => {
+                return Err(Trap::VirtualInstruction { inst }.into());
+            }
+            (Privilege::User, false) => return Err(Trap::IllegalInstruction { inst }.into()),
+            (Privilege::Supervisor, false) if tvm => {
+                return Err(Trap::IllegalInstruction { inst }.into());
+            }
+            _ => {}
+        }
+
+        let vaddr = (rs1 != 0).then(|| hart.uxreg(rs1));
+        let asid = (rs2 != 0).then(|| hart.xreg(rs2) as u16);
+        hart.sfence_vma(vaddr, asid);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            hart::Hart,
+            instruction::InstrExec,
+            mmu::{PTE_A, PTE_D, PTE_G, PTE_R, PTE_V, PTE_W, SATP_MODE_SV39},
+            pmp::{self, PMP_A_NAPOT, PMPADDR_MASK},
+        },
+    };
+
+    const ROOT: u64 = 0x1000;
+
+    fn encode_sfence_vma(rs1: u32, rs2: u32) -> u32 {
+        (0b0001001 << 25) | (rs2 << 20) | (rs1 << 15) | 0b1110011
+    }
+
+    /// Runs in S-mode under Sv39 with ASID 1, the page at 0x5000 mapped to
+    /// 0x8000 through tables at 0x1000, 0x2000 and 0x3000.
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_privilege(Privilege::Supervisor);
+        // Open all of memory to S and U modes, as firmware would.
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut().set(
+            csr::PMPCFG0,
+            u64::from(PMP_A_NAPOT | pmp::PMP_R | pmp::PMP_W | pmp::PMP_X),
+        );
+        hart.csrs_mut()
+            .set(csr::SATP, (SATP_MODE_SV39 << 60) | (1 << 44) | (ROOT >> 12));
+        bus.write64(ROOT, (0x2000 >> 12 << 10) | PTE_V).unwrap();
+        bus.write64(0x2000, (0x3000 >> 12 << 10) | PTE_V).unwrap();
+        map(&mut bus, 0x8000, 0);
+        (hart, bus)
+    }
+
+    fn map(bus: &mut Mmap, paddr: u64, flags: u64) {
+        let pte = (paddr >> 12 << 10) | flags | PTE_R | PTE_W | PTE_A | PTE_D | PTE_V;
+        bus.write64(0x3000 + 5 * 8, pte).unwrap();
+    }
+
+    fn exec(hart: &mut Hart, bus: &mut Mmap, inst: u32) -> anyhow::Result<()> {
+        SfenceVma.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn sfence_vma_stale_translation_until_flushed() {
+        let (mut hart, mut bus) = setup();
+        bus.write64(0x8000, 0xaa).unwrap();
+        bus.write64(0x9000, 0xbb).unwrap();
+
+        assert_eq!(hart.read64(&mut bus, 0x5000).unwrap(), 0xaa);
+        map(&mut bus, 0x9000, 0);
+        assert_eq!(hart.read64(&mut bus, 0x5000).unwrap(), 0xaa);
+
+        exec(&mut hart, &mut bus, encode_sfence_vma(0, 0)).unwrap();
+
+        assert_eq!(hart.read64(&mut bus, 0x5000).unwrap(), 0xbb);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn sfence_vma_counts_hits_and_misses() {
+        let (mut hart, mut bus) = setup();
+
+        hart.read64(&mut bus, 0x5000).unwrap();
+        hart.read64(&mut bus, 0x5008).unwrap();
+
+        let stats = hart.mmu().dtlb_stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert_eq!(hart.mmu().itlb_stats().misses, 0);
+    }
+
+    #[test]
+    fn sfence_vma_by_address() {
+        let (mut hart, mut bus) = setup();
+        bus.write64(0x9000, 0xbb).unwrap();
+        hart.read64(&mut bus, 0x5000).unwrap();
+        map(&mut bus, 0x9000, 0);
+
+        hart.set_xreg(1, 0x6000);
+        exec(&mut hart, &mut bus, encode_sfence_vma(1, 0)).unwrap();
+        assert_ne!(hart.read64(&mut bus, 0x5000).unwrap(), 0xbb);
+
+        hart.set_xreg(1, 0x5000);
+        exec(&mut hart, &mut bus, encode_sfence_vma(1, 0)).unwrap();
+        assert_eq!(hart.read64(&mut bus, 0x5000).unwrap(), 0xbb);
+    }
+
+    #[test]
+    fn sfence_vma_by_asid_keeps_global_pages() {
+        let (mut hart, mut bus) = setup();
+        map(&mut bus, 0x8000, PTE_G);
+        bus.write64(0x9000, 0xbb).unwrap();
+        hart.read64(&mut bus, 0x5000).unwrap();
+        map(&mut bus, 0x9000, PTE_G);
+
+        hart.set_xreg(2, 1);
+        exec(&mut hart, &mut bus, encode_sfence_vma(0, 2)).unwrap();
+
+        assert_ne!(hart.read64(&mut bus, 0x5000).unwrap(), 0xbb);
+    }
+
+    #[test]
+    fn sfence_vma_other_asid_misses() {
+        let (mut hart, mut bus) = setup();
+        hart.read64(&mut bus, 0x5000).unwrap();
+
+        let satp = hart.csrs().get(csr::SATP);
+        hart.csrs_mut().set(csr::SATP, satp ^ (3 << 44));
+        hart.read64(&mut bus, 0x5000).unwrap();
+
+        assert_eq!(hart.mmu().dtlb_stats().misses, 2);
+    }
+
+    #[test]
+    fn sfence_vma_illegal_in_user_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::User);
+        let inst = encode_sfence_vma(0, 0);
+
+        let err = exec(&mut hart, &mut bus, inst).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn sfence_vma_illegal_when_tvm_set() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_TVM);
+        let inst = encode_sfence_vma(0, 0);
+
+        let err = exec(&mut hart, &mut bus, inst).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn sfence_vma_virtual_instruction_when_vtvm_set() {
+        let (mut hart, mut bus) = setup();
+        hart.set_virt(true);
+        hart.csrs_mut().set(csr::HSTATUS, csr::HSTATUS_VTVM);
+        let inst = encode_sfence_vma(0, 0);
+
+        let err = exec(&mut hart, &mut bus, inst).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn sfence_vma_in_guest_leaves_host_translations_alone() {
+        let (mut hart, mut bus) = setup();
+        bus.write64(0x8000, 0xaa).unwrap();
+        bus.write64(0x9000, 0xbb).unwrap();
+        hart.read64(&mut bus, 0x5000).unwrap();
+        map(&mut bus, 0x9000, 0);
+
+        hart.set_virt(true);
+        exec(&mut hart, &mut bus, encode_sfence_vma(0, 0)).unwrap();
+        hart.set_virt(false);
+
+        assert_eq!(hart.read64(&mut bus, 0x5000).unwrap(), 0xaa);
+    }
+}
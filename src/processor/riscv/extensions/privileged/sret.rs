@@ -0,0 +1,182 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        hart::{Hart, Privilege},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Sret;
+
+impl InstrExec for Sret {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst == 0x1020_0073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "SRET", skip_all))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let tsr = hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_TSR != 0;
+        let vtsr = hart.csrs().get(csr::HSTATUS) & csr::HSTATUS_VTSR != 0;
+        match (hart.privilege(), hart.virt()) {
+            (Privilege::User, true) => return Err(Trap::VirtualInstruction { inst }.into()),
+            (Privilege::Supervisor, true) if vtsr => {
+                return Err(Trap::VirtualInstruction { inst }.into());
+            }
+            (Privilege::User, false) => return Err(Trap::IllegalInstruction { inst }.into()),
+            (Privilege::Supervisor, false) if tsr => {
+                return Err(Trap::IllegalInstruction { inst }.into());
+            }
+            _ => {}
+        }
+
+        hart.sret();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{hart::Hart, instruction::InstrExec},
+    };
+
+    const SRET: u32 = 0x1020_0073;
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        hart.set_privilege(Privilege::Supervisor);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        Sret.call(SRET, hart, bus)
+    }
+
+    fn set_mstatus(hart: &mut Hart, mstatus: u64) {
+        hart.csrs_mut().set(csr::MSTATUS, mstatus);
+    }
+
+    #[test]
+    fn sret_jumps_to_sepc() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::SEPC, 0x4000);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x4000);
+        assert_eq!(hart.privilege(), Privilege::User);
+    }
+
+    #[test]
+    fn sret_restores_supervisor_from_spp() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, csr::MSTATUS_SPP);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_SPP, 0);
+    }
+
+    #[test]
+    fn sret_restores_interrupt_enable() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, csr::MSTATUS_SPIE);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        assert_ne!(mstatus & csr::MSTATUS_SIE, 0);
+        assert_ne!(mstatus & csr::MSTATUS_SPIE, 0);
+    }
+
+    #[test]
+    fn sret_clears_mprv() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, csr::MSTATUS_MPRV);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MPRV, 0);
+    }
+
+    #[test]
+    fn sret_allowed_in_machine_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::Machine);
+        set_mstatus(&mut hart, csr::MSTATUS_TSR | csr::MSTATUS_SPP);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+    }
+
+    #[test]
+    fn sret_illegal_in_user_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::User);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst: SRET }
+        );
+    }
+
+    #[test]
+    fn sret_illegal_when_tsr_set() {
+        let (mut hart, mut bus) = setup();
+        set_mstatus(&mut hart, csr::MSTATUS_TSR);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst: SRET }
+        );
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+    }
+
+    #[test]
+    fn sret_virtual_instruction_in_virtual_user_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::User);
+        hart.set_virt(true);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst: SRET }
+        );
+    }
+
+    #[test]
+    fn sret_virtual_instruction_when_vtsr_set() {
+        let (mut hart, mut bus) = setup();
+        hart.set_virt(true);
+        hart.csrs_mut().set(csr::HSTATUS, csr::HSTATUS_VTSR);
+        // mstatus.TSR governs HS-mode only.
+        set_mstatus(&mut hart, csr::MSTATUS_TSR);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst: SRET }
+        );
+
+        hart.csrs_mut().set(csr::HSTATUS, 0);
+        hart.csrs_mut().set(csr::VSEPC, 0x4000);
+        exec(&mut hart, &mut bus).unwrap();
+        assert_eq!(hart.pc(), 0x4000);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        hart::{Hart, Privilege},
+        instruction::InstrExec,
+    },
+};
+
+/// Stalls the hart until an interrupt is pending. Below M-mode the wait is
+/// not allowed to be unbounded when `mstatus.TW` is set, nor in a guest when
+/// `hstatus.VTW` is, and this hart never lets it time out, so those trap at
+/// once. U-mode may never wait.
+#[derive(Debug)]
+pub struct Wfi;
+
+impl InstrExec for Wfi {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst == 0x1050_0073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "WFI", skip_all))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let tw = hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_TW != 0;
+        let vtw = hart.csrs().get(csr::HSTATUS) & csr::HSTATUS_VTW != 0;
+        match (hart.privilege(), hart.virt()) {
+            (Privilege::Machine, _) => {}
+            _ if tw => return Err(Trap::IllegalInstruction { inst }.into()),
+            (Privilege::User, false) => return Err(Trap::IllegalInstruction { inst }.into()),
+            (Privilege::User, true) => return Err(Trap::VirtualInstruction { inst }.into()),
+            (Privilege::Supervisor, true) if vtw => {
+                return Err(Trap::VirtualInstruction { inst }.into());
+            }
+            _ => {}
+        }
+
+        // The interrupt that ends the wait is taken after WFI retires, so
+        // `xepc` points past it.
+        hart.next_pc();
+        hart.wait_for_interrupt();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::{
+            Cpu,
+            riscv::{hart::Hart, instruction::InstrExec},
+        },
+    };
+
+    const WFI: u32 = 0x1050_0073;
+    const NOP: u32 = 0x0000_0013;
+
+    fn setup() -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write32(0x1000, WFI).unwrap();
+        bus.write32(0x1004, NOP).unwrap();
+
+        let mut hart = Hart::new(0x1000);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+
+        (hart, bus)
+    }
+
+    fn exec(hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        Wfi.call(WFI, hart, bus)
+    }
+
+    #[test]
+    fn wfi_stalls_until_interrupt_pending() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MIE, csr::MIP_MTIP);
+
+        hart.step(&mut bus).unwrap();
+        assert!(hart.idle());
+        assert_eq!(hart.pc(), 0x1004);
+
+        hart.step(&mut bus).unwrap();
+        assert!(hart.idle());
+        assert_eq!(hart.pc(), 0x1004);
+        assert_eq!(hart.csrs().get(csr::MINSTRET), 1);
+
+        hart.set_interrupt(csr::MIP_MTIP, true);
+        hart.step(&mut bus).unwrap();
+        assert!(!hart.idle());
+        assert_eq!(hart.pc(), 0x1008);
+    }
+
+    #[test]
+    fn wfi_ignores_interrupts_disabled_in_mie() {
+        let (mut hart, mut bus) = setup();
+        hart.set_interrupt(csr::MIP_MTIP, true);
+
+        hart.step(&mut bus).unwrap();
+        hart.step(&mut bus).unwrap();
+
+        assert!(hart.idle());
+        assert_eq!(hart.pc(), 0x1004);
+    }
+
+    #[test]
+    fn wfi_wakes_into_enabled_interrupt() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MIE, csr::MIP_MSIP);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_MIE);
+
+        hart.step(&mut bus).unwrap();
+        hart.set_interrupt(csr::MIP_MSIP, true);
+        hart.step(&mut bus).unwrap();
+
+        assert!(!hart.idle());
+        assert_eq!(hart.pc(), 0x2000);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1004);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), (1 << 63) | 3);
+    }
+
+    #[test]
+    fn wfi_allowed_in_supervisor_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::Supervisor);
+
+        exec(&mut hart, &mut bus).unwrap();
+
+        assert!(hart.idle());
+        assert_eq!(hart.pc(), 0x1004);
+    }
+
+    #[test]
+    fn wfi_illegal_in_user_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::User);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst: WFI }
+        );
+        assert!(!hart.idle());
+    }
+
+    #[test]
+    fn wfi_illegal_when_tw_set() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::Supervisor);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_TW);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst: WFI }
+        );
+
+        hart.set_privilege(Privilege::Machine);
+        exec(&mut hart, &mut bus).unwrap();
+        assert!(hart.idle());
+    }
+
+    #[test]
+    fn wfi_virtual_instruction_in_guest() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::User);
+        hart.set_virt(true);
+
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst: WFI }
+        );
+
+        hart.set_privilege(Privilege::Supervisor);
+        hart.csrs_mut().set(csr::HSTATUS, csr::HSTATUS_VTW);
+        let err = exec(&mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::VirtualInstruction { inst: WFI }
+        );
+
+        hart.csrs_mut().set(csr::HSTATUS, 0);
+        exec(&mut hart, &mut bus).unwrap();
+        assert!(hart.idle());
+    }
+}
@@ -0,0 +1,1490 @@
+//! Decoding and element loops shared by the V extension instructions.
+//!
+//! Every instruction works on the body elements between `vstart` and `vl`.
+//! Results are collected before any of them is written, so that a
+//! destination group may overlap its sources where the spec allows it.
+//! Agnostic elements, inactive ones under `vma` and tail ones under `vta`
+//! or in mask destinations, are always overwritten with all ones.
+
+pub mod vaadd;
+pub mod vaaddu;
+pub mod vadc;
+pub mod vadd;
+pub mod vand;
+pub mod vasub;
+pub mod vasubu;
+pub mod vcompress;
+pub mod vcpop;
+pub mod vdiv;
+pub mod vdivu;
+pub mod vfadd;
+pub mod vfclass;
+pub mod vfcvt_f_x;
+pub mod vfcvt_f_xu;
+pub mod vfcvt_rtz_x_f;
+pub mod vfcvt_rtz_xu_f;
+pub mod vfcvt_x_f;
+pub mod vfcvt_xu_f;
+pub mod vfdiv;
+pub mod vfirst;
+pub mod vfmacc;
+pub mod vfmadd;
+pub mod vfmax;
+pub mod vfmerge;
+pub mod vfmin;
+pub mod vfmsac;
+pub mod vfmsub;
+pub mod vfmul;
+pub mod vfmv_f_s;
+pub mod vfmv_s_f;
+pub mod vfmv_v_f;
+pub mod vfncvt_f_f;
+pub mod vfncvt_f_x;
+pub mod vfncvt_f_xu;
+pub mod vfncvt_rod_f_f;
+pub mod vfncvt_rtz_x_f;
+pub mod vfncvt_rtz_xu_f;
+pub mod vfncvt_x_f;
+pub mod vfncvt_xu_f;
+pub mod vfnmacc;
+pub mod vfnmadd;
+pub mod vfnmsac;
+pub mod vfnmsub;
+pub mod vfrdiv;
+pub mod vfrec7;
+pub mod vfredmax;
+pub mod vfredmin;
+pub mod vfredosum;
+pub mod vfredusum;
+pub mod vfrsqrt7;
+pub mod vfrsub;
+pub mod vfsgnj;
+pub mod vfsgnjn;
+pub mod vfsgnjx;
+pub mod vfslide1down;
+pub mod vfslide1up;
+pub mod vfsqrt;
+pub mod vfsub;
+pub mod vfwadd;
+pub mod vfwadd_w;
+pub mod vfwcvt_f_f;
+pub mod vfwcvt_f_x;
+pub mod vfwcvt_f_xu;
+pub mod vfwcvt_rtz_x_f;
+pub mod vfwcvt_rtz_xu_f;
+pub mod vfwcvt_x_f;
+pub mod vfwcvt_xu_f;
+pub mod vfwmacc;
+pub mod vfwmsac;
+pub mod vfwmul;
+pub mod vfwnmacc;
+pub mod vfwnmsac;
+pub mod vfwredosum;
+pub mod vfwredusum;
+pub mod vfwsub;
+pub mod vfwsub_w;
+pub mod vid;
+pub mod viota;
+pub mod vle;
+pub mod vleff;
+pub mod vlm;
+pub mod vloxei;
+pub mod vlr;
+pub mod vlse;
+pub mod vluxei;
+pub mod vmacc;
+pub mod vmadc;
+pub mod vmadd;
+pub mod vmand;
+pub mod vmandn;
+pub mod vmax;
+pub mod vmaxu;
+pub mod vmerge;
+pub mod vmfeq;
+pub mod vmfge;
+pub mod vmfgt;
+pub mod vmfle;
+pub mod vmflt;
+pub mod vmfne;
+pub mod vmin;
+pub mod vminu;
+pub mod vmnand;
+pub mod vmnor;
+pub mod vmor;
+pub mod vmorn;
+pub mod vmsbc;
+pub mod vmsbf;
+pub mod vmseq;
+pub mod vmsgt;
+pub mod vmsgtu;
+pub mod vmsif;
+pub mod vmsle;
+pub mod vmsleu;
+pub mod vmslt;
+pub mod vmsltu;
+pub mod vmsne;
+pub mod vmsof;
+pub mod vmul;
+pub mod vmulh;
+pub mod vmulhsu;
+pub mod vmulhu;
+pub mod vmv_nr_r;
+pub mod vmv_s_x;
+pub mod vmv_v;
+pub mod vmv_x_s;
+pub mod vmxnor;
+pub mod vmxor;
+pub mod vnclip;
+pub mod vnclipu;
+pub mod vnmsac;
+pub mod vnmsub;
+pub mod vnsra;
+pub mod vnsrl;
+pub mod vor;
+pub mod vredand;
+pub mod vredmax;
+pub mod vredmaxu;
+pub mod vredmin;
+pub mod vredminu;
+pub mod vredor;
+pub mod vredsum;
+pub mod vredxor;
+pub mod vrem;
+pub mod vremu;
+pub mod vrgather;
+pub mod vrgatherei16;
+pub mod vrsub;
+pub mod vsadd;
+pub mod vsaddu;
+pub mod vsbc;
+pub mod vse;
+pub mod vsetivli;
+pub mod vsetvl;
+pub mod vsetvli;
+pub mod vsext;
+pub mod vslide1down;
+pub mod vslide1up;
+pub mod vslidedown;
+pub mod vslideup;
+pub mod vsll;
+pub mod vsm;
+pub mod vsmul;
+pub mod vsoxei;
+pub mod vsr;
+pub mod vsra;
+pub mod vsrl;
+pub mod vsse;
+pub mod vssra;
+pub mod vssrl;
+pub mod vssub;
+pub mod vssubu;
+pub mod vsub;
+pub mod vsuxei;
+pub mod vwadd;
+pub mod vwadd_w;
+pub mod vwaddu;
+pub mod vwaddu_w;
+pub mod vwmacc;
+pub mod vwmaccsu;
+pub mod vwmaccu;
+pub mod vwmaccus;
+pub mod vwmul;
+pub mod vwmulsu;
+pub mod vwmulu;
+pub mod vwredsum;
+pub mod vwredsumu;
+pub mod vwsub;
+pub mod vwsub_w;
+pub mod vwsubu;
+pub mod vwsubu_w;
+pub mod vxor;
+pub mod vzext;
+
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        hart::Hart,
+        softfloat::{F32, F64, Format, RoundingMode, SoftFloat},
+        vector::{VTYPE_VILL, VectorRegs, Vtype},
+    },
+};
+
+pub(crate) const OP_V: u32 = 0b101_0111;
+pub(crate) const OP_LOAD_FP: u32 = 0b000_0111;
+pub(crate) const OP_STORE_FP: u32 = 0b010_0111;
+
+pub(crate) const OPIVV: u32 = 0b000;
+pub(crate) const OPFVV: u32 = 0b001;
+pub(crate) const OPMVV: u32 = 0b010;
+pub(crate) const OPIVI: u32 = 0b011;
+pub(crate) const OPIVX: u32 = 0b100;
+pub(crate) const OPFVF: u32 = 0b101;
+pub(crate) const OPMVX: u32 = 0b110;
+pub(crate) const OPCFG: u32 = 0b111;
+
+/// Operand forms an instruction accepts, one bit per `funct3` category.
+pub(crate) const IVV: u8 = 1 << OPIVV;
+pub(crate) const FVV: u8 = 1 << OPFVV;
+pub(crate) const MVV: u8 = 1 << OPMVV;
+pub(crate) const IVI: u8 = 1 << OPIVI;
+pub(crate) const IVX: u8 = 1 << OPIVX;
+pub(crate) const FVF: u8 = 1 << OPFVF;
+pub(crate) const MVX: u8 = 1 << OPMVX;
+
+/// Addressing modes in the `mop` field of loads and stores.
+pub(crate) const MOP_UNIT: u32 = 0b00;
+pub(crate) const MOP_INDEXED_UNORDERED: u32 = 0b01;
+pub(crate) const MOP_STRIDED: u32 = 0b10;
+pub(crate) const MOP_INDEXED_ORDERED: u32 = 0b11;
+
+/// Unit-stride variants in the `lumop`/`sumop` field.
+pub(crate) const UMOP_UNIT: u32 = 0b00000;
+pub(crate) const UMOP_WHOLE: u32 = 0b01000;
+pub(crate) const UMOP_MASK: u32 = 0b01011;
+pub(crate) const UMOP_FAULT_FIRST: u32 = 0b10000;
+
+/// Matches an OP-V instruction by `funct6` and operand form.
+#[inline(always)]
+pub(crate) const fn op_v(inst: u32, funct6: u32, forms: u8) -> bool {
+    inst & 0x7f == OP_V && inst >> 26 == funct6 && forms & (1 << ((inst >> 12) & 0b111)) != 0
+}
+
+/// Like [`op_v`], for unary instructions selected by the `vs1` field, or
+/// by `vs2` for the moves from scalars.
+#[inline(always)]
+pub(crate) const fn op_v_unary(inst: u32, funct6: u32, forms: u8, field: u32, sel: u32) -> bool {
+    op_v(inst, funct6, forms) && (inst >> field) & 0x1f == sel
+}
+
+/// Matches a vector load or store by opcode and `mop`, and by the unit-stride
+/// variant when `umop` is given. Scalar FP widths and `mew` are excluded.
+#[inline(always)]
+pub(crate) const fn op_mem(inst: u32, opcode: u32, mop: u32, umop: Option<u32>) -> bool {
+    if inst & 0x7f != opcode || (inst >> 28) & 1 != 0 || (inst >> 26) & 0b11 != mop {
+        return false;
+    }
+    if !matches!((inst >> 12) & 0b111, 0b000 | 0b101 | 0b110 | 0b111) {
+        return false;
+    }
+    match umop {
+        Some(umop) => (inst >> 20) & 0x1f == umop,
+        None => true,
+    }
+}
+
+/// Element width in bits encoded in the `width` field of a load or store.
+#[inline(always)]
+pub(crate) const fn mem_eew(inst: u32) -> u32 {
+    match (inst >> 12) & 0b111 {
+        0b000 => 8,
+        0b101 => 16,
+        0b110 => 32,
+        _ => 64,
+    }
+}
+
+/// Number of fields of a segment load or store.
+#[inline(always)]
+pub(crate) const fn nf(inst: u32) -> usize {
+    ((inst >> 29) & 0b111) as usize + 1
+}
+
+/// All ones in the low `bits` bits.
+#[inline(always)]
+pub(crate) const fn ones(bits: u32) -> u64 {
+    u64::MAX >> (64 - bits)
+}
+
+/// Sign-extends the low `bits` bits of `val`.
+#[inline(always)]
+pub(crate) const fn sext(val: u64, bits: u32) -> i64 {
+    ((val << (64 - bits)) as i64) >> (64 - bits)
+}
+
+/// Number of registers in a group with EMUL `2^emul`.
+#[inline(always)]
+pub(crate) const fn group_regs(emul: i32) -> usize {
+    if emul > 0 { 1 << emul } else { 1 }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Operands {
+    pub vd: usize,
+    pub vs1: usize,
+    pub vs2: usize,
+    pub funct3: u32,
+    /// Set when `vm` is clear, i.e. when `v0` masks the operation.
+    pub masked: bool,
+}
+
+impl Operands {
+    #[inline(always)]
+    pub(crate) const fn decode(inst: u32) -> Self {
+        Self {
+            vd: ((inst >> 7) & 0x1f) as usize,
+            vs1: ((inst >> 15) & 0x1f) as usize,
+            vs2: ((inst >> 20) & 0x1f) as usize,
+            funct3: (inst >> 12) & 0b111,
+            masked: (inst >> 25) & 1 == 0,
+        }
+    }
+
+    /// The 5-bit immediate of the `.vi` forms, sign-extended.
+    #[inline(always)]
+    pub(crate) const fn simm5(&self) -> i64 {
+        sext(self.vs1 as u64, 5)
+    }
+}
+
+/// The vector configuration an instruction executes under.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Config {
+    pub vl: usize,
+    pub vstart: usize,
+    pub sew: u32,
+    pub lmul: i32,
+    pub vta: bool,
+    pub vma: bool,
+    pub vlen: u32,
+    pub elen: u32,
+}
+
+impl Config {
+    /// Element width and EMUL of an operand `shift` times as wide as SEW
+    /// (narrower for negative shifts), failing when either is out of range.
+    pub(crate) const fn width(&self, inst: u32, shift: i32) -> Result<(u32, i32), Trap> {
+        let eew = if shift >= 0 {
+            self.sew << shift
+        } else {
+            self.sew >> -shift
+        };
+        let emul = self.lmul + shift;
+        if eew < 8 || eew > self.elen || emul < -3 || emul > 3 {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        Ok((eew, emul))
+    }
+
+    /// Number of `eew`-bit elements in a group with EMUL `2^emul`, counting
+    /// the whole register for fractional groups, which is what tail policy
+    /// applies to.
+    pub(crate) const fn group_elements(&self, eew: u32, emul: i32) -> usize {
+        VectorRegs::elements(self.vlen, eew, if emul > 0 { emul } else { 0 })
+    }
+
+    /// Largest `vl` for the current SEW and LMUL.
+    pub(crate) const fn vlmax(&self) -> usize {
+        VectorRegs::elements(self.vlen, self.sew, self.lmul)
+    }
+}
+
+/// Reads the vector configuration, failing when V is off or `vtype` is
+/// illegal.
+#[inline(always)]
+pub(crate) fn config(hart: &Hart, inst: u32) -> Result<Config, Trap> {
+    hart.check_vector(inst)?;
+    let vtype = Vtype::from_bits(hart.csrs().get(csr::VTYPE));
+    if vtype.vill() {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+
+    Ok(Config {
+        vl: hart.csrs().get(csr::VL) as usize,
+        vstart: hart.csrs().get(csr::VSTART) as usize,
+        sew: vtype.sew(),
+        lmul: vtype.lmul(),
+        vta: vtype.vta(),
+        vma: vtype.vma(),
+        vlen: hart.vregs().vlen(),
+        elen: hart.vregs().elen(),
+    })
+}
+
+/// Fails unless `reg` can start a group with EMUL `2^emul`.
+#[inline(always)]
+pub(crate) const fn check_group(inst: u32, reg: usize, emul: i32) -> Result<(), Trap> {
+    if !reg.is_multiple_of(group_regs(emul)) {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+    Ok(())
+}
+
+/// Fails when a destination group overlaps a source group of another element
+/// width, except where the overlap is in the lowest-numbered part of a wider
+/// source or in the highest-numbered part of a wider destination.
+pub(crate) const fn check_overlap(
+    inst: u32,
+    (dst, dst_eew, dst_emul): (usize, u32, i32),
+    (src, src_eew, src_emul): (usize, u32, i32),
+) -> Result<(), Trap> {
+    let dst_regs = group_regs(dst_emul);
+    let src_regs = group_regs(src_emul);
+    let legal = dst + dst_regs <= src
+        || src + src_regs <= dst
+        || dst_eew == src_eew
+        || (dst_eew < src_eew && dst == src)
+        || (dst_eew > src_eew && src_emul >= 0 && src + src_regs == dst + dst_regs);
+    if !legal {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+    Ok(())
+}
+
+/// Fails when a masked instruction's destination data group includes `v0`.
+#[inline(always)]
+pub(crate) const fn check_mask_overlap(inst: u32, ops: &Operands) -> Result<(), Trap> {
+    if ops.masked && ops.vd == 0 {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+    Ok(())
+}
+
+/// Fails unless `vstart` is zero, as reductions and the mask-scanning
+/// instructions require.
+#[inline(always)]
+pub(crate) const fn check_vstart_zero(inst: u32, cfg: &Config) -> Result<(), Trap> {
+    if cfg.vstart != 0 {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+    Ok(())
+}
+
+/// Tells whether element `i` is active under `v0`.
+#[inline(always)]
+pub(crate) fn active(hart: &Hart, ops: &Operands, i: usize) -> bool {
+    !ops.masked || hart.vregs().mask(0, i)
+}
+
+/// How the 5-bit immediate of a `.vi` form is extended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Imm {
+    Signed,
+    Unsigned,
+}
+
+/// The scalar operand of a `.vx`, `.vi` or `.vf` form, truncated to `sew`
+/// bits, or `None` for the `.vv` forms.
+#[inline(always)]
+pub(crate) fn scalar(hart: &Hart, ops: &Operands, sew: u32, imm: Imm) -> Option<u64> {
+    match ops.funct3 {
+        OPIVX | OPMVX => Some(hart.xreg(ops.vs1) & ones(sew)),
+        OPIVI if imm == Imm::Signed => Some(ops.simm5() as u64 & ones(sew)),
+        OPIVI => Some(ops.vs1 as u64),
+        OPFVF if sew == 64 => Some(hart.read_freg(F64, ops.vs1)),
+        OPFVF => Some(hart.read_freg(F32, ops.vs1) & ones(sew)),
+        _ => None,
+    }
+}
+
+/// Element widths of the destination and the `vs2` source, as a power of two
+/// times SEW. `vs1` and scalar operands are always SEW wide.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Shape {
+    pub dst: i32,
+    pub vs2: i32,
+}
+
+impl Shape {
+    pub(crate) const SINGLE: Self = Self { dst: 0, vs2: 0 };
+    /// `2*SEW = SEW op SEW`.
+    pub(crate) const WIDEN: Self = Self { dst: 1, vs2: 0 };
+    /// `2*SEW = 2*SEW op SEW`, the `.w` forms.
+    pub(crate) const WIDEN_W: Self = Self { dst: 1, vs2: 1 };
+    /// `SEW = 2*SEW op SEW`.
+    pub(crate) const NARROW: Self = Self { dst: 0, vs2: 1 };
+
+    /// `SEW = SEW / 2^factor`, for the integer extensions.
+    pub(crate) const fn extend(factor: i32) -> Self {
+        Self {
+            dst: 0,
+            vs2: -factor,
+        }
+    }
+}
+
+/// The second source of an element-wise instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Src1 {
+    /// `vs1` is a register, or a scalar for the `.vx`/`.vi`/`.vf` forms.
+    Operand(Imm),
+    /// `vs1` selects the operation and there is no second source.
+    None,
+}
+
+/// Runs an element-wise instruction, `op(sew, vd, vs2, vs1, v0)` giving each
+/// active destination element from the old destination, the sources and
+/// the element's bit of `v0`. With `v0_operand`, `v0` is an operand rather
+/// than a mask and every body element is active.
+fn elementwise(
+    hart: &mut Hart,
+    inst: u32,
+    shape: Shape,
+    src1: Src1,
+    v0_operand: bool,
+    mut op: impl FnMut(u32, u64, u64, u64, bool) -> u64,
+) -> Result<(), Trap> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    let (dst_eew, dst_emul) = cfg.width(inst, shape.dst)?;
+    let (src_eew, src_emul) = cfg.width(inst, shape.vs2)?;
+    let dst = (ops.vd, dst_eew, dst_emul);
+
+    check_group(inst, ops.vd, dst_emul)?;
+    check_group(inst, ops.vs2, src_emul)?;
+    check_overlap(inst, dst, (ops.vs2, src_eew, src_emul))?;
+    check_mask_overlap(inst, &ops)?;
+
+    let scalar = match src1 {
+        Src1::Operand(imm) => scalar(hart, &ops, cfg.sew, imm),
+        Src1::None => Some(0),
+    };
+    if scalar.is_none() {
+        check_group(inst, ops.vs1, cfg.lmul)?;
+        check_overlap(inst, dst, (ops.vs1, cfg.sew, cfg.lmul))?;
+    }
+
+    let regs = hart.vregs();
+    let body: Vec<_> = (cfg.vstart..cfg.vl)
+        .map(|i| {
+            let v0 = regs.mask(0, i);
+            (v0_operand || active(hart, &ops, i)).then(|| {
+                let a = regs.get(ops.vs2, i, src_eew);
+                let b = scalar.unwrap_or_else(|| regs.get(ops.vs1, i, cfg.sew));
+                let old = regs.get(ops.vd, i, dst_eew);
+                op(cfg.sew, old, a, b, v0) & ones(dst_eew)
+            })
+        })
+        .collect();
+
+    commit(hart, &cfg, (ops.vd, dst_eew, dst_emul), &body);
+    Ok(())
+}
+
+/// `vd[i] = op(sew, vs2[i], vs1[i])`, or the scalar operand in place of
+/// `vs1[i]`. The result is truncated to the destination width.
+pub(crate) fn binary(
+    hart: &mut Hart,
+    inst: u32,
+    shape: Shape,
+    imm: Imm,
+    mut op: impl FnMut(u32, u64, u64) -> u64,
+) -> Result<(), Trap> {
+    elementwise(
+        hart,
+        inst,
+        shape,
+        Src1::Operand(imm),
+        false,
+        |sew, _, a, b, _| op(sew, a, b),
+    )
+}
+
+/// `vd[i] = op(sew, vd[i], vs1[i], vs2[i])`, for the multiply-adds, with
+/// `vs1[i]` or the scalar operand.
+pub(crate) fn ternary(
+    hart: &mut Hart,
+    inst: u32,
+    shape: Shape,
+    mut op: impl FnMut(u32, u64, u64, u64) -> u64,
+) -> Result<(), Trap> {
+    elementwise(
+        hart,
+        inst,
+        shape,
+        Src1::Operand(Imm::Signed),
+        false,
+        |sew, old, a, b, _| op(sew, old, b, a),
+    )
+}
+
+/// `vd[i] = op(sew, vs2[i])`.
+pub(crate) fn unary(
+    hart: &mut Hart,
+    inst: u32,
+    shape: Shape,
+    mut op: impl FnMut(u32, u64) -> u64,
+) -> Result<(), Trap> {
+    elementwise(hart, inst, shape, Src1::None, false, |sew, _, a, _, _| {
+        op(sew, a)
+    })
+}
+
+/// `vd[i] = op(sew, vs2[i], vs1[i], v0.mask[i])` over every body element,
+/// for the merges and the add/subtract-with-carry instructions that take
+/// `v0` as an operand.
+pub(crate) fn merge(
+    hart: &mut Hart,
+    inst: u32,
+    imm: Imm,
+    mut op: impl FnMut(u32, u64, u64, bool) -> u64,
+) -> Result<(), Trap> {
+    elementwise(
+        hart,
+        inst,
+        Shape::SINGLE,
+        Src1::Operand(imm),
+        true,
+        |sew, _, a, b, v0| op(sew, a, b, v0),
+    )
+}
+
+/// Writes the body results of an instruction to the `(reg, eew, emul)`
+/// destination group, `None` standing for inactive elements, then applies
+/// the tail policy and clears `vstart`. Nothing is written when `vstart` is
+/// at or past `vl`.
+pub(crate) fn commit(
+    hart: &mut Hart,
+    cfg: &Config,
+    (vd, eew, emul): (usize, u32, i32),
+    body: &[Option<u64>],
+) {
+    if cfg.vstart < cfg.vl {
+        let regs = hart.vregs_mut();
+        for (i, val) in (cfg.vstart..).zip(body) {
+            match val {
+                Some(val) => regs.set(vd, i, eew, *val),
+                None if cfg.vma => regs.set(vd, i, eew, ones(eew)),
+                None => {}
+            }
+        }
+        if cfg.vta {
+            for i in cfg.vl..cfg.group_elements(eew, emul) {
+                regs.set(vd, i, eew, ones(eew));
+            }
+        }
+    }
+    finish(hart);
+}
+
+/// Like [`commit`] for a mask destination, whose tail is always agnostic.
+pub(crate) fn commit_mask(hart: &mut Hart, cfg: &Config, vd: usize, body: &[Option<bool>]) {
+    if cfg.vstart < cfg.vl {
+        let regs = hart.vregs_mut();
+        for (i, bit) in (cfg.vstart..).zip(body) {
+            match bit {
+                Some(bit) => regs.set_mask(vd, i, *bit),
+                None if cfg.vma => regs.set_mask(vd, i, true),
+                None => {}
+            }
+        }
+        for i in cfg.vl..cfg.vlen as usize {
+            regs.set_mask(vd, i, true);
+        }
+    }
+    finish(hart);
+}
+
+/// Completes a vector instruction: `vstart` goes back to zero and the
+/// vector state becomes dirty.
+#[inline(always)]
+pub(crate) fn finish(hart: &mut Hart) {
+    hart.csrs_mut().set(csr::VSTART, 0);
+    hart.dirty_vector();
+}
+
+/// Mask-producing counterpart of [`elementwise`].
+fn compare_core(
+    hart: &mut Hart,
+    inst: u32,
+    imm: Imm,
+    v0_operand: bool,
+    mut op: impl FnMut(u32, u64, u64, bool) -> bool,
+) -> Result<(), Trap> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    let dst = (ops.vd, 1, 0);
+
+    check_group(inst, ops.vs2, cfg.lmul)?;
+    check_overlap(inst, dst, (ops.vs2, cfg.sew, cfg.lmul))?;
+    let scalar = scalar(hart, &ops, cfg.sew, imm);
+    if scalar.is_none() {
+        check_group(inst, ops.vs1, cfg.lmul)?;
+        check_overlap(inst, dst, (ops.vs1, cfg.sew, cfg.lmul))?;
+    }
+
+    let regs = hart.vregs();
+    let body: Vec<_> = (cfg.vstart..cfg.vl)
+        .map(|i| {
+            let v0 = v0_operand && regs.mask(0, i);
+            (v0_operand || active(hart, &ops, i)).then(|| {
+                let a = regs.get(ops.vs2, i, cfg.sew);
+                let b = scalar.unwrap_or_else(|| regs.get(ops.vs1, i, cfg.sew));
+                op(cfg.sew, a, b, v0)
+            })
+        })
+        .collect();
+
+    commit_mask(hart, &cfg, ops.vd, &body);
+    Ok(())
+}
+
+/// `vd.mask[i] = op(sew, vs2[i], vs1[i])`, or the scalar operand in place of
+/// `vs1[i]`.
+pub(crate) fn compare(
+    hart: &mut Hart,
+    inst: u32,
+    imm: Imm,
+    mut op: impl FnMut(u32, u64, u64) -> bool,
+) -> Result<(), Trap> {
+    compare_core(hart, inst, imm, false, |sew, a, b, _| op(sew, a, b))
+}
+
+/// `vd.mask[i] = op(sew, vs2[i], vs1[i], carry)` over every body element,
+/// for the carry and borrow outputs. The carry in comes from `v0` when the
+/// instruction is encoded with `vm` clear and is zero otherwise.
+pub(crate) fn carry_out(
+    hart: &mut Hart,
+    inst: u32,
+    imm: Imm,
+    op: impl FnMut(u32, u64, u64, bool) -> bool,
+) -> Result<(), Trap> {
+    let masked = Operands::decode(inst).masked;
+    compare_core(hart, inst, imm, masked, op)?;
+    Ok(())
+}
+
+/// `vd.mask[i] = op(vs2.mask[i], vs1.mask[i])` over the first `vl` bits.
+/// The mask-register logical instructions are always unmasked.
+pub(crate) fn mask_logical(
+    hart: &mut Hart,
+    inst: u32,
+    op: impl Fn(bool, bool) -> bool,
+) -> Result<(), Trap> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+
+    let regs = hart.vregs();
+    let body: Vec<_> = (cfg.vstart..cfg.vl)
+        .map(|i| Some(op(regs.mask(ops.vs2, i), regs.mask(ops.vs1, i))))
+        .collect();
+
+    commit_mask(hart, &cfg, ops.vd, &body);
+    Ok(())
+}
+
+/// Runs one of the set-before, set-including and set-only-first
+/// instructions, `op(before, first)` giving each active result bit from
+/// whether the element comes before the first active set bit of `vs2` or is
+/// that bit.
+pub(crate) fn mask_scan(
+    hart: &mut Hart,
+    inst: u32,
+    op: impl Fn(bool, bool) -> bool,
+) -> Result<(), Trap> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    check_vstart_zero(inst, &cfg)?;
+    check_mask_overlap(inst, &ops)?;
+    if ops.vd == ops.vs2 {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+
+    let regs = hart.vregs();
+    let mut seen = false;
+    let body: Vec<_> = (0..cfg.vl)
+        .map(|i| {
+            active(hart, &ops, i).then(|| {
+                let first = !seen && regs.mask(ops.vs2, i);
+                let before = !seen && !first;
+                seen |= first;
+                op(before, first)
+            })
+        })
+        .collect();
+
+    commit_mask(hart, &cfg, ops.vd, &body);
+    Ok(())
+}
+
+/// The offset of a slide or the index of a `.vx`/`.vi` gather: the whole of
+/// `x[rs1]`, or the unsigned immediate.
+#[inline(always)]
+pub(crate) fn scalar_index(hart: &Hart, ops: &Operands) -> u64 {
+    match ops.funct3 {
+        OPIVI => ops.vs1 as u64,
+        _ => hart.xreg(ops.vs1),
+    }
+}
+
+/// Fails when the `vd` and `vs2` groups of a permutation overlap, which
+/// slides up and gathers do not allow.
+#[inline(always)]
+pub(crate) const fn check_disjoint(
+    inst: u32,
+    (a, a_emul): (usize, i32),
+    (b, b_emul): (usize, i32),
+) -> Result<(), Trap> {
+    if a < b + group_regs(b_emul) && b < a + group_regs(a_emul) {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+    Ok(())
+}
+
+/// Slides `vs2` up or down by one element, filling the vacated element with
+/// the scalar operand.
+pub(crate) fn slide1(hart: &mut Hart, inst: u32, up: bool) -> Result<(), Trap> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    check_group(inst, ops.vd, cfg.lmul)?;
+    check_group(inst, ops.vs2, cfg.lmul)?;
+    check_mask_overlap(inst, &ops)?;
+    if up {
+        check_disjoint(inst, (ops.vd, cfg.lmul), (ops.vs2, cfg.lmul))?;
+    }
+
+    let scalar = scalar(hart, &ops, cfg.sew, Imm::Signed).unwrap_or(0);
+    let regs = hart.vregs();
+    let body: Vec<_> = (cfg.vstart..cfg.vl)
+        .map(|i| {
+            active(hart, &ops, i).then(|| match (up, i) {
+                (true, 0) => scalar,
+                (true, _) => regs.get(ops.vs2, i - 1, cfg.sew),
+                (false, _) if i + 1 == cfg.vl => scalar,
+                (false, _) => regs.get(ops.vs2, i + 1, cfg.sew),
+            })
+        })
+        .collect();
+
+    commit(hart, &cfg, (ops.vd, cfg.sew, cfg.lmul), &body);
+    Ok(())
+}
+
+/// `vd[i] = vs2[index[i]]`, zero for indices past VLMAX. Indices come from
+/// `vs1` at SEW, or at 16 bits for VRGATHEREI16, or from the scalar operand.
+pub(crate) fn gather(hart: &mut Hart, inst: u32, ei16: bool) -> Result<(), Trap> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    check_group(inst, ops.vd, cfg.lmul)?;
+    check_group(inst, ops.vs2, cfg.lmul)?;
+    check_mask_overlap(inst, &ops)?;
+    check_disjoint(inst, (ops.vd, cfg.lmul), (ops.vs2, cfg.lmul))?;
+
+    let (index_eew, index_emul) = if ei16 {
+        cfg.width(inst, 4 - cfg.sew.trailing_zeros() as i32)?
+    } else {
+        (cfg.sew, cfg.lmul)
+    };
+    let scalar = (ops.funct3 != OPIVV).then(|| scalar_index(hart, &ops));
+    if scalar.is_none() {
+        check_group(inst, ops.vs1, index_emul)?;
+        check_disjoint(inst, (ops.vd, cfg.lmul), (ops.vs1, index_emul))?;
+    }
+
+    let vlmax = cfg.vlmax() as u64;
+    let regs = hart.vregs();
+    let body: Vec<_> = (cfg.vstart..cfg.vl)
+        .map(|i| {
+            active(hart, &ops, i).then(|| {
+                let index = scalar.unwrap_or_else(|| regs.get(ops.vs1, i, index_eew));
+                if index < vlmax {
+                    regs.get(ops.vs2, index as usize, cfg.sew)
+                } else {
+                    0
+                }
+            })
+        })
+        .collect();
+
+    commit(hart, &cfg, (ops.vd, cfg.sew, cfg.lmul), &body);
+    Ok(())
+}
+
+/// Folds the active elements of `vs2` into `vs1[0]` with
+/// `op(sew, acc, vs2[i])` and writes the result to `vd[0]`. Widening
+/// reductions accumulate at `2*SEW`.
+pub(crate) fn reduce(
+    hart: &mut Hart,
+    inst: u32,
+    widen: bool,
+    mut op: impl FnMut(u32, u64, u64) -> u64,
+) -> Result<(), Trap> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    let (eew, _) = cfg.width(inst, i32::from(widen))?;
+
+    check_vstart_zero(inst, &cfg)?;
+    check_group(inst, ops.vs2, cfg.lmul)?;
+
+    let regs = hart.vregs();
+    let mut acc = regs.get(ops.vs1, 0, eew);
+    for i in 0..cfg.vl {
+        if active(hart, &ops, i) {
+            acc = op(cfg.sew, acc, regs.get(ops.vs2, i, cfg.sew)) & ones(eew);
+        }
+    }
+
+    let body = [Some(acc)];
+    let scalar = Config { vl: 1, ..cfg };
+    if cfg.vl == 0 {
+        finish(hart);
+    } else {
+        commit(hart, &scalar, (ops.vd, eew, 0), &body);
+    }
+    Ok(())
+}
+
+/// Applies a new `vtype` and application vector length as the vsetvl
+/// instructions do, returning the new `vl`. Without an AVL the current `vl`
+/// is kept, clipped to the new VLMAX. An unsupported `vtype` sets `vill`
+/// and clears `vl`.
+pub(crate) fn set_vl(hart: &mut Hart, avl: Option<u64>, vtype: u64) -> u64 {
+    let (vtype, vl) = match hart.vregs().vlmax(Vtype::from_bits(vtype)) {
+        Some(vlmax) => {
+            let avl = avl.unwrap_or_else(|| hart.csrs().get(csr::VL));
+            (vtype, avl.min(vlmax as u64))
+        }
+        None => (VTYPE_VILL, 0),
+    };
+
+    hart.csrs_mut().set(csr::VTYPE, vtype);
+    hart.csrs_mut().set(csr::VL, vl);
+    finish(hart);
+    vl
+}
+
+/// The floating-point format of `sew`-bit elements; only single and double
+/// precision are supported.
+#[inline(always)]
+pub(crate) const fn fp_format(inst: u32, sew: u32) -> Result<Format, Trap> {
+    match sew {
+        32 => Ok(F32),
+        64 => Ok(F64),
+        _ => Err(Trap::IllegalInstruction { inst }),
+    }
+}
+
+/// Format twice as wide as `fmt`, for widening and narrowing operations.
+#[inline(always)]
+pub(crate) fn wide(fmt: Format) -> Format {
+    if fmt == F32 { F64 } else { fmt }
+}
+
+/// Rounding state for a vector floating-point instruction, which always
+/// rounds as `frm` says. Fails while `mstatus.FS` is Off or `frm` is
+/// reserved.
+#[inline(always)]
+pub(crate) fn softfloat(hart: &Hart, inst: u32) -> Result<SoftFloat, Trap> {
+    hart.check_fp(inst)?;
+    let frm = (hart.csrs().get(csr::FCSR) >> 5) & 0b111;
+    RoundingMode::from_bits(frm)
+        .map(SoftFloat::new)
+        .ok_or(Trap::IllegalInstruction { inst })
+}
+
+/// Floating-point counterpart of [`binary`], with `op(sf, fmt, vs2[i],
+/// vs1[i])` where `fmt` is the SEW format. Raised flags accumulate in
+/// `fflags`.
+pub(crate) fn fp_binary(
+    hart: &mut Hart,
+    inst: u32,
+    shape: Shape,
+    mut op: impl FnMut(&mut SoftFloat, Format, u64, u64) -> u64,
+) -> Result<(), Trap> {
+    let fmt = fp_format(inst, config(hart, inst)?.sew)?;
+    let mut sf = softfloat(hart, inst)?;
+    binary(hart, inst, shape, Imm::Signed, |_, a, b| {
+        op(&mut sf, fmt, a, b)
+    })?;
+    hart.raise_fflags(sf.flags());
+    Ok(())
+}
+
+/// Floating-point counterpart of [`ternary`], with `op(sf, fmt, vd[i],
+/// vs1[i], vs2[i])`.
+pub(crate) fn fp_ternary(
+    hart: &mut Hart,
+    inst: u32,
+    shape: Shape,
+    mut op: impl FnMut(&mut SoftFloat, Format, u64, u64, u64) -> u64,
+) -> Result<(), Trap> {
+    let fmt = fp_format(inst, config(hart, inst)?.sew)?;
+    let mut sf = softfloat(hart, inst)?;
+    ternary(hart, inst, shape, |_, old, a, b| {
+        op(&mut sf, fmt, old, a, b)
+    })?;
+    hart.raise_fflags(sf.flags());
+    Ok(())
+}
+
+/// Floating-point counterpart of [`unary`], for the single-width
+/// operations on SEW-wide values.
+pub(crate) fn fp_unary(
+    hart: &mut Hart,
+    inst: u32,
+    mut op: impl FnMut(&mut SoftFloat, Format, u64) -> u64,
+) -> Result<(), Trap> {
+    let fmt = fp_format(inst, config(hart, inst)?.sew)?;
+    let mut sf = softfloat(hart, inst)?;
+    unary(hart, inst, Shape::SINGLE, |_, a| op(&mut sf, fmt, a))?;
+    hart.raise_fflags(sf.flags());
+    Ok(())
+}
+
+/// Element conversions between integer and floating-point or between
+/// floating-point widths, with `op(sf, vs2[i])`. The caller checks that the
+/// floating-point side is single or double precision. `rm` overrides `frm`
+/// for the forms with a static rounding mode.
+pub(crate) fn fp_convert(
+    hart: &mut Hart,
+    inst: u32,
+    shape: Shape,
+    rm: Option<RoundingMode>,
+    mut op: impl FnMut(&mut SoftFloat, u64) -> u64,
+) -> Result<(), Trap> {
+    let mut sf = match rm {
+        Some(rm) => {
+            hart.check_fp(inst)?;
+            SoftFloat::new(rm)
+        }
+        None => softfloat(hart, inst)?,
+    };
+    unary(hart, inst, shape, |_, a| op(&mut sf, a))?;
+    hart.raise_fflags(sf.flags());
+    Ok(())
+}
+
+/// Floating-point counterpart of [`merge`].
+pub(crate) fn fp_merge(
+    hart: &mut Hart,
+    inst: u32,
+    op: impl FnMut(u32, u64, u64, bool) -> u64,
+) -> Result<(), Trap> {
+    fp_format(inst, config(hart, inst)?.sew)?;
+    hart.check_fp(inst)?;
+    merge(hart, inst, Imm::Signed, op)
+}
+
+/// Floating-point counterpart of [`compare`].
+pub(crate) fn fp_compare(
+    hart: &mut Hart,
+    inst: u32,
+    mut op: impl FnMut(&mut SoftFloat, Format, u64, u64) -> bool,
+) -> Result<(), Trap> {
+    let fmt = fp_format(inst, config(hart, inst)?.sew)?;
+    let mut sf = softfloat(hart, inst)?;
+    compare(hart, inst, Imm::Signed, |_, a, b| op(&mut sf, fmt, a, b))?;
+    hart.raise_fflags(sf.flags());
+    Ok(())
+}
+
+/// Floating-point counterpart of [`reduce`], folding in element order.
+pub(crate) fn fp_reduce(
+    hart: &mut Hart,
+    inst: u32,
+    widen: bool,
+    mut op: impl FnMut(&mut SoftFloat, Format, u64, u64) -> u64,
+) -> Result<(), Trap> {
+    let fmt = fp_format(inst, config(hart, inst)?.sew)?;
+    let mut sf = softfloat(hart, inst)?;
+    reduce(hart, inst, widen, |_, acc, a| op(&mut sf, fmt, acc, a))?;
+    hart.raise_fflags(sf.flags());
+    Ok(())
+}
+
+/// The fixed-point rounding mode in `vxrm`.
+#[inline(always)]
+pub(crate) fn vxrm(hart: &Hart) -> u64 {
+    (hart.csrs().get(csr::VCSR) >> 1) & 0b11
+}
+
+/// Sets `vxsat` after an instruction saturated.
+#[inline(always)]
+pub(crate) fn saturate(hart: &mut Hart) {
+    let vcsr = hart.csrs().get(csr::VCSR);
+    hart.csrs_mut().set(csr::VCSR, vcsr | 1);
+}
+
+/// Shifts `val` right by `shift` bits, rounding as `vxrm` says.
+#[inline(always)]
+pub(crate) fn roundoff(val: i128, shift: u32, vxrm: u64) -> i128 {
+    if shift == 0 {
+        return val;
+    }
+    let bit = |n: u32| (val >> n) & 1;
+    let below = |n: u32| val & ((1 << n) - 1) != 0;
+    let round = match vxrm {
+        // Round to nearest, ties up.
+        0 => bit(shift - 1),
+        // Round to nearest, ties to even.
+        1 => bit(shift - 1) & (below(shift - 1) as i128 | bit(shift)),
+        // Round down.
+        2 => 0,
+        // Round to odd.
+        _ => (bit(shift) == 0 && below(shift)) as i128,
+    };
+    (val >> shift) + round
+}
+
+/// Clamps `val` to the signed `bits`-bit range, reporting whether it had to.
+#[inline(always)]
+pub(crate) const fn clamp_signed(val: i128, bits: u32) -> (u64, bool) {
+    let max = (1i128 << (bits - 1)) - 1;
+    let min = -(1i128 << (bits - 1));
+    if val > max {
+        (max as u64, true)
+    } else if val < min {
+        (min as u64, true)
+    } else {
+        (val as u64, false)
+    }
+}
+
+/// Clamps `val` to the unsigned `bits`-bit range, reporting whether it had
+/// to.
+#[inline(always)]
+pub(crate) const fn clamp_unsigned(val: i128, bits: u32) -> (u64, bool) {
+    let max = (1i128 << bits) - 1;
+    if val > max {
+        (max as u64, true)
+    } else if val < 0 {
+        (0, true)
+    } else {
+        (val as u64, false)
+    }
+}
+
+/// How the elements of a load or store are laid out in memory.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Addressing {
+    UnitStride,
+    Strided(u64),
+    /// Offsets are read from the `eew`-bit group at `vs2`.
+    Indexed {
+        vs2: usize,
+        eew: u32,
+    },
+}
+
+/// Geometry of a load or store: the data element width and EMUL, and the
+/// number of registers each segment field takes.
+struct Layout {
+    eew: u32,
+    emul: i32,
+    regs: usize,
+}
+
+impl Layout {
+    /// Checks the register groups of a load (`dest`) or store under `cfg`.
+    fn new(inst: u32, cfg: &Config, addressing: Addressing, dest: bool) -> Result<Self, Trap> {
+        let ops = Operands::decode(inst);
+        let nf = nf(inst);
+        let shift = |eew: u32| eew.trailing_zeros() as i32 - cfg.sew.trailing_zeros() as i32;
+
+        let (eew, emul) = match addressing {
+            Addressing::Indexed { vs2, eew } => {
+                let (_, index_emul) = cfg.width(inst, shift(eew))?;
+                check_group(inst, vs2, index_emul)?;
+                let index = (vs2, eew, index_emul);
+                let regs = group_regs(cfg.lmul);
+                for field in (0..nf).filter(|_| dest) {
+                    let dst = (ops.vd + field * regs, cfg.sew, cfg.lmul);
+                    // Segment destinations may not overlap the index at all.
+                    let disjoint = dst.0 + regs <= vs2 || vs2 + group_regs(index_emul) <= dst.0;
+                    if nf > 1 && !disjoint {
+                        return Err(Trap::IllegalInstruction { inst });
+                    }
+                    check_overlap(inst, dst, index)?;
+                }
+                (cfg.sew, cfg.lmul)
+            }
+            _ => {
+                let eew = mem_eew(inst);
+                let emul = cfg.lmul + shift(eew);
+                if eew > cfg.elen || !(-3..=3).contains(&emul) {
+                    return Err(Trap::IllegalInstruction { inst });
+                }
+                (eew, emul)
+            }
+        };
+
+        let regs = group_regs(emul);
+        check_group(inst, ops.vd, emul)?;
+        if nf * regs > 8 || ops.vd + nf * regs > 32 {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        Ok(Self { eew, emul, regs })
+    }
+}
+
+/// Address of field `field` of element `i`.
+fn element_addr(
+    hart: &Hart,
+    base: u64,
+    addressing: Addressing,
+    (eew, nf): (u32, usize),
+    i: usize,
+    field: usize,
+) -> u64 {
+    let size = u64::from(eew / 8);
+    let field = field as u64 * size;
+    match addressing {
+        Addressing::UnitStride => base.wrapping_add(i as u64 * nf as u64 * size + field),
+        Addressing::Strided(stride) => base
+            .wrapping_add((i as u64).wrapping_mul(stride))
+            .wrapping_add(field),
+        Addressing::Indexed { vs2, eew } => base
+            .wrapping_add(hart.vregs().get(vs2, i, eew))
+            .wrapping_add(field),
+    }
+}
+
+/// Reads one `eew`-bit element from memory.
+#[inline(always)]
+pub(crate) fn read_elem(
+    hart: &mut Hart,
+    bus: &mut dyn Bus,
+    addr: u64,
+    eew: u32,
+) -> anyhow::Result<u64> {
+    Ok(match eew {
+        8 => u64::from(hart.read8(bus, addr)?),
+        16 => u64::from(hart.read16(bus, addr)?),
+        32 => u64::from(hart.read32(bus, addr)?),
+        _ => hart.read64(bus, addr)?,
+    })
+}
+
+/// Writes one `eew`-bit element to memory.
+#[inline(always)]
+pub(crate) fn write_elem(
+    hart: &mut Hart,
+    bus: &mut dyn Bus,
+    addr: u64,
+    eew: u32,
+    val: u64,
+) -> anyhow::Result<()> {
+    match eew {
+        8 => hart.write8(bus, addr, val as u8),
+        16 => hart.write16(bus, addr, val as u16),
+        32 => hart.write32(bus, addr, val as u32),
+        _ => hart.write64(bus, addr, val),
+    }
+}
+
+/// Runs a load, segment loads included. A trap on element `i` leaves
+/// `vstart` at `i`, except that a fault-only-first load trapping past the
+/// first element shrinks `vl` to `i` instead.
+pub(crate) fn load(
+    hart: &mut Hart,
+    bus: &mut dyn Bus,
+    inst: u32,
+    addressing: Addressing,
+    fault_first: bool,
+) -> anyhow::Result<()> {
+    let mut cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    let layout = Layout::new(inst, &cfg, addressing, true)?;
+    check_mask_overlap(inst, &ops)?;
+
+    let nf = nf(inst);
+    let base = hart.xreg(ops.vs1);
+    'elements: for i in cfg.vstart..cfg.vl {
+        let active = active(hart, &ops, i);
+        for field in 0..nf {
+            let reg = ops.vd + field * layout.regs;
+            if !active {
+                if cfg.vma {
+                    hart.vregs_mut().set(reg, i, layout.eew, ones(layout.eew));
+                }
+                continue;
+            }
+
+            let addr = element_addr(hart, base, addressing, (layout.eew, nf), i, field);
+            match read_elem(hart, bus, addr, layout.eew) {
+                Ok(val) => hart.vregs_mut().set(reg, i, layout.eew, val),
+                Err(err) if fault_first && i > 0 && Trap::from_error(&err).is_some() => {
+                    hart.csrs_mut().set(csr::VL, i as u64);
+                    cfg.vl = i;
+                    break 'elements;
+                }
+                Err(err) => {
+                    hart.csrs_mut().set(csr::VSTART, i as u64);
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    if cfg.vta && cfg.vstart < cfg.vl {
+        for field in 0..nf {
+            let reg = ops.vd + field * layout.regs;
+            for i in cfg.vl..cfg.group_elements(layout.eew, layout.emul) {
+                hart.vregs_mut().set(reg, i, layout.eew, ones(layout.eew));
+            }
+        }
+    }
+    finish(hart);
+    Ok(())
+}
+
+/// Runs a store, segment stores included. A trap on element `i` leaves
+/// `vstart` at `i`.
+pub(crate) fn store(
+    hart: &mut Hart,
+    bus: &mut dyn Bus,
+    inst: u32,
+    addressing: Addressing,
+) -> anyhow::Result<()> {
+    let cfg = config(hart, inst)?;
+    let ops = Operands::decode(inst);
+    let layout = Layout::new(inst, &cfg, addressing, false)?;
+
+    let nf = nf(inst);
+    let base = hart.xreg(ops.vs1);
+    for i in cfg.vstart..cfg.vl {
+        if !active(hart, &ops, i) {
+            continue;
+        }
+        for field in 0..nf {
+            let addr = element_addr(hart, base, addressing, (layout.eew, nf), i, field);
+            let val = hart
+                .vregs()
+                .get(ops.vd + field * layout.regs, i, layout.eew);
+            if let Err(err) = write_elem(hart, bus, addr, layout.eew, val) {
+                hart.csrs_mut().set(csr::VSTART, i as u64);
+                return Err(err);
+            }
+        }
+    }
+
+    finish(hart);
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use crate::memory::mmap::Mmap;
+
+    /// A hart with vector and floating-point state enabled.
+    pub(crate) fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut().set(
+            csr::MSTATUS,
+            mstatus | csr::MSTATUS_VS_INITIAL | csr::MSTATUS_FS_INITIAL,
+        );
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    /// Configures SEW, LMUL (as a power of two) and `vl` directly, with
+    /// undisturbed tails and inactive elements.
+    pub(crate) fn vset(hart: &mut Hart, sew: u32, lmul: i32, vl: usize) {
+        let vlmul = (lmul & 0b111) as u64;
+        let vsew = u64::from(sew.trailing_zeros() - 3);
+        hart.csrs_mut().set(csr::VTYPE, (vsew << 3) | vlmul);
+        hart.csrs_mut().set(csr::VL, vl as u64);
+    }
+
+    /// Like [`vset`] with agnostic tails and inactive elements.
+    pub(crate) fn vset_agnostic(hart: &mut Hart, sew: u32, lmul: i32, vl: usize) {
+        vset(hart, sew, lmul, vl);
+        let vtype = hart.csrs().get(csr::VTYPE);
+        hart.csrs_mut().set(csr::VTYPE, vtype | 0b1100_0000);
+    }
+
+    pub(crate) fn set_elems(hart: &mut Hart, reg: usize, eew: u32, vals: &[u64]) {
+        for (i, &val) in vals.iter().enumerate() {
+            hart.vregs_mut().set(reg, i, eew, val);
+        }
+    }
+
+    pub(crate) fn s(val: f32) -> u64 {
+        u64::from(val.to_bits())
+    }
+
+    pub(crate) fn d(val: f64) -> u64 {
+        val.to_bits()
+    }
+
+    pub(crate) fn elems(hart: &Hart, reg: usize, eew: u32, n: usize) -> Vec<u64> {
+        (0..n).map(|i| hart.vregs().get(reg, i, eew)).collect()
+    }
+
+    /// Sets the low 64 bits of mask register `reg`.
+    pub(crate) fn set_mask(hart: &mut Hart, reg: usize, bits: u64) {
+        hart.vregs_mut().set(reg, 0, 64, bits);
+    }
+
+    pub(crate) const fn encode(
+        funct6: u32,
+        vm: u32,
+        vs2: u32,
+        vs1: u32,
+        funct3: u32,
+        vd: u32,
+    ) -> u32 {
+        (funct6 << 26) | (vm << 25) | (vs2 << 20) | (vs1 << 15) | (funct3 << 12) | (vd << 7) | OP_V
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) const fn encode_mem(
+        opcode: u32,
+        nf: u32,
+        mop: u32,
+        vm: u32,
+        rs2: u32,
+        rs1: u32,
+        width: u32,
+        vd: u32,
+    ) -> u32 {
+        ((nf - 1) << 29)
+            | (mop << 26)
+            | (vm << 25)
+            | (rs2 << 20)
+            | (rs1 << 15)
+            | (width << 12)
+            | (vd << 7)
+            | opcode
+    }
+
+    /// Asserts that `result` is an illegal-instruction trap for `inst`.
+    pub(crate) fn assert_illegal<T: std::fmt::Debug>(result: anyhow::Result<T>, inst: u32) {
+        assert_eq!(
+            result.unwrap_err().downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundoff_follows_vxrm() {
+        // 0b1011 >> 2: exactly 2.75.
+        assert_eq!(roundoff(0b1011, 2, 0), 3);
+        assert_eq!(roundoff(0b1011, 2, 1), 3);
+        assert_eq!(roundoff(0b1011, 2, 2), 2);
+        assert_eq!(roundoff(0b1011, 2, 3), 3);
+        // 0b1010 >> 2: a tie at 2.5.
+        assert_eq!(roundoff(0b1010, 2, 0), 3);
+        assert_eq!(roundoff(0b1010, 2, 1), 2);
+        assert_eq!(roundoff(0b1010, 2, 3), 3);
+        assert_eq!(roundoff(-3, 1, 0), -1);
+    }
+
+    #[test]
+    fn overlap_rules() {
+        let inst = 0;
+        // Narrowing into the lowest register of the source.
+        assert!(check_overlap(inst, (2, 8, 0), (2, 16, 1)).is_ok());
+        assert!(check_overlap(inst, (3, 8, 0), (2, 16, 1)).is_err());
+        // Widening over the highest register of the destination.
+        assert!(check_overlap(inst, (2, 16, 1), (3, 8, 0)).is_ok());
+        assert!(check_overlap(inst, (2, 16, 1), (2, 8, 0)).is_err());
+        assert!(check_overlap(inst, (2, 16, 0), (2, 8, -1)).is_err());
+        assert!(check_overlap(inst, (4, 16, 1), (2, 8, 0)).is_ok());
+    }
+
+    #[test]
+    fn mem_matches_only_vector_widths() {
+        // vle32.v v1, (a0)
+        assert!(op_mem(0x0205_6087, OP_LOAD_FP, MOP_UNIT, Some(UMOP_UNIT)));
+        // flw f1, 0(a0)
+        assert!(!op_mem(0x0005_2087, OP_LOAD_FP, MOP_UNIT, Some(UMOP_UNIT)));
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vadc;
+
+impl InstrExec for Vadc {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010000, IVV | IVX | IVI) && inst & (1 << 25) == 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VADC", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::merge(hart, inst, Imm::Signed, |_, a, b, carry| {
+            a.wrapping_add(b).wrapping_add(u64::from(carry))
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVV,
+            testing::{assert_illegal, elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vadc(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vadc.call(inst, hart, bus)
+            .expect("VADC execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vadc_vvm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        set_elems(&mut hart, 2, 8, &[0xff, 1, 2]);
+        set_elems(&mut hart, 3, 8, &[1, 1, 1]);
+        set_mask(&mut hart, 0, 0b011);
+
+        exec(encode_vadc(0, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 3), [1, 3, 3]);
+    }
+
+    #[test]
+    fn vadc_requires_vm_clear() {
+        assert!(!Vadc.matches(encode_vadc(1, 2, 3, OPIVV, 1)));
+    }
+
+    #[test]
+    fn vadc_illegal_into_v0() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        let inst = encode_vadc(0, 2, 3, OPIVV, 0);
+
+        assert_illegal(Vadc.call(inst, &mut hart, &mut bus), inst);
+    }
+}
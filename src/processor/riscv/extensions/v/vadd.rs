@@ -0,0 +1,134 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vadd;
+
+impl InstrExec for Vadd {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b000000, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VADD", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |_, a, b| {
+            a.wrapping_add(b)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPIVI, OPIVV, OPIVX,
+                testing::{
+                    assert_illegal, elems, encode, set_elems, set_mask, setup, vset, vset_agnostic,
+                },
+            },
+        },
+    };
+
+    const fn encode_vadd(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b000000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vadd.call(inst, hart, bus)
+            .expect("VADD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vadd_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 4);
+        set_elems(&mut hart, 2, 32, &[1, 2, 3, 0xffff_ffff]);
+        set_elems(&mut hart, 3, 32, &[10, 20, 30, 1]);
+
+        exec(encode_vadd(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 4), [11, 22, 33, 0]);
+        assert_eq!(
+            hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_VS_DIRTY,
+            csr::MSTATUS_VS_DIRTY
+        );
+    }
+
+    #[test]
+    fn vadd_vi_masked_keeps_inactive_elements() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_elems(&mut hart, 2, 8, &[1, 2, 3, 4]);
+        set_elems(&mut hart, 1, 8, &[0xaa; 4]);
+        set_mask(&mut hart, 0, 0b0101);
+
+        exec(encode_vadd(0, 2, 0b11111, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 4), [0, 0xaa, 2, 0xaa]);
+    }
+
+    #[test]
+    fn vadd_vx_agnostic_tail_is_all_ones() {
+        let (mut hart, mut bus) = setup();
+        vset_agnostic(&mut hart, 16, 0, 2);
+        hart.set_xreg(5, 0x1_0007);
+
+        exec(encode_vadd(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(
+            elems(&hart, 1, 16, 8),
+            [7, 7, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff]
+        );
+    }
+
+    #[test]
+    fn vadd_starts_at_vstart() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_elems(&mut hart, 2, 8, &[1, 1, 1, 1]);
+        hart.csrs_mut().set(csr::VSTART, 2);
+
+        exec(encode_vadd(1, 2, 2, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 4), [0, 0, 2, 2]);
+        assert_eq!(hart.csrs().get(csr::VSTART), 0);
+    }
+
+    #[test]
+    fn vadd_illegal_for_misaligned_group() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 1, 4);
+        let inst = encode_vadd(1, 3, 4, OPIVV, 2);
+
+        assert_illegal(Vadd.call(inst, &mut hart, &mut bus), inst);
+    }
+
+    #[test]
+    fn vadd_illegal_when_vtype_is_ill() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_vadd(1, 2, 3, OPIVV, 1);
+
+        assert_illegal(Vadd.call(inst, &mut hart, &mut bus), inst);
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vand;
+
+impl InstrExec for Vand {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001001, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VAND", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |_, a, b| a & b)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI, OPIVV,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vand(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001001, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vand.call(inst, hart, bus)
+            .expect("VAND execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vand_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0x0c, 0xf0]);
+        set_elems(&mut hart, 3, 8, &[0xfe, 0x0f]);
+
+        exec(encode_vand(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0x0c, 0x00]);
+    }
+
+    #[test]
+    fn vand_vi_sign_extends_immediate() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0x0c, 0xf0]);
+
+        exec(encode_vand(1, 2, 0b11111, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0x0c, 0xf0]);
+    }
+}
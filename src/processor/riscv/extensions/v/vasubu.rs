@@ -0,0 +1,87 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Imm, MVV, MVX, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vasubu;
+
+impl InstrExec for Vasubu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001010, MVV | MVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VASUBU", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let vxrm = v::vxrm(hart);
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |_, a, b| {
+            v::roundoff(i128::from(a) - i128::from(b), 1, vxrm) as u64
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPMVV, OPMVX,
+                testing::{elems, encode, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vasubu(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vasubu
+            .call(inst, hart, bus)
+            .expect("VASUBU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vasubu_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0, 5]);
+        set_elems(&mut hart, 3, 8, &[1, 1]);
+
+        exec(encode_vasubu(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0, 2]);
+    }
+
+    #[test]
+    fn vasubu_vx_round_down() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 1);
+        set_elems(&mut hart, 2, 8, &[0]);
+        hart.set_xreg(5, 1);
+        hart.csrs_mut().set(csr::VCSR, 2 << 1);
+
+        exec(encode_vasubu(1, 2, 5, OPMVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0xff]);
+    }
+}
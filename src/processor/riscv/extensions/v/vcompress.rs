@@ -0,0 +1,98 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vcompress;
+
+impl InstrExec for Vcompress {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010111, MVV) && inst & (1 << 25) != 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VCOMPRESS.VM", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+        v::check_vstart_zero(inst, &cfg)?;
+        v::check_group(inst, ops.vd, cfg.lmul)?;
+        v::check_group(inst, ops.vs2, cfg.lmul)?;
+        v::check_disjoint(inst, (ops.vd, cfg.lmul), (ops.vs2, cfg.lmul))?;
+        v::check_disjoint(inst, (ops.vd, cfg.lmul), (ops.vs1, 0))?;
+
+        let regs = hart.vregs();
+        let packed: Vec<_> = (0..cfg.vl)
+            .filter(|&i| regs.mask(ops.vs1, i))
+            .map(|i| Some(regs.get(ops.vs2, i, cfg.sew)))
+            .collect();
+
+        // Elements past the packed ones are tail elements.
+        let packed_cfg = v::Config {
+            vl: packed.len(),
+            ..cfg
+        };
+        v::commit(hart, &packed_cfg, (ops.vd, cfg.sew, cfg.lmul), &packed);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{assert_illegal, elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vcompress(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vcompress
+            .call(inst, hart, bus)
+            .expect("VCOMPRESS.VM execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vcompress_vm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 5);
+        set_elems(&mut hart, 2, 8, &[1, 2, 3, 4, 5]);
+        set_elems(&mut hart, 1, 8, &[9, 9, 9, 9, 9]);
+        set_mask(&mut hart, 3, 0b10110);
+
+        exec(encode_vcompress(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 5), [2, 3, 5, 9, 9]);
+    }
+
+    #[test]
+    fn vcompress_illegal_over_mask() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 5);
+        let inst = encode_vcompress(1, 2, 3, OPMVV, 3);
+
+        assert_illegal(Vcompress.call(inst, &mut hart, &mut bus), inst);
+    }
+}
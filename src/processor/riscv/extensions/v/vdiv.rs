@@ -0,0 +1,88 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Imm, MVV, MVX, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vdiv;
+
+impl InstrExec for Vdiv {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b100001, MVV | MVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VDIV", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(
+            hart,
+            inst,
+            Shape::SINGLE,
+            Imm::Signed,
+            |sew, a, b| match v::sext(b, sew) {
+                0 => u64::MAX,
+                b => v::sext(a, sew).wrapping_div(b) as u64,
+            },
+        )?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV, OPMVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vdiv(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b100001, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vdiv.call(inst, hart, bus)
+            .expect("VDIV execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vdiv_vv_overflow_and_zero() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        set_elems(&mut hart, 2, 8, &[0x80, 0xf9, 5]);
+        set_elems(&mut hart, 3, 8, &[0xff, 2, 0]);
+
+        exec(encode_vdiv(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 3), [0x80, 0xfd, 0xff]);
+    }
+
+    #[test]
+    fn vdiv_vx_at_64_bits() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 1);
+        set_elems(&mut hart, 2, 64, &[i64::MIN as u64]);
+        hart.set_xreg(5, u64::MAX);
+
+        exec(encode_vdiv(1, 2, 5, OPMVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 1), [i64::MIN as u64]);
+    }
+}
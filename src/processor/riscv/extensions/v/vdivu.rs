@@ -0,0 +1,82 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Imm, MVV, MVX, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vdivu;
+
+impl InstrExec for Vdivu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b100000, MVV | MVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VDIVU", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |_, a, b| {
+            a.checked_div(b).unwrap_or(u64::MAX)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV, OPMVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vdivu(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b100000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vdivu
+            .call(inst, hart, bus)
+            .expect("VDIVU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vdivu_vv_by_zero_is_all_ones() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[7, 7]);
+        set_elems(&mut hart, 3, 8, &[2, 0]);
+
+        exec(encode_vdivu(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [3, 0xff]);
+    }
+
+    #[test]
+    fn vdivu_vx() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 1);
+        set_elems(&mut hart, 2, 64, &[u64::MAX]);
+        hart.set_xreg(5, 3);
+
+        exec(encode_vdivu(1, 2, 5, OPMVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 1), [u64::MAX / 3]);
+    }
+}
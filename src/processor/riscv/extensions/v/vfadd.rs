@@ -0,0 +1,122 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfadd;
+
+impl InstrExec for Vfadd {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b000000, FVV | FVF)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFADD", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_binary(hart, inst, Shape::SINGLE, |sf, fmt, a, b| sf.add(fmt, a, b))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVF, OPFVV,
+                testing::{assert_illegal, d, elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::{F64, FLAG_NX, FLAG_OF},
+        },
+    };
+
+    const fn encode_vfadd(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b000000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfadd
+            .call(inst, hart, bus)
+            .expect("VFADD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfadd_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 2, 32, &[s(1.5), s(-2.0)]);
+        set_elems(&mut hart, 3, 32, &[s(2.25), s(0.5)]);
+
+        exec(encode_vfadd(1, 2, 3, OPFVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 2), [s(3.75), s(-1.5)]);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn vfadd_vf() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        set_elems(&mut hart, 2, 64, &[d(1.0), d(f64::MAX)]);
+        hart.write_freg(F64, 5, d(f64::MAX));
+
+        exec(encode_vfadd(1, 2, 5, OPFVF, 1), &mut hart, &mut bus);
+        assert_eq!(
+            elems(&hart, 1, 64, 2),
+            [d(1.0 + f64::MAX), d(f64::INFINITY)]
+        );
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_OF | FLAG_NX));
+    }
+
+    #[test]
+    fn vfadd_illegal_at_sew_16() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 2);
+        let inst = encode_vfadd(1, 2, 3, OPFVV, 1);
+
+        assert_illegal(Vfadd.call(inst, &mut hart, &mut bus), inst);
+    }
+
+    #[test]
+    fn vfadd_illegal_when_fs_is_off() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus & !csr::MSTATUS_FS);
+        let inst = encode_vfadd(1, 2, 3, OPFVV, 1);
+
+        assert_illegal(Vfadd.call(inst, &mut hart, &mut bus), inst);
+    }
+
+    #[test]
+    fn vfadd_rounds_per_frm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        set_elems(&mut hart, 2, 32, &[s(1.0)]);
+        set_elems(&mut hart, 3, 32, &[s(f32::EPSILON / 4.0)]);
+        hart.csrs_mut().set(csr::FCSR, 0b011 << 5);
+
+        exec(encode_vfadd(1, 2, 3, OPFVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 1), [s(1.0 + f32::EPSILON)]);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV},
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::SoftFloat,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfclass;
+
+impl InstrExec for Vfclass {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010011, FVV, 15, 0b10000)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFCLASS.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        v::fp_unary(hart, inst, |_, fmt, a| SoftFloat::classify(fmt, a))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::{
+                OPFVV,
+                testing::{elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::F32,
+        },
+    };
+
+    const fn encode_vfclass(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010011, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfclass
+            .call(inst, hart, bus)
+            .expect("VFCLASS.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfclass_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+        set_elems(&mut hart, 2, 32, &[s(-1.0), s(0.0), F32.canonical_nan()]);
+
+        exec(encode_vfclass(1, 2, 0b10000, OPFVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 3), [1 << 1, 1 << 4, 1 << 9]);
+    }
+}
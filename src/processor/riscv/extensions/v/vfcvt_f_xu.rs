@@ -0,0 +1,80 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfcvtFXu;
+
+impl InstrExec for VfcvtFXu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b00010)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFCVT.F.XU.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, sew)?;
+        v::fp_convert(hart, inst, Shape::SINGLE, None, |sf, a| {
+            sf.from_int(fmt, a, sew, false)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVV,
+                testing::{elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::FLAG_NX,
+        },
+    };
+
+    const fn encode_vfcvt_f_xu(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfcvtFXu
+            .call(inst, hart, bus)
+            .expect("VFCVT.F.XU.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfcvt_f_xu_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 4, 32, &[3, 0xffff_ffff]);
+
+        exec(
+            encode_vfcvt_f_xu(1, 4, 0b00010, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 32, 2), [s(3.0), s(4294967296.0)]);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+}
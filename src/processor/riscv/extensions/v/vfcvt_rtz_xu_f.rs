@@ -0,0 +1,84 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::RoundingMode,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfcvtRtzXuF;
+
+impl InstrExec for VfcvtRtzXuF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b00110)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFCVT.RTZ.XU.F.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, sew)?;
+        v::fp_convert(
+            hart,
+            inst,
+            Shape::SINGLE,
+            Some(RoundingMode::TowardZero),
+            |sf, a| sf.to_int(fmt, a, sew, false),
+        )?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVV,
+                testing::{elems, encode, s, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vfcvt_rtz_xu_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfcvtRtzXuF
+            .call(inst, hart, bus)
+            .expect("VFCVT.RTZ.XU.F.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfcvt_rtz_xu_f_v_ignores_frm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        set_elems(&mut hart, 4, 32, &[s(2.75)]);
+        hart.csrs_mut().set(csr::FCSR, 0b011 << 5);
+
+        exec(
+            encode_vfcvt_rtz_xu_f(1, 4, 0b00110, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 32, 1), [2]);
+    }
+}
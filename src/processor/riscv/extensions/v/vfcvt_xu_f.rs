@@ -0,0 +1,89 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfcvtXuF;
+
+impl InstrExec for VfcvtXuF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b00000)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFCVT.XU.F.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, sew)?;
+        v::fp_convert(hart, inst, Shape::SINGLE, None, |sf, a| {
+            sf.to_int(fmt, a, sew, false)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVV,
+                testing::{assert_illegal, elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::{FLAG_NV, FLAG_NX},
+        },
+    };
+
+    const fn encode_vfcvt_xu_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfcvtXuF
+            .call(inst, hart, bus)
+            .expect("VFCVT.XU.F.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfcvt_xu_f_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+        set_elems(&mut hart, 4, 32, &[s(2.5), s(-1.0), s(5e9)]);
+
+        exec(
+            encode_vfcvt_xu_f(1, 4, 0b00000, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 32, 3), [2, 0, 0xffff_ffff]);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV | FLAG_NX));
+    }
+
+    #[test]
+    fn vfcvt_xu_f_illegal_at_sew_8() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 1);
+        let inst = encode_vfcvt_xu_f(1, 4, 0b00000, OPFVV, 2);
+
+        assert_illegal(VfcvtXuF.call(inst, &mut hart, &mut bus), inst);
+    }
+}
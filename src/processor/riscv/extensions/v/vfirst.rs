@@ -0,0 +1,83 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfirst;
+
+impl InstrExec for Vfirst {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010000, MVV, 15, 0b10001)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFIRST.M", skip_all, fields(rd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[ops.vd]);
+            span.record("vs2", ops.vs2);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+        v::check_vstart_zero(inst, &cfg)?;
+
+        let first =
+            (0..cfg.vl).find(|&i| v::active(hart, &ops, i) && hart.vregs().mask(ops.vs2, i));
+        hart.set_xreg(ops.vd, first.map_or(u64::MAX, |i| i as u64));
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{encode, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vfirst(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfirst
+            .call(inst, hart, bus)
+            .expect("VFIRST.M execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfirst_m() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 8);
+        set_mask(&mut hart, 2, 0b0110_0000);
+
+        exec(encode_vfirst(1, 2, 0b10001, OPMVV, 10), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(10), 5);
+    }
+
+    #[test]
+    fn vfirst_m_without_set_bits_is_minus_one() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 5);
+        set_mask(&mut hart, 2, 0b0110_0000);
+
+        exec(encode_vfirst(1, 2, 0b10001, OPMVV, 10), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(10), u64::MAX);
+    }
+}
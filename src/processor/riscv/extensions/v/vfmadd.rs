@@ -0,0 +1,72 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfmadd;
+
+impl InstrExec for Vfmadd {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b101000, FVV | FVF)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFMADD", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_ternary(hart, inst, Shape::SINGLE, |sf, fmt, a, b, c| {
+            sf.mul_add(fmt, b, a, c)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPFVV,
+            testing::{elems, encode, s, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vfmadd(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b101000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfmadd
+            .call(inst, hart, bus)
+            .expect("VFMADD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfmadd_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        set_elems(&mut hart, 2, 32, &[s(3.0)]);
+        set_elems(&mut hart, 3, 32, &[s(2.0)]);
+        set_elems(&mut hart, 1, 32, &[s(1.0)]);
+
+        exec(encode_vfmadd(1, 2, 3, OPFVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 1), [s(5.0)]);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfmax;
+
+impl InstrExec for Vfmax {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b000110, FVV | FVF)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFMAX", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_binary(hart, inst, Shape::SINGLE, |sf, fmt, a, b| sf.max(fmt, a, b))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::{
+                OPFVF,
+                testing::{d, elems, encode, set_elems, setup, vset},
+            },
+            softfloat::F64,
+        },
+    };
+
+    const fn encode_vfmax(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b000110, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfmax
+            .call(inst, hart, bus)
+            .expect("VFMAX execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfmax_vf() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        set_elems(&mut hart, 2, 64, &[d(1.0), d(-0.0)]);
+        hart.write_freg(F64, 5, d(0.0));
+
+        exec(encode_vfmax(1, 2, 5, OPFVF, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 2), [d(1.0), d(0.0)]);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfmerge;
+
+impl InstrExec for Vfmerge {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010111, FVF) && inst & (1 << 25) == 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFMERGE.VFM", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_merge(hart, inst, |_, a, b, v0| if v0 { b } else { a })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::{
+                OPFVF,
+                testing::{elems, encode, s, set_elems, set_mask, setup, vset},
+            },
+            softfloat::F32,
+        },
+    };
+
+    const fn encode_vfmerge(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfmerge
+            .call(inst, hart, bus)
+            .expect("VFMERGE.VFM execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfmerge_vfm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+        set_elems(&mut hart, 2, 32, &[s(1.0), s(2.0), s(3.0)]);
+        set_mask(&mut hart, 0, 0b101);
+        hart.write_freg(F32, 5, s(-1.0));
+
+        exec(encode_vfmerge(0, 2, 5, OPFVF, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 3), [s(-1.0), s(2.0), s(-1.0)]);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfmul;
+
+impl InstrExec for Vfmul {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b100100, FVV | FVF)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFMUL", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_binary(hart, inst, Shape::SINGLE, |sf, fmt, a, b| sf.mul(fmt, a, b))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVF, OPFVV,
+                testing::{d, elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::{F64, FLAG_NV},
+        },
+    };
+
+    const fn encode_vfmul(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b100100, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfmul
+            .call(inst, hart, bus)
+            .expect("VFMUL execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfmul_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 2, 32, &[s(1.5), s(-3.0)]);
+        set_elems(&mut hart, 3, 32, &[s(2.0), s(0.5)]);
+
+        exec(encode_vfmul(1, 2, 3, OPFVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 2), [s(3.0), s(-1.5)]);
+    }
+
+    #[test]
+    fn vfmul_vf_invalid() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 1);
+        set_elems(&mut hart, 2, 64, &[d(f64::INFINITY)]);
+        hart.write_freg(F64, 5, d(0.0));
+
+        exec(encode_vfmul(1, 2, 5, OPFVF, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 1), [F64.canonical_nan()]);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        extensions::v::{self, FVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfmvFS;
+
+impl InstrExec for VfmvFS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010000, FVV, 15, 0b00000) && inst & (1 << 25) != 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFMV.F.S", skip_all, fields(rd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[ops.vd]);
+            span.record("vs2", ops.vs2);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let fmt = v::fp_format(inst, cfg.sew)?;
+        hart.check_fp(inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let val = hart.vregs().get(ops.vs2, 0, cfg.sew);
+        hart.write_freg(fmt, ops.vd, val);
+        hart.csrs_mut().set(csr::VSTART, 0);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::{
+                OPFVV,
+                testing::{encode, s, set_elems, setup, vset},
+            },
+            softfloat::{F32, F64},
+        },
+    };
+
+    const fn encode_vfmv_f_s(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfmvFS
+            .call(inst, hart, bus)
+            .expect("VFMV.F.S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfmv_f_s_nan_boxes_single() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 0);
+        set_elems(&mut hart, 2, 32, &[s(1.5)]);
+
+        exec(encode_vfmv_f_s(1, 2, 0, OPFVV, 5), &mut hart, &mut bus);
+        assert_eq!(hart.read_freg(F32, 5), s(1.5));
+        assert_eq!(hart.read_freg(F64, 5), 0xffff_ffff_0000_0000 | s(1.5));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF, Imm},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfmvSF;
+
+impl InstrExec for VfmvSF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010000, FVF, 20, 0b00000) && inst & (1 << 25) != 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFMV.S.F", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::FABI[ops.vs1]);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        v::fp_format(inst, cfg.sew)?;
+        hart.check_fp(inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let body = [v::scalar(hart, &ops, cfg.sew, Imm::Signed)];
+        let first = v::Config {
+            vl: cfg.vl.min(1),
+            ..cfg
+        };
+        v::commit(hart, &first, (ops.vd, cfg.sew, 0), &body);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::{
+                OPFVF,
+                testing::{d, elems, encode, set_elems, setup, vset},
+            },
+            softfloat::F64,
+        },
+    };
+
+    const fn encode_vfmv_s_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfmvSF
+            .call(inst, hart, bus)
+            .expect("VFMV.S.F execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfmv_s_f_writes_element_zero() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        set_elems(&mut hart, 1, 64, &[d(1.0), d(2.0)]);
+        hart.write_freg(F64, 5, d(-4.0));
+
+        exec(encode_vfmv_s_f(1, 0, 5, OPFVF, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 2), [d(-4.0), d(2.0)]);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfmvVF;
+
+impl InstrExec for VfmvVF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010111, FVF) && (inst >> 20) & 0x3f == 0b10_0000
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFMV.V.F", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::FABI[ops.vs1]);
+        }
+
+        v::fp_merge(hart, inst, |_, _, b, _| b)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::{
+                OPFVF,
+                testing::{d, elems, encode, setup, vset},
+            },
+            softfloat::{F32, F64},
+        },
+    };
+
+    const fn encode_vfmv_v_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfmvVF
+            .call(inst, hart, bus)
+            .expect("VFMV.V.F execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfmv_v_f() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        hart.write_freg(F64, 5, d(2.5));
+
+        exec(encode_vfmv_v_f(1, 0, 5, OPFVF, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 2), [d(2.5), d(2.5)]);
+    }
+
+    #[test]
+    fn vfmv_v_f_reads_nan_boxed_single() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        hart.write_freg(F64, 5, d(2.5));
+
+        exec(encode_vfmv_v_f(1, 0, 5, OPFVF, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 1), [F32.canonical_nan()]);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfncvtFF;
+
+impl InstrExec for VfncvtFF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b10100)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFNCVT.F.F.W", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, sew)?;
+        let wide = v::fp_format(inst, 2 * sew)?;
+        v::fp_convert(hart, inst, Shape::NARROW, None, |sf, a| {
+            sf.convert(wide, fmt, a)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVV,
+                testing::{d, elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::{FLAG_NX, FLAG_OF},
+        },
+    };
+
+    const fn encode_vfncvt_f_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfncvtFF
+            .call(inst, hart, bus)
+            .expect("VFNCVT.F.F.W execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfncvt_f_f_w_overflows() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 4, 64, &[d(1.5), d(1e300)]);
+
+        exec(
+            encode_vfncvt_f_f(1, 4, 0b10100, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 32, 2), [s(1.5), s(f32::INFINITY)]);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_OF | FLAG_NX));
+    }
+}
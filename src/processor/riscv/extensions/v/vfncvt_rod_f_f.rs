@@ -0,0 +1,81 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::RoundingMode,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfncvtRodFF;
+
+impl InstrExec for VfncvtRodFF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b10101)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFNCVT.ROD.F.F.W", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, sew)?;
+        let wide = v::fp_format(inst, 2 * sew)?;
+        v::fp_convert(
+            hart,
+            inst,
+            Shape::NARROW,
+            Some(RoundingMode::Odd),
+            |sf, a| sf.convert(wide, fmt, a),
+        )?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPFVV,
+            testing::{d, elems, encode, s, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vfncvt_rod_f_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfncvtRodFF
+            .call(inst, hart, bus)
+            .expect("VFNCVT.ROD.F.F.W execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfncvt_rod_f_f_w_rounds_to_odd() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 4, 64, &[d(1.0 + f64::EPSILON), d(1.5)]);
+
+        exec(
+            encode_vfncvt_rod_f_f(1, 4, 0b10101, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 32, 2), [s(1.0 + f32::EPSILON), s(1.5)]);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+        softfloat::RoundingMode,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfncvtRtzXF;
+
+impl InstrExec for VfncvtRtzXF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b10111)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFNCVT.RTZ.X.F.W", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, 2 * sew)?;
+        v::fp_convert(
+            hart,
+            inst,
+            Shape::NARROW,
+            Some(RoundingMode::TowardZero),
+            |sf, a| sf.to_int(fmt, a, sew, true),
+        )?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPFVV,
+            testing::{elems, encode, s, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vfncvt_rtz_x_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfncvtRtzXF
+            .call(inst, hart, bus)
+            .expect("VFNCVT.RTZ.X.F.W execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfncvt_rtz_x_f_w() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 1);
+        set_elems(&mut hart, 4, 32, &[s(-7.9)]);
+
+        exec(
+            encode_vfncvt_rtz_x_f(1, 4, 0b10111, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 16, 1), [0xfff9]);
+    }
+}
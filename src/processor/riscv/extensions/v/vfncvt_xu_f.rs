@@ -0,0 +1,89 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfncvtXuF;
+
+impl InstrExec for VfncvtXuF {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b10000)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFNCVT.XU.F.W", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, 2 * sew)?;
+        v::fp_convert(hart, inst, Shape::NARROW, None, |sf, a| {
+            sf.to_int(fmt, a, sew, false)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVV,
+                testing::{assert_illegal, elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::FLAG_NV,
+        },
+    };
+
+    const fn encode_vfncvt_xu_f(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfncvtXuF
+            .call(inst, hart, bus)
+            .expect("VFNCVT.XU.F.W execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfncvt_xu_f_w_saturates() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 2);
+        set_elems(&mut hart, 4, 32, &[s(300.0), s(70000.0)]);
+
+        exec(
+            encode_vfncvt_xu_f(1, 4, 0b10000, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 16, 2), [300, 0xffff]);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn vfncvt_xu_f_illegal_at_sew_64() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 1);
+        let inst = encode_vfncvt_xu_f(1, 4, 0b10000, OPFVV, 2);
+
+        assert_illegal(VfncvtXuF.call(inst, &mut hart, &mut bus), inst);
+    }
+}
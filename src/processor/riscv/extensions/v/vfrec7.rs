@@ -0,0 +1,72 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfrec7;
+
+impl InstrExec for Vfrec7 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010011, FVV, 15, 0b00101)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFREC7.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        v::fp_unary(hart, inst, |sf, fmt, a| sf.rec7(fmt, a))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVV,
+                testing::{elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::FLAG_DZ,
+        },
+    };
+
+    const fn encode_vfrec7(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010011, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfrec7
+            .call(inst, hart, bus)
+            .expect("VFREC7.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfrec7_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 2, 32, &[s(1.0), s(0.0)]);
+
+        exec(encode_vfrec7(1, 2, 0b00101, OPFVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 2), [s(0.996_093_75), s(f32::INFINITY)]);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_DZ));
+    }
+}
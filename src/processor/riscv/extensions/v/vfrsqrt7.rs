@@ -0,0 +1,71 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfrsqrt7;
+
+impl InstrExec for Vfrsqrt7 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010011, FVV, 15, 0b00100)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFRSQRT7.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        v::fp_unary(hart, inst, |sf, fmt, a| sf.rsqrt7(fmt, a))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPFVV,
+            testing::{d, elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vfrsqrt7(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010011, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfrsqrt7
+            .call(inst, hart, bus)
+            .expect("VFRSQRT7.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfrsqrt7_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 1);
+        set_elems(&mut hart, 2, 64, &[d(4.0)]);
+
+        exec(
+            encode_vfrsqrt7(1, 2, 0b00100, OPFVV, 1),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 1, 64, 1), [d(0.498_046_875)]);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfslide1down;
+
+impl InstrExec for Vfslide1down {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001111, FVF)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFSLIDE1DOWN.VF", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_format(inst, v::config(hart, inst)?.sew)?;
+        hart.check_fp(inst)?;
+        v::slide1(hart, inst, false)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::{
+                OPFVF,
+                testing::{assert_illegal, elems, encode, s, set_elems, setup, vset},
+            },
+            softfloat::F32,
+        },
+    };
+
+    const fn encode_vfslide1down(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfslide1down
+            .call(inst, hart, bus)
+            .expect("VFSLIDE1DOWN.VF execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfslide1down_vf() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+        set_elems(&mut hart, 2, 32, &[s(1.0), s(2.0), s(3.0)]);
+        hart.write_freg(F32, 5, s(0.5));
+
+        exec(encode_vfslide1down(1, 2, 5, OPFVF, 4), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 4, 32, 3), [s(2.0), s(3.0), s(0.5)]);
+    }
+
+    #[test]
+    fn vfslide1down_illegal_at_sew_8() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        let inst = encode_vfslide1down(1, 2, 5, OPFVF, 4);
+
+        assert_illegal(Vfslide1down.call(inst, &mut hart, &mut bus), inst);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfwadd;
+
+impl InstrExec for Vfwadd {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b110000, FVV | FVF)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFWADD", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_binary(hart, inst, Shape::WIDEN, |sf, fmt, a, b| {
+            let w = v::wide(fmt);
+            let (a, b) = (sf.convert(fmt, w, a), sf.convert(fmt, w, b));
+            sf.add(w, a, b)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPFVV,
+            testing::{assert_illegal, d, elems, encode, s, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vfwadd(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b110000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfwadd
+            .call(inst, hart, bus)
+            .expect("VFWADD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfwadd_vv_is_exact() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 4, 32, &[s(1.0), s(f32::MAX)]);
+        set_elems(&mut hart, 5, 32, &[s(f32::EPSILON / 2.0), s(f32::MAX)]);
+
+        exec(encode_vfwadd(1, 4, 5, OPFVV, 2), &mut hart, &mut bus);
+        assert_eq!(
+            elems(&hart, 2, 64, 2),
+            [
+                d(1.0 + f64::from(f32::EPSILON) / 2.0),
+                d(2.0 * f64::from(f32::MAX))
+            ]
+        );
+    }
+
+    #[test]
+    fn vfwadd_illegal_at_sew_64() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 1);
+        let inst = encode_vfwadd(1, 4, 5, OPFVV, 2);
+
+        assert_illegal(Vfwadd.call(inst, &mut hart, &mut bus), inst);
+    }
+}
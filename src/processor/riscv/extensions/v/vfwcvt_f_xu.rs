@@ -0,0 +1,101 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VfwcvtFXu;
+
+impl InstrExec for VfwcvtFXu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010010, FVV, 15, 0b01010)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFWCVT.F.XU.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        let sew = v::config(hart, inst)?.sew;
+        let fmt = v::fp_format(inst, 2 * sew)?;
+        v::fp_convert(hart, inst, Shape::WIDEN, None, |sf, a| {
+            sf.from_int(fmt, a, sew, false)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPFVV,
+                testing::{assert_illegal, d, elems, encode, s, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vfwcvt_f_xu(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VfwcvtFXu
+            .call(inst, hart, bus)
+            .expect("VFWCVT.F.XU.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfwcvt_f_xu_v_is_exact() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        set_elems(&mut hart, 4, 32, &[0xffff_ffff]);
+
+        exec(
+            encode_vfwcvt_f_xu(1, 4, 0b01010, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 64, 1), [d(4294967295.0)]);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+    #[test]
+    fn vfwcvt_f_xu_v_from_sew_16() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 1);
+        set_elems(&mut hart, 4, 16, &[0xffff]);
+
+        exec(
+            encode_vfwcvt_f_xu(1, 4, 0b01010, OPFVV, 2),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(elems(&hart, 2, 32, 1), [s(65535.0)]);
+    }
+
+    #[test]
+    fn vfwcvt_f_xu_illegal_at_sew_8() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 1);
+        let inst = encode_vfwcvt_f_xu(1, 4, 0b01010, OPFVV, 2);
+
+        assert_illegal(VfwcvtFXu.call(inst, &mut hart, &mut bus), inst);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, FVF, FVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vfwmul;
+
+impl InstrExec for Vfwmul {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b111000, FVV | FVF)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VFWMUL", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::fp_binary(hart, inst, Shape::WIDEN, |sf, fmt, a, b| {
+            let w = v::wide(fmt);
+            let (a, b) = (sf.convert(fmt, w, a), sf.convert(fmt, w, b));
+            sf.mul(w, a, b)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPFVV,
+            testing::{d, elems, encode, s, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vfwmul(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b111000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vfwmul
+            .call(inst, hart, bus)
+            .expect("VFWMUL execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vfwmul_vv_does_not_overflow() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        set_elems(&mut hart, 4, 32, &[s(f32::MAX)]);
+        set_elems(&mut hart, 5, 32, &[s(2.0)]);
+
+        exec(encode_vfwmul(1, 4, 5, OPFVV, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 64, 1), [d(2.0 * f64::from(f32::MAX))]);
+    }
+}
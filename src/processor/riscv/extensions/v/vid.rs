@@ -0,0 +1,87 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vid;
+
+impl InstrExec for Vid {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010100, MVV, 15, 0b10001) && (inst >> 20) & 0x1f == 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VID.V", skip_all, fields(vd = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+        v::check_group(inst, ops.vd, cfg.lmul)?;
+        v::check_mask_overlap(inst, &ops)?;
+
+        let body: Vec<_> = (cfg.vstart..cfg.vl)
+            .map(|i| v::active(hart, &ops, i).then_some(i as u64))
+            .collect();
+
+        v::commit(hart, &cfg, (ops.vd, cfg.sew, cfg.lmul), &body);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vid(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010100, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vid.call(inst, hart, bus)
+            .expect("VID.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vid_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 1, 12);
+
+        exec(encode_vid(1, 0, 0b10001, OPMVV, 2), &mut hart, &mut bus);
+        assert_eq!(
+            elems(&hart, 2, 16, 12),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn vid_v_masked() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_mask(&mut hart, 0, 0b1010);
+        set_elems(&mut hart, 1, 8, &[7, 7, 7, 7]);
+
+        exec(encode_vid(0, 0, 0b10001, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 4), [7, 1, 7, 3]);
+    }
+}
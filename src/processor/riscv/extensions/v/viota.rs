@@ -0,0 +1,108 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Viota;
+
+impl InstrExec for Viota {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010100, MVV, 15, 0b10000)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VIOTA.M", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+        v::check_vstart_zero(inst, &cfg)?;
+        v::check_group(inst, ops.vd, cfg.lmul)?;
+        v::check_mask_overlap(inst, &ops)?;
+        v::check_disjoint(inst, (ops.vd, cfg.lmul), (ops.vs2, 0))?;
+
+        let regs = hart.vregs();
+        let mut count = 0;
+        let body: Vec<_> = (0..cfg.vl)
+            .map(|i| {
+                v::active(hart, &ops, i).then(|| {
+                    let val = count;
+                    count += u64::from(regs.mask(ops.vs2, i));
+                    val
+                })
+            })
+            .collect();
+
+        v::commit(hart, &cfg, (ops.vd, cfg.sew, cfg.lmul), &body);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{assert_illegal, elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_viota(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010100, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Viota
+            .call(inst, hart, bus)
+            .expect("VIOTA.M execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn viota_m() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 6);
+        set_mask(&mut hart, 2, 0b10_1101);
+
+        exec(encode_viota(1, 2, 0b10000, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 6), [0, 1, 1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn viota_m_masked_counts_active_bits_only() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 4);
+        set_mask(&mut hart, 2, 0b1111);
+        set_mask(&mut hart, 0, 0b1101);
+        set_elems(&mut hart, 1, 16, &[9, 9, 9, 9]);
+
+        exec(encode_viota(0, 2, 0b10000, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 4), [0, 9, 1, 2]);
+    }
+
+    #[test]
+    fn viota_illegal_over_source() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 1, 4);
+        let inst = encode_viota(1, 3, 0b10000, OPMVV, 2);
+
+        assert_illegal(Viota.call(inst, &mut hart, &mut bus), inst);
+    }
+}
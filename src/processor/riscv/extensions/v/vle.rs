@@ -0,0 +1,121 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Addressing, MOP_UNIT, OP_LOAD_FP, UMOP_UNIT},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vle;
+
+impl InstrExec for Vle {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_LOAD_FP, MOP_UNIT, Some(UMOP_UNIT))
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VLE.V", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        v::load(hart, bus, inst, Addressing::UnitStride, false)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::testing::{
+                assert_illegal, elems, encode_mem, set_elems, set_mask, setup, vset,
+            },
+        },
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vle.call(inst, hart, bus)
+            .expect("VLE.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vle(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_LOAD_FP, nf, MOP_UNIT, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vle32_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+        hart.set_xreg(10, 0x100);
+        bus.write64(0x100, 0x2222_2222_1111_1111).unwrap();
+        bus.write32(0x108, 0x3333_3333).unwrap();
+
+        exec(encode_vle(1, 1, 0, 10, 0b110, 1), &mut hart, &mut bus);
+        assert_eq!(
+            elems(&hart, 1, 32, 4),
+            [0x1111_1111, 0x2222_2222, 0x3333_3333, 0]
+        );
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn vle8_v_masked_leaves_inactive_elements() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        hart.set_xreg(10, 0x100);
+        bus.write32(0x100, 0x4433_2211).unwrap();
+        set_elems(&mut hart, 1, 8, &[0xaa, 0xaa, 0xaa, 0xaa]);
+        set_mask(&mut hart, 0, 0b0101);
+
+        exec(encode_vle(1, 0, 0, 10, 0b000, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 4), [0x11, 0xaa, 0x33, 0xaa]);
+    }
+
+    #[test]
+    fn vlseg2e16_v_deinterleaves_fields() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 2);
+        hart.set_xreg(10, 0x100);
+        bus.write64(0x100, 0x0004_0003_0002_0001).unwrap();
+
+        exec(encode_vle(2, 1, 0, 10, 0b101, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 16, 2), [1, 3]);
+        assert_eq!(elems(&hart, 3, 16, 2), [2, 4]);
+    }
+
+    #[test]
+    fn vle64_v_fault_records_vstart() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        hart.set_xreg(10, 0xf_fff8);
+        bus.write64(0xf_fff8, 7).unwrap();
+        let inst = encode_vle(1, 1, 0, 10, 0b111, 1);
+
+        assert!(Vle.call(inst, &mut hart, &mut bus).is_err());
+        assert_eq!(hart.csrs().get(csr::VSTART), 1);
+        assert_eq!(elems(&hart, 1, 64, 1), [7]);
+    }
+
+    #[test]
+    fn vle64_v_illegal_when_eew_exceeds_elen() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        let inst = encode_vle(1, 1, 0, 10, 0b111, 1);
+
+        assert_illegal(Vle.call(inst, &mut hart, &mut bus), inst);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Addressing, MOP_UNIT, OP_LOAD_FP, UMOP_FAULT_FIRST},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vleff;
+
+impl InstrExec for Vleff {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_LOAD_FP, MOP_UNIT, Some(UMOP_FAULT_FIRST))
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VLEFF.V", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        v::load(hart, bus, inst, Addressing::UnitStride, true)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::testing::{elems, encode_mem, setup, vset},
+        },
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vleff
+            .call(inst, hart, bus)
+            .expect("VLEFF.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vleff(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_LOAD_FP, nf, MOP_UNIT, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vle32ff_v_trims_vl_on_later_fault() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 4);
+        hart.set_xreg(10, 0xf_fff8);
+        bus.write64(0xf_fff8, 0x2222_2222_1111_1111).unwrap();
+
+        exec(
+            encode_vleff(1, 1, 0b10000, 10, 0b110, 1),
+            &mut hart,
+            &mut bus,
+        );
+        assert_eq!(hart.csrs().get(csr::VL), 2);
+        assert_eq!(elems(&hart, 1, 32, 2), [0x1111_1111, 0x2222_2222]);
+    }
+
+    #[test]
+    fn vle32ff_v_traps_on_first_element() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 4);
+        hart.set_xreg(10, 0x10_0000);
+        let inst = encode_vleff(1, 1, 0b10000, 10, 0b110, 1);
+
+        assert!(Vleff.call(inst, &mut hart, &mut bus).is_err());
+        assert_eq!(hart.csrs().get(csr::VL), 4);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        extensions::v::{self, MOP_UNIT, OP_LOAD_FP, UMOP_MASK},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vlm;
+
+impl InstrExec for Vlm {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_LOAD_FP, MOP_UNIT, Some(UMOP_MASK))
+            && (inst >> 12) & 0b111 == 0
+            && inst >> 25 == 1
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VLM.V", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+
+        // One byte per eight mask bits, as an EEW=8 load; the tail is agnostic.
+        let base = hart.xreg(ops.vs1);
+        let evl = cfg.vl.div_ceil(8);
+        for i in cfg.vstart..evl {
+            match hart.read8(bus, base.wrapping_add(i as u64)) {
+                Ok(byte) => hart.vregs_mut().set(ops.vd, i, 8, u64::from(byte)),
+                Err(err) => {
+                    hart.csrs_mut().set(csr::VSTART, i as u64);
+                    return Err(err);
+                }
+            }
+        }
+        if cfg.vstart < evl {
+            for i in evl..hart.vregs().vlenb() {
+                hart.vregs_mut().set(ops.vd, i, 8, 0xff);
+            }
+        }
+        v::finish(hart);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{elems, encode_mem, setup, vset},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vlm.call(inst, hart, bus)
+            .expect("VLM.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vlm(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_LOAD_FP, nf, MOP_UNIT, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vlm_v_loads_ceil_vl_over_8_bytes() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 4);
+        hart.set_xreg(10, 0x100);
+        bus.write32(0x100, 0x0403_0201).unwrap();
+
+        exec(encode_vlm(1, 1, 0b01011, 10, 0b000, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0x01, 0xff]);
+    }
+}
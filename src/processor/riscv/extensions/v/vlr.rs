@@ -0,0 +1,109 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        extensions::v::{self, MOP_UNIT, OP_LOAD_FP, UMOP_WHOLE},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vlr;
+
+impl InstrExec for Vlr {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_LOAD_FP, MOP_UNIT, Some(UMOP_WHOLE))
+            && v::nf(inst).is_power_of_two()
+            && inst & (1 << 25) != 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VLRE.V", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        hart.check_vector(inst)?;
+        let ops = v::Operands::decode(inst);
+        let nf = v::nf(inst);
+        if !ops.vd.is_multiple_of(nf) {
+            return Err(Trap::IllegalInstruction { inst }.into());
+        }
+
+        // Whole registers are transferred regardless of vtype and vl.
+        let eew = v::mem_eew(inst);
+        let base = hart.xreg(ops.vs1);
+        let vstart = hart.csrs().get(csr::VSTART) as usize;
+        let evl = nf * hart.vregs().vlenb() / (eew as usize / 8);
+        for i in vstart..evl {
+            let addr = base.wrapping_add((i * eew as usize / 8) as u64);
+            match v::read_elem(hart, bus, addr, eew) {
+                Ok(val) => hart.vregs_mut().set(ops.vd, i, eew, val),
+                Err(err) => {
+                    hart.csrs_mut().set(csr::VSTART, i as u64);
+                    return Err(err);
+                }
+            }
+        }
+        v::finish(hart);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            extensions::v::testing::{assert_illegal, elems, encode_mem, setup},
+            vector::VTYPE_VILL,
+        },
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vlr.call(inst, hart, bus)
+            .expect("VLRE.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vlr(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_LOAD_FP, nf, MOP_UNIT, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vl2re32_v_ignores_vl_and_vill() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::VTYPE, VTYPE_VILL);
+        hart.set_xreg(10, 0x100);
+        for i in 0..8 {
+            bus.write32(0x100 + 4 * i, i as u32 + 1).unwrap();
+        }
+
+        exec(encode_vlr(2, 1, 0b01000, 10, 0b110, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 32, 4), [1, 2, 3, 4]);
+        assert_eq!(elems(&hart, 3, 32, 4), [5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn vl2re8_v_illegal_for_misaligned_group() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_vlr(2, 1, 0b01000, 10, 0b000, 3);
+
+        assert_illegal(Vlr.call(inst, &mut hart, &mut bus), inst);
+    }
+
+    #[test]
+    fn vl3re8_v_is_not_an_instruction() {
+        assert!(!Vlr.matches(encode_vlr(3, 1, 0b01000, 10, 0b000, 3)));
+    }
+}
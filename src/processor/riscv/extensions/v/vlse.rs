@@ -0,0 +1,81 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Addressing, MOP_STRIDED, OP_LOAD_FP},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vlse;
+
+impl InstrExec for Vlse {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_LOAD_FP, MOP_STRIDED, None)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VLSE.V", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+            span.record("rs2", Hart::IABI[ops.vs2]);
+        }
+
+        let stride = hart.xreg(v::Operands::decode(inst).vs2);
+        v::load(hart, bus, inst, Addressing::Strided(stride), false)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{elems, encode_mem, setup, vset},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vlse.call(inst, hart, bus)
+            .expect("VLSE.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vlse(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_LOAD_FP, nf, MOP_STRIDED, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vlse16_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 3);
+        hart.set_xreg(10, 0x100);
+        hart.set_xreg(11, 6);
+        bus.write16(0x100, 1).unwrap();
+        bus.write16(0x106, 2).unwrap();
+        bus.write16(0x10c, 3).unwrap();
+
+        exec(encode_vlse(1, 1, 11, 10, 0b101, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 3), [1, 2, 3]);
+    }
+
+    #[test]
+    fn vlse32_v_negative_stride() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        hart.set_xreg(10, 0x104);
+        hart.set_xreg(11, -4i64 as u64);
+        bus.write64(0x100, 0x2222_2222_1111_1111).unwrap();
+
+        exec(encode_vlse(1, 1, 11, 10, 0b110, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 2), [0x2222_2222, 0x1111_1111]);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Addressing, MOP_INDEXED_UNORDERED, OP_LOAD_FP},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vluxei;
+
+impl InstrExec for Vluxei {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_LOAD_FP, MOP_INDEXED_UNORDERED, None)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VLUXEI.V", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+            span.record("vs2", ops.vs2);
+        }
+
+        let addressing = Addressing::Indexed {
+            vs2: v::Operands::decode(inst).vs2,
+            eew: v::mem_eew(inst),
+        };
+        v::load(hart, bus, inst, addressing, false)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{
+            assert_illegal, elems, encode_mem, set_elems, setup, vset,
+        },
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vluxei
+            .call(inst, hart, bus)
+            .expect("VLUXEI.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vluxei(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(
+            OP_LOAD_FP,
+            nf,
+            MOP_INDEXED_UNORDERED,
+            vm,
+            rs2,
+            rs1,
+            width,
+            vd,
+        )
+    }
+
+    #[test]
+    fn vluxei8_v_with_sew_32_data() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+        hart.set_xreg(10, 0x100);
+        set_elems(&mut hart, 4, 8, &[8, 0, 4]);
+        bus.write64(0x100, 0x2222_2222_1111_1111).unwrap();
+        bus.write32(0x108, 0x3333_3333).unwrap();
+
+        exec(encode_vluxei(1, 1, 4, 10, 0b000, 1), &mut hart, &mut bus);
+        assert_eq!(
+            elems(&hart, 1, 32, 3),
+            [0x3333_3333, 0x1111_1111, 0x2222_2222]
+        );
+    }
+
+    #[test]
+    fn vluxei64_v_illegal_when_vd_overlaps_narrower_index() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        let inst = encode_vluxei(1, 1, 4, 10, 0b111, 5);
+
+        assert_illegal(Vluxei.call(inst, &mut hart, &mut bus), inst);
+    }
+}
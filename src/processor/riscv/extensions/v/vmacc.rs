@@ -0,0 +1,84 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV, MVX, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmacc;
+
+impl InstrExec for Vmacc {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b101101, MVV | MVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMACC", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::ternary(hart, inst, Shape::SINGLE, |_, vd, a, b| {
+            vd.wrapping_add(a.wrapping_mul(b))
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV, OPMVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vmacc(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b101101, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmacc
+            .call(inst, hart, bus)
+            .expect("VMACC execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmacc_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 2);
+        set_elems(&mut hart, 2, 16, &[5, 6]);
+        set_elems(&mut hart, 3, 16, &[3, 4]);
+        set_elems(&mut hart, 1, 16, &[1, 2]);
+
+        exec(encode_vmacc(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 2), [16, 26]);
+    }
+
+    #[test]
+    fn vmacc_vx() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 2, 32, &[5, 6]);
+        hart.set_xreg(5, 2);
+        set_elems(&mut hart, 1, 32, &[1, 2]);
+
+        exec(encode_vmacc(1, 2, 5, OPMVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 2), [11, 14]);
+    }
+}
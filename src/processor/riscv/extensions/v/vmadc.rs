@@ -0,0 +1,94 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmadc;
+
+impl InstrExec for Vmadc {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010001, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMADC", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::carry_out(hart, inst, Imm::Signed, |sew, a, b, carry| {
+            (u128::from(a) + u128::from(b) + u128::from(carry)) >> sew != 0
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI, OPIVV,
+            testing::{elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vmadc(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010001, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmadc
+            .call(inst, hart, bus)
+            .expect("VMADC execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmadc_vvm_adds_carry_in() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0xff, 0xfe]);
+        set_elems(&mut hart, 3, 8, &[1, 1]);
+        set_mask(&mut hart, 0, 0b10);
+
+        exec(encode_vmadc(0, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_1111]);
+    }
+
+    #[test]
+    fn vmadc_vv_without_carry_in() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0xff, 0xfe]);
+        set_elems(&mut hart, 3, 8, &[1, 1]);
+        set_mask(&mut hart, 0, 0b10);
+
+        exec(encode_vmadc(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_1101]);
+    }
+
+    #[test]
+    fn vmadc_vi_at_64_bits() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        set_elems(&mut hart, 2, 64, &[u64::MAX, 0]);
+
+        exec(encode_vmadc(1, 2, 1, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_1101]);
+    }
+}
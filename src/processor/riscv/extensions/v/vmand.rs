@@ -0,0 +1,74 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmand;
+
+impl InstrExec for Vmand {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b011001, MVV) && inst & (1 << 25) != 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMAND.MM", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::mask_logical(hart, inst, |a, b| a && b)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{elems, encode, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vmand(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b011001, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmand
+            .call(inst, hart, bus)
+            .expect("VMAND.MM execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmand_mm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_mask(&mut hart, 2, 0b1010);
+        set_mask(&mut hart, 3, 0b1100);
+
+        exec(encode_vmand(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_1000]);
+    }
+
+    #[test]
+    fn vmand_is_always_unmasked() {
+        assert!(!Vmand.matches(encode_vmand(0, 2, 3, OPMVV, 1)));
+    }
+}
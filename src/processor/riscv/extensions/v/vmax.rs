@@ -0,0 +1,85 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmax;
+
+impl InstrExec for Vmax {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b000111, IVV | IVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMAX", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |sew, a, b| {
+            if v::sext(a, sew) > v::sext(b, sew) {
+                a
+            } else {
+                b
+            }
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVV, OPIVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vmax(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b000111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmax.call(inst, hart, bus)
+            .expect("VMAX execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmax_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0x80, 1]);
+        set_elems(&mut hart, 3, 8, &[1, 0xff]);
+
+        exec(encode_vmax(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [1, 1]);
+    }
+
+    #[test]
+    fn vmax_vx_truncates_scalar_to_sew() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0x80, 1]);
+        hart.set_xreg(5, 0x101);
+
+        exec(encode_vmax(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [1, 1]);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmerge;
+
+impl InstrExec for Vmerge {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010111, IVV | IVX | IVI) && inst & (1 << 25) == 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMERGE", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::merge(
+            hart,
+            inst,
+            Imm::Signed,
+            |_, a, b, v0| if v0 { b } else { a },
+        )?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI, OPIVV,
+            testing::{elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vmerge(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmerge
+            .call(inst, hart, bus)
+            .expect("VMERGE execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmerge_vvm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 3);
+        set_elems(&mut hart, 2, 16, &[1, 2, 3]);
+        set_elems(&mut hart, 3, 16, &[7, 8, 9]);
+        set_mask(&mut hart, 0, 0b101);
+
+        exec(encode_vmerge(0, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 3), [7, 2, 9]);
+    }
+
+    #[test]
+    fn vmerge_vim() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        set_elems(&mut hart, 2, 8, &[1, 2, 3]);
+        set_mask(&mut hart, 0, 0b101);
+
+        exec(encode_vmerge(0, 2, 0b11111, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 3), [0xff, 2, 0xff]);
+    }
+}
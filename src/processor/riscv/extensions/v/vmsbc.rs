@@ -0,0 +1,83 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVV, IVX, Imm},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmsbc;
+
+impl InstrExec for Vmsbc {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010011, IVV | IVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMSBC", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::carry_out(hart, inst, Imm::Signed, |_, a, b, borrow| {
+            u128::from(a) < u128::from(b) + u128::from(borrow)
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVV, OPIVX,
+            testing::{elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vmsbc(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010011, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmsbc
+            .call(inst, hart, bus)
+            .expect("VMSBC execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmsbc_vvm() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0, 5]);
+        set_elems(&mut hart, 3, 8, &[0, 5]);
+        set_mask(&mut hart, 0, 0b10);
+
+        exec(encode_vmsbc(0, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_1110]);
+    }
+
+    #[test]
+    fn vmsbc_vx() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0, 5]);
+        hart.set_xreg(5, 1);
+
+        exec(encode_vmsbc(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_1101]);
+    }
+}
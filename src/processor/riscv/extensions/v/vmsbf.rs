@@ -0,0 +1,89 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmsbf;
+
+impl InstrExec for Vmsbf {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010100, MVV, 15, 0b00001)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMSBF.M", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::mask_scan(hart, inst, |before, _| before)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{assert_illegal, elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vmsbf(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010100, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmsbf
+            .call(inst, hart, bus)
+            .expect("VMSBF.M execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmsbf_m() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_mask(&mut hart, 2, 0b1100);
+
+        exec(encode_vmsbf(1, 2, 0b00001, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_0011]);
+    }
+
+    #[test]
+    fn vmsbf_m_masked_ignores_inactive_bits() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_mask(&mut hart, 2, 0b0101);
+        set_mask(&mut hart, 0, 0b1110);
+        set_elems(&mut hart, 1, 8, &[0]);
+
+        exec(encode_vmsbf(0, 2, 0b00001, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_0010]);
+    }
+
+    #[test]
+    fn vmsbf_illegal_in_place() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        let inst = encode_vmsbf(1, 2, 0b00001, OPMVV, 2);
+
+        assert_illegal(Vmsbf.call(inst, &mut hart, &mut bus), inst);
+    }
+}
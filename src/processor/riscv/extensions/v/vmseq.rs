@@ -0,0 +1,91 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vmseq;
+
+impl InstrExec for Vmseq {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b011000, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMSEQ", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::compare(hart, inst, Imm::Signed, |_, a, b| a == b)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI, OPIVV,
+            testing::{assert_illegal, elems, encode, set_elems, set_mask, setup, vset},
+        },
+    };
+
+    const fn encode_vmseq(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b011000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vmseq
+            .call(inst, hart, bus)
+            .expect("VMSEQ execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmseq_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 4);
+        set_elems(&mut hart, 2, 32, &[1, 2, 3, 4]);
+        set_elems(&mut hart, 3, 32, &[1, 0, 3, 0]);
+
+        exec(encode_vmseq(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_0101]);
+    }
+
+    #[test]
+    fn vmseq_vi_masked() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_elems(&mut hart, 2, 8, &[0xff, 0xff, 1, 0xff]);
+        set_elems(&mut hart, 1, 8, &[0b0010]);
+        set_mask(&mut hart, 0, 0b1001);
+
+        exec(encode_vmseq(0, 2, 0b11111, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0b1111_1011]);
+    }
+
+    #[test]
+    fn vmseq_destination_may_overlap_lowest_source_register() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 1, 2);
+
+        let inst = encode_vmseq(1, 2, 4, OPIVV, 3);
+        assert_illegal(Vmseq.call(inst, &mut hart, &mut bus), inst);
+        exec(encode_vmseq(1, 2, 4, OPIVV, 2), &mut hart, &mut bus);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        extensions::v::{self, IVI},
+        hart::Hart,
+        instruction::InstrExec,
+        vector::{VectorRegs, Vtype},
+    },
+};
+
+#[derive(Debug)]
+pub struct VmvNrR;
+
+impl InstrExec for VmvNrR {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b100111, IVI)
+            && inst & (1 << 25) != 0
+            && matches!((inst >> 15) & 0x1f, 0 | 1 | 3 | 7)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMV<NR>R.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+        }
+
+        hart.check_vector(inst)?;
+        let ops = v::Operands::decode(inst);
+        let nreg = ops.vs1 + 1;
+        if !ops.vd.is_multiple_of(nreg) || !ops.vs2.is_multiple_of(nreg) {
+            return Err(Trap::IllegalInstruction { inst }.into());
+        }
+
+        // Whole-register moves ignore vl, and run at SEW even when vtype is ill.
+        let vtype = Vtype::from_bits(hart.csrs().get(csr::VTYPE));
+        let eew = if vtype.vill() { 8 } else { vtype.sew() };
+        let evl = VectorRegs::elements(hart.vregs().vlen(), eew, nreg.trailing_zeros() as i32);
+        let vstart = hart.csrs().get(csr::VSTART) as usize;
+        for i in vstart..evl {
+            let val = hart.vregs().get(ops.vs2, i, eew);
+            hart.vregs_mut().set(ops.vd, i, eew, val);
+        }
+        v::finish(hart);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI,
+            testing::{assert_illegal, elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vmv_nr_r(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b100111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VmvNrR
+            .call(inst, hart, bus)
+            .expect("VMV<NR>R.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmv2r_v_copies_whole_registers() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 0);
+        set_elems(&mut hart, 4, 64, &[1, 2, 3, 4]);
+
+        exec(encode_vmv_nr_r(1, 4, 1, OPIVI, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 64, 4), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn vmv1r_v_works_with_vill() {
+        let (mut hart, mut bus) = setup();
+        set_elems(&mut hart, 4, 8, &[7; 16]);
+
+        exec(encode_vmv_nr_r(1, 4, 0, OPIVI, 5), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 5, 8, 16), [7; 16]);
+    }
+
+    #[test]
+    fn vmv_nr_r_illegal_for_misaligned_registers() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_vmv_nr_r(1, 4, 3, OPIVI, 2);
+
+        assert_illegal(VmvNrR.call(inst, &mut hart, &mut bus), inst);
+        assert!(!VmvNrR.matches(encode_vmv_nr_r(1, 4, 2, OPIVI, 2)));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Imm, MVX},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VmvSX;
+
+impl InstrExec for VmvSX {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010000, MVX, 20, 0b00000) && inst & (1 << 25) != 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMV.S.X", skip_all, fields(vd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let body = [v::scalar(hart, &ops, cfg.sew, Imm::Signed)];
+        let first = v::Config {
+            vl: cfg.vl.min(1),
+            ..cfg
+        };
+        v::commit(hart, &first, (ops.vd, cfg.sew, 0), &body);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVX,
+            testing::{elems, encode, set_elems, setup, vset, vset_agnostic},
+        },
+    };
+
+    const fn encode_vmv_s_x(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VmvSX
+            .call(inst, hart, bus)
+            .expect("VMV.S.X execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmv_s_x_writes_element_zero() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 2, 8);
+        set_elems(&mut hart, 4, 32, &[1, 2, 3, 4]);
+        hart.set_xreg(5, 0x1_2345_6789);
+
+        exec(encode_vmv_s_x(1, 0, 5, OPMVX, 4), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 4, 32, 4), [0x2345_6789, 2, 3, 4]);
+    }
+
+    #[test]
+    fn vmv_s_x_tail_agnostic_covers_one_register() {
+        let (mut hart, mut bus) = setup();
+        vset_agnostic(&mut hart, 64, 1, 4);
+        set_elems(&mut hart, 3, 64, &[5, 5]);
+        hart.set_xreg(5, 7);
+
+        exec(encode_vmv_s_x(1, 0, 5, OPMVX, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 64, 4), [7, u64::MAX, 5, 5]);
+    }
+
+    #[test]
+    fn vmv_s_x_with_zero_vl_does_nothing() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 0);
+        hart.set_xreg(5, 7);
+
+        exec(encode_vmv_s_x(1, 0, 5, OPMVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [0]);
+    }
+}
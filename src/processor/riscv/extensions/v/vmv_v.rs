@@ -0,0 +1,85 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VmvV;
+
+impl InstrExec for VmvV {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010111, IVV | IVX | IVI) && (inst >> 20) & 0x3f == 0b10_0000
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMV.V", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |_, _, b| b)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI, OPIVV, OPIVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vmv_v(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VmvV.call(inst, hart, bus)
+            .expect("VMV.V execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmv_v_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 1, 8);
+        set_elems(&mut hart, 4, 32, &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        exec(encode_vmv_v(1, 0, 4, OPIVV, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 32, 8), [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn vmv_v_x_and_v_i() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        hart.set_xreg(5, 0xdead);
+
+        exec(encode_vmv_v(1, 0, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 2), [0xdead, 0xdead]);
+        exec(encode_vmv_v(1, 0, 0b10000, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 2), [(-16i64) as u64; 2]);
+    }
+
+    #[test]
+    fn vmv_v_requires_vs2_zero() {
+        assert!(!VmvV.matches(encode_vmv_v(1, 1, 4, OPIVV, 2)));
+        assert!(!VmvV.matches(encode_vmv_v(0, 0, 4, OPIVV, 2)));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VmvXS;
+
+impl InstrExec for VmvXS {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v_unary(inst, 0b010000, MVV, 15, 0b00000) && inst & (1 << 25) != 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VMV.X.S", skip_all, fields(rd = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[ops.vd]);
+            span.record("vs2", ops.vs2);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let val = v::sext(hart.vregs().get(ops.vs2, 0, cfg.sew), cfg.sew) as u64;
+        hart.set_xreg(ops.vd, val);
+        hart.csrs_mut().set(csr::VSTART, 0);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{assert_illegal, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vmv_x_s(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VmvXS
+            .call(inst, hart, bus)
+            .expect("VMV.X.S execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vmv_x_s_sign_extends() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 0);
+        set_elems(&mut hart, 2, 16, &[0x8001, 5]);
+
+        exec(encode_vmv_x_s(1, 2, 0, OPMVV, 10), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(10), 0xffff_ffff_ffff_8001);
+    }
+
+    #[test]
+    fn vmv_x_s_illegal_when_vtype_is_ill() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_vmv_x_s(1, 2, 0, OPMVV, 10);
+
+        assert_illegal(VmvXS.call(inst, &mut hart, &mut bus), inst);
+    }
+}
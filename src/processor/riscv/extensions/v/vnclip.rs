@@ -0,0 +1,95 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vnclip;
+
+impl InstrExec for Vnclip {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b101111, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VNCLIP", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let vxrm = v::vxrm(hart);
+        let mut saturated = false;
+        v::binary(hart, inst, Shape::NARROW, Imm::Unsigned, |sew, a, b| {
+            let shift = (b & u64::from(2 * sew - 1)) as u32;
+            let val = v::roundoff(i128::from(v::sext(a, 2 * sew)), shift, vxrm);
+            let (val, clamped) = v::clamp_signed(val, sew);
+            saturated |= clamped;
+            val
+        })?;
+        if saturated {
+            v::saturate(hart);
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPIVI, OPIVV,
+                testing::{elems, encode, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vnclip(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b101111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vnclip
+            .call(inst, hart, bus)
+            .expect("VNCLIP execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vnclip_vi_saturates() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 16, &[0x8000, 0x0070]);
+
+        exec(encode_vnclip(1, 2, 4, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0x80, 7]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 1);
+    }
+
+    #[test]
+    fn vnclip_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 1);
+        set_elems(&mut hart, 2, 32, &[0xffff_fff0]);
+        set_elems(&mut hart, 4, 16, &[3]);
+
+        exec(encode_vnclip(1, 2, 4, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 1), [0xfffe]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 0);
+    }
+}
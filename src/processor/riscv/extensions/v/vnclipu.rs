@@ -0,0 +1,94 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vnclipu;
+
+impl InstrExec for Vnclipu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b101110, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VNCLIPU", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let vxrm = v::vxrm(hart);
+        let mut saturated = false;
+        v::binary(hart, inst, Shape::NARROW, Imm::Unsigned, |sew, a, b| {
+            let shift = (b & u64::from(2 * sew - 1)) as u32;
+            let (val, clamped) = v::clamp_unsigned(v::roundoff(i128::from(a), shift, vxrm), sew);
+            saturated |= clamped;
+            val
+        })?;
+        if saturated {
+            v::saturate(hart);
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPIVI, OPIVX,
+                testing::{elems, encode, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vnclipu(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b101110, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vnclipu
+            .call(inst, hart, bus)
+            .expect("VNCLIPU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vnclipu_vi_saturates() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 16, &[0x1234, 0x0018]);
+
+        exec(encode_vnclipu(1, 2, 4, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0xff, 2]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 1);
+    }
+
+    #[test]
+    fn vnclipu_vx() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        set_elems(&mut hart, 2, 64, &[0x1_0000_0000]);
+        hart.set_xreg(5, 1);
+
+        exec(encode_vnclipu(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 1), [0x8000_0000]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 0);
+    }
+}
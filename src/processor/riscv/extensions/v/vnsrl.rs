@@ -0,0 +1,83 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vnsrl;
+
+impl InstrExec for Vnsrl {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b101100, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VNSRL", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::NARROW, Imm::Unsigned, |sew, a, b| {
+            a >> (b & u64::from(2 * sew - 1))
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI,
+            testing::{assert_illegal, elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vnsrl(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b101100, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vnsrl
+            .call(inst, hart, bus)
+            .expect("VNSRL execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vnsrl_vi() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 16, &[0x1234, 0xabcd]);
+
+        exec(encode_vnsrl(1, 2, 4, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0x23, 0xbc]);
+    }
+
+    #[test]
+    fn vnsrl_may_overwrite_lowest_source_register() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 16, &[0x1234, 0xabcd]);
+
+        exec(encode_vnsrl(1, 2, 8, OPIVI, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 8, 2), [0x12, 0xab]);
+
+        let inst = encode_vnsrl(1, 2, 8, OPIVI, 3);
+        assert_illegal(Vnsrl.call(inst, &mut hart, &mut bus), inst);
+    }
+}
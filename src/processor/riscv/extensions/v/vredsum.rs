@@ -0,0 +1,106 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vredsum;
+
+impl InstrExec for Vredsum {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b000000, MVV)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VREDSUM.VS", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::reduce(hart, inst, false, |_, acc, a| acc.wrapping_add(a))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPMVV,
+                testing::{assert_illegal, elems, encode, set_elems, set_mask, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vredsum(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b000000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vredsum
+            .call(inst, hart, bus)
+            .expect("VREDSUM.VS execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vredsum_vs() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_elems(&mut hart, 2, 8, &[1, 2, 3, 4]);
+        set_elems(&mut hart, 3, 8, &[10]);
+
+        exec(encode_vredsum(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [20]);
+    }
+
+    #[test]
+    fn vredsum_skips_inactive_elements_and_keeps_tail() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 1, 8);
+        set_elems(&mut hart, 2, 32, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        set_elems(&mut hart, 4, 32, &[100]);
+        set_elems(&mut hart, 1, 32, &[0, 9, 9, 9]);
+        set_mask(&mut hart, 0, 0b1000_0001);
+
+        exec(encode_vredsum(0, 2, 4, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 4), [109, 9, 9, 9]);
+    }
+
+    #[test]
+    fn vredsum_with_zero_vl_leaves_destination() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 0);
+        set_elems(&mut hart, 3, 8, &[5]);
+        set_elems(&mut hart, 1, 8, &[7]);
+
+        exec(encode_vredsum(1, 2, 3, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 1), [7]);
+    }
+
+    #[test]
+    fn vredsum_illegal_with_nonzero_vstart() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        hart.csrs_mut().set(csr::VSTART, 1);
+        let inst = encode_vredsum(1, 2, 3, OPMVV, 1);
+
+        assert_illegal(Vredsum.call(inst, &mut hart, &mut bus), inst);
+    }
+}
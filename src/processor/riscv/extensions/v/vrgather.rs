@@ -0,0 +1,89 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vrgather;
+
+impl InstrExec for Vrgather {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001100, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VRGATHER", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::gather(hart, inst, false)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVV, OPIVX,
+            testing::{assert_illegal, elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vrgather(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001100, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vrgather
+            .call(inst, hart, bus)
+            .expect("VRGATHER execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vrgather_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_elems(&mut hart, 2, 8, &[10, 20, 30, 40]);
+        set_elems(&mut hart, 3, 8, &[3, 0, 200, 1]);
+
+        exec(encode_vrgather(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 4), [40, 10, 0, 20]);
+    }
+
+    #[test]
+    fn vrgather_vx_splats_one_element() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        set_elems(&mut hart, 2, 64, &[5, 6]);
+        hart.set_xreg(5, 1);
+
+        exec(encode_vrgather(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 2), [6, 6]);
+    }
+
+    #[test]
+    fn vrgather_illegal_over_index() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        let inst = encode_vrgather(1, 2, 3, OPIVV, 3);
+
+        assert_illegal(Vrgather.call(inst, &mut hart, &mut bus), inst);
+    }
+}
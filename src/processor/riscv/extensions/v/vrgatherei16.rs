@@ -0,0 +1,78 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVV},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vrgatherei16;
+
+impl InstrExec for Vrgatherei16 {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001110, IVV)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VRGATHEREI16", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::gather(hart, inst, true)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVV,
+            testing::{assert_illegal, elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vrgatherei16(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001110, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vrgatherei16
+            .call(inst, hart, bus)
+            .expect("VRGATHEREI16 execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vrgatherei16_vv_uses_16_bit_indices() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        set_elems(&mut hart, 2, 8, &[10, 20, 30, 40]);
+        set_elems(&mut hart, 4, 16, &[0x0100, 2, 1, 0]);
+
+        exec(encode_vrgatherei16(1, 2, 4, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 4), [0, 30, 20, 10]);
+    }
+
+    #[test]
+    fn vrgatherei16_illegal_for_misaligned_index_group() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 4);
+        let inst = encode_vrgatherei16(1, 2, 5, OPIVV, 1);
+
+        assert_illegal(Vrgatherei16.call(inst, &mut hart, &mut bus), inst);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsadd;
+
+impl InstrExec for Vsadd {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b100001, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSADD", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let mut saturated = false;
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |sew, a, b| {
+            let (val, clamped) = v::clamp_signed(
+                i128::from(v::sext(a, sew)) + i128::from(v::sext(b, sew)),
+                sew,
+            );
+            saturated |= clamped;
+            val
+        })?;
+        if saturated {
+            v::saturate(hart);
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPIVV, OPIVX,
+                testing::{elems, encode, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vsadd(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b100001, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsadd
+            .call(inst, hart, bus)
+            .expect("VSADD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vsadd_vv_saturates_both_ways() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        set_elems(&mut hart, 2, 8, &[0x7f, 0x80, 5]);
+        set_elems(&mut hart, 3, 8, &[1, 0xff, 0xff]);
+
+        exec(encode_vsadd(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 3), [0x7f, 0x80, 4]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 1);
+    }
+
+    #[test]
+    fn vsadd_vx_at_64_bits() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 1);
+        set_elems(&mut hart, 2, 64, &[i64::MAX as u64]);
+        hart.set_xreg(5, 1);
+
+        exec(encode_vsadd(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 64, 1), [i64::MAX as u64]);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsaddu;
+
+impl InstrExec for Vsaddu {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b100000, IVV | IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSADDU", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let mut saturated = false;
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |sew, a, b| {
+            let (val, clamped) = v::clamp_unsigned(i128::from(a) + i128::from(b), sew);
+            saturated |= clamped;
+            val
+        })?;
+        if saturated {
+            v::saturate(hart);
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPIVI, OPIVV,
+                testing::{elems, encode, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vsaddu(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b100000, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsaddu
+            .call(inst, hart, bus)
+            .expect("VSADDU execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vsaddu_vv_saturates() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0xf0, 1]);
+        set_elems(&mut hart, 3, 8, &[0x20, 2]);
+
+        exec(encode_vsaddu(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0xff, 3]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 1);
+    }
+
+    #[test]
+    fn vsaddu_vi_leaves_vxsat_clear_without_overflow() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 2);
+        set_elems(&mut hart, 2, 16, &[1, 2]);
+
+        exec(encode_vsaddu(1, 2, 3, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 2), [4, 5]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 0);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Addressing, MOP_UNIT, OP_STORE_FP, UMOP_UNIT},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vse;
+
+impl InstrExec for Vse {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_STORE_FP, MOP_UNIT, Some(UMOP_UNIT))
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSE.V", skip_all, fields(vs3 = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vs3", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        v::store(hart, bus, inst, Addressing::UnitStride)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{encode_mem, set_elems, set_mask, setup, vset},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vse.call(inst, hart, bus)
+            .expect("VSE.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vse(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_STORE_FP, nf, MOP_UNIT, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vse32_v_skips_inactive_elements() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+        hart.set_xreg(10, 0x100);
+        set_elems(&mut hart, 1, 32, &[1, 2, 3]);
+        set_mask(&mut hart, 0, 0b101);
+
+        exec(encode_vse(1, 0, 0, 10, 0b110, 1), &mut hart, &mut bus);
+        assert_eq!(bus.read64(0x100).unwrap(), 1);
+        assert_eq!(bus.read32(0x108).unwrap(), 3);
+    }
+
+    #[test]
+    fn vsseg2e8_v_interleaves_fields() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        hart.set_xreg(10, 0x100);
+        set_elems(&mut hart, 2, 8, &[0x11, 0x33]);
+        set_elems(&mut hart, 3, 8, &[0x22, 0x44]);
+
+        exec(encode_vse(2, 1, 0, 10, 0b000, 2), &mut hart, &mut bus);
+        assert_eq!(bus.read32(0x100).unwrap(), 0x4433_2211);
+    }
+}
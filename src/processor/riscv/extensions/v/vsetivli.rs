@@ -0,0 +1,67 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, OP_V, OPCFG},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsetivli;
+
+impl InstrExec for Vsetivli {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x7f == OP_V && (inst >> 12) & 0b111 == OPCFG && inst >> 30 == 0b11
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSETIVLI", skip_all, fields(rd = tracing::field::Empty, uimm = tracing::field::Empty, vtypei = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[ops.vd]);
+            span.record("uimm", ops.vs1);
+            span.record("vtypei", (inst >> 20) & 0x3ff);
+        }
+
+        hart.check_vector(inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let vl = v::set_vl(hart, Some(ops.vs1 as u64), u64::from(inst >> 20) & 0x3ff);
+        hart.set_xreg(ops.vd, vl);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{memory::mmap::Mmap, processor::riscv::extensions::v::testing::setup};
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsetivli
+            .call(inst, hart, bus)
+            .expect("VSETIVLI execution unexpectedly trapped");
+    }
+
+    const fn encode_vsetivli(vtypei: u32, uimm: u32, rd: u32) -> u32 {
+        (0b11 << 30) | (vtypei << 20) | (uimm << 15) | (OPCFG << 12) | (rd << 7) | OP_V
+    }
+
+    #[test]
+    fn vsetivli_takes_avl_from_immediate() {
+        let (mut hart, mut bus) = setup();
+
+        exec(encode_vsetivli(0, 31, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(6), 16);
+
+        exec(encode_vsetivli(0, 3, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(6), 3);
+    }
+}
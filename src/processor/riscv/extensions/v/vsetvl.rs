@@ -0,0 +1,87 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, OP_V, OPCFG},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsetvl;
+
+impl InstrExec for Vsetvl {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x7f == OP_V && (inst >> 12) & 0b111 == OPCFG && inst >> 25 == 0b100_0000
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSETVL", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[ops.vd]);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+            span.record("rs2", Hart::IABI[ops.vs2]);
+        }
+
+        hart.check_vector(inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let avl = match (ops.vs1, ops.vd) {
+            (0, 0) => None,
+            (0, _) => Some(u64::MAX),
+            (rs1, _) => Some(hart.xreg(rs1)),
+        };
+        let vtype = hart.xreg(ops.vs2);
+        let vl = v::set_vl(hart, avl, vtype);
+        hart.set_xreg(ops.vd, vl);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, extensions::v::testing::setup, vector::VTYPE_VILL},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsetvl
+            .call(inst, hart, bus)
+            .expect("VSETVL execution unexpectedly trapped");
+    }
+
+    const fn encode_vsetvl(rs2: u32, rs1: u32, rd: u32) -> u32 {
+        (0b100_0000 << 25) | (rs2 << 20) | (rs1 << 15) | (OPCFG << 12) | (rd << 7) | OP_V
+    }
+
+    #[test]
+    fn vsetvl_takes_vtype_from_register() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(5, 7);
+        hart.set_xreg(7, 0b011_001);
+
+        exec(encode_vsetvl(7, 5, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(6), 4);
+        assert_eq!(hart.csrs().get(csr::VTYPE), 0b011_001);
+    }
+
+    #[test]
+    fn vsetvl_sets_vill_for_reserved_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(5, 7);
+        hart.set_xreg(7, 1 << 8);
+
+        exec(encode_vsetvl(7, 5, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(6), 0);
+        assert_eq!(hart.csrs().get(csr::VTYPE), VTYPE_VILL);
+    }
+}
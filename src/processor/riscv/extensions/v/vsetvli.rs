@@ -0,0 +1,118 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, OP_V, OPCFG},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsetvli;
+
+impl InstrExec for Vsetvli {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x7f == OP_V && (inst >> 12) & 0b111 == OPCFG && inst >> 31 == 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSETVLI", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, vtypei = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[ops.vd]);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+            span.record("vtypei", (inst >> 20) & 0x7ff);
+        }
+
+        hart.check_vector(inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let avl = match (ops.vs1, ops.vd) {
+            (0, 0) => None,
+            (0, _) => Some(u64::MAX),
+            (rs1, _) => Some(hart.xreg(rs1)),
+        };
+        let vl = v::set_vl(hart, avl, u64::from(inst >> 20) & 0x7ff);
+        hart.set_xreg(ops.vd, vl);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::testing::{assert_illegal, setup, vset},
+            vector::VTYPE_VILL,
+        },
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsetvli
+            .call(inst, hart, bus)
+            .expect("VSETVLI execution unexpectedly trapped");
+    }
+
+    const fn encode_vsetvli(vtypei: u32, rs1: u32, rd: u32) -> u32 {
+        (vtypei << 20) | (rs1 << 15) | (OPCFG << 12) | (rd << 7) | OP_V
+    }
+
+    #[test]
+    fn vsetvli_clips_avl_to_vlmax() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(5, 100);
+
+        exec(encode_vsetvli(0b1101_0000, 5, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(6), 4);
+        assert_eq!(hart.csrs().get(csr::VL), 4);
+        assert_eq!(hart.csrs().get(csr::VTYPE), 0b1101_0000);
+    }
+
+    #[test]
+    fn vsetvli_uses_vlmax_when_rs1_is_zero() {
+        let (mut hart, mut bus) = setup();
+
+        exec(encode_vsetvli(0b000_011, 0, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(6), 128);
+    }
+
+    #[test]
+    fn vsetvli_keeps_vl_when_rs1_and_rd_are_zero() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 3);
+
+        exec(encode_vsetvli(0b1101_0000, 0, 0), &mut hart, &mut bus);
+        assert_eq!(hart.csrs().get(csr::VL), 3);
+        assert_eq!(hart.csrs().get(csr::VTYPE), 0b1101_0000);
+    }
+
+    #[test]
+    fn vsetvli_sets_vill_for_unsupported_vtype() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(5, 4);
+
+        exec(encode_vsetvli(0b100, 5, 6), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(6), 0);
+        assert_eq!(hart.csrs().get(csr::VTYPE), VTYPE_VILL);
+    }
+
+    #[test]
+    fn vsetvli_illegal_when_vs_is_off() {
+        let (mut hart, mut bus) = setup();
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus & !csr::MSTATUS_VS);
+        let inst = encode_vsetvli(0, 5, 6);
+
+        assert_illegal(Vsetvli.call(inst, &mut hart, &mut bus), inst);
+    }
+}
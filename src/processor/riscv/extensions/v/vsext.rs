@@ -0,0 +1,85 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVV, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsext;
+
+impl InstrExec for Vsext {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b010010, MVV) && matches!((inst >> 15) & 0x1f, 0b00011 | 0b00101 | 0b00111)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSEXT", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let factor = match (inst >> 16) & 0b1111 {
+            0b0001 => 3,
+            0b0010 => 2,
+            _ => 1,
+        };
+        v::unary(hart, inst, Shape::extend(factor), |sew, a| {
+            v::sext(a, sew >> factor) as u64
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vsext(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b010010, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsext
+            .call(inst, hart, bus)
+            .expect("VSEXT execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vsext_vf2() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 2);
+        set_elems(&mut hart, 4, 8, &[0xff, 0x7f]);
+
+        exec(encode_vsext(1, 4, 0b00111, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 2), [0xffff, 0x7f]);
+    }
+
+    #[test]
+    fn vsext_vf4() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 1);
+        set_elems(&mut hart, 4, 8, &[0x80]);
+
+        exec(encode_vsext(1, 4, 0b00101, OPMVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 1), [0xffff_ff80]);
+    }
+}
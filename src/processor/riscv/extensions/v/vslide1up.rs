@@ -0,0 +1,78 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, MVX},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vslide1up;
+
+impl InstrExec for Vslide1up {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001110, MVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSLIDE1UP", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::slide1(hart, inst, true)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVX,
+            testing::{assert_illegal, elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vslide1up(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001110, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vslide1up
+            .call(inst, hart, bus)
+            .expect("VSLIDE1UP execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vslide1up_vx() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 4);
+        set_elems(&mut hart, 2, 16, &[1, 2, 3, 4]);
+        hart.set_xreg(5, 0xabcd);
+
+        exec(encode_vslide1up(1, 2, 5, OPMVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 4), [0xabcd, 1, 2, 3]);
+    }
+
+    #[test]
+    fn vslide1up_illegal_in_place() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 4);
+        let inst = encode_vslide1up(1, 2, 5, OPMVX, 2);
+
+        assert_illegal(Vslide1up.call(inst, &mut hart, &mut bus), inst);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVX},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vslidedown;
+
+impl InstrExec for Vslidedown {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001111, IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSLIDEDOWN", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+        v::check_group(inst, ops.vd, cfg.lmul)?;
+        v::check_group(inst, ops.vs2, cfg.lmul)?;
+        v::check_mask_overlap(inst, &ops)?;
+
+        let offset = v::scalar_index(hart, &ops);
+        let vlmax = cfg.vlmax() as u64;
+        let regs = hart.vregs();
+        let body: Vec<_> = (cfg.vstart..cfg.vl)
+            .map(|i| {
+                v::active(hart, &ops, i).then(|| match (i as u64).checked_add(offset) {
+                    Some(from) if from < vlmax => regs.get(ops.vs2, from as usize, cfg.sew),
+                    _ => 0,
+                })
+            })
+            .collect();
+
+        v::commit(hart, &cfg, (ops.vd, cfg.sew, cfg.lmul), &body);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI, OPIVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vslidedown(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vslidedown
+            .call(inst, hart, bus)
+            .expect("VSLIDEDOWN execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vslidedown_vi_reads_past_vl_up_to_vlmax() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 2);
+        set_elems(&mut hart, 2, 32, &[1, 2, 3, 4]);
+
+        exec(encode_vslidedown(1, 2, 2, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 32, 2), [3, 4]);
+    }
+
+    #[test]
+    fn vslidedown_vx_zero_fills_past_vlmax() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 32, 0, 4);
+        set_elems(&mut hart, 2, 32, &[1, 2, 3, 4]);
+        hart.set_xreg(5, 3);
+
+        exec(encode_vslidedown(1, 2, 5, OPIVX, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 32, 4), [4, 0, 0, 0]);
+    }
+}
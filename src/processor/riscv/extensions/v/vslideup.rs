@@ -0,0 +1,108 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVI, IVX},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vslideup;
+
+impl InstrExec for Vslideup {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b001110, IVX | IVI)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSLIDEUP", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+        v::check_group(inst, ops.vd, cfg.lmul)?;
+        v::check_group(inst, ops.vs2, cfg.lmul)?;
+        v::check_mask_overlap(inst, &ops)?;
+        v::check_disjoint(inst, (ops.vd, cfg.lmul), (ops.vs2, cfg.lmul))?;
+
+        let offset = v::scalar_index(hart, &ops);
+        let regs = hart.vregs();
+        let body: Vec<_> = (cfg.vstart..cfg.vl)
+            .map(|i| match (i as u64).checked_sub(offset) {
+                // Elements below the offset are left as they are.
+                None => Some(regs.get(ops.vd, i, cfg.sew)),
+                Some(from) => {
+                    v::active(hart, &ops, i).then(|| regs.get(ops.vs2, from as usize, cfg.sew))
+                }
+            })
+            .collect();
+
+        v::commit(hart, &cfg, (ops.vd, cfg.sew, cfg.lmul), &body);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPIVI, OPIVX,
+            testing::{assert_illegal, elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vslideup(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b001110, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vslideup
+            .call(inst, hart, bus)
+            .expect("VSLIDEUP execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vslideup_vi() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 5);
+        set_elems(&mut hart, 2, 8, &[1, 2, 3, 4, 5]);
+        set_elems(&mut hart, 1, 8, &[9, 9, 9, 9, 9]);
+
+        exec(encode_vslideup(1, 2, 2, OPIVI, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 5), [9, 9, 1, 2, 3]);
+    }
+
+    #[test]
+    fn vslideup_vx_past_vl_changes_nothing() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        set_elems(&mut hart, 2, 8, &[1, 2, 3]);
+        hart.set_xreg(5, u64::MAX);
+
+        exec(encode_vslideup(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 3), [0, 0, 0]);
+    }
+
+    #[test]
+    fn vslideup_illegal_in_place() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        let inst = encode_vslideup(1, 2, 1, OPIVI, 2);
+
+        assert_illegal(Vslideup.call(inst, &mut hart, &mut bus), inst);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        extensions::v::{self, MOP_UNIT, OP_STORE_FP, UMOP_MASK},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsm;
+
+impl InstrExec for Vsm {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_STORE_FP, MOP_UNIT, Some(UMOP_MASK))
+            && (inst >> 12) & 0b111 == 0
+            && inst >> 25 == 1
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSM.V", skip_all, fields(vs3 = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vs3", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        let cfg = v::config(hart, inst)?;
+        let ops = v::Operands::decode(inst);
+
+        let base = hart.xreg(ops.vs1);
+        for i in cfg.vstart..cfg.vl.div_ceil(8) {
+            let byte = hart.vregs().get(ops.vd, i, 8) as u8;
+            if let Err(err) = hart.write8(bus, base.wrapping_add(i as u64), byte) {
+                hart.csrs_mut().set(csr::VSTART, i as u64);
+                return Err(err);
+            }
+        }
+        v::finish(hart);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{encode_mem, set_elems, setup, vset},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsm.call(inst, hart, bus)
+            .expect("VSM.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vsm(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_STORE_FP, nf, MOP_UNIT, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vsm_v_stores_ceil_vl_over_8_bytes() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 1, 9);
+        hart.set_xreg(10, 0x100);
+        set_elems(&mut hart, 1, 8, &[0xab, 0xcd, 0xef]);
+
+        exec(encode_vsm(1, 1, 0b01011, 10, 0b000, 1), &mut hart, &mut bus);
+        assert_eq!(bus.read32(0x100).unwrap(), 0xcdab);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, IVV, IVX, Imm, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsmul;
+
+impl InstrExec for Vsmul {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b100111, IVV | IVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSMUL", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        let vxrm = v::vxrm(hart);
+        let mut saturated = false;
+        v::binary(hart, inst, Shape::SINGLE, Imm::Signed, |sew, a, b| {
+            let product = i128::from(v::sext(a, sew)) * i128::from(v::sext(b, sew));
+            let (val, clamped) = v::clamp_signed(v::roundoff(product, sew - 1, vxrm), sew);
+            saturated |= clamped;
+            val
+        })?;
+        if saturated {
+            v::saturate(hart);
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            extensions::v::{
+                OPIVV, OPIVX,
+                testing::{elems, encode, set_elems, setup, vset},
+            },
+        },
+    };
+
+    const fn encode_vsmul(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b100111, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsmul
+            .call(inst, hart, bus)
+            .expect("VSMUL execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vsmul_vv_saturates_minus_one_squared() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 2, 8, &[0x80, 0x40]);
+        set_elems(&mut hart, 3, 8, &[0x80, 0x40]);
+
+        exec(encode_vsmul(1, 2, 3, OPIVV, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 8, 2), [0x7f, 0x20]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 1);
+    }
+
+    #[test]
+    fn vsmul_vx_rounds() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 16, 0, 1);
+        set_elems(&mut hart, 2, 16, &[3]);
+        hart.set_xreg(5, 0x4000);
+
+        exec(encode_vsmul(1, 2, 5, OPIVX, 1), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 1, 16, 1), [2]);
+        assert_eq!(hart.csrs().get(csr::VCSR) & 1, 0);
+    }
+}
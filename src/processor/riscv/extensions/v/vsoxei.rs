@@ -0,0 +1,80 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Addressing, MOP_INDEXED_ORDERED, OP_STORE_FP},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsoxei;
+
+impl InstrExec for Vsoxei {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_STORE_FP, MOP_INDEXED_ORDERED, None)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSOXEI.V", skip_all, fields(vs3 = tracing::field::Empty, rs1 = tracing::field::Empty, vs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vs3", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+            span.record("vs2", ops.vs2);
+        }
+
+        let addressing = Addressing::Indexed {
+            vs2: v::Operands::decode(inst).vs2,
+            eew: v::mem_eew(inst),
+        };
+        v::store(hart, bus, inst, addressing)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{encode_mem, set_elems, setup, vset},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsoxei
+            .call(inst, hart, bus)
+            .expect("VSOXEI.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vsoxei(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(
+            OP_STORE_FP,
+            nf,
+            MOP_INDEXED_ORDERED,
+            vm,
+            rs2,
+            rs1,
+            width,
+            vd,
+        )
+    }
+
+    #[test]
+    fn vsoxei16_v_with_sew_8_data() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 3);
+        hart.set_xreg(10, 0x100);
+        set_elems(&mut hart, 4, 16, &[2, 0, 1]);
+        set_elems(&mut hart, 1, 8, &[0x33, 0x11, 0x22]);
+
+        exec(encode_vsoxei(1, 1, 4, 10, 0b101, 1), &mut hart, &mut bus);
+        assert_eq!(bus.read32(0x100).unwrap(), 0x33_2211);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        extensions::v::{self, MOP_UNIT, OP_STORE_FP, UMOP_WHOLE},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsr;
+
+impl InstrExec for Vsr {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_STORE_FP, MOP_UNIT, Some(UMOP_WHOLE))
+            && v::nf(inst).is_power_of_two()
+            && inst & (1 << 25) != 0
+            && (inst >> 12) & 0b111 == 0
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSR.V", skip_all, fields(vs3 = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vs3", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+        }
+
+        hart.check_vector(inst)?;
+        let ops = v::Operands::decode(inst);
+        let nf = v::nf(inst);
+        if !ops.vd.is_multiple_of(nf) {
+            return Err(Trap::IllegalInstruction { inst }.into());
+        }
+
+        let base = hart.xreg(ops.vs1);
+        let vstart = hart.csrs().get(csr::VSTART) as usize;
+        for i in vstart..nf * hart.vregs().vlenb() {
+            let byte = hart.vregs().get(ops.vd, i, 8) as u8;
+            if let Err(err) = hart.write8(bus, base.wrapping_add(i as u64), byte) {
+                hart.csrs_mut().set(csr::VSTART, i as u64);
+                return Err(err);
+            }
+        }
+        v::finish(hart);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{encode_mem, set_elems, setup, vset},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsr.call(inst, hart, bus)
+            .expect("VSR.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vsr(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_STORE_FP, nf, MOP_UNIT, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vs1r_v_stores_whole_register() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 1);
+        hart.set_xreg(10, 0x100);
+        set_elems(
+            &mut hart,
+            1,
+            64,
+            &[0x0807_0605_0403_0201, 0x100f_0e0d_0c0b_0a09],
+        );
+
+        exec(encode_vsr(1, 1, 0b01000, 10, 0b000, 1), &mut hart, &mut bus);
+        assert_eq!(bus.read64(0x100).unwrap(), 0x0807_0605_0403_0201);
+        assert_eq!(bus.read64(0x108).unwrap(), 0x100f_0e0d_0c0b_0a09);
+    }
+
+    #[test]
+    fn vs1r_v_resumes_from_vstart() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(10, 0x100);
+        set_elems(&mut hart, 1, 8, &[1, 2, 3]);
+        hart.csrs_mut().set(csr::VSTART, 2);
+
+        exec(encode_vsr(1, 1, 0b01000, 10, 0b000, 1), &mut hart, &mut bus);
+        assert_eq!(bus.read32(0x100).unwrap(), 0x03_0000);
+        assert_eq!(hart.csrs().get(csr::VSTART), 0);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Addressing, MOP_STRIDED, OP_STORE_FP},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vsse;
+
+impl InstrExec for Vsse {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_mem(inst, OP_STORE_FP, MOP_STRIDED, None)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VSSE.V", skip_all, fields(vs3 = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vs3", ops.vd);
+            span.record("rs1", Hart::IABI[ops.vs1]);
+            span.record("rs2", Hart::IABI[ops.vs2]);
+        }
+
+        let stride = hart.xreg(v::Operands::decode(inst).vs2);
+        v::store(hart, bus, inst, Addressing::Strided(stride))?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::testing::{encode_mem, set_elems, setup, vset},
+    };
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vsse.call(inst, hart, bus)
+            .expect("VSSE.V execution unexpectedly trapped");
+    }
+
+    const fn encode_vsse(nf: u32, vm: u32, rs2: u32, rs1: u32, width: u32, vd: u32) -> u32 {
+        encode_mem(OP_STORE_FP, nf, MOP_STRIDED, vm, rs2, rs1, width, vd)
+    }
+
+    #[test]
+    fn vsse64_v() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 64, 0, 2);
+        hart.set_xreg(10, 0x100);
+        hart.set_xreg(11, 16);
+        set_elems(&mut hart, 1, 64, &[1, 2]);
+
+        exec(encode_vsse(1, 1, 11, 10, 0b111, 1), &mut hart, &mut bus);
+        assert_eq!(bus.read64(0x100).unwrap(), 1);
+        assert_eq!(bus.read64(0x108).unwrap(), 0);
+        assert_eq!(bus.read64(0x110).unwrap(), 2);
+    }
+}
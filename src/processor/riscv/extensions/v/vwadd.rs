@@ -0,0 +1,82 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Imm, MVV, MVX, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct Vwadd;
+
+impl InstrExec for Vwadd {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b110001, MVV | MVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VWADD", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::WIDEN, Imm::Signed, |sew, a, b| {
+            v::sext(a, sew).wrapping_add(v::sext(b, sew)) as u64
+        })?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV, OPMVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vwadd(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b110001, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Vwadd
+            .call(inst, hart, bus)
+            .expect("VWADD execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vwadd_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 4, 8, &[0xff, 1]);
+        set_elems(&mut hart, 5, 8, &[0xff, 2]);
+
+        exec(encode_vwadd(1, 4, 5, OPMVV, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 16, 2), [0xfffe, 3]);
+    }
+
+    #[test]
+    fn vwadd_vx() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 1);
+        set_elems(&mut hart, 4, 8, &[0x80]);
+        hart.set_xreg(5, 0x80);
+
+        exec(encode_vwadd(1, 4, 5, OPMVX, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 16, 1), [0xff00]);
+    }
+}
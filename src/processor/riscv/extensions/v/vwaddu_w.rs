@@ -0,0 +1,80 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        extensions::v::{self, Imm, MVV, MVX, Shape},
+        hart::Hart,
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct VwadduW;
+
+impl InstrExec for VwadduW {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        v::op_v(inst, 0b110100, MVV | MVX)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "VWADDU.W", skip_all, fields(vd = tracing::field::Empty, vs2 = tracing::field::Empty, vs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let ops = v::Operands::decode(inst);
+            let span = tracing::Span::current();
+            span.record("vd", ops.vd);
+            span.record("vs2", ops.vs2);
+            span.record("vs1", ops.vs1);
+        }
+
+        v::binary(hart, inst, Shape::WIDEN_W, Imm::Signed, |_, a, b| a + b)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::extensions::v::{
+            OPMVV, OPMVX,
+            testing::{elems, encode, set_elems, setup, vset},
+        },
+    };
+
+    const fn encode_vwaddu_w(vm: u32, vs2: u32, vs1: u32, funct3: u32, vd: u32) -> u32 {
+        encode(0b110100, vm, vs2, vs1, funct3, vd)
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        VwadduW
+            .call(inst, hart, bus)
+            .expect("VWADDU.W execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn vwaddu_w_vv() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 2);
+        set_elems(&mut hart, 4, 16, &[0xffff, 1]);
+        set_elems(&mut hart, 6, 8, &[1, 0xff]);
+
+        exec(encode_vwaddu_w(1, 4, 6, OPMVV, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 16, 2), [0, 0x100]);
+    }
+
+    #[test]
+    fn vwaddu_w_wide_source_may_equal_destination() {
+        let (mut hart, mut bus) = setup();
+        vset(&mut hart, 8, 0, 1);
+        set_elems(&mut hart, 2, 16, &[0x1234]);
+        hart.set_xreg(5, 0x11);
+
+        exec(encode_vwaddu_w(1, 2, 5, OPMVX, 2), &mut hart, &mut bus);
+        assert_eq!(elems(&hart, 2, 16, 1), [0x1245]);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod wrs_nto;
+pub mod wrs_sto;
@@ -0,0 +1,69 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct WrsSto;
+
+impl InstrExec for WrsSto {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst == 0x01d0_0073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "WRS.STO", skip_all))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        hart.check_ext(Extension::Zawrs, inst)?;
+        // Only this hart's own stores can invalidate its reservation set, so
+        // there is never anything to wait for and the wait ends at once.
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    const WRS_STO: u32 = 0x01d0_0073;
+
+    #[test]
+    fn wrs_sto_completes_immediately() {
+        let mut hart = Hart::new(0);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+
+        WrsSto.call(WRS_STO, &mut hart, &mut bus).unwrap();
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn wrs_sto_illegal_without_zawrs() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zawrs),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+
+        let err = WrsSto.call(WRS_STO, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst: WRS_STO }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+        softfloat::{F16, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FaddH;
+
+impl InstrExec for FaddH {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_007f == 0x0400_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FADD.H", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+            span.record("rs2", Hart::FABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zfh, inst)?;
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.add(F16, hart.read_freg(F16, rs1), hart.read_freg(F16, rs2));
+
+        hart.write_freg(F16, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+            softfloat::FLAG_NX,
+        },
+    };
+
+    fn encode_fadd_h(rd: u32, rs1: u32, rs2: u32, rm: u32) -> u32 {
+        (0b0000010 << 25) | (rs2 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: u16) {
+        hart.write_freg(F16, i, u64::from(val));
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> u16 {
+        hart.read_freg(F16, i) as u16
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FaddH
+            .call(inst, hart, bus)
+            .expect("FADD.H execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fadd_h_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0x3e00);
+        set_f(&mut hart, 2, 0x4080);
+
+        exec(encode_fadd_h(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 0x4380);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fadd_h_inexact() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0x3c00);
+        set_f(&mut hart, 2, 0x0c00);
+
+        exec(encode_fadd_h(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 0x3c00);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+
+    #[test]
+    fn fadd_h_static_rounding_mode() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0x3c00);
+        set_f(&mut hart, 2, 0x0c00);
+
+        exec(encode_fadd_h(3, 1, 2, 0b011), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 0x3c01);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+
+    #[test]
+    fn fadd_h_subnormals() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0x0001);
+        set_f(&mut hart, 2, 0x03ff);
+
+        exec(encode_fadd_h(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 0x0400);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fadd_h_unboxed_operand_is_canonical_nan() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0xffff_ffff_0000_3c00);
+        set_f(&mut hart, 2, 0x3c00);
+
+        exec(encode_fadd_h(3, 1, 2, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(3), 0xffff_ffff_ffff_7e00);
+    }
+
+    #[test]
+    fn fadd_h_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fadd_h(3, 1, 2, 0b000);
+
+        let err = FaddH.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn fadd_h_illegal_with_only_zfhmin() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zfh),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        let inst = encode_fadd_h(3, 1, 2, 0b000);
+
+        let err = FaddH.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
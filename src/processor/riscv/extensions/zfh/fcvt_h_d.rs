@@ -0,0 +1,147 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+        softfloat::{F16, F64, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FcvtHD;
+
+impl InstrExec for FcvtHD {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_007f == 0x4410_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCVT.H.D", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_any_ext(&[Extension::Zfh, Extension::Zfhmin], inst)?;
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.convert(F64, F16, hart.read_freg(F64, rs1));
+
+        hart.write_freg(F16, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            softfloat::{FLAG_NX, FLAG_OF, FLAG_UF},
+        },
+    };
+
+    fn encode_fcvt_h_d(rd: u32, rs1: u32, rm: u32) -> u32 {
+        (0b0100010 << 25) | (0b00001 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> u16 {
+        hart.read_freg(F16, i) as u16
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FcvtHD
+            .call(inst, hart, bus)
+            .expect("FCVT.H.D execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fcvt_h_d_basic() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 1.5f64.to_bits());
+
+        exec(encode_fcvt_h_d(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 0x3e00);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_h_d_underflow() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 1e-10f64.to_bits());
+
+        exec(encode_fcvt_h_d(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 0x0000);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_UF | FLAG_NX));
+    }
+
+    #[test]
+    fn fcvt_h_d_overflow() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, (-65_520f64).to_bits());
+
+        exec(encode_fcvt_h_d(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 0xfc00);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_OF | FLAG_NX));
+    }
+
+    #[test]
+    fn fcvt_h_d_below_overflow_toward_zero() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, (-65_520f64).to_bits());
+
+        exec(encode_fcvt_h_d(2, 1, 0b001), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 0xfbff);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NX));
+    }
+
+    #[test]
+    fn fcvt_h_d_overflow_toward_zero_saturates() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, (-70_000f64).to_bits());
+
+        exec(encode_fcvt_h_d(2, 1, 0b001), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 0xfbff);
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_OF | FLAG_NX));
+    }
+
+    #[test]
+    fn fcvt_h_d_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fcvt_h_d(2, 1, 0b000);
+
+        let err = FcvtHD.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
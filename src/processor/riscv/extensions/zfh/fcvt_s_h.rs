@@ -0,0 +1,157 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+        softfloat::{F16, F32, SoftFloat},
+    },
+};
+
+#[derive(Debug)]
+pub struct FcvtSH;
+
+impl InstrExec for FcvtSH {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_007f == 0x4020_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FCVT.S.H", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_any_ext(&[Extension::Zfh, Extension::Zfhmin], inst)?;
+        hart.check_fp(inst)?;
+        let mut sf = SoftFloat::new(hart.rounding_mode(inst)?);
+        let val = sf.convert(F16, F32, hart.read_freg(F16, rs1));
+
+        hart.write_freg(F32, rd, val);
+        hart.raise_fflags(sf.flags());
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            csr,
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+            softfloat::FLAG_NV,
+        },
+    };
+
+    fn encode_fcvt_s_h(rd: u32, rs1: u32, rm: u32) -> u32 {
+        (0b0100000 << 25) | (0b00010 << 20) | (rs1 << 15) | (rm << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: u16) {
+        hart.write_freg(F16, i, u64::from(val));
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FcvtSH
+            .call(inst, hart, bus)
+            .expect("FCVT.S.H execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fcvt_s_h_basic() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0x3e00);
+
+        exec(encode_fcvt_s_h(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(2), 0xffff_ffff_3fc0_0000);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_s_h_subnormal_is_exact() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0x0001);
+
+        exec(encode_fcvt_s_h(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.read_freg(F32, 2), u64::from(2f32.powi(-24).to_bits()));
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fcvt_s_h_signaling_nan_is_invalid() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0x7c01);
+
+        exec(encode_fcvt_s_h(2, 1, 0b000), &mut hart, &mut bus);
+
+        assert_eq!(hart.read_freg(F32, 2), F32.canonical_nan());
+        assert_eq!(hart.csrs().get(csr::FCSR), u64::from(FLAG_NV));
+    }
+
+    #[test]
+    fn fcvt_s_h_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fcvt_s_h(2, 1, 0b000);
+
+        let err = FcvtSH.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn fcvt_s_h_needs_zfh_or_zfhmin() {
+        let with = |extensions| {
+            let mut hart = Hart::with_config(
+                0,
+                HartConfig {
+                    extensions,
+                    ..HartConfig::default()
+                },
+            );
+            let mstatus = hart.csrs().get(csr::MSTATUS);
+            hart.csrs_mut()
+                .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+            hart
+        };
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        let inst = encode_fcvt_s_h(2, 1, 0b000);
+
+        let mut hart = with(Extensions::default().with(Extension::Zfhmin));
+        set_f(&mut hart, 1, 0x3e00);
+        exec(inst, &mut hart, &mut bus);
+        assert_eq!(hart.freg(2), 0xffff_ffff_3fc0_0000);
+
+        let mut hart = with(Extensions::default());
+        let err = FcvtSH.call(inst, &mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
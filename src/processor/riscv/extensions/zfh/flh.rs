@@ -0,0 +1,111 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+        softfloat::F16,
+    },
+};
+
+#[derive(Debug)]
+pub struct Flh;
+
+impl InstrExec for Flh {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x707f == 0x0000_1007
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FLH", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, imm = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let imm = ((inst as i32) >> 20) as i64;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("imm", imm);
+        }
+
+        hart.check_any_ext(&[Extension::Zfh, Extension::Zfhmin], inst)?;
+        hart.check_fp(inst)?;
+        let addr = hart.xreg(rs1).wrapping_add_signed(imm);
+        let val = hart.read16(bus, addr)?;
+
+        hart.write_freg(F16, rd, u64::from(val));
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_flh(rd: u32, rs1: u32, imm: i16) -> u32 {
+        let imm12 = (imm as u32) & 0xfff;
+        (imm12 << 20) | (rs1 << 15) | (0b001 << 12) | (rd << 7) | 0b0000111
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> u16 {
+        hart.read_freg(F16, i) as u16
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        Flh.call(inst, hart, bus)
+            .expect("FLH execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn flh_nan_boxes() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x100);
+        bus.write16(0x102, 0x3e00).unwrap();
+
+        exec(encode_flh(2, 1, 2), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(2), 0xffff_ffff_ffff_3e00);
+    }
+
+    #[test]
+    fn flh_negative_offset() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x208);
+        bus.write16(0x200, 0xbc00).unwrap();
+
+        exec(encode_flh(3, 1, -8), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 3), 0xbc00);
+    }
+
+    #[test]
+    fn flh_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_flh(2, 1, 0);
+
+        let err = Flh.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
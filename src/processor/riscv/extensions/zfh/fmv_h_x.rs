@@ -0,0 +1,105 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+        softfloat::F16,
+    },
+};
+
+#[derive(Debug)]
+pub struct FmvHX;
+
+impl InstrExec for FmvHX {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0xf400_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FMV.H.X", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::FABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_any_ext(&[Extension::Zfh, Extension::Zfhmin], inst)?;
+        hart.check_fp(inst)?;
+        hart.write_freg(F16, rd, hart.xreg(rs1));
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap},
+    };
+
+    fn encode_fmv_h_x(rd: u32, rs1: u32) -> u32 {
+        (0b1111010 << 25) | (0b00000 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn get_f(hart: &Hart, i: usize) -> u16 {
+        hart.read_freg(F16, i) as u16
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FmvHX
+            .call(inst, hart, bus)
+            .expect("FMV.H.X execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fmv_h_x_nan_boxes() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0xdead_beef_cafe_3c00);
+
+        exec(encode_fmv_h_x(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.freg(2), 0xffff_ffff_ffff_3c00);
+    }
+
+    #[test]
+    fn fmv_h_x_keeps_signaling_nan() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x7c01);
+
+        exec(encode_fmv_h_x(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(get_f(&hart, 2), 0x7c01);
+        assert_eq!(hart.csrs().get(csr::FCSR), 0);
+    }
+
+    #[test]
+    fn fmv_h_x_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fmv_h_x(2, 1);
+
+        let err = FmvHX.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct FmvXH;
+
+impl InstrExec for FmvXH {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfff0_707f == 0xe400_0053
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FMV.X.H", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::FABI[rs1]);
+        }
+
+        hart.check_any_ext(&[Extension::Zfh, Extension::Zfhmin], inst)?;
+        hart.check_fp(inst)?;
+        let val = hart.freg(rs1) as i16 as i64 as u64;
+
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, softfloat::F16},
+    };
+
+    fn encode_fmv_x_h(rd: u32, rs1: u32) -> u32 {
+        (0b1110010 << 25) | (0b00000 << 20) | (rs1 << 15) | (0b000 << 12) | (rd << 7) | 0b1010011
+    }
+
+    fn set_f(hart: &mut Hart, i: usize, val: u16) {
+        hart.write_freg(F16, i, u64::from(val));
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        let mut hart = Hart::new(0);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_FS_INITIAL);
+        (hart, Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        FmvXH
+            .call(inst, hart, bus)
+            .expect("FMV.X.H execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn fmv_x_h_sign_extends() {
+        let (mut hart, mut bus) = setup();
+        set_f(&mut hart, 1, 0xbc00);
+
+        exec(encode_fmv_x_h(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 0xffff_ffff_ffff_bc00);
+    }
+
+    #[test]
+    fn fmv_x_h_ignores_nan_boxing() {
+        let (mut hart, mut bus) = setup();
+        hart.set_freg(1, 0x1234_5678_9abc_3c00);
+
+        exec(encode_fmv_x_h(2, 1), &mut hart, &mut bus);
+
+        assert_eq!(hart.xreg(2), 0x3c00);
+    }
+
+    #[test]
+    fn fmv_x_h_illegal_when_fs_off() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSTATUS, 0);
+        let inst = encode_fmv_x_h(2, 1);
+
+        let err = FmvXH.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -0,0 +1,34 @@
+pub mod fadd_h;
+pub mod fclass_h;
+pub mod fcvt_d_h;
+pub mod fcvt_h_d;
+pub mod fcvt_h_l;
+pub mod fcvt_h_lu;
+pub mod fcvt_h_s;
+pub mod fcvt_h_w;
+pub mod fcvt_h_wu;
+pub mod fcvt_l_h;
+pub mod fcvt_lu_h;
+pub mod fcvt_s_h;
+pub mod fcvt_w_h;
+pub mod fcvt_wu_h;
+pub mod fdiv_h;
+pub mod feq_h;
+pub mod fle_h;
+pub mod flh;
+pub mod flt_h;
+pub mod fmadd_h;
+pub mod fmax_h;
+pub mod fmin_h;
+pub mod fmsub_h;
+pub mod fmul_h;
+pub mod fmv_h_x;
+pub mod fmv_x_h;
+pub mod fnmadd_h;
+pub mod fnmsub_h;
+pub mod fsgnj_h;
+pub mod fsgnjn_h;
+pub mod fsgnjx_h;
+pub mod fsh;
+pub mod fsqrt_h;
+pub mod fsub_h;
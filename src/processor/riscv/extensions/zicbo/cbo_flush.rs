@@ -0,0 +1,131 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        extensions::zicbo,
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct CboFlush;
+
+impl InstrExec for CboFlush {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        zicbo::op_cbo(inst, 0b0000_0000_0010)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CBO.FLUSH", skip_all, fields(rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zicbom, inst)?;
+        zicbo::check_enabled(hart, inst, csr::ENVCFG_CBCFE)?;
+        let addr = hart.xreg(rs1);
+        zicbo::probe(hart, bus, addr)?;
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{exception::Trap as MemoryTrap, mmap::Mmap},
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig, Privilege},
+        },
+    };
+
+    fn encode_cbo_flush(rs1: u32) -> u32 {
+        (0b0000_0000_0010 << 20) | (rs1 << 15) | (0b010 << 12) | 0b0001111
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        CboFlush
+            .call(inst, hart, bus)
+            .expect("CBO.FLUSH execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn cbo_flush_in_m_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1040);
+
+        exec(encode_cbo_flush(1), &mut hart, &mut bus);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn cbo_flush_illegal_in_s_mode_unless_enabled() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::Supervisor);
+        let inst = encode_cbo_flush(1);
+
+        let err = CboFlush.call(inst, &mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+
+        hart.csrs_mut().set(csr::MENVCFG, csr::ENVCFG_CBCFE);
+        hart.set_privilege(Privilege::User);
+        let err = CboFlush.call(inst, &mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn cbo_flush_illegal_without_zicbom() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zicbom),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        let inst = encode_cbo_flush(1);
+
+        let err = CboFlush.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.csrs().get(csr::MENVCFG), 0);
+    }
+
+    #[test]
+    fn cbo_flush_inaccessible_block_faults_as_store() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MENVCFG, csr::ENVCFG_CBCFE);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_xreg(1, 0x1040);
+
+        let err = CboFlush
+            .call(encode_cbo_flush(1), &mut hart, &mut bus)
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::StoreAccessFault { addr: 0x1040 }
+        );
+    }
+}
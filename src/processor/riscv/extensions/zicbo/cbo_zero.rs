@@ -0,0 +1,172 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        extensions::zicbo::{self, CACHE_BLOCK_SIZE},
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+        mmu::Access,
+    },
+};
+
+#[derive(Debug)]
+pub struct CboZero;
+
+impl InstrExec for CboZero {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        zicbo::op_cbo(inst, 0b0000_0000_0100)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CBO.ZERO", skip_all, fields(rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        hart.check_ext(Extension::Zicboz, inst)?;
+        zicbo::check_enabled(hart, inst, csr::ENVCFG_CBZE)?;
+        // Check the block as a whole first so that a fault leaves it
+        // untouched and reports the address in rs1.
+        let addr = hart.xreg(rs1);
+        hart.translate(bus, addr, 1, Access::Store)?;
+        let block = addr & !(CACHE_BLOCK_SIZE - 1);
+        for offset in (0..CACHE_BLOCK_SIZE).step_by(8) {
+            hart.write64(bus, block + offset, 0)?;
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{exception::Trap as MemoryTrap, mmap::Mmap},
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig, Privilege},
+            pmp::{PMP_A_NAPOT, PMP_R, PMP_W},
+        },
+    };
+
+    fn encode_cbo_zero(rs1: u32) -> u32 {
+        (0b0000_0000_0100 << 20) | (rs1 << 15) | (0b010 << 12) | 0b0001111
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        CboZero
+            .call(inst, hart, bus)
+            .expect("CBO.ZERO execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn cbo_zero_clears_the_aligned_block() {
+        let (mut hart, mut bus) = setup();
+        for offset in (0..0x80).step_by(8) {
+            bus.write64(0x1000 + offset, u64::MAX).unwrap();
+        }
+        hart.set_xreg(1, 0x1047);
+
+        exec(encode_cbo_zero(1), &mut hart, &mut bus);
+        assert_eq!(bus.read64(0x1038).unwrap(), u64::MAX);
+        for offset in (0x40..0x80).step_by(8) {
+            assert_eq!(bus.read64(0x1000 + offset).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn cbo_zero_allowed_in_u_mode_when_both_levels_enable_it() {
+        let (mut hart, mut bus) = setup();
+        bus.write64(0x1000, u64::MAX).unwrap();
+        hart.csrs_mut().set(csr::PMPADDR0, u64::MAX >> 10);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W));
+        hart.csrs_mut().set(csr::MENVCFG, csr::ENVCFG_CBZE);
+        hart.csrs_mut().set(csr::SENVCFG, csr::ENVCFG_CBZE);
+        hart.set_privilege(Privilege::User);
+        hart.set_xreg(1, 0x1000);
+
+        exec(encode_cbo_zero(1), &mut hart, &mut bus);
+        assert_eq!(bus.read64(0x1000).unwrap(), 0);
+    }
+
+    #[test]
+    fn cbo_zero_in_m_mode() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1040);
+
+        exec(encode_cbo_zero(1), &mut hart, &mut bus);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn cbo_zero_illegal_in_s_mode_unless_enabled() {
+        let (mut hart, mut bus) = setup();
+        hart.set_privilege(Privilege::Supervisor);
+        let inst = encode_cbo_zero(1);
+
+        let err = CboZero.call(inst, &mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+
+        hart.csrs_mut().set(csr::MENVCFG, csr::ENVCFG_CBZE);
+        hart.set_privilege(Privilege::User);
+        let err = CboZero.call(inst, &mut hart, &mut bus).unwrap_err();
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+
+    #[test]
+    fn cbo_zero_illegal_without_zicboz() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zicboz),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        let inst = encode_cbo_zero(1);
+
+        let err = CboZero.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.csrs().get(csr::MENVCFG), 0);
+    }
+
+    #[test]
+    fn cbo_zero_fault_leaves_block_untouched() {
+        let (mut hart, mut bus) = setup();
+        bus.write64(0x1000, u64::MAX).unwrap();
+        hart.csrs_mut().set(csr::MENVCFG, csr::ENVCFG_CBZE);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_xreg(1, 0x1008);
+
+        let err = CboZero
+            .call(encode_cbo_zero(1), &mut hart, &mut bus)
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::StoreAccessFault { addr: 0x1008 }
+        );
+        assert_eq!(bus.read64(0x1000).unwrap(), u64::MAX);
+    }
+}
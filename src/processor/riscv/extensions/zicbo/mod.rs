@@ -0,0 +1,75 @@
+//! Cache-block management (Zicbom), zeroing (Zicboz) and prefetch (Zicbop)
+//! instructions. Memory is not cached, so management operations only check
+//! that they are allowed and that the block is accessible.
+
+pub mod cbo_clean;
+pub mod cbo_flush;
+pub mod cbo_inval;
+pub mod cbo_zero;
+pub mod prefetch_i;
+pub mod prefetch_r;
+pub mod prefetch_w;
+
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        hart::{Hart, Privilege},
+        mmu::Access,
+    },
+};
+
+/// Size in bytes of the naturally aligned block a CBO instruction acts on.
+pub const CACHE_BLOCK_SIZE: u64 = 64;
+
+/// Matches a CBO instruction, MISC-MEM with `funct3` 010, by its `imm` field.
+#[inline(always)]
+pub(crate) const fn op_cbo(inst: u32, imm: u32) -> bool {
+    inst & 0xfff0_7fff == (imm << 20) | 0x200f
+}
+
+/// Matches a prefetch hint, an ORI to `x0`, by the low five offset bits.
+#[inline(always)]
+pub(crate) const fn op_prefetch(inst: u32, sel: u32) -> bool {
+    inst & 0x01f0_7fff == (sel << 20) | 0x6013
+}
+
+/// Fails with an illegal-instruction exception when `field` of `menvcfg`
+/// is zero in S-mode, or either that or the same field of `senvcfg` is zero
+/// in U-mode. In a guest, a zero field of `henvcfg`, or of `senvcfg` in
+/// VU-mode, raises a virtual-instruction exception instead.
+#[inline(always)]
+pub(crate) fn check_enabled(hart: &Hart, inst: u32, field: u64) -> Result<(), Trap> {
+    let menvcfg = hart.csrs().get(csr::MENVCFG) & field != 0;
+    let henvcfg = hart.csrs().get(csr::HENVCFG) & field != 0;
+    let senvcfg = hart.csrs().get(csr::SENVCFG) & field != 0;
+    let enabled = match hart.privilege() {
+        Privilege::Machine => true,
+        Privilege::Supervisor => menvcfg,
+        Privilege::User => menvcfg && (hart.virt() || senvcfg),
+    };
+    if !enabled {
+        return Err(Trap::IllegalInstruction { inst });
+    }
+    let permitted = match hart.privilege() {
+        _ if !hart.virt() => true,
+        Privilege::User => henvcfg && senvcfg,
+        _ => henvcfg,
+    };
+    if !permitted {
+        return Err(Trap::VirtualInstruction { inst });
+    }
+    Ok(())
+}
+
+/// Checks that the block holding `addr` may be cleaned, flushed or
+/// invalidated: either read or write permission will do, and a block that
+/// has neither faults as a store would.
+#[inline(always)]
+pub(crate) fn probe(hart: &mut Hart, bus: &mut dyn Bus, addr: u64) -> anyhow::Result<()> {
+    if hart.translate(bus, addr, 1, Access::Load).is_ok() {
+        return Ok(());
+    }
+    hart.translate(bus, addr, 1, Access::Store).map(|_| ())
+}
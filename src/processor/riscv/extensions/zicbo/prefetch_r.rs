@@ -0,0 +1,59 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{extensions::zicbo, hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct PrefetchR;
+
+impl InstrExec for PrefetchR {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        zicbo::op_prefetch(inst, 0b00001)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "PREFETCH.R", skip_all, fields(offset = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, _inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        #[cfg(feature = "trace")]
+        {
+            let rs1 = ((_inst >> 15) & 0x1f) as usize;
+            let span = tracing::Span::current();
+            span.record("offset", ((_inst as i32) >> 20) & !0x1f);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        // A hint encoded as ORI to x0, so it never traps, with or without
+        // Zicbop; with no cache there is nothing to fetch ahead.
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{memory::mmap::Mmap, processor::riscv::instruction::ISA};
+
+    fn encode_prefetch_r(offset: i32, rs1: u32) -> u32 {
+        (((offset as u32) & 0xfe0) << 20)
+            | (0b00001 << 20)
+            | (rs1 << 15)
+            | (0b110 << 12)
+            | 0b0010011
+    }
+
+    #[test]
+    fn prefetch_r_only_advances_pc() {
+        let mut hart = Hart::new(0);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(1, 0x1234);
+        let inst = encode_prefetch_r(-64, 1);
+
+        assert!(PrefetchR.matches(inst));
+        ISA.dispatch(inst, &mut hart, &mut bus).unwrap();
+        assert_eq!(hart.pc(), 4);
+        assert_eq!(hart.xreg(0), 0);
+    }
+}
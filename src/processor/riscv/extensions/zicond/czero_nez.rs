@@ -0,0 +1,110 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct CzeroNez;
+
+impl InstrExec for CzeroNez {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xfe00_707f == 0x0e00_7033
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CZERO.NEZ", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let rs2 = ((inst >> 20) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zicond, inst)?;
+        let val = if hart.xreg(rs2) != 0 {
+            0
+        } else {
+            hart.xreg(rs1)
+        };
+        hart.set_xreg(rd, val);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_czero_nez(rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (0b0000111 << 25) | (rs2 << 20) | (rs1 << 15) | (0b111 << 12) | (rd << 7) | 0b0110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) {
+        CzeroNez
+            .call(inst, hart, bus)
+            .expect("CZERO.NEZ execution unexpectedly trapped");
+    }
+
+    #[test]
+    fn czero_nez_selects() {
+        for (rs1, rs2, expected) in [(5, 0, 5), (5, 7, 0)] {
+            let (mut hart, mut bus) = setup();
+            hart.set_xreg(1, rs1);
+            hart.set_xreg(2, rs2);
+
+            exec(encode_czero_nez(3, 1, 2), &mut hart, &mut bus);
+            assert_eq!(hart.xreg(3), expected);
+        }
+    }
+
+    #[test]
+    fn czero_nez_same_register() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 0x1234);
+
+        exec(encode_czero_nez(1, 1, 1), &mut hart, &mut bus);
+        assert_eq!(hart.xreg(1), 0);
+    }
+
+    #[test]
+    fn czero_nez_illegal_without_zicond() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zicond),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        let inst = encode_czero_nez(3, 1, 2);
+
+        let err = CzeroNez.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+}
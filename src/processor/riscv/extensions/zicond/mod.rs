@@ -0,0 +1,2 @@
+pub mod czero_eqz;
+pub mod czero_nez;
@@ -0,0 +1,105 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Csrrci;
+
+impl InstrExec for Csrrci {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x707f == 0x7073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CSRRCI", skip_all, fields(rd = tracing::field::Empty, uimm = tracing::field::Empty, csr = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let uimm = (inst >> 15) & 0x1f;
+        let csr = (inst >> 20) as u16;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("uimm", uimm);
+            span.record("csr", csr);
+        }
+
+        let old = hart.read_csr(csr, inst)?;
+        if uimm != 0 {
+            hart.write_csr(csr, old & !u64::from(uimm), inst)?;
+        }
+
+        hart.set_xreg(rd, old);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_csrrci(rd: u32, uimm: u32, csr: u16) -> u32 {
+        (u32::from(csr) << 20) | (uimm << 15) | (0b111 << 12) | (rd << 7) | 0b1110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        Csrrci.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn csrrci_clears_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSCRATCH, 0b1111);
+
+        exec(encode_csrrci(2, 0b0101, csr::MSCRATCH), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 0b1111);
+        assert_eq!(hart.csrs().get(csr::MSCRATCH), 0b1010);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn csrrci_clears_mstatus_mie() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut()
+            .set(csr::MSTATUS, csr::MSTATUS_MIE | csr::MSTATUS_MPP);
+
+        exec(encode_csrrci(0, 0b1000, csr::MSTATUS), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MSTATUS), csr::MSTATUS_MPP);
+    }
+
+    #[test]
+    fn csrrci_reads_read_only_without_write() {
+        let (mut hart, mut bus) = setup();
+        exec(encode_csrrci(2, 0, csr::MHARTID), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 0);
+    }
+
+    #[test]
+    fn csrrci_write_to_read_only_is_illegal() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_csrrci(2, 1, csr::MVENDORID);
+        let err = exec(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Csrrs;
+
+impl InstrExec for Csrrs {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x707f == 0x2073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CSRRS", skip_all, fields(rd = tracing::field::Empty, rs1 = tracing::field::Empty, csr = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let rs1 = ((inst >> 15) & 0x1f) as usize;
+        let csr = (inst >> 20) as u16;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("csr", csr);
+        }
+
+        let old = hart.read_csr(csr, inst)?;
+        if rs1 != 0 {
+            hart.write_csr(csr, old | hart.xreg(rs1), inst)?;
+        }
+
+        hart.set_xreg(rd, old);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_csrrs(rd: u32, rs1: u32, csr: u16) -> u32 {
+        (u32::from(csr) << 20) | (rs1 << 15) | (0b010 << 12) | (rd << 7) | 0b1110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        Csrrs.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn csrrs_sets_bits() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSCRATCH, 0b1000_0001);
+        hart.set_xreg(1, 0b0110);
+
+        exec(encode_csrrs(2, 1, csr::MSCRATCH), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 0b1000_0001);
+        assert_eq!(hart.csrs().get(csr::MSCRATCH), 0b1000_0111);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn csrrs_reads_read_only_without_write() {
+        let (mut hart, mut bus) = setup();
+        exec(encode_csrrs(2, 0, csr::MISA), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), hart.csrs().get(csr::MISA));
+    }
+
+    #[test]
+    fn csrrs_reads_counter() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MINSTRET, 99);
+
+        exec(encode_csrrs(2, 0, csr::INSTRET), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 99);
+    }
+
+    #[test]
+    fn csrrs_write_to_read_only_is_illegal() {
+        let (mut hart, mut bus) = setup();
+        hart.set_xreg(1, 1);
+        let inst = encode_csrrs(2, 1, csr::MHARTID);
+        let err = exec(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn csrrs_unimplemented_is_illegal() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_csrrs(2, 0, 0x7c0);
+        let err = exec(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+}
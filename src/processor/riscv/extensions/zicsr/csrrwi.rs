@@ -0,0 +1,111 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+#[derive(Debug)]
+pub struct Csrrwi;
+
+impl InstrExec for Csrrwi {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x707f == 0x5073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "CSRRWI", skip_all, fields(rd = tracing::field::Empty, uimm = tracing::field::Empty, csr = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+        let uimm = (inst >> 15) & 0x1f;
+        let csr = (inst >> 20) as u16;
+
+        #[cfg(feature = "trace")]
+        {
+            let span = tracing::Span::current();
+            span.record("rd", Hart::IABI[rd]);
+            span.record("uimm", uimm);
+            span.record("csr", csr);
+        }
+
+        let src = u64::from(uimm);
+        let old = if rd != 0 {
+            hart.read_csr(csr, inst)?
+        } else {
+            0
+        };
+        hart.write_csr(csr, src, inst)?;
+
+        hart.set_xreg(rd, old);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{csr, exception::Trap, hart::Hart, instruction::InstrExec},
+    };
+
+    fn encode_csrrwi(rd: u32, uimm: u32, csr: u16) -> u32 {
+        (u32::from(csr) << 20) | (uimm << 15) | (0b101 << 12) | (rd << 7) | 0b1110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    fn exec(inst: u32, hart: &mut Hart, bus: &mut Mmap) -> anyhow::Result<()> {
+        Csrrwi.call(inst, hart, bus)
+    }
+
+    #[test]
+    fn csrrwi_swaps() {
+        let (mut hart, mut bus) = setup();
+        hart.csrs_mut().set(csr::MSCRATCH, 0x1234);
+
+        exec(encode_csrrwi(2, 0x15, csr::MSCRATCH), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(2), 0x1234);
+        assert_eq!(hart.csrs().get(csr::MSCRATCH), 0x15);
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn csrrwi_rd_x0_still_writes() {
+        let (mut hart, mut bus) = setup();
+        exec(encode_csrrwi(0, 0x7, csr::MSCRATCH), &mut hart, &mut bus).unwrap();
+
+        assert_eq!(hart.xreg(0), 0);
+        assert_eq!(hart.csrs().get(csr::MSCRATCH), 0x7);
+    }
+
+    #[test]
+    fn csrrwi_read_only_is_illegal() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_csrrwi(2, 1, csr::MHARTID);
+        let err = exec(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+
+    #[test]
+    fn csrrwi_unimplemented_is_illegal() {
+        let (mut hart, mut bus) = setup();
+        let inst = encode_csrrwi(2, 1, 0x7c0);
+        let err = exec(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.pc(), 0);
+    }
+}
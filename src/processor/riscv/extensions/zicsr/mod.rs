@@ -0,0 +1,6 @@
+pub mod csrrc;
+pub mod csrrci;
+pub mod csrrs;
+pub mod csrrsi;
+pub mod csrrw;
+pub mod csrrwi;
@@ -0,0 +1,75 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{hart::Hart, instruction::InstrExec},
+};
+
+/// FENCE.I, which makes earlier stores visible to instruction fetches.
+/// Every fetch reads memory and nothing decoded is kept across steps, so
+/// there is nothing to invalidate; were a decode cache added, it would have
+/// to be dropped here.
+#[derive(Debug)]
+pub struct FenceI;
+
+impl InstrExec for FenceI {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0x707f == 0x100f
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "FENCE.I", skip_all))]
+    fn call(&self, _inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::{Cpu, riscv::instruction::ISA},
+    };
+
+    const FENCE_I: u32 = 0x0000_100f;
+
+    #[test]
+    fn fence_i_advances_pc() {
+        let mut hart = Hart::new(0);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+
+        ISA.dispatch(FENCE_I, &mut hart, &mut bus).unwrap();
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn fence_i_ignores_reserved_fields() {
+        assert!(FenceI.matches(FENCE_I));
+        // fence.i with rd = x1, rs1 = x2 and a non-zero immediate
+        assert!(FenceI.matches(0x0011_108f));
+        // fence
+        assert!(!FenceI.matches(0x0ff0_000f));
+    }
+
+    #[test]
+    fn fence_i_runs_patched_code() {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        // sw x2, 8(x1); fence.i; addi x3, x0, 1
+        bus.write32(0x1000, 0x0020_a423).unwrap();
+        bus.write32(0x1004, FENCE_I).unwrap();
+        bus.write32(0x1008, 0x0010_0193).unwrap();
+        let mut hart = Hart::new(0x1000);
+        hart.set_xreg(1, 0x1000);
+        // addi x3, x0, 42
+        hart.set_xreg(2, 0x02a0_0193);
+
+        hart.step(&mut bus).unwrap();
+        hart.step(&mut bus).unwrap();
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(3), 42);
+        assert_eq!(hart.pc(), 0x100c);
+    }
+}
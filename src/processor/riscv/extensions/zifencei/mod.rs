@@ -0,0 +1 @@
+pub mod fence_i;
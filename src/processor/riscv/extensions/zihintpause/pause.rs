@@ -0,0 +1,74 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+/// The PAUSE hint, a FENCE with only the W predecessor bit set. It must come
+/// ahead of FENCE in the instruction table; without Zihintpause it still
+/// executes, as the FENCE it encodes.
+#[derive(Debug)]
+pub struct Pause;
+
+impl InstrExec for Pause {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst == 0x0100_000f
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "PAUSE", skip_all))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        if hart.check_ext(Extension::Zihintpause, inst).is_ok() {
+            std::hint::spin_loop();
+        }
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            hart::{Extensions, HartConfig},
+            instruction::ISA,
+        },
+    };
+
+    const PAUSE: u32 = 0x0100_000f;
+
+    #[test]
+    fn pause_only_advances_pc() {
+        let mut hart = Hart::new(0);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+
+        Pause.call(PAUSE, &mut hart, &mut bus).unwrap();
+        assert_eq!(hart.pc(), 4);
+    }
+
+    #[test]
+    fn pause_decodes_ahead_of_fence() {
+        assert!(Pause.matches(PAUSE));
+        // fence w, rw
+        assert!(!Pause.matches(0x0130_000f));
+    }
+
+    #[test]
+    fn pause_is_a_fence_without_zihintpause() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zihintpause),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+
+        ISA.dispatch(PAUSE, &mut hart, &mut bus).unwrap();
+        assert_eq!(hart.pc(), 4);
+    }
+}
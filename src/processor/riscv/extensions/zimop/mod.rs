@@ -0,0 +1,2 @@
+pub mod mop_r;
+pub mod mop_rr;
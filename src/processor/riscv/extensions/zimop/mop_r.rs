@@ -0,0 +1,109 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct MopR;
+
+impl InstrExec for MopR {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xb3c0_707f == 0x81c0_4073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MOP.R", skip_all, fields(n = tracing::field::Empty, rd = tracing::field::Empty, rs1 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let n = ((inst >> 26) & 0b10000) | ((inst >> 24) & 0b1100) | ((inst >> 20) & 0b11);
+            let rs1 = ((inst >> 15) & 0x1f) as usize;
+            let span = tracing::Span::current();
+            span.record("n", n);
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+        }
+
+        // C.MOP.n expands to MOP.R.n with rd = x0, so a compressed encoding
+        // is gated by Zcmop instead.
+        let ext = if hart.ilen() == 2 {
+            Extension::Zcmop
+        } else {
+            Extension::Zimop
+        };
+        hart.check_ext(ext, inst)?;
+        hart.set_xreg(rd, 0);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_mop_r(n: u32, rd: u32, rs1: u32) -> u32 {
+        (1 << 31)
+            | ((n & 0b10000) << 26)
+            | ((n & 0b1100) << 24)
+            | (0b0111 << 22)
+            | ((n & 0b11) << 20)
+            | (rs1 << 15)
+            | (0b100 << 12)
+            | (rd << 7)
+            | 0b1110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    #[test]
+    fn mop_r_writes_zero() {
+        for n in [0, 7, 28, 31] {
+            let (mut hart, mut bus) = setup();
+            hart.set_xreg(1, 0x1234);
+            hart.set_xreg(2, 0x5678);
+            let inst = encode_mop_r(n, 2, 1);
+
+            assert!(MopR.matches(inst));
+            MopR.call(inst, &mut hart, &mut bus).unwrap();
+            assert_eq!(hart.xreg(2), 0);
+            assert_eq!(hart.pc(), 4);
+        }
+    }
+
+    #[test]
+    fn mop_r_illegal_without_zimop() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zimop),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        hart.set_xreg(2, 0x5678);
+        let inst = encode_mop_r(3, 2, 1);
+
+        let err = MopR.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+        assert_eq!(hart.xreg(2), 0x5678);
+    }
+}
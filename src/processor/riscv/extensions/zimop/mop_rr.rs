@@ -0,0 +1,100 @@
+use crate::{
+    memory::Bus,
+    processor::riscv::{
+        hart::{Extension, Hart},
+        instruction::InstrExec,
+    },
+};
+
+#[derive(Debug)]
+pub struct MopRr;
+
+impl InstrExec for MopRr {
+    #[inline(always)]
+    fn matches(&self, inst: u32) -> bool {
+        inst & 0xb200_707f == 0x8200_4073
+    }
+
+    #[inline(always)]
+    #[cfg_attr(feature = "trace", tracing::instrument(name = "MOP.RR", skip_all, fields(n = tracing::field::Empty, rd = tracing::field::Empty, rs1 = tracing::field::Empty, rs2 = tracing::field::Empty)))]
+    fn call(&self, inst: u32, hart: &mut Hart, _bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let rd = ((inst >> 7) & 0x1f) as usize;
+
+        #[cfg(feature = "trace")]
+        {
+            let n = ((inst >> 28) & 0b100) | ((inst >> 26) & 0b11);
+            let rs1 = ((inst >> 15) & 0x1f) as usize;
+            let rs2 = ((inst >> 20) & 0x1f) as usize;
+            let span = tracing::Span::current();
+            span.record("n", n);
+            span.record("rd", Hart::IABI[rd]);
+            span.record("rs1", Hart::IABI[rs1]);
+            span.record("rs2", Hart::IABI[rs2]);
+        }
+
+        hart.check_ext(Extension::Zimop, inst)?;
+        hart.set_xreg(rd, 0);
+        hart.next_pc();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::{
+            exception::Trap,
+            hart::{Extensions, HartConfig},
+        },
+    };
+
+    fn encode_mop_rr(n: u32, rd: u32, rs1: u32, rs2: u32) -> u32 {
+        (1 << 31)
+            | ((n & 0b100) << 28)
+            | ((n & 0b11) << 26)
+            | (1 << 25)
+            | (rs2 << 20)
+            | (rs1 << 15)
+            | (0b100 << 12)
+            | (rd << 7)
+            | 0b1110011
+    }
+
+    fn setup() -> (Hart, Mmap) {
+        (Hart::new(0), Mmap::new(0x0, 0x10_0000))
+    }
+
+    #[test]
+    fn mop_rr_writes_zero() {
+        for n in 0..8 {
+            let (mut hart, mut bus) = setup();
+            hart.set_xreg(3, 0x5678);
+            let inst = encode_mop_rr(n, 3, 1, 2);
+
+            assert!(MopRr.matches(inst));
+            MopRr.call(inst, &mut hart, &mut bus).unwrap();
+            assert_eq!(hart.xreg(3), 0);
+        }
+    }
+
+    #[test]
+    fn mop_rr_illegal_without_zimop() {
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zimop),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        let inst = encode_mop_rr(5, 3, 1, 2);
+
+        let err = MopRr.call(inst, &mut hart, &mut bus).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::IllegalInstruction { inst }
+        );
+    }
+}
@@ -1,26 +1,353 @@
+use std::str::FromStr;
+
 use crate::{
-    memory::Bus,
-    processor::{Cpu, riscv::instruction::ISA},
+    memory::{Bus, exception::Trap as MemoryTrap},
+    processor::{
+        Cpu,
+        riscv::{
+            csr::{self, CsrFile},
+            exception::Trap,
+            instruction::{ExpandTable, ISA, ISA32, InstrTable, RVC, RVC32},
+            mmu::{self, Access, Context, Mmu},
+            pmp::Pmp,
+            softfloat::{F64, Format, RoundingMode},
+            vector::VectorRegs,
+        },
+    },
 };
 
-#[derive(Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Privilege {
+    User = 0,
+    Supervisor = 1,
+    #[default]
+    Machine = 3,
+}
+
+impl Privilege {
+    /// Decodes a privilege field such as `mstatus.MPP`. The reserved encoding
+    /// never reaches here because the CSR file legalizes it away.
+    pub const fn from_bits(bits: u64) -> Self {
+        match bits & 0b11 {
+            0 => Self::User,
+            1 => Self::Supervisor,
+            _ => Self::Machine,
+        }
+    }
+}
+
+/// Standard extensions that have no `misa` bit of their own and are
+/// selected individually instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extension {
+    Zba,
+    Zbb,
+    Zbc,
+    Zbs,
+    Zbkb,
+    Zbkc,
+    Zbkx,
+    Zknd,
+    Zkne,
+    Zknh,
+    Zksed,
+    Zksh,
+    Zicond,
+    Zihintpause,
+    Zawrs,
+    Zicbom,
+    Zicboz,
+    Zicbop,
+    Zimop,
+    Zcmop,
+    Zfh,
+    /// The half-precision loads, stores, moves and conversions of Zfh.
+    Zfhmin,
+}
+
+impl Extension {
+    pub const ALL: [Self; 22] = [
+        Self::Zba,
+        Self::Zbb,
+        Self::Zbc,
+        Self::Zbs,
+        Self::Zbkb,
+        Self::Zbkc,
+        Self::Zbkx,
+        Self::Zknd,
+        Self::Zkne,
+        Self::Zknh,
+        Self::Zksed,
+        Self::Zksh,
+        Self::Zicond,
+        Self::Zihintpause,
+        Self::Zawrs,
+        Self::Zicbom,
+        Self::Zicboz,
+        Self::Zicbop,
+        Self::Zimop,
+        Self::Zcmop,
+        Self::Zfh,
+        Self::Zfhmin,
+    ];
+
+    /// Name as it appears in ISA strings.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Zba => "zba",
+            Self::Zbb => "zbb",
+            Self::Zbc => "zbc",
+            Self::Zbs => "zbs",
+            Self::Zbkb => "zbkb",
+            Self::Zbkc => "zbkc",
+            Self::Zbkx => "zbkx",
+            Self::Zknd => "zknd",
+            Self::Zkne => "zkne",
+            Self::Zknh => "zknh",
+            Self::Zksed => "zksed",
+            Self::Zksh => "zksh",
+            Self::Zicond => "zicond",
+            Self::Zihintpause => "zihintpause",
+            Self::Zawrs => "zawrs",
+            Self::Zicbom => "zicbom",
+            Self::Zicboz => "zicboz",
+            Self::Zicbop => "zicbop",
+            Self::Zimop => "zimop",
+            Self::Zcmop => "zcmop",
+            Self::Zfh => "zfh",
+            Self::Zfhmin => "zfhmin",
+        }
+    }
+}
+
+impl FromStr for Extension {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|ext| ext.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| anyhow::anyhow!("unknown extension '{s}'"))
+    }
+}
+
+/// A set of [`Extension`]s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Extensions(u64);
+
+impl Extensions {
+    pub const fn all() -> Self {
+        let mut set = Self(0);
+        let mut i = 0;
+        while i < Extension::ALL.len() {
+            set = set.with(Extension::ALL[i]);
+            i += 1;
+        }
+        set
+    }
+
+    pub const fn with(self, ext: Extension) -> Self {
+        Self(self.0 | (1 << ext as u32))
+    }
+
+    pub const fn without(self, ext: Extension) -> Self {
+        Self(self.0 & !(1 << ext as u32))
+    }
+
+    pub const fn contains(self, ext: Extension) -> bool {
+        self.0 & (1 << ext as u32) != 0
+    }
+}
+
+/// What a hart does with loads, stores and AMOs that are not naturally
+/// aligned. LR and SC always trap, since a reservation cannot span granules.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Misaligned {
+    /// Raise an address-misaligned exception and leave the access to the
+    /// trap handler, as firmware does when emulating it.
+    #[default]
+    Trap,
+    /// Perform the access, splitting it where it crosses a page.
+    Transparent,
+    /// Perform accesses that stay within a page, and trap those that cross
+    /// one.
+    WithinPage,
+}
+
+impl Misaligned {
+    pub const ALL: [Self; 3] = [Self::Trap, Self::Transparent, Self::WithinPage];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Trap => "trap",
+            Self::Transparent => "transparent",
+            Self::WithinPage => "page",
+        }
+    }
+}
+
+impl FromStr for Misaligned {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| anyhow::anyhow!("unknown misaligned access policy '{s}'"))
+    }
+}
+
+/// Implementation choices that vary between harts.
+#[derive(Clone, Copy, Debug)]
+pub struct HartConfig {
+    /// Register width in bits, 32 or 64. RV32 harts leave out V, H and the
+    /// scalar crypto extensions, which are only implemented for RV64.
+    pub xlen: u32,
+    /// Number of implemented PMP entries, at most [`Pmp::MAX_ENTRIES`].
+    pub pmp_entries: usize,
+    /// Whether the C extension is implemented, relaxing instruction
+    /// alignment to two bytes.
+    pub compressed: bool,
+    /// Implemented extensions beyond those in `misa`.
+    pub extensions: Extensions,
+    /// Whether the V extension is implemented.
+    pub vector: bool,
+    /// Whether the H extension is implemented.
+    pub hypervisor: bool,
+    /// Width in bits of a vector register, a power of two between ELEN and
+    /// [`VectorRegs::MAX_VLEN`].
+    pub vlen: u32,
+    /// Widest supported vector element in bits, 32 or 64.
+    pub elen: u32,
+    /// How loads, stores and AMOs that are not naturally aligned are handled.
+    pub misaligned: Misaligned,
+}
+
+impl Default for HartConfig {
+    fn default() -> Self {
+        Self {
+            xlen: 64,
+            pmp_entries: Pmp::DEFAULT_ENTRIES,
+            compressed: true,
+            extensions: Extensions::all(),
+            vector: true,
+            hypervisor: true,
+            vlen: VectorRegs::DEFAULT_VLEN,
+            elen: VectorRegs::DEFAULT_ELEN,
+            misaligned: Misaligned::Trap,
+        }
+    }
+}
+
+#[derive(Debug)]
 pub struct Hart {
     pc: u64,
+    xlen: u32,
+    /// Length in bytes of the instruction being executed.
+    ilen: u64,
     xregs: [u64; 32],
+    fregs: [u64; 32],
+    vregs: VectorRegs,
+    reservation: Option<u64>,
+    privilege: Privilege,
+    /// Whether the hart runs a guest, in VS- or VU-mode.
+    virt: bool,
+    /// Set when an access made as the guest faults, so that the trap reports
+    /// its `tval` as a guest virtual address.
+    guest_fault: bool,
+    /// Set by WFI until an enabled interrupt becomes pending.
+    wfi: bool,
+    csrs: CsrFile,
+    mmu: Mmu,
+    pmp: Pmp,
+    extensions: Extensions,
+    misaligned: Misaligned,
 }
 
 impl Hart {
-    pub const ILEN: u64 = 4;
+    pub const RESERVATION_GRANULE: u64 = 8;
+    /// Interrupt causes in decreasing priority, among those bound for the
+    /// same privilege mode.
+    pub const INTERRUPT_PRIORITY: [u64; 10] = [11, 3, 7, 9, 1, 5, 12, 10, 2, 6];
     pub const IABI: [&str; 32] = [
         "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
         "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
         "t5", "t6",
     ];
+    pub const FABI: [&str; 32] = [
+        "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2",
+        "fa3", "fa4", "fa5", "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9",
+        "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+    ];
+    /// Multi-letter extensions that every hart implements.
+    pub const BASE_EXTENSIONS: [&str; 4] = ["zicsr", "zicntr", "zifencei", "zihpm"];
+    /// Extensions only implemented for RV64.
+    pub const RV64_EXTENSIONS: [Extension; 8] = [
+        Extension::Zbkb,
+        Extension::Zbkc,
+        Extension::Zbkx,
+        Extension::Zknd,
+        Extension::Zkne,
+        Extension::Zknh,
+        Extension::Zksed,
+        Extension::Zksh,
+    ];
 
     pub fn new(entry: u64) -> Self {
+        Self::with_config(entry, HartConfig::default())
+    }
+
+    pub fn with_config(entry: u64, config: HartConfig) -> Self {
+        let config = if config.xlen == 32 {
+            HartConfig {
+                vector: false,
+                hypervisor: false,
+                extensions: Self::RV64_EXTENSIONS
+                    .iter()
+                    .fold(config.extensions, |set, &ext| set.without(ext)),
+                ..config
+            }
+        } else {
+            config
+        };
+
+        let mut csrs = CsrFile::with_pmp_entries(0, config.pmp_entries);
+        if !config.compressed {
+            csrs.set(csr::MISA, csrs.get(csr::MISA) & !csr::misa_ext(b'C'));
+        }
+        if config.vector {
+            csrs.enable_vector(config.vlen);
+        }
+        if config.hypervisor {
+            csrs.enable_hypervisor();
+        }
+        if config.extensions.contains(Extension::Zicbom) {
+            csrs.enable_envcfg(csr::ENVCFG_CBIE | csr::ENVCFG_CBCFE);
+        }
+        if config.extensions.contains(Extension::Zicboz) {
+            csrs.enable_envcfg(csr::ENVCFG_CBZE);
+        }
+        if config.xlen == 32 {
+            csrs.enable_rv32();
+        }
+
         Self {
             pc: entry,
+            xlen: config.xlen,
+            ilen: 4,
             xregs: [0u64; 32],
+            fregs: [0u64; 32],
+            vregs: VectorRegs::new(config.vlen, config.elen),
+            reservation: None,
+            privilege: Privilege::Machine,
+            virt: false,
+            guest_fault: false,
+            wfi: false,
+            csrs,
+            mmu: Mmu::default(),
+            pmp: Pmp::new(config.pmp_entries),
+            extensions: config.extensions,
+            misaligned: config.misaligned,
         }
     }
 
@@ -31,12 +358,42 @@ impl Hart {
 
     #[inline(always)]
     pub fn set_pc(&mut self, paddr: u64) {
-        self.pc = paddr;
+        self.pc = paddr & self.xmask();
     }
 
     #[inline(always)]
     pub fn next_pc(&mut self) {
-        self.pc = self.pc.wrapping_add(Self::ILEN);
+        self.pc = self.pc.wrapping_add(self.ilen) & self.xmask();
+    }
+
+    /// Register width in bits, 32 or 64.
+    #[inline(always)]
+    pub fn xlen(&self) -> u32 {
+        self.xlen
+    }
+
+    /// Mask of the low XLEN bits, which is all an address or `pc` has.
+    #[inline(always)]
+    fn xmask(&self) -> u64 {
+        u64::MAX >> (64 - self.xlen)
+    }
+
+    /// Length in bytes of the instruction being executed, which is what
+    /// `pc` advances by and what jumps link past.
+    #[inline(always)]
+    pub fn ilen(&self) -> u64 {
+        self.ilen
+    }
+
+    /// Required alignment of instruction addresses: two bytes with the C
+    /// extension, four without.
+    #[inline(always)]
+    pub fn ialign(&self) -> u64 {
+        if self.csrs.get(csr::MISA) & csr::misa_ext(b'C') != 0 {
+            2
+        } else {
+            4
+        }
     }
 
     #[inline(always)]
@@ -44,49 +401,2089 @@ impl Hart {
         unsafe { *self.xregs.get_unchecked(i) }
     }
 
+    /// Reads `x[i]` zero-extended from XLEN bits, for operations that take
+    /// it as unsigned.
+    #[inline(always)]
+    pub fn uxreg(&self, i: usize) -> u64 {
+        self.xreg(i) & self.xmask()
+    }
+
+    /// Writes `x[i]`. On RV32 only the low 32 bits are kept, held
+    /// sign-extended so that signed and unsigned comparisons work unchanged.
     #[inline(always)]
     pub fn set_xreg(&mut self, i: usize, val: u64) {
+        let val = if self.xlen == 32 {
+            val as i32 as i64 as u64
+        } else {
+            val
+        };
         if i != 0 {
             unsafe {
                 *self.xregs.get_unchecked_mut(i) = val;
             }
         }
     }
-}
 
-impl std::fmt::Display for Hart {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "pc 0x{:018x}", self.pc)?;
-        for row in 0..8 {
-            let i = row * 4;
-            writeln!(
-                f,
-                "x{:<2} [{:<4}] 0x{:018x}    x{:<2} [{:<4}] 0x{:018x}    x{:<2} [{:<4}] 0x{:018x}    x{:<2} [{:<4}] 0x{:018x}",
-                i,
-                Self::IABI[i],
-                self.xregs[i],
-                i + 1,
-                Self::IABI[i + 1],
-                self.xregs[i + 1],
-                i + 2,
-                Self::IABI[i + 2],
-                self.xregs[i + 2],
-                i + 3,
-                Self::IABI[i + 3],
-                self.xregs[i + 3],
-            )?;
+    /// Shift amount of a register operand, its low log2(XLEN) bits.
+    #[inline(always)]
+    pub fn shamt(&self, val: u64) -> u32 {
+        (val & u64::from(self.xlen - 1)) as u32
+    }
+
+    /// Fails with an illegal-instruction exception when an immediate shift
+    /// amount does not fit XLEN, which RV32 reserves.
+    #[inline(always)]
+    pub fn check_shamt(&self, inst: u32, shamt: u32) -> Result<(), Trap> {
+        if shamt >= self.xlen {
+            return Err(Trap::IllegalInstruction { inst });
         }
         Ok(())
     }
-}
 
-impl Cpu for Hart {
     #[inline(always)]
-    fn step<B>(&mut self, bus: &mut B) -> anyhow::Result<()>
-    where
-        B: Bus,
-    {
-        let inst = bus.fetch(self.pc);
-        ISA.dispatch(inst, self, bus)
+    pub fn freg(&self, i: usize) -> u64 {
+        unsafe { *self.fregs.get_unchecked(i) }
+    }
+
+    #[inline(always)]
+    pub fn set_freg(&mut self, i: usize, val: u64) {
+        unsafe {
+            *self.fregs.get_unchecked_mut(i) = val;
+        }
+        self.dirty_fp();
+    }
+
+    /// Reads an f-register as a value of `fmt`. Narrower values must be
+    /// NaN-boxed, anything else reads as the canonical NaN.
+    #[inline(always)]
+    pub fn read_freg(&self, fmt: Format, i: usize) -> u64 {
+        let val = self.freg(i);
+        if fmt == F64 {
+            val
+        } else if val | fmt.mask() == u64::MAX {
+            val & fmt.mask()
+        } else {
+            fmt.canonical_nan()
+        }
+    }
+
+    /// Writes a value of `fmt` to an f-register, NaN-boxing narrower ones.
+    #[inline(always)]
+    pub fn write_freg(&mut self, fmt: Format, i: usize, val: u64) {
+        self.set_freg(i, (val & fmt.mask()) | !fmt.mask());
+    }
+
+    /// Fails with an illegal-instruction exception while `mstatus.FS`, or
+    /// `vsstatus.FS` in a guest, is Off.
+    #[inline(always)]
+    pub fn check_fp(&self, inst: u32) -> Result<(), Trap> {
+        if self.status_off(csr::MSTATUS_FS) {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        Ok(())
+    }
+
+    /// Whether the `field` of `mstatus`, or of `vsstatus` in a guest, is Off.
+    #[inline(always)]
+    fn status_off(&self, field: u64) -> bool {
+        self.csrs.get(csr::MSTATUS) & field == 0
+            || (self.virt && self.csrs.get(csr::VSSTATUS) & field == 0)
+    }
+
+    /// Sets the `field` of `mstatus`, and of `vsstatus` in a guest, to Dirty.
+    #[inline(always)]
+    fn dirty_status(&mut self, field: u64) {
+        self.csrs
+            .set(csr::MSTATUS, self.csrs.get(csr::MSTATUS) | field);
+        if self.virt {
+            self.csrs
+                .set(csr::VSSTATUS, self.csrs.get(csr::VSSTATUS) | field);
+        }
+    }
+
+    #[inline(always)]
+    pub fn vregs(&self) -> &VectorRegs {
+        &self.vregs
+    }
+
+    #[inline(always)]
+    pub fn vregs_mut(&mut self) -> &mut VectorRegs {
+        &mut self.vregs
+    }
+
+    /// Fails with an illegal-instruction exception unless V is implemented
+    /// and `mstatus.VS`, and `vsstatus.VS` in a guest, is not Off.
+    #[inline(always)]
+    pub fn check_vector(&self, inst: u32) -> Result<(), Trap> {
+        if self.csrs.get(csr::MISA) & csr::misa_ext(b'V') == 0 || self.status_off(csr::MSTATUS_VS) {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        Ok(())
+    }
+
+    /// Marks the vector state dirty in `mstatus.VS`, and in `vsstatus.VS` in
+    /// a guest.
+    #[inline(always)]
+    pub fn dirty_vector(&mut self) {
+        self.dirty_status(csr::MSTATUS_VS_DIRTY);
+    }
+
+    /// Fails unless the H extension is implemented and hypervisor
+    /// instructions may run: always in M- and HS-mode, in U-mode only when
+    /// `user` allows it and `hstatus.HU` is set, and never in a guest, where
+    /// they raise a virtual-instruction exception.
+    #[inline(always)]
+    pub fn check_hypervisor(&self, inst: u32, user: bool) -> Result<(), Trap> {
+        if self.csrs.get(csr::MISA) & csr::misa_ext(b'H') == 0 {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        if self.virt {
+            return Err(Trap::VirtualInstruction { inst });
+        }
+        if self.privilege == Privilege::User
+            && !(user && self.csrs.get(csr::HSTATUS) & csr::HSTATUS_HU != 0)
+        {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        Ok(())
+    }
+
+    /// Fails with an illegal-instruction exception unless `ext` is
+    /// implemented.
+    #[inline(always)]
+    pub fn check_ext(&self, ext: Extension, inst: u32) -> Result<(), Trap> {
+        if !self.extensions.contains(ext) {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        Ok(())
+    }
+
+    /// Like [`Hart::check_ext`], for instructions that several extensions
+    /// share.
+    #[inline(always)]
+    pub fn check_any_ext(&self, exts: &[Extension], inst: u32) -> Result<(), Trap> {
+        if !exts.iter().any(|&ext| self.extensions.contains(ext)) {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        Ok(())
+    }
+
+    /// Names what this hart implements, e.g. `rv64imafdc_zicsr_zicntr`.
+    /// Single-letter extensions come from `misa`, multi-letter ones are
+    /// ordered by the category their second letter names, then by name.
+    pub fn isa_string(&self) -> String {
+        const ORDER: &[u8] = b"imafdqlcbkjtpvh";
+
+        let misa = self.csrs.get(csr::MISA);
+        let mut isa = format!("rv{}", self.xlen);
+        isa.extend(
+            ORDER
+                .iter()
+                .filter(|&&letter| misa & csr::misa_ext(letter.to_ascii_uppercase()) != 0)
+                .map(|&letter| char::from(letter)),
+        );
+
+        let mut names: Vec<&str> = Self::BASE_EXTENSIONS.to_vec();
+        names.extend(
+            Extension::ALL
+                .into_iter()
+                .filter(|&ext| self.extensions.contains(ext))
+                .map(Extension::name),
+        );
+        names.sort_by_key(|name| {
+            let category = name.as_bytes()[1];
+            let rank = ORDER.iter().position(|&c| c == category);
+            (rank.unwrap_or(ORDER.len()), *name)
+        });
+        for name in names {
+            isa.push('_');
+            isa.push_str(name);
+        }
+        isa
+    }
+
+    /// Decodes the `rm` field of `inst`, taking the dynamic mode from `frm`.
+    /// Reserved rounding modes are illegal.
+    #[inline(always)]
+    pub fn rounding_mode(&self, inst: u32) -> Result<RoundingMode, Trap> {
+        let rm = match (inst >> 12) & 0b111 {
+            0b111 => (self.csrs.get(csr::FCSR) >> 5) & 0b111,
+            rm => u64::from(rm),
+        };
+        RoundingMode::from_bits(rm).ok_or(Trap::IllegalInstruction { inst })
+    }
+
+    /// Accumulates floating-point exception flags into `fflags`.
+    #[inline(always)]
+    pub fn raise_fflags(&mut self, flags: u8) {
+        if flags != 0 {
+            self.csrs
+                .set(csr::FCSR, self.csrs.get(csr::FCSR) | u64::from(flags));
+            self.dirty_fp();
+        }
+    }
+
+    #[inline(always)]
+    fn dirty_fp(&mut self) {
+        self.dirty_status(csr::MSTATUS_FS_DIRTY);
+    }
+
+    #[inline(always)]
+    pub fn privilege(&self) -> Privilege {
+        self.privilege
+    }
+
+    #[inline(always)]
+    pub fn set_privilege(&mut self, privilege: Privilege) {
+        self.privilege = privilege;
+    }
+
+    /// Whether the hart runs a guest: VS-mode in S-mode, VU-mode in U-mode.
+    #[inline(always)]
+    pub fn virt(&self) -> bool {
+        self.virt
+    }
+
+    #[inline(always)]
+    pub fn set_virt(&mut self, virt: bool) {
+        self.virt = virt;
+    }
+
+    #[inline(always)]
+    pub fn csrs(&self) -> &CsrFile {
+        &self.csrs
+    }
+
+    #[inline(always)]
+    pub fn csrs_mut(&mut self) -> &mut CsrFile {
+        &mut self.csrs
+    }
+
+    /// Stalls the hart, as WFI does, until an interrupt that `mie` enables
+    /// becomes pending.
+    #[inline(always)]
+    pub fn wait_for_interrupt(&mut self) {
+        self.wfi = true;
+    }
+
+    #[inline(always)]
+    pub fn mmu(&self) -> &Mmu {
+        &self.mmu
+    }
+
+    /// Flushes the translations of the current address space: the host's,
+    /// or in a guest those of its VMID.
+    #[inline(always)]
+    pub fn sfence_vma(&mut self, vaddr: Option<u64>, asid: Option<u16>) {
+        if self.virt {
+            self.hfence_vvma(vaddr, asid);
+        } else {
+            self.mmu.flush(vaddr, asid);
+        }
+    }
+
+    /// Flushes guest translations of the VMID in `hgatp`.
+    #[inline(always)]
+    pub fn hfence_vvma(&mut self, vaddr: Option<u64>, asid: Option<u16>) {
+        let vmid = (self.csrs.get(csr::HGATP) & csr::HGATP_VMID) >> 44;
+        self.mmu.flush_guest(Some(vmid as u16), vaddr, asid);
+    }
+
+    /// Flushes the guest translations of `vmid`, or of every guest. Cached
+    /// translations combine both stages, so a single guest physical address
+    /// cannot be singled out and the whole VMID goes.
+    #[inline(always)]
+    pub fn hfence_gvma(&mut self, vmid: Option<u16>) {
+        self.mmu.flush_guest(vmid, None, None);
+    }
+
+    /// Reads a CSR as a CSR instruction would.
+    #[inline(always)]
+    pub fn read_csr(&self, addr: u16, inst: u32) -> Result<u64, Trap> {
+        let addr = self.csr_target(addr, inst)?;
+        let val = self
+            .csrs
+            .read(addr, self.privilege)
+            .ok_or(Trap::IllegalInstruction { inst })?;
+        // A guest sees `time` offset by `htimedelta`.
+        if self.virt && addr == csr::TIME {
+            return Ok(val.wrapping_add(self.csrs.get(csr::HTIMEDELTA)));
+        }
+        Ok(val)
+    }
+
+    /// Writes a CSR as a CSR instruction would.
+    #[inline(always)]
+    pub fn write_csr(&mut self, addr: u16, val: u64, inst: u32) -> Result<(), Trap> {
+        let addr = self.csr_target(addr, inst)?;
+        self.csrs
+            .write(addr, val, self.privilege)
+            .ok_or(Trap::IllegalInstruction { inst })?;
+        if self.virt && addr <= csr::FCSR {
+            self.dirty_status(csr::MSTATUS_FS_DIRTY);
+        }
+        if self.virt && matches!(addr, csr::VSTART..=csr::VCSR) {
+            self.dirty_status(csr::MSTATUS_VS_DIRTY);
+        }
+        Ok(())
+    }
+
+    /// Resolves the CSR that an access to `addr` reaches. In a guest,
+    /// supervisor CSRs stand for their VS counterparts, and accesses that
+    /// HS-mode could make but the guest may not raise a virtual-instruction
+    /// exception rather than an illegal-instruction one.
+    fn csr_target(&self, addr: u16, inst: u32) -> Result<u16, Trap> {
+        if !self.virt {
+            return Ok(addr);
+        }
+
+        let virtual_instruction = Err(Trap::VirtualInstruction { inst });
+        let level = (addr >> 8) & 0b11;
+        if level == Privilege::Machine as u16 {
+            return Ok(addr);
+        }
+        if (addr <= csr::FCSR && self.csrs.get(csr::VSSTATUS) & csr::MSTATUS_FS == 0)
+            || ((csr::VSTART..=csr::VCSR).contains(&addr) || (csr::VL..=csr::VLENB).contains(&addr))
+                && self.csrs.get(csr::VSSTATUS) & csr::MSTATUS_VS == 0
+        {
+            return Err(Trap::IllegalInstruction { inst });
+        }
+        if (csr::CYCLE..=csr::HPMCOUNTER31).contains(&addr) {
+            let bit = 1 << (addr - csr::CYCLE);
+            if self.csrs.get(csr::MCOUNTEREN) & bit != 0
+                && (self.csrs.get(csr::HCOUNTEREN) & bit == 0
+                    || (self.privilege == Privilege::User
+                        && self.csrs.get(csr::SCOUNTEREN) & bit == 0))
+            {
+                return virtual_instruction;
+            }
+            return Ok(addr);
+        }
+        if level == 0 {
+            return Ok(addr);
+        }
+        if self.privilege == Privilege::User || level == 0b10 {
+            return if self.csrs.read(addr, Privilege::Supervisor).is_some() {
+                virtual_instruction
+            } else {
+                Err(Trap::IllegalInstruction { inst })
+            };
+        }
+
+        let vtvm = self.csrs.get(csr::HSTATUS) & csr::HSTATUS_VTVM != 0;
+        match addr {
+            csr::SATP if vtvm => virtual_instruction,
+            csr::SSTATUS
+            | csr::SIE
+            | csr::STVEC
+            | csr::SSCRATCH
+            | csr::SEPC
+            | csr::SCAUSE
+            | csr::STVAL
+            | csr::SIP
+            | csr::SATP => Ok(addr + 0x100),
+            _ => Ok(addr),
+        }
+    }
+
+    /// Translates `vaddr` for an access of `size` bytes of the given kind,
+    /// using the effective privilege of the access (`mstatus.MPRV` and
+    /// `mstatus.MPV` apply to loads and stores), and checks the result against
+    /// PMP.
+    pub fn translate(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        access: Access,
+    ) -> anyhow::Result<u64> {
+        let vaddr = vaddr & self.xmask();
+        let mstatus = self.csrs.get(csr::MSTATUS);
+        let (privilege, virt) = if access != Access::Fetch
+            && self.privilege == Privilege::Machine
+            && mstatus & csr::MSTATUS_MPRV != 0
+        {
+            let mpp = Privilege::from_bits((mstatus & csr::MSTATUS_MPP) >> 11);
+            (
+                mpp,
+                mpp != Privilege::Machine && mstatus & csr::MSTATUS_MPV != 0,
+            )
+        } else {
+            (self.privilege, self.virt)
+        };
+        self.translate_as(bus, vaddr, size, access, privilege, virt, false)
+    }
+
+    /// Translates an access made in `privilege`, as a guest when `virt` is
+    /// set. `hlvx` asks for execute permission on loads.
+    #[allow(clippy::too_many_arguments)]
+    fn translate_as(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        access: Access,
+        privilege: Privilege,
+        virt: bool,
+        hlvx: bool,
+    ) -> anyhow::Result<u64> {
+        let mstatus = self.csrs.get(csr::MSTATUS);
+        let ctx = if virt {
+            let vsstatus = self.csrs.get(csr::VSSTATUS);
+            Context {
+                satp: self.csrs.get(csr::VSATP),
+                privilege,
+                sum: vsstatus & csr::MSTATUS_SUM != 0,
+                mxr: mstatus & csr::MSTATUS_MXR != 0,
+                vmxr: vsstatus & csr::MSTATUS_MXR != 0,
+                hgatp: Some(self.csrs.get(csr::HGATP)),
+                hlvx,
+            }
+        } else {
+            Context {
+                satp: self.satp(),
+                privilege,
+                sum: mstatus & csr::MSTATUS_SUM != 0,
+                mxr: mstatus & csr::MSTATUS_MXR != 0,
+                vmxr: false,
+                hgatp: None,
+                hlvx,
+            }
+        };
+        let (csrs, pmp) = (&self.csrs, &self.pmp);
+        // Page-table accesses are implicit supervisor-level accesses.
+        let walk = |paddr, access| pmp.check(csrs, paddr, 8, access, Privilege::Supervisor);
+        let result = self
+            .mmu
+            .translate(bus, vaddr, access, ctx, walk)
+            .and_then(|paddr| {
+                if pmp.check(csrs, paddr, size, access, privilege) {
+                    Ok(paddr)
+                } else {
+                    Err(access.access_fault(vaddr).into())
+                }
+            });
+        if virt && result.is_err() {
+            self.guest_fault = true;
+        }
+        result
+    }
+
+    /// Loads `size` bytes for HLV and HLVX, which access memory as a guest
+    /// would in the mode `hstatus.SPVP` names. HLVX needs execute rather
+    /// than read permission.
+    pub fn read_guest(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        hlvx: bool,
+    ) -> anyhow::Result<u64> {
+        let privilege = self.guest_privilege();
+        let translate = |hart: &mut Self, bus: &mut dyn Bus, vaddr, size| {
+            hart.translate_as(bus, vaddr, size, Access::Load, privilege, true, hlvx)
+        };
+        let result = self.load(bus, vaddr, size, translate);
+        // The access fault the bus may raise reports a guest address too.
+        self.guest_fault |= result.is_err();
+        result
+    }
+
+    /// Stores the low `size` bytes of `val` for HSV, which accesses memory as
+    /// a guest would in the mode `hstatus.SPVP` names.
+    pub fn write_guest(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        val: u64,
+    ) -> anyhow::Result<()> {
+        let privilege = self.guest_privilege();
+        let translate = |hart: &mut Self, bus: &mut dyn Bus, vaddr, size| {
+            hart.translate_as(bus, vaddr, size, Access::Store, privilege, true, false)
+        };
+        let result = self.store(bus, vaddr, size, val, translate);
+        self.guest_fault |= result.is_err();
+        result
+    }
+
+    /// `satp`, with the RV32 layout widened to the RV64 one the MMU decodes.
+    fn satp(&self) -> u64 {
+        let satp = self.csrs.get(csr::SATP);
+        if self.xlen == 64 {
+            return satp;
+        }
+        let mode = if satp >> 31 != 0 {
+            mmu::SATP_MODE_SV32
+        } else {
+            mmu::SATP_MODE_BARE
+        };
+        (mode << 60) | (((satp >> 22) & 0x1ff) << 44) | (satp & 0x3f_ffff)
+    }
+
+    fn guest_privilege(&self) -> Privilege {
+        if self.csrs.get(csr::HSTATUS) & csr::HSTATUS_SPVP != 0 {
+            Privilege::Supervisor
+        } else {
+            Privilege::User
+        }
+    }
+
+    /// Fetches the instruction at `pc` and records its length. Compressed
+    /// instructions are returned as is, in the low half. Each 16-bit parcel
+    /// is translated on its own since a 32-bit instruction may straddle a
+    /// page or PMP boundary.
+    #[inline(always)]
+    pub fn fetch(&mut self, bus: &mut dyn Bus) -> anyhow::Result<u32> {
+        let pc = self.pc;
+        if pc & (self.ialign() - 1) != 0 {
+            return Err(MemoryTrap::MisalignedFetch { addr: pc }.into());
+        }
+
+        let lo = self.fetch_parcel(bus, pc)?;
+        if lo & 0b11 != 0b11 && self.ialign() == 2 {
+            self.ilen = 2;
+            return Ok(u32::from(lo));
+        }
+        let hi = self.fetch_parcel(bus, pc.wrapping_add(2))?;
+        self.ilen = 4;
+        Ok((u32::from(hi) << 16) | u32::from(lo))
+    }
+
+    #[inline(always)]
+    fn fetch_parcel(&mut self, bus: &mut dyn Bus, vaddr: u64) -> anyhow::Result<u16> {
+        let paddr = self.translate(bus, vaddr, 2, Access::Fetch)?;
+        bus.fetch(paddr)
+            .map_err(|err| Self::bus_fault(err, Access::Fetch, vaddr))
+    }
+
+    /// Executes a fetched instruction, expanding compressed ones first. An
+    /// illegal expansion reports the original 16-bit encoding.
+    #[inline(always)]
+    fn execute(&mut self, inst: u32, bus: &mut dyn Bus) -> anyhow::Result<()> {
+        let (isa, rvc): (&InstrTable, &ExpandTable) = if self.xlen == 32 {
+            (&ISA32, &RVC32)
+        } else {
+            (&ISA, &RVC)
+        };
+        if self.ilen == 4 {
+            return isa.dispatch(inst, self, bus);
+        }
+
+        let expanded = rvc
+            .expand(inst as u16)
+            .ok_or(Trap::IllegalInstruction { inst })?;
+        isa.dispatch(expanded, self, bus)
+            .map_err(|err| match err.downcast::<Trap>() {
+                Ok(Trap::IllegalInstruction { .. }) => Trap::IllegalInstruction { inst }.into(),
+                Ok(Trap::VirtualInstruction { .. }) => Trap::VirtualInstruction { inst }.into(),
+                Ok(trap) => trap.into(),
+                Err(err) => err,
+            })
+    }
+
+    #[inline(always)]
+    pub fn read8(&mut self, bus: &mut dyn Bus, vaddr: u64) -> anyhow::Result<u8> {
+        let paddr = self.translate(bus, vaddr, 1, Access::Load)?;
+        bus.read8(paddr)
+            .map_err(|err| Self::bus_fault(err, Access::Load, vaddr))
+    }
+
+    #[inline(always)]
+    pub fn read16(&mut self, bus: &mut dyn Bus, vaddr: u64) -> anyhow::Result<u16> {
+        self.load(bus, vaddr, 2, Self::translate_load)
+            .map(|val| val as u16)
+    }
+
+    #[inline(always)]
+    pub fn read32(&mut self, bus: &mut dyn Bus, vaddr: u64) -> anyhow::Result<u32> {
+        self.load(bus, vaddr, 4, Self::translate_load)
+            .map(|val| val as u32)
+    }
+
+    #[inline(always)]
+    pub fn read64(&mut self, bus: &mut dyn Bus, vaddr: u64) -> anyhow::Result<u64> {
+        self.load(bus, vaddr, 8, Self::translate_load)
+    }
+
+    #[inline(always)]
+    pub fn write8(&mut self, bus: &mut dyn Bus, vaddr: u64, val: u8) -> anyhow::Result<()> {
+        let paddr = self.translate(bus, vaddr, 1, Access::Store)?;
+        bus.write8(paddr, val)
+            .map_err(|err| Self::bus_fault(err, Access::Store, vaddr))?;
+        self.invalidate_reservation(paddr);
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn write16(&mut self, bus: &mut dyn Bus, vaddr: u64, val: u16) -> anyhow::Result<()> {
+        self.store(bus, vaddr, 2, u64::from(val), Self::translate_store)
+    }
+
+    #[inline(always)]
+    pub fn write32(&mut self, bus: &mut dyn Bus, vaddr: u64, val: u32) -> anyhow::Result<()> {
+        self.store(bus, vaddr, 4, u64::from(val), Self::translate_store)
+    }
+
+    #[inline(always)]
+    pub fn write64(&mut self, bus: &mut dyn Bus, vaddr: u64, val: u64) -> anyhow::Result<()> {
+        self.store(bus, vaddr, 8, val, Self::translate_store)
+    }
+
+    /// Performs the read-modify-write of a word AMO at `vaddr`, storing
+    /// `op(old)` and returning `old`.
+    #[inline(always)]
+    pub fn amo32(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        op: impl FnOnce(u32) -> u32,
+    ) -> anyhow::Result<u32> {
+        self.amo(bus, vaddr, 4, |old| u64::from(op(old as u32)))
+            .map(|old| old as u32)
+    }
+
+    /// Performs the read-modify-write of a doubleword AMO at `vaddr`, storing
+    /// `op(old)` and returning `old`.
+    #[inline(always)]
+    pub fn amo64(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        op: impl FnOnce(u64) -> u64,
+    ) -> anyhow::Result<u64> {
+        self.amo(bus, vaddr, 8, op)
+    }
+
+    /// AMOs are store operations as far as the privileged spec is concerned,
+    /// so a fault raised by the read half, like an access fault, is reported
+    /// as a store/AMO exception.
+    fn amo(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        op: impl FnOnce(u64) -> u64,
+    ) -> anyhow::Result<u64> {
+        let parts = self.translate_parts(bus, vaddr, size, Access::Store, Self::translate_store)?;
+        let vaddr = vaddr & self.xmask();
+        let old = Self::read_parts(bus, parts)
+            .map_err(|err| Self::bus_fault(err, Access::Store, vaddr))?;
+        self.write_parts(bus, parts, op(old))
+            .map_err(|err| Self::bus_fault(err, Access::Store, vaddr))?;
+        Ok(old)
+    }
+
+    /// Reports an access fault raised by the bus at the virtual address of
+    /// the access, as `tval` holds, rather than at the physical one the bus
+    /// saw. Other errors pass through.
+    fn bus_fault(err: anyhow::Error, access: Access, vaddr: u64) -> anyhow::Error {
+        match err.downcast_ref::<MemoryTrap>() {
+            Some(
+                MemoryTrap::FetchAccessFault { .. }
+                | MemoryTrap::LoadAccessFault { .. }
+                | MemoryTrap::StoreAccessFault { .. },
+            ) => access.access_fault(vaddr).into(),
+            _ => err,
+        }
+    }
+
+    fn translate_load(&mut self, bus: &mut dyn Bus, vaddr: u64, size: u64) -> anyhow::Result<u64> {
+        self.translate(bus, vaddr, size, Access::Load)
+    }
+
+    fn translate_store(&mut self, bus: &mut dyn Bus, vaddr: u64, size: u64) -> anyhow::Result<u64> {
+        self.translate(bus, vaddr, size, Access::Store)
+    }
+
+    /// Loads `size` bytes at `vaddr`, translated by `translate`.
+    fn load(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        translate: impl Fn(&mut Self, &mut dyn Bus, u64, u64) -> anyhow::Result<u64>,
+    ) -> anyhow::Result<u64> {
+        let parts = self.translate_parts(bus, vaddr, size, Access::Load, translate)?;
+        let vaddr = vaddr & self.xmask();
+        Self::read_parts(bus, parts).map_err(|err| Self::bus_fault(err, Access::Load, vaddr))
+    }
+
+    /// Stores the low `size` bytes of `val` at `vaddr`, translated by
+    /// `translate`.
+    fn store(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        val: u64,
+        translate: impl Fn(&mut Self, &mut dyn Bus, u64, u64) -> anyhow::Result<u64>,
+    ) -> anyhow::Result<()> {
+        let parts = self.translate_parts(bus, vaddr, size, Access::Store, translate)?;
+        let vaddr = vaddr & self.xmask();
+        self.write_parts(bus, parts, val)
+            .map_err(|err| Self::bus_fault(err, Access::Store, vaddr))
+    }
+
+    /// Translates an access of `size` bytes at `vaddr` into the physical
+    /// address and length of its part in each page it touches, applying the
+    /// misaligned access policy. Both parts are translated before either is
+    /// accessed, so that a fault on the second leaves memory untouched.
+    fn translate_parts(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        size: u64,
+        access: Access,
+        translate: impl Fn(&mut Self, &mut dyn Bus, u64, u64) -> anyhow::Result<u64>,
+    ) -> anyhow::Result<[(u64, u64); 2]> {
+        let vaddr = vaddr & self.xmask();
+        let first = (mmu::PAGE_SIZE - vaddr % mmu::PAGE_SIZE).min(size);
+        let misaligned = match access {
+            Access::Load => MemoryTrap::MisalignedLoad {
+                addr: vaddr,
+                align: size as usize,
+            },
+            _ => MemoryTrap::MisalignedStore {
+                addr: vaddr,
+                align: size as usize,
+            },
+        };
+        match self.misaligned {
+            _ if vaddr.is_multiple_of(size) => {}
+            Misaligned::Trap => return Err(misaligned.into()),
+            Misaligned::WithinPage if first < size => return Err(misaligned.into()),
+            Misaligned::WithinPage | Misaligned::Transparent => {}
+        }
+
+        let lo = translate(self, bus, vaddr, first)?;
+        if first == size {
+            return Ok([(lo, size), (0, 0)]);
+        }
+        let hi = translate(self, bus, (vaddr + first) & self.xmask(), size - first)?;
+        Ok([(lo, first), (hi, size - first)])
+    }
+
+    /// Reads the parts of an access, in one bus access when it is aligned and
+    /// byte by byte otherwise.
+    fn read_parts(bus: &mut dyn Bus, parts: [(u64, u64); 2]) -> anyhow::Result<u64> {
+        match parts {
+            [(paddr, 2), (_, 0)] if paddr.is_multiple_of(2) => bus.read16(paddr).map(u64::from),
+            [(paddr, 4), (_, 0)] if paddr.is_multiple_of(4) => bus.read32(paddr).map(u64::from),
+            [(paddr, 8), (_, 0)] if paddr.is_multiple_of(8) => bus.read64(paddr),
+            _ => {
+                let mut val = 0;
+                let mut shift = 0;
+                for (paddr, len) in parts {
+                    for i in 0..len {
+                        val |= u64::from(bus.read8(paddr + i)?) << shift;
+                        shift += 8;
+                    }
+                }
+                Ok(val)
+            }
+        }
+    }
+
+    /// Writes `val` to the parts of an access, in one bus access when it is
+    /// aligned and byte by byte otherwise, and drops any reservation it hits.
+    fn write_parts(
+        &mut self,
+        bus: &mut dyn Bus,
+        parts: [(u64, u64); 2],
+        val: u64,
+    ) -> anyhow::Result<()> {
+        match parts {
+            [(paddr, 2), (_, 0)] if paddr.is_multiple_of(2) => bus.write16(paddr, val as u16)?,
+            [(paddr, 4), (_, 0)] if paddr.is_multiple_of(4) => bus.write32(paddr, val as u32)?,
+            [(paddr, 8), (_, 0)] if paddr.is_multiple_of(8) => bus.write64(paddr, val)?,
+            _ => {
+                let mut val = val;
+                for (paddr, len) in parts {
+                    for i in 0..len {
+                        bus.write8(paddr + i, val as u8)?;
+                        self.invalidate_reservation(paddr + i);
+                        val >>= 8;
+                    }
+                }
+                return Ok(());
+            }
+        }
+        self.invalidate_reservation(parts[0].0);
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn reserve(&mut self, paddr: u64) {
+        self.reservation = Some(paddr & !(Self::RESERVATION_GRANULE - 1));
+    }
+
+    /// Consumes the reservation, reporting whether it covered `paddr`. SC always
+    /// clears the reservation, whether it succeeds or not.
+    #[inline(always)]
+    pub fn take_reservation(&mut self, paddr: u64) -> bool {
+        self.reservation.take() == Some(paddr & !(Self::RESERVATION_GRANULE - 1))
+    }
+
+    #[inline(always)]
+    pub fn invalidate_reservation(&mut self, paddr: u64) {
+        if self.reservation == Some(paddr & !(Self::RESERVATION_GRANULE - 1)) {
+            self.reservation = None;
+        }
+    }
+
+    #[inline(always)]
+    pub fn clear_reservation(&mut self) {
+        self.reservation = None;
+    }
+
+    #[inline(always)]
+    pub fn reservation(&self) -> Option<u64> {
+        self.reservation
+    }
+
+    /// Picks the interrupt to take before the next instruction, if any. An
+    /// interrupt bound for a more privileged mode than the current one is
+    /// always enabled, one bound for the current mode only when its `xIE`
+    /// bit is set, and one bound for a less privileged mode never is. VS-level
+    /// interrupts are only taken by a guest, and HS-level ones always are.
+    /// Interrupts bound for M-mode go before those for HS-mode, which go
+    /// before those for VS-mode; [`Hart::INTERRUPT_PRIORITY`] orders the rest.
+    fn interrupt(&self) -> Option<u64> {
+        let pending = self.csrs.pending() & self.csrs.get(csr::MIE);
+        if pending == 0 {
+            return None;
+        }
+
+        let mstatus = self.csrs.get(csr::MSTATUS);
+        let mideleg = self.csrs.get(csr::MIDELEG);
+        let hideleg = self.csrs.get(csr::HIDELEG);
+        let machine = self.privilege < Privilege::Machine || mstatus & csr::MSTATUS_MIE != 0;
+        let supervisor = self.virt
+            || self.privilege < Privilege::Supervisor
+            || (self.privilege == Privilege::Supervisor && mstatus & csr::MSTATUS_SIE != 0);
+        let guest = self.virt
+            && (self.privilege < Privilege::Supervisor
+                || self.csrs.get(csr::VSSTATUS) & csr::MSTATUS_SIE != 0);
+
+        let enabled = [
+            (machine, !mideleg),
+            (supervisor, mideleg & !hideleg),
+            (guest, mideleg & hideleg),
+        ]
+        .into_iter()
+        .filter(|&(enabled, _)| enabled)
+        .map(|(_, interrupts)| pending & interrupts)
+        .find(|&interrupts| interrupts != 0)?;
+
+        Self::INTERRUPT_PRIORITY
+            .into_iter()
+            .find(|&code| (enabled >> code) & 1 != 0)
+    }
+
+    /// Takes interrupt `code`, which resumes execution at the trap vector
+    /// with `xepc` pointing to the next instruction to execute.
+    fn take_interrupt(&mut self, code: u64) {
+        self.wfi = false;
+        self.enter_trap((1 << 63) | code, 0, 0, false);
+    }
+
+    /// Takes a synchronous exception: the trapping instruction is not retired
+    /// and execution resumes at the trap vector of the handling privilege mode.
+    pub fn take_trap(&mut self, trap: Trap) {
+        let gva = trap.has_vaddr() && (self.virt || self.guest_fault);
+        self.guest_fault = false;
+        self.enter_trap(trap.cause(), trap.tval(), trap.tval2(), gva);
+    }
+
+    /// Enters the trap handler for `cause` (bit 63 set for interrupts), saving
+    /// the interrupted pc and privilege as the privileged spec describes. Traps
+    /// taken from S- or U-mode go to HS-mode when `medeleg`/`mideleg` say so,
+    /// and those taken from a guest further down to VS-mode when
+    /// `hedeleg`/`hideleg` do. `tval2` goes to `htval`/`mtval2` and `gva`
+    /// tells whether `tval` is a guest virtual address.
+    fn enter_trap(&mut self, cause: u64, tval: u64, tval2: u64, gva: bool) {
+        self.clear_reservation();
+
+        let interrupt = cause >> 63 != 0;
+        let code = cause << 1 >> 1;
+        // The interrupt flag is the top bit of an XLEN-wide cause.
+        let cause = (u64::from(interrupt) << (self.xlen - 1)) | code;
+        let (deleg, hdeleg) = if interrupt {
+            (csr::MIDELEG, csr::HIDELEG)
+        } else {
+            (csr::MEDELEG, csr::HEDELEG)
+        };
+        let delegated =
+            self.privilege <= Privilege::Supervisor && (self.csrs.get(deleg) >> code) & 1 != 0;
+        let to_guest = delegated && self.virt && (self.csrs.get(hdeleg) >> code) & 1 != 0;
+        // VS-level interrupts show up as their supervisor counterparts, and
+        // are vectored as such.
+        let (code, cause) = if to_guest && interrupt {
+            (code - 1, cause - 1)
+        } else {
+            (code, cause)
+        };
+
+        let tvec = if to_guest {
+            self.csrs.set(csr::VSEPC, self.pc);
+            self.csrs.set(csr::VSCAUSE, cause);
+            self.csrs.set(csr::VSTVAL, tval);
+
+            let vsstatus = self.csrs.get(csr::VSSTATUS);
+            let vsstatus = self.stack_supervisor(vsstatus);
+            self.csrs.set(csr::VSSTATUS, vsstatus);
+
+            self.csrs.get(csr::VSTVEC)
+        } else if delegated {
+            self.csrs.set(csr::SEPC, self.pc);
+            self.csrs.set(csr::SCAUSE, cause);
+            self.csrs.set(csr::STVAL, tval);
+            self.csrs.set(csr::HTVAL, tval2);
+            self.csrs.set(csr::HTINST, 0);
+
+            let mut hstatus = self.csrs.get(csr::HSTATUS);
+            hstatus &= !(csr::HSTATUS_SPV | csr::HSTATUS_GVA);
+            if self.virt {
+                hstatus |= csr::HSTATUS_SPV;
+                hstatus &= !csr::HSTATUS_SPVP;
+                if self.privilege == Privilege::Supervisor {
+                    hstatus |= csr::HSTATUS_SPVP;
+                }
+            }
+            if gva {
+                hstatus |= csr::HSTATUS_GVA;
+            }
+            self.csrs.set(csr::HSTATUS, hstatus);
+
+            let mstatus = self.csrs.get(csr::MSTATUS);
+            let mstatus = self.stack_supervisor(mstatus);
+            self.csrs.set(csr::MSTATUS, mstatus);
+            self.virt = false;
+
+            self.csrs.get(csr::STVEC)
+        } else {
+            self.csrs.set(csr::MEPC, self.pc);
+            self.csrs.set(csr::MCAUSE, cause);
+            self.csrs.set(csr::MTVAL, tval);
+            self.csrs.set(csr::MTVAL2, tval2);
+            self.csrs.set(csr::MTINST, 0);
+
+            let mut mstatus = self.csrs.get(csr::MSTATUS);
+            mstatus = Self::stack_interrupt_enable(mstatus, csr::MSTATUS_MIE, csr::MSTATUS_MPIE);
+            mstatus &= !(csr::MSTATUS_MPP | csr::MSTATUS_MPV | csr::MSTATUS_GVA);
+            mstatus |= (self.privilege as u64) << csr::MSTATUS_MPP.trailing_zeros();
+            if self.virt {
+                mstatus |= csr::MSTATUS_MPV;
+            }
+            if gva {
+                mstatus |= csr::MSTATUS_GVA;
+            }
+            self.csrs.set(csr::MSTATUS, mstatus);
+            self.privilege = Privilege::Machine;
+            self.virt = false;
+
+            self.csrs.get(csr::MTVEC)
+        };
+
+        let base = tvec & !0b11;
+        self.pc = if tvec & 0b11 == 1 && interrupt {
+            base.wrapping_add(code * 4)
+        } else {
+            base
+        };
+    }
+
+    /// Stacks `SIE` and the current privilege in the supervisor fields of
+    /// `status`, `mstatus` or `vsstatus`, and enters S-mode.
+    fn stack_supervisor(&mut self, status: u64) -> u64 {
+        let mut status = Self::stack_interrupt_enable(status, csr::MSTATUS_SIE, csr::MSTATUS_SPIE);
+        if self.privilege == Privilege::Supervisor {
+            status |= csr::MSTATUS_SPP;
+        } else {
+            status &= !csr::MSTATUS_SPP;
+        }
+        self.privilege = Privilege::Supervisor;
+        status
+    }
+
+    /// Moves `ie` into `pie` and disables interrupts, as done on trap entry.
+    const fn stack_interrupt_enable(mstatus: u64, ie: u64, pie: u64) -> u64 {
+        let mstatus = if mstatus & ie != 0 {
+            mstatus | pie
+        } else {
+            mstatus & !pie
+        };
+        mstatus & !ie
+    }
+
+    /// Moves `pie` back into `ie` and sets `pie`, as done on trap return.
+    const fn unstack_interrupt_enable(mstatus: u64, ie: u64, pie: u64) -> u64 {
+        let mstatus = if mstatus & pie != 0 {
+            mstatus | ie
+        } else {
+            mstatus & !ie
+        };
+        mstatus | pie
+    }
+
+    /// Returns from an M-mode trap handler, restoring the privilege,
+    /// virtualization mode and interrupt enable stacked in `mstatus`.
+    pub fn mret(&mut self) {
+        let mut mstatus = self.csrs.get(csr::MSTATUS);
+        let mpp = Privilege::from_bits((mstatus & csr::MSTATUS_MPP) >> 11);
+        let mpv = mstatus & csr::MSTATUS_MPV != 0;
+
+        mstatus = Self::unstack_interrupt_enable(mstatus, csr::MSTATUS_MIE, csr::MSTATUS_MPIE);
+        mstatus &= !(csr::MSTATUS_MPP | csr::MSTATUS_MPV);
+        if mpp != Privilege::Machine {
+            mstatus &= !csr::MSTATUS_MPRV;
+        }
+        self.csrs.set(csr::MSTATUS, mstatus);
+
+        self.privilege = mpp;
+        self.virt = mpp != Privilege::Machine && mpv;
+        self.pc = self.csrs.get(csr::MEPC);
+    }
+
+    /// Returns from an S-mode trap handler, restoring the privilege and
+    /// interrupt enable stacked in `sstatus`. HS-mode also returns to the
+    /// virtualization mode in `hstatus.SPV`; a guest returns through
+    /// `vsstatus` and `vsepc` and stays virtualized.
+    pub fn sret(&mut self) {
+        let (status, epc) = if self.virt {
+            (csr::VSSTATUS, csr::VSEPC)
+        } else {
+            (csr::MSTATUS, csr::SEPC)
+        };
+
+        let mut mstatus = self.csrs.get(status);
+        let spp = if mstatus & csr::MSTATUS_SPP != 0 {
+            Privilege::Supervisor
+        } else {
+            Privilege::User
+        };
+
+        mstatus = Self::unstack_interrupt_enable(mstatus, csr::MSTATUS_SIE, csr::MSTATUS_SPIE);
+        mstatus &= !csr::MSTATUS_SPP;
+        if !self.virt {
+            mstatus &= !csr::MSTATUS_MPRV;
+            let hstatus = self.csrs.get(csr::HSTATUS);
+            self.virt = hstatus & csr::HSTATUS_SPV != 0;
+            self.csrs.set(csr::HSTATUS, hstatus & !csr::HSTATUS_SPV);
+        }
+        self.csrs.set(status, mstatus);
+
+        self.privilege = spp;
+        self.pc = self.csrs.get(epc);
+    }
+}
+
+impl std::fmt::Display for Hart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "pc 0x{:018x}", self.pc)?;
+        for row in 0..8 {
+            let i = row * 4;
+            writeln!(
+                f,
+                "x{:<2} [{:<4}] 0x{:018x}    x{:<2} [{:<4}] 0x{:018x}    x{:<2} [{:<4}] 0x{:018x}    x{:<2} [{:<4}] 0x{:018x}",
+                i,
+                Self::IABI[i],
+                self.xregs[i],
+                i + 1,
+                Self::IABI[i + 1],
+                self.xregs[i + 1],
+                i + 2,
+                Self::IABI[i + 2],
+                self.xregs[i + 2],
+                i + 3,
+                Self::IABI[i + 3],
+                self.xregs[i + 3],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Hart {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Cpu for Hart {
+    #[inline(always)]
+    fn step<B>(&mut self, bus: &mut B) -> anyhow::Result<bool>
+    where
+        B: Bus,
+    {
+        self.guest_fault = false;
+        if self.wfi {
+            // WFI ends once an enabled interrupt is pending, even one that
+            // is globally disabled and so not taken.
+            if self.csrs.pending() & self.csrs.get(csr::MIE) == 0 {
+                return Ok(false);
+            }
+            self.wfi = false;
+        }
+        if let Some(code) = self.interrupt() {
+            self.csrs.tick(false);
+            self.take_interrupt(code);
+            return Ok(false);
+        }
+
+        let result = self.fetch(bus).and_then(|inst| self.execute(inst, bus));
+        self.csrs.tick(result.is_ok());
+
+        match result {
+            Ok(()) => Ok(true),
+            Err(err) => match Trap::from_error(&err) {
+                Some(trap) => {
+                    self.take_trap(trap);
+                    Ok(false)
+                }
+                None => Err(err),
+            },
+        }
+    }
+
+    /// Interrupt lines show up as the corresponding bits of `mip`.
+    #[inline(always)]
+    fn set_interrupt(&mut self, mask: u64, pending: bool) {
+        self.csrs.set_lines(mask, pending);
+    }
+
+    #[inline(always)]
+    fn post_interrupt(&mut self, mask: u64) {
+        self.csrs.set(csr::MIP, self.csrs.get(csr::MIP) | mask);
+    }
+
+    #[inline(always)]
+    fn set_time(&mut self, time: u64) {
+        self.csrs.set(csr::TIME, time);
+    }
+
+    #[inline(always)]
+    fn idle(&self) -> bool {
+        self.wfi
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::mmap::Mmap,
+        processor::riscv::pmp::{
+            PMP_A_NA4, PMP_A_NAPOT, PMP_A_TOR, PMP_R, PMP_W, PMP_X, PMPADDR_MASK,
+        },
+    };
+
+    const ILLEGAL: u32 = 0xffff_ffff;
+
+    fn setup(inst: u32) -> (Hart, Mmap) {
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        bus.write32(0x1000, inst).unwrap();
+
+        let mut hart = Hart::new(0x1000);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+        // Open all of memory to S and U modes, as firmware would.
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W | PMP_X));
+
+        (hart, bus)
+    }
+
+    #[test]
+    fn isa_string_lists_all_extensions() {
+        let hart = Hart::new(0);
+        let isa = hart.isa_string();
+
+        assert!(isa.starts_with("rv64imafdcvh_zicbom_zicbop_zicboz_zicntr_zicond_zicsr_"));
+        assert!(isa.ends_with("_zknd_zkne_zknh_zksed_zksh"));
+
+        let config = HartConfig {
+            compressed: false,
+            vector: false,
+            hypervisor: false,
+            extensions: Extensions::default().with(Extension::Zbb),
+            ..HartConfig::default()
+        };
+        assert_eq!(
+            Hart::with_config(0, config).isa_string(),
+            "rv64imafd_zicntr_zicsr_zifencei_zihpm_zbb"
+        );
+    }
+
+    #[test]
+    fn trap_illegal_instruction() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x2000);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), u64::from(ILLEGAL));
+    }
+
+    #[test]
+    fn trap_updates_mstatus() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_MIE);
+
+        hart.step(&mut bus).unwrap();
+
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        assert_eq!(mstatus & csr::MSTATUS_MIE, 0);
+        assert_ne!(mstatus & csr::MSTATUS_MPIE, 0);
+        assert_eq!(mstatus & csr::MSTATUS_MPP, csr::MSTATUS_MPP);
+        assert_eq!(hart.privilege(), Privilege::Machine);
+    }
+
+    #[test]
+    fn trap_clears_mpie_when_mie_was_clear() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_MPIE);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MPIE, 0);
+    }
+
+    #[test]
+    fn trap_vectored_mode_uses_base_for_exceptions() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MTVEC, 0x2001);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x2000);
+    }
+
+    #[test]
+    fn trap_vectored_mode_offsets_interrupts() {
+        let (mut hart, _) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MTVEC, 0x2001);
+
+        hart.enter_trap((1 << 63) | 7, 0, 0, false);
+
+        assert_eq!(hart.pc(), 0x2000 + 7 * 4);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), (1 << 63) | 7);
+    }
+
+    #[test]
+    fn trap_memory_fault() {
+        // ld x1, 16(x0) with RAM starting above address zero.
+        let mut bus = Mmap::new(0x8000_0000, 0x1000);
+        bus.write32(0x8000_0000, 0x0100_3083).unwrap();
+        let mut hart = Hart::new(0x8000_0000);
+        hart.csrs_mut().set(csr::MTVEC, 0x8000_0100);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x8000_0100);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x8000_0000);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 5);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 16);
+    }
+
+    #[test]
+    fn trap_fetch_access_fault() {
+        let mut bus = Mmap::new(0x8000_0000, 0x1000);
+        let mut hart = Hart::new(0x1000);
+        hart.csrs_mut().set(csr::MTVEC, 0x8000_0100);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x8000_0100);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 1);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1000);
+    }
+
+    #[test]
+    fn trap_does_not_retire() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MINSTRET), 0);
+        assert_eq!(hart.csrs().get(csr::MCYCLE), 1);
+    }
+
+    #[test]
+    fn trap_from_user_mode_records_mpp() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.set_privilege(Privilege::User);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::Machine);
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MPP, 0);
+    }
+
+    #[test]
+    fn trap_delegated_to_supervisor() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MEDELEG, 1 << 2);
+        hart.csrs_mut().set(csr::STVEC, 0x3000);
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_SIE);
+        hart.set_privilege(Privilege::User);
+
+        hart.step(&mut bus).unwrap();
+
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert_eq!(hart.pc(), 0x3000);
+        assert_eq!(hart.csrs().get(csr::SEPC), 0x1000);
+        assert_eq!(hart.csrs().get(csr::SCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::STVAL), u64::from(ILLEGAL));
+        assert_eq!(mstatus & csr::MSTATUS_SIE, 0);
+        assert_ne!(mstatus & csr::MSTATUS_SPIE, 0);
+        assert_eq!(mstatus & csr::MSTATUS_SPP, 0);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0);
+    }
+
+    #[test]
+    fn trap_delegated_from_supervisor_sets_spp() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MEDELEG, 1 << 2);
+        hart.csrs_mut().set(csr::STVEC, 0x3000);
+        hart.set_privilege(Privilege::Supervisor);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_ne!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_SPP, 0);
+    }
+
+    #[test]
+    fn trap_never_delegated_from_machine_mode() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MEDELEG, 1 << 2);
+        hart.csrs_mut().set(csr::STVEC, 0x3000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::Machine);
+        assert_eq!(hart.pc(), 0x2000);
+    }
+
+    #[test]
+    fn trap_from_guest_to_hypervisor_sets_spv() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MEDELEG, 1 << 2);
+        hart.csrs_mut().set(csr::STVEC, 0x3000);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_virt(true);
+
+        hart.step(&mut bus).unwrap();
+
+        let hstatus = hart.csrs().get(csr::HSTATUS);
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert!(!hart.virt());
+        assert_eq!(hart.pc(), 0x3000);
+        assert_eq!(hart.csrs().get(csr::SEPC), 0x1000);
+        assert_ne!(hstatus & csr::HSTATUS_SPV, 0);
+        assert_ne!(hstatus & csr::HSTATUS_SPVP, 0);
+        assert_eq!(hstatus & csr::HSTATUS_GVA, 0);
+    }
+
+    #[test]
+    fn trap_delegated_to_guest() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MEDELEG, 1 << 2);
+        hart.csrs_mut().set(csr::HEDELEG, 1 << 2);
+        hart.csrs_mut().set(csr::VSTVEC, 0x4000);
+        hart.csrs_mut().set(csr::VSSTATUS, csr::MSTATUS_SIE);
+        hart.set_privilege(Privilege::User);
+        hart.set_virt(true);
+
+        hart.step(&mut bus).unwrap();
+
+        let vsstatus = hart.csrs().get(csr::VSSTATUS);
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert!(hart.virt());
+        assert_eq!(hart.pc(), 0x4000);
+        assert_eq!(hart.csrs().get(csr::VSEPC), 0x1000);
+        assert_eq!(hart.csrs().get(csr::VSCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::VSTVAL), u64::from(ILLEGAL));
+        assert_eq!(vsstatus & (csr::MSTATUS_SIE | csr::MSTATUS_SPP), 0);
+        assert_ne!(vsstatus & csr::MSTATUS_SPIE, 0);
+        assert_eq!(hart.csrs().get(csr::SEPC), 0);
+    }
+
+    #[test]
+    fn trap_guest_page_fault_sets_mtval2() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        // An empty Sv39x4 root table maps no guest physical memory.
+        hart.csrs_mut()
+            .set(csr::HGATP, (csr::HGATP_MODE_SV39X4 << 60) | 0x8);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_virt(true);
+
+        hart.step(&mut bus).unwrap();
+
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        assert!(!hart.virt());
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 20);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1000);
+        assert_eq!(hart.csrs().get(csr::MTVAL2), 0x1000 >> 2);
+        assert_ne!(mstatus & csr::MSTATUS_MPV, 0);
+        assert_ne!(mstatus & csr::MSTATUS_GVA, 0);
+    }
+
+    #[test]
+    fn trap_hypervisor_load_fault_sets_gva() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut()
+            .set(csr::HGATP, (csr::HGATP_MODE_SV39X4 << 60) | 0x8);
+
+        let err = hart.read_guest(&mut bus, 0x100, 8, false).unwrap_err();
+        hart.take_trap(*err.downcast_ref::<Trap>().unwrap());
+
+        let mstatus = hart.csrs().get(csr::MSTATUS);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 21);
+        assert_ne!(mstatus & csr::MSTATUS_GVA, 0);
+        assert_eq!(mstatus & csr::MSTATUS_MPV, 0);
+    }
+
+    #[test]
+    fn guest_csr_access_reaches_vs_csrs() {
+        // csrr a0, sscratch
+        const CSRR_SSCRATCH: u32 = 0x1400_2573;
+        let (mut hart, mut bus) = setup(CSRR_SSCRATCH);
+        hart.csrs_mut().set(csr::SSCRATCH, 1);
+        hart.csrs_mut().set(csr::VSSCRATCH, 2);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_virt(true);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 2);
+    }
+
+    #[test]
+    fn guest_hypervisor_csr_access_is_virtual_instruction() {
+        // csrr a0, hstatus
+        const CSRR_HSTATUS: u32 = 0x6000_2573;
+        let (mut hart, mut bus) = setup(CSRR_HSTATUS);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_virt(true);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 22);
+        assert_eq!(hart.csrs().get(csr::MTVAL), u64::from(CSRR_HSTATUS));
+
+        hart.set_pc(0x1000);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.step(&mut bus).unwrap();
+        assert_eq!(hart.xreg(10), csr::HSTATUS_VSXL_64);
+    }
+
+    #[test]
+    fn sret_enters_guest_from_spv() {
+        let mut hart = Hart::new(0);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.csrs_mut().set(csr::SEPC, 0x4000);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_SPP);
+        hart.csrs_mut().set(csr::HSTATUS, csr::HSTATUS_SPV);
+
+        hart.sret();
+
+        assert!(hart.virt());
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert_eq!(hart.pc(), 0x4000);
+        assert_eq!(hart.csrs().get(csr::HSTATUS) & csr::HSTATUS_SPV, 0);
+    }
+
+    #[test]
+    fn sret_in_guest_uses_vsstatus() {
+        let mut hart = Hart::new(0);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_virt(true);
+        hart.csrs_mut().set(csr::VSEPC, 0x5000);
+        hart.csrs_mut().set(csr::VSSTATUS, csr::MSTATUS_SPIE);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_SPP);
+
+        hart.sret();
+
+        assert!(hart.virt());
+        assert_eq!(hart.privilege(), Privilege::User);
+        assert_eq!(hart.pc(), 0x5000);
+        assert_ne!(hart.csrs().get(csr::VSSTATUS) & csr::MSTATUS_SIE, 0);
+        assert_ne!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_SPP, 0);
+    }
+
+    #[test]
+    fn mret_enters_guest_from_mpv() {
+        let mut hart = Hart::new(0);
+        let mstatus = csr::MSTATUS_MPV | (0b01 << 11);
+        hart.csrs_mut().set(csr::MSTATUS, mstatus);
+
+        hart.mret();
+
+        assert!(hart.virt());
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MPV, 0);
+    }
+
+    #[test]
+    fn trap_fetch_page_fault_sets_mtval() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::SATP, (8 << 60) | 0x80);
+        hart.set_privilege(Privilege::Supervisor);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 12);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1000);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
+    }
+
+    #[test]
+    fn pmp_denied_fetch_raises_access_fault() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::PMPADDR0, 0x1000 >> 2);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NA4 | PMP_R));
+        hart.set_privilege(Privilege::User);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 1);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1000);
+    }
+
+    #[test]
+    fn pmp_reports_virtual_address_of_denied_store() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R));
+        hart.set_privilege(Privilege::Supervisor);
+
+        let err = hart.write32(&mut bus, 0x100, 0).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::StoreAccessFault { addr: 0x100 }
+        );
+    }
+
+    #[test]
+    fn pmp_applies_to_mprv_accesses() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::PMPCFG0, 0);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_MPRV);
+
+        assert!(hart.read32(&mut bus, 0x1000).is_err());
+    }
+
+    #[test]
+    fn pmp_checks_page_table_walk() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        // Entry 0 denies the root page table, entry 1 opens everything else.
+        hart.csrs_mut().set(csr::PMPADDR0, (0x8_0000 | 0x7ff) >> 2);
+        hart.csrs_mut().set(csr::PMPADDR0 + 1, PMPADDR_MASK);
+        let open = PMP_A_NAPOT | PMP_R | PMP_W | PMP_X;
+        hart.csrs_mut().set(
+            csr::PMPCFG0,
+            (u64::from(open) << 8) | u64::from(PMP_A_NAPOT),
+        );
+        hart.csrs_mut().set(csr::SATP, (8 << 60) | 0x80);
+        hart.set_privilege(Privilege::Supervisor);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 1);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1000);
+    }
+
+    fn without_compressed(entry: u64) -> Hart {
+        let config = HartConfig {
+            compressed: false,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(entry, config);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+        hart
+    }
+
+    #[test]
+    fn fetch_compressed_advances_by_two() {
+        // c.addi a0, 1; c.addi a0, 1
+        let (mut hart, mut bus) = setup(0x0505_0505);
+
+        hart.step(&mut bus).unwrap();
+        assert_eq!(hart.pc(), 0x1002);
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 2);
+        assert_eq!(hart.pc(), 0x1004);
+    }
+
+    #[test]
+    fn fetch_full_instruction_at_halfword_boundary() {
+        // c.nop; addi a0, a0, 1
+        let (mut hart, mut bus) = setup(0x0513_0001);
+        bus.write16(0x1004, 0x0015).unwrap();
+
+        hart.step(&mut bus).unwrap();
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(10), 1);
+        assert_eq!(hart.pc(), 0x1006);
+    }
+
+    #[test]
+    fn fetch_fault_on_second_parcel_reports_its_address() {
+        // c.nop; addi a0, a0, 1
+        let (mut hart, mut bus) = setup(0x0513_0001);
+        bus.write16(0x1004, 0x0015).unwrap();
+        hart.csrs_mut().set(csr::PMPADDR0, 0x1004 >> 2);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_TOR | PMP_X));
+        hart.set_privilege(Privilege::User);
+
+        hart.step(&mut bus).unwrap();
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 1);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1002);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1004);
+    }
+
+    #[test]
+    fn fetch_illegal_compressed_reports_parcel() {
+        let (mut hart, mut bus) = setup(0xffff_0000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0);
+    }
+
+    #[test]
+    fn fetch_without_compressed_decodes_full_words() {
+        let (_, mut bus) = setup(0x0505_0505);
+        let mut hart = without_compressed(0x1000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x0505_0505);
+    }
+
+    #[test]
+    fn fetch_misaligned_pc_without_compressed() {
+        let (_, mut bus) = setup(ILLEGAL);
+        let mut hart = without_compressed(0x1002);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 0);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x1002);
+        assert_eq!(hart.csrs().get(csr::MISA) & csr::misa_ext(b'C'), 0);
+    }
+
+    #[test]
+    fn translate_applies_mprv() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::SATP, (8 << 60) | 0x80);
+
+        assert_eq!(hart.read64(&mut bus, 0x1000).unwrap(), u64::from(ILLEGAL));
+
+        let mstatus = csr::MSTATUS_MPRV | (1 << 11);
+        hart.csrs_mut().set(csr::MSTATUS, mstatus);
+        let err = hart.read64(&mut bus, 0x1000).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::LoadPageFault { addr: 0x1000 }
+        );
+        assert_eq!(hart.fetch(&mut bus).unwrap(), ILLEGAL);
+    }
+
+    #[test]
+    fn translate_applies_mpv() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::VSATP, (8 << 60) | 0x80);
+
+        let mstatus = csr::MSTATUS_MPRV | (1 << 11);
+        hart.csrs_mut().set(csr::MSTATUS, mstatus);
+        assert_eq!(hart.read64(&mut bus, 0x1000).unwrap(), u64::from(ILLEGAL));
+
+        hart.csrs_mut()
+            .set(csr::MSTATUS, mstatus | csr::MSTATUS_MPV);
+        let err = hart.read64(&mut bus, 0x1000).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<Trap>().unwrap(),
+            Trap::LoadPageFault { addr: 0x1000 }
+        );
+    }
+
+    #[test]
+    fn translate_reports_vaddr_of_bus_fault() {
+        // ld x1, 0(x2)
+        let (mut hart, mut bus) = setup(0x0001_3083);
+        // A gigapage mapping 0x4000_0000 to 0x8000_0000, past the end of RAM.
+        bus.write64(0x10008, (0x8_0000 << 10) | 0xcf).unwrap();
+        hart.csrs_mut().set(csr::SATP, (8 << 60) | 0x10);
+        hart.csrs_mut()
+            .set(csr::MSTATUS, csr::MSTATUS_MPRV | (1 << 11));
+        hart.set_xreg(2, 0x4000_0010);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 5);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0x4000_0010);
+    }
+
+    #[test]
+    fn trap_clears_reservation() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.reserve(0x100);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.reservation(), None);
+    }
+
+    fn with_misaligned(misaligned: Misaligned) -> (Hart, Mmap) {
+        let config = HartConfig {
+            misaligned,
+            ..HartConfig::default()
+        };
+        (Hart::with_config(0, config), Mmap::new(0x0, 0x10_0000))
+    }
+
+    #[test]
+    fn misaligned_trap_reports_vaddr() {
+        let (mut hart, mut bus) = with_misaligned(Misaligned::Trap);
+
+        let err = hart.read32(&mut bus, 0x102).unwrap_err();
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::MisalignedLoad {
+                addr: 0x102,
+                align: 4
+            }
+        );
+        let err = hart.write64(&mut bus, 0x104, 0).unwrap_err();
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::MisalignedStore {
+                addr: 0x104,
+                align: 8
+            }
+        );
+    }
+
+    #[test]
+    fn misaligned_transparent_crosses_pages() {
+        let (mut hart, mut bus) = with_misaligned(Misaligned::Transparent);
+
+        hart.write64(&mut bus, 0xffd, 0x0807_0605_0403_0201)
+            .unwrap();
+
+        assert_eq!(bus.read8(0xffd).unwrap(), 0x01);
+        assert_eq!(bus.read8(0x1004).unwrap(), 0x08);
+        assert_eq!(hart.read64(&mut bus, 0xffd).unwrap(), 0x0807_0605_0403_0201);
+        assert_eq!(hart.read16(&mut bus, 0xfff).unwrap(), 0x0403);
+    }
+
+    #[test]
+    fn misaligned_within_page_traps_across_pages() {
+        let (mut hart, mut bus) = with_misaligned(Misaligned::WithinPage);
+
+        hart.write32(&mut bus, 0x101, 0xdead_beef).unwrap();
+        assert_eq!(hart.read32(&mut bus, 0x101).unwrap(), 0xdead_beef);
+
+        let err = hart.read32(&mut bus, 0xffe).unwrap_err();
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::MisalignedLoad {
+                addr: 0xffe,
+                align: 4
+            }
+        );
+    }
+
+    #[test]
+    fn misaligned_store_faulting_in_second_page_writes_nothing() {
+        let (mut hart, mut bus) = with_misaligned(Misaligned::Transparent);
+        // Give U-mode the first page only.
+        hart.csrs_mut().set(csr::PMPADDR0, 0x1000 >> 2);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_TOR | PMP_R | PMP_W));
+        hart.set_privilege(Privilege::User);
+
+        let err = hart.write32(&mut bus, 0xffe, u32::MAX).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::StoreAccessFault { addr: 0x1000 }
+        );
+        assert_eq!(bus.read16(0xffe).unwrap(), 0);
+    }
+
+    #[test]
+    fn misaligned_store_invalidates_reservation() {
+        let (mut hart, mut bus) = with_misaligned(Misaligned::Transparent);
+        hart.reserve(0x108);
+
+        hart.write32(&mut bus, 0x106, 0).unwrap();
+
+        assert_eq!(hart.reservation(), None);
+    }
+
+    #[test]
+    fn interrupt_taken_before_next_instruction() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MIE, csr::MIP_MTIP);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_MIE);
+        hart.set_interrupt(csr::MIP_MTIP, true);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x2000);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), (1 << 63) | 7);
+        assert_eq!(hart.csrs().get(csr::MEPC), 0x1000);
+        assert_eq!(hart.csrs().get(csr::MTVAL), 0);
+        assert_eq!(hart.csrs().get(csr::MSTATUS) & csr::MSTATUS_MIE, 0);
+        assert_eq!(hart.csrs().get(csr::MINSTRET), 0);
+    }
+
+    #[test]
+    fn interrupt_masked_by_mstatus_mie_in_machine_mode() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MIE, csr::MIP_MTIP);
+        hart.set_interrupt(csr::MIP_MTIP, true);
+
+        assert_eq!(hart.interrupt(), None);
+
+        hart.set_privilege(Privilege::Supervisor);
+        assert_eq!(hart.interrupt(), Some(7));
+        hart.step(&mut bus).unwrap();
+        assert_eq!(hart.privilege(), Privilege::Machine);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), (1 << 63) | 7);
+    }
+
+    #[test]
+    fn interrupt_priority_order() {
+        let (mut hart, _) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MIE, csr::MIP_MASK);
+        hart.csrs_mut().set(csr::MSTATUS, csr::MSTATUS_MIE);
+
+        hart.set_interrupt(csr::MIP_MASK, true);
+        for code in [11, 3, 7, 9, 1, 5] {
+            assert_eq!(hart.interrupt(), Some(code));
+            hart.set_interrupt(1 << code, false);
+        }
+        assert_eq!(hart.interrupt(), None);
+    }
+
+    #[test]
+    fn interrupt_delegated_to_supervisor() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::STVEC, 0x3001);
+        hart.csrs_mut().set(csr::MIDELEG, csr::MIP_STIP);
+        hart.csrs_mut().set(csr::MIE, csr::MIP_STIP);
+        hart.set_interrupt(csr::MIP_STIP, true);
+
+        // Never taken in M-mode, and only with SIE set in S-mode.
+        assert_eq!(hart.interrupt(), None);
+        hart.set_privilege(Privilege::Supervisor);
+        assert_eq!(hart.interrupt(), None);
+        hart.set_privilege(Privilege::User);
+        assert_eq!(hart.interrupt(), Some(5));
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.privilege(), Privilege::Supervisor);
+        assert_eq!(hart.csrs().get(csr::SCAUSE), (1 << 63) | 5);
+        assert_eq!(hart.pc(), 0x3000 + 5 * 4);
+    }
+
+    #[test]
+    fn interrupt_to_machine_mode_goes_first() {
+        let (mut hart, _) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::MIDELEG, csr::MIP_SEIP);
+        hart.csrs_mut().set(csr::MIE, csr::MIP_SEIP | csr::MIP_MTIP);
+        hart.set_interrupt(csr::MIP_SEIP | csr::MIP_MTIP, true);
+        hart.set_privilege(Privilege::User);
+
+        assert_eq!(hart.interrupt(), Some(7));
+    }
+
+    #[test]
+    fn interrupt_to_guest_uses_supervisor_cause() {
+        let (mut hart, mut bus) = setup(ILLEGAL);
+        hart.csrs_mut().set(csr::HIDELEG, csr::MIP_VSTIP);
+        hart.csrs_mut().set(csr::HVIP, csr::MIP_VSTIP);
+        hart.csrs_mut().set(csr::MIE, csr::MIP_VSTIP);
+        hart.csrs_mut().set(csr::VSTVEC, 0x4001);
+        hart.set_privilege(Privilege::Supervisor);
+
+        // VS-level interrupts wait for a guest with interrupts enabled.
+        assert_eq!(hart.interrupt(), None);
+        hart.set_virt(true);
+        assert_eq!(hart.interrupt(), None);
+        hart.csrs_mut().set(csr::VSSTATUS, csr::MSTATUS_SIE);
+        assert_eq!(hart.interrupt(), Some(6));
+
+        hart.step(&mut bus).unwrap();
+
+        assert!(hart.virt());
+        assert_eq!(hart.csrs().get(csr::VSCAUSE), (1 << 63) | 5);
+        assert_eq!(hart.pc(), 0x4000 + 5 * 4);
+    }
+
+    #[test]
+    fn guest_time_offset_by_htimedelta() {
+        // csrr a0, time
+        const RDTIME: u32 = 0xc010_2573;
+        let (mut hart, _) = setup(ILLEGAL);
+        hart.set_time(100);
+        hart.csrs_mut().set(csr::HTIMEDELTA, 0x20);
+        hart.csrs_mut().set(csr::MCOUNTEREN, csr::COUNTER_TM);
+        hart.csrs_mut().set(csr::HCOUNTEREN, csr::COUNTER_TM);
+        hart.set_privilege(Privilege::Supervisor);
+
+        assert_eq!(hart.read_csr(csr::TIME, RDTIME), Ok(100));
+        hart.set_virt(true);
+        assert_eq!(hart.read_csr(csr::TIME, RDTIME), Ok(132));
+    }
+
+    #[test]
+    fn misaligned_parses_by_name() {
+        assert_eq!("trap".parse::<Misaligned>().unwrap(), Misaligned::Trap);
+        assert_eq!(
+            "transparent".parse::<Misaligned>().unwrap(),
+            Misaligned::Transparent
+        );
+        assert_eq!(
+            "page".parse::<Misaligned>().unwrap(),
+            Misaligned::WithinPage
+        );
+        assert!("emulate".parse::<Misaligned>().is_err());
+    }
+
+    #[test]
+    fn extension_parses_by_name() {
+        assert_eq!("zbc".parse::<Extension>().unwrap(), Extension::Zbc);
+        assert_eq!("Zba".parse::<Extension>().unwrap(), Extension::Zba);
+        assert!("zbx".parse::<Extension>().is_err());
+    }
+
+    #[test]
+    fn disabled_extension_traps_as_illegal() {
+        // clz a0, a0
+        const CLZ: u32 = 0x6005_1513;
+        let (mut hart, mut bus) = setup(CLZ);
+        hart.step(&mut bus).unwrap();
+        assert_eq!(hart.pc(), 0x1004);
+
+        let config = HartConfig {
+            extensions: Extensions::all().without(Extension::Zbb),
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0x1000, config);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0x2000);
+        assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        assert_eq!(hart.csrs().get(csr::MTVAL), u64::from(CLZ));
+    }
+
+    fn setup_rv32(inst: u32) -> (Hart, Mmap) {
+        let (_, mut bus) = setup(inst);
+        let config = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        let mut hart = Hart::with_config(0x1000, config);
+        hart.csrs_mut().set(csr::MTVEC, 0x2000);
+        hart.csrs_mut().set(csr::PMPADDR0, PMPADDR_MASK);
+        hart.csrs_mut()
+            .set(csr::PMPCFG0, u64::from(PMP_A_NAPOT | PMP_R | PMP_W | PMP_X));
+        bus.write32(0x1000, inst).unwrap();
+
+        (hart, bus)
+    }
+
+    #[test]
+    fn rv32_isa_string_and_misa() {
+        let (hart, _) = setup_rv32(ILLEGAL);
+        let isa = hart.isa_string();
+
+        assert!(isa.starts_with("rv32imafdc_zicbom_"));
+        assert!(!isa.contains("_zk"));
+        assert_eq!(hart.csrs().get(csr::MISA) >> 30, 1);
+    }
+
+    #[test]
+    fn rv32_registers_hold_sign_extended_words() {
+        let (mut hart, _) = setup_rv32(ILLEGAL);
+
+        hart.set_xreg(1, 0x1_8000_0000);
+
+        assert_eq!(hart.xreg(1), 0xffff_ffff_8000_0000);
+        assert_eq!(hart.uxreg(1), 0x8000_0000);
+    }
+
+    #[test]
+    fn rv32_rejects_rv64_only_instructions() {
+        // ld x1, 0(x0); addiw x1, x1, 1
+        for inst in [0x0000_3083, 0x0010_809b] {
+            let (mut hart, mut bus) = setup_rv32(inst);
+
+            hart.step(&mut bus).unwrap();
+
+            assert_eq!(hart.pc(), 0x2000);
+            assert_eq!(hart.csrs().get(csr::MCAUSE), 2);
+        }
+    }
+
+    #[test]
+    fn rv32_pc_wraps_at_4_gib() {
+        // jalr x0, -4(x0)
+        let (mut hart, mut bus) = setup_rv32(0xffc0_0067);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.pc(), 0xffff_fffc);
+    }
+
+    #[test]
+    fn rv32_interrupt_cause_uses_bit_31() {
+        let (mut hart, _) = setup_rv32(ILLEGAL);
+
+        hart.enter_trap((1 << 63) | 7, 0, 0, false);
+
+        assert_eq!(hart.csrs().get(csr::MCAUSE), (1 << 31) | 7);
+    }
+
+    #[test]
+    fn rv32_fetches_through_sv32() {
+        // addi x1, x0, 5
+        let (mut hart, mut bus) = setup_rv32(0x0050_0093);
+        // 0x8040_1000: VPN[1] = 0x201, VPN[0] = 0x001.
+        bus.write32(0x4000 + 0x201 * 4, (0x5 << 10) | 1).unwrap();
+        bus.write32(0x5000 + 4, (0x1 << 10) | 0xcb).unwrap();
+        hart.csrs_mut().set(csr::SATP, (1 << 31) | 0x4);
+        hart.set_privilege(Privilege::Supervisor);
+        hart.set_pc(0x8040_1000);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(hart.xreg(1), 5);
+        assert_eq!(hart.pc(), 0x8040_1004);
     }
 }
@@ -2,10 +2,20 @@ use std::{fmt::Debug, sync::LazyLock};
 
 use crate::{
     memory::Bus,
-    processor::riscv::{exception::Trap, extensions::i, hart::Hart},
+    processor::riscv::{
+        exception::Trap,
+        extensions::{
+            a, b, c, d, f, h, i, k, m, privileged, v, zawrs, zfh, zicbo, zicond, zicsr, zifencei,
+            zihintpause, zimop,
+        },
+        hart::Hart,
+    },
 };
 
 pub static ISA: LazyLock<InstrTable> = LazyLock::new(InstrTable::default);
+pub static RVC: LazyLock<ExpandTable> = LazyLock::new(ExpandTable::default);
+pub static ISA32: LazyLock<InstrTable> = LazyLock::new(InstrTable::rv32);
+pub static RVC32: LazyLock<ExpandTable> = LazyLock::new(ExpandTable::rv32);
 
 pub trait InstrExec: Debug + Send + Sync {
     fn matches(&self, inst: u32) -> bool;
@@ -13,6 +23,13 @@ pub trait InstrExec: Debug + Send + Sync {
     fn call(&self, inst: u32, hart: &mut Hart, bus: &mut dyn Bus) -> anyhow::Result<()>;
 }
 
+/// A compressed instruction, executed as the 32-bit instruction it expands to.
+pub trait InstrExpand: Debug + Send + Sync {
+    fn matches(&self, inst: u16) -> bool;
+
+    fn expand(&self, inst: u16) -> u32;
+}
+
 #[derive(Debug)]
 pub struct InstrTable(Box<[Box<dyn InstrExec>]>);
 
@@ -25,15 +42,24 @@ impl InstrTable {
         }
         Err(Trap::IllegalInstruction { inst }.into())
     }
-}
 
-impl Default for InstrTable {
-    fn default() -> Self {
-        let table: Vec<Box<dyn InstrExec>> = vec![
+    /// The RV32 instruction set: the instructions both XLENs share plus the
+    /// RV32 encodings of `rev8` and `zext.h`, the latter ahead of the `pack`
+    /// it is a special case of.
+    pub fn rv32() -> Self {
+        let mut table: Vec<Box<dyn InstrExec>> =
+            vec![Box::new(b::rev8::Rev8Rv32), Box::new(b::zext_h::ZextHRv32)];
+        table.extend(Self::common());
+
+        Self(table.into_boxed_slice())
+    }
+
+    /// Instructions shared by RV32 and RV64, which look at [`Hart::xlen`]
+    /// where their behaviour depends on it.
+    fn common() -> Vec<Box<dyn InstrExec>> {
+        vec![
             Box::new(i::add::Add),
             Box::new(i::addi::Addi),
-            Box::new(i::addiw::Addiw),
-            Box::new(i::addw::Addw),
             Box::new(i::and::And),
             Box::new(i::andi::Andi),
             Box::new(i::auipc::Auipc),
@@ -43,46 +69,575 @@ impl Default for InstrTable {
             Box::new(i::blt::Blt),
             Box::new(i::bltu::Bltu),
             Box::new(i::bne::Bne),
-            Box::new(i::ecall::Ebreak),
-            Box::new(i::ebreak::Ecall),
+            Box::new(i::ebreak::Ebreak),
+            Box::new(i::ecall::Ecall),
+            Box::new(zihintpause::pause::Pause),
             Box::new(i::fence::Fence),
+            Box::new(zifencei::fence_i::FenceI),
             Box::new(i::jal::Jal),
             Box::new(i::jalr::Jalr),
             Box::new(i::lb::Lb),
             Box::new(i::lbu::Lbu),
-            Box::new(i::ld::Ld),
             Box::new(i::lh::Lh),
             Box::new(i::lhu::Lhu),
             Box::new(i::lui::Lui),
             Box::new(i::lw::Lw),
-            Box::new(i::lwu::Lwu),
             Box::new(i::or::Or),
+            Box::new(zicbo::prefetch_i::PrefetchI),
+            Box::new(zicbo::prefetch_r::PrefetchR),
+            Box::new(zicbo::prefetch_w::PrefetchW),
             Box::new(i::ori::Ori),
             Box::new(i::sb::Sb),
-            Box::new(i::sd::Sd),
             Box::new(i::sh::Sh),
             Box::new(i::sll::Sll),
             Box::new(i::slli::Slli),
-            Box::new(i::slliw::Slliw),
-            Box::new(i::sllw::Sllw),
             Box::new(i::slt::Slt),
             Box::new(i::slti::Slti),
             Box::new(i::sltiu::Sltiu),
             Box::new(i::sltu::Sltu),
             Box::new(i::sra::Sra),
             Box::new(i::srai::Srai),
-            Box::new(i::sraiw::Sraiw),
-            Box::new(i::sraw::Sraw),
             Box::new(i::srl::Srl),
             Box::new(i::srli::Srli),
-            Box::new(i::srliw::Srliw),
-            Box::new(i::srlw::Srlw),
             Box::new(i::sub::Sub),
-            Box::new(i::subw::Subw),
             Box::new(i::sw::Sw),
             Box::new(i::xor::Xor),
             Box::new(i::xori::Xori),
+            Box::new(m::div::Div),
+            Box::new(m::divu::Divu),
+            Box::new(m::mul::Mul),
+            Box::new(m::mulh::Mulh),
+            Box::new(m::mulhsu::Mulhsu),
+            Box::new(m::mulhu::Mulhu),
+            Box::new(m::rem::Rem),
+            Box::new(m::remu::Remu),
+            Box::new(privileged::mret::Mret),
+            Box::new(privileged::sret::Sret),
+            Box::new(privileged::sfence_vma::SfenceVma),
+            Box::new(privileged::wfi::Wfi),
+            Box::new(h::hfence_gvma::HfenceGvma),
+            Box::new(h::hfence_vvma::HfenceVvma),
+            Box::new(h::hlv_b::HlvB),
+            Box::new(h::hlv_bu::HlvBu),
+            Box::new(h::hlv_h::HlvH),
+            Box::new(h::hlv_hu::HlvHu),
+            Box::new(h::hlv_w::HlvW),
+            Box::new(h::hlvx_hu::HlvxHu),
+            Box::new(h::hlvx_wu::HlvxWu),
+            Box::new(h::hsv_b::HsvB),
+            Box::new(h::hsv_h::HsvH),
+            Box::new(h::hsv_w::HsvW),
+            Box::new(zicond::czero_eqz::CzeroEqz),
+            Box::new(zicond::czero_nez::CzeroNez),
+            Box::new(zawrs::wrs_nto::WrsNto),
+            Box::new(zawrs::wrs_sto::WrsSto),
+            Box::new(zicbo::cbo_clean::CboClean),
+            Box::new(zicbo::cbo_flush::CboFlush),
+            Box::new(zicbo::cbo_inval::CboInval),
+            Box::new(zicbo::cbo_zero::CboZero),
+            Box::new(zimop::mop_r::MopR),
+            Box::new(zimop::mop_rr::MopRr),
+            Box::new(zicsr::csrrc::Csrrc),
+            Box::new(zicsr::csrrci::Csrrci),
+            Box::new(zicsr::csrrs::Csrrs),
+            Box::new(zicsr::csrrsi::Csrrsi),
+            Box::new(zicsr::csrrw::Csrrw),
+            Box::new(zicsr::csrrwi::Csrrwi),
+            Box::new(f::fadd_s::FaddS),
+            Box::new(f::fclass_s::FclassS),
+            Box::new(f::fcvt_s_w::FcvtSW),
+            Box::new(f::fcvt_s_wu::FcvtSWu),
+            Box::new(f::fcvt_w_s::FcvtWS),
+            Box::new(f::fcvt_wu_s::FcvtWuS),
+            Box::new(f::fdiv_s::FdivS),
+            Box::new(f::feq_s::FeqS),
+            Box::new(f::fle_s::FleS),
+            Box::new(f::flt_s::FltS),
+            Box::new(f::flw::Flw),
+            Box::new(f::fmadd_s::FmaddS),
+            Box::new(f::fmax_s::FmaxS),
+            Box::new(f::fmin_s::FminS),
+            Box::new(f::fmsub_s::FmsubS),
+            Box::new(f::fmul_s::FmulS),
+            Box::new(f::fmv_w_x::FmvWX),
+            Box::new(f::fmv_x_w::FmvXW),
+            Box::new(f::fnmadd_s::FnmaddS),
+            Box::new(f::fnmsub_s::FnmsubS),
+            Box::new(f::fsgnj_s::FsgnjS),
+            Box::new(f::fsgnjn_s::FsgnjnS),
+            Box::new(f::fsgnjx_s::FsgnjxS),
+            Box::new(f::fsqrt_s::FsqrtS),
+            Box::new(f::fsub_s::FsubS),
+            Box::new(f::fsw::Fsw),
+            Box::new(d::fadd_d::FaddD),
+            Box::new(d::fclass_d::FclassD),
+            Box::new(d::fcvt_d_s::FcvtDS),
+            Box::new(d::fcvt_d_w::FcvtDW),
+            Box::new(d::fcvt_d_wu::FcvtDWu),
+            Box::new(d::fcvt_s_d::FcvtSD),
+            Box::new(d::fcvt_w_d::FcvtWD),
+            Box::new(d::fcvt_wu_d::FcvtWuD),
+            Box::new(d::fdiv_d::FdivD),
+            Box::new(d::feq_d::FeqD),
+            Box::new(d::fld::Fld),
+            Box::new(d::fle_d::FleD),
+            Box::new(d::flt_d::FltD),
+            Box::new(d::fmadd_d::FmaddD),
+            Box::new(d::fmax_d::FmaxD),
+            Box::new(d::fmin_d::FminD),
+            Box::new(d::fmsub_d::FmsubD),
+            Box::new(d::fmul_d::FmulD),
+            Box::new(d::fnmadd_d::FnmaddD),
+            Box::new(d::fnmsub_d::FnmsubD),
+            Box::new(d::fsd::Fsd),
+            Box::new(d::fsgnj_d::FsgnjD),
+            Box::new(d::fsgnjn_d::FsgnjnD),
+            Box::new(d::fsgnjx_d::FsgnjxD),
+            Box::new(d::fsqrt_d::FsqrtD),
+            Box::new(d::fsub_d::FsubD),
+            Box::new(zfh::fadd_h::FaddH),
+            Box::new(zfh::fclass_h::FclassH),
+            Box::new(zfh::fcvt_d_h::FcvtDH),
+            Box::new(zfh::fcvt_h_d::FcvtHD),
+            Box::new(zfh::fcvt_h_s::FcvtHS),
+            Box::new(zfh::fcvt_h_w::FcvtHW),
+            Box::new(zfh::fcvt_h_wu::FcvtHWu),
+            Box::new(zfh::fcvt_s_h::FcvtSH),
+            Box::new(zfh::fcvt_w_h::FcvtWH),
+            Box::new(zfh::fcvt_wu_h::FcvtWuH),
+            Box::new(zfh::fdiv_h::FdivH),
+            Box::new(zfh::feq_h::FeqH),
+            Box::new(zfh::fle_h::FleH),
+            Box::new(zfh::flh::Flh),
+            Box::new(zfh::flt_h::FltH),
+            Box::new(zfh::fmadd_h::FmaddH),
+            Box::new(zfh::fmax_h::FmaxH),
+            Box::new(zfh::fmin_h::FminH),
+            Box::new(zfh::fmsub_h::FmsubH),
+            Box::new(zfh::fmul_h::FmulH),
+            Box::new(zfh::fmv_h_x::FmvHX),
+            Box::new(zfh::fmv_x_h::FmvXH),
+            Box::new(zfh::fnmadd_h::FnmaddH),
+            Box::new(zfh::fnmsub_h::FnmsubH),
+            Box::new(zfh::fsgnj_h::FsgnjH),
+            Box::new(zfh::fsgnjn_h::FsgnjnH),
+            Box::new(zfh::fsgnjx_h::FsgnjxH),
+            Box::new(zfh::fsh::Fsh),
+            Box::new(zfh::fsqrt_h::FsqrtH),
+            Box::new(zfh::fsub_h::FsubH),
+            Box::new(b::andn::Andn),
+            Box::new(b::bclr::Bclr),
+            Box::new(b::bclri::Bclri),
+            Box::new(b::bext::Bext),
+            Box::new(b::bexti::Bexti),
+            Box::new(b::binv::Binv),
+            Box::new(b::binvi::Binvi),
+            Box::new(b::bset::Bset),
+            Box::new(b::bseti::Bseti),
+            Box::new(b::clmul::Clmul),
+            Box::new(b::clmulh::Clmulh),
+            Box::new(b::clmulr::Clmulr),
+            Box::new(b::clz::Clz),
+            Box::new(b::cpop::Cpop),
+            Box::new(b::ctz::Ctz),
+            Box::new(b::max::Max),
+            Box::new(b::maxu::Maxu),
+            Box::new(b::min::Min),
+            Box::new(b::minu::Minu),
+            Box::new(b::orc_b::OrcB),
+            Box::new(b::orn::Orn),
+            Box::new(b::rol::Rol),
+            Box::new(b::ror::Ror),
+            Box::new(b::rori::Rori),
+            Box::new(b::sext_b::SextB),
+            Box::new(b::sext_h::SextH),
+            Box::new(b::sh1add::Sh1add),
+            Box::new(b::sh2add::Sh2add),
+            Box::new(b::sh3add::Sh3add),
+            Box::new(b::xnor::Xnor),
+            Box::new(k::brev8::Brev8),
+            Box::new(k::pack::Pack),
+            Box::new(k::packh::Packh),
+            Box::new(k::sha256sig0::Sha256sig0),
+            Box::new(k::sha256sig1::Sha256sig1),
+            Box::new(k::sha256sum0::Sha256sum0),
+            Box::new(k::sha256sum1::Sha256sum1),
+            Box::new(k::sm3p0::Sm3p0),
+            Box::new(k::sm3p1::Sm3p1),
+            Box::new(k::sm4ed::Sm4ed),
+            Box::new(k::sm4ks::Sm4ks),
+            Box::new(k::xperm4::Xperm4),
+            Box::new(k::xperm8::Xperm8),
+            Box::new(a::amoadd_w::AmoaddW),
+            Box::new(a::amoand_w::AmoandW),
+            Box::new(a::amomax_w::AmomaxW),
+            Box::new(a::amomaxu_w::AmomaxuW),
+            Box::new(a::amomin_w::AmominW),
+            Box::new(a::amominu_w::AmominuW),
+            Box::new(a::amoor_w::AmoorW),
+            Box::new(a::amoswap_w::AmoswapW),
+            Box::new(a::amoxor_w::AmoxorW),
+            Box::new(a::lr_w::LrW),
+            Box::new(a::sc_w::ScW),
+            Box::new(v::vaadd::Vaadd),
+            Box::new(v::vaaddu::Vaaddu),
+            Box::new(v::vadc::Vadc),
+            Box::new(v::vadd::Vadd),
+            Box::new(v::vand::Vand),
+            Box::new(v::vasub::Vasub),
+            Box::new(v::vasubu::Vasubu),
+            Box::new(v::vcompress::Vcompress),
+            Box::new(v::vcpop::Vcpop),
+            Box::new(v::vdiv::Vdiv),
+            Box::new(v::vdivu::Vdivu),
+            Box::new(v::vfadd::Vfadd),
+            Box::new(v::vfclass::Vfclass),
+            Box::new(v::vfcvt_f_x::VfcvtFX),
+            Box::new(v::vfcvt_f_xu::VfcvtFXu),
+            Box::new(v::vfcvt_rtz_x_f::VfcvtRtzXF),
+            Box::new(v::vfcvt_rtz_xu_f::VfcvtRtzXuF),
+            Box::new(v::vfcvt_x_f::VfcvtXF),
+            Box::new(v::vfcvt_xu_f::VfcvtXuF),
+            Box::new(v::vfdiv::Vfdiv),
+            Box::new(v::vfirst::Vfirst),
+            Box::new(v::vfmacc::Vfmacc),
+            Box::new(v::vfmadd::Vfmadd),
+            Box::new(v::vfmax::Vfmax),
+            Box::new(v::vfmerge::Vfmerge),
+            Box::new(v::vfmin::Vfmin),
+            Box::new(v::vfmsac::Vfmsac),
+            Box::new(v::vfmsub::Vfmsub),
+            Box::new(v::vfmul::Vfmul),
+            Box::new(v::vfmv_f_s::VfmvFS),
+            Box::new(v::vfmv_s_f::VfmvSF),
+            Box::new(v::vfmv_v_f::VfmvVF),
+            Box::new(v::vfncvt_f_f::VfncvtFF),
+            Box::new(v::vfncvt_f_x::VfncvtFX),
+            Box::new(v::vfncvt_f_xu::VfncvtFXu),
+            Box::new(v::vfncvt_rod_f_f::VfncvtRodFF),
+            Box::new(v::vfncvt_rtz_x_f::VfncvtRtzXF),
+            Box::new(v::vfncvt_rtz_xu_f::VfncvtRtzXuF),
+            Box::new(v::vfncvt_x_f::VfncvtXF),
+            Box::new(v::vfncvt_xu_f::VfncvtXuF),
+            Box::new(v::vfnmacc::Vfnmacc),
+            Box::new(v::vfnmadd::Vfnmadd),
+            Box::new(v::vfnmsac::Vfnmsac),
+            Box::new(v::vfnmsub::Vfnmsub),
+            Box::new(v::vfrdiv::Vfrdiv),
+            Box::new(v::vfrec7::Vfrec7),
+            Box::new(v::vfredmax::Vfredmax),
+            Box::new(v::vfredmin::Vfredmin),
+            Box::new(v::vfredosum::Vfredosum),
+            Box::new(v::vfredusum::Vfredusum),
+            Box::new(v::vfrsqrt7::Vfrsqrt7),
+            Box::new(v::vfrsub::Vfrsub),
+            Box::new(v::vfsgnj::Vfsgnj),
+            Box::new(v::vfsgnjn::Vfsgnjn),
+            Box::new(v::vfsgnjx::Vfsgnjx),
+            Box::new(v::vfslide1down::Vfslide1down),
+            Box::new(v::vfslide1up::Vfslide1up),
+            Box::new(v::vfsqrt::Vfsqrt),
+            Box::new(v::vfsub::Vfsub),
+            Box::new(v::vfwadd::Vfwadd),
+            Box::new(v::vfwadd_w::VfwaddW),
+            Box::new(v::vfwcvt_f_f::VfwcvtFF),
+            Box::new(v::vfwcvt_f_x::VfwcvtFX),
+            Box::new(v::vfwcvt_f_xu::VfwcvtFXu),
+            Box::new(v::vfwcvt_rtz_x_f::VfwcvtRtzXF),
+            Box::new(v::vfwcvt_rtz_xu_f::VfwcvtRtzXuF),
+            Box::new(v::vfwcvt_x_f::VfwcvtXF),
+            Box::new(v::vfwcvt_xu_f::VfwcvtXuF),
+            Box::new(v::vfwmacc::Vfwmacc),
+            Box::new(v::vfwmsac::Vfwmsac),
+            Box::new(v::vfwmul::Vfwmul),
+            Box::new(v::vfwnmacc::Vfwnmacc),
+            Box::new(v::vfwnmsac::Vfwnmsac),
+            Box::new(v::vfwredosum::Vfwredosum),
+            Box::new(v::vfwredusum::Vfwredusum),
+            Box::new(v::vfwsub::Vfwsub),
+            Box::new(v::vfwsub_w::VfwsubW),
+            Box::new(v::vid::Vid),
+            Box::new(v::viota::Viota),
+            Box::new(v::vle::Vle),
+            Box::new(v::vleff::Vleff),
+            Box::new(v::vlm::Vlm),
+            Box::new(v::vloxei::Vloxei),
+            Box::new(v::vlr::Vlr),
+            Box::new(v::vlse::Vlse),
+            Box::new(v::vluxei::Vluxei),
+            Box::new(v::vmacc::Vmacc),
+            Box::new(v::vmadc::Vmadc),
+            Box::new(v::vmadd::Vmadd),
+            Box::new(v::vmand::Vmand),
+            Box::new(v::vmandn::Vmandn),
+            Box::new(v::vmax::Vmax),
+            Box::new(v::vmaxu::Vmaxu),
+            Box::new(v::vmerge::Vmerge),
+            Box::new(v::vmfeq::Vmfeq),
+            Box::new(v::vmfge::Vmfge),
+            Box::new(v::vmfgt::Vmfgt),
+            Box::new(v::vmfle::Vmfle),
+            Box::new(v::vmflt::Vmflt),
+            Box::new(v::vmfne::Vmfne),
+            Box::new(v::vmin::Vmin),
+            Box::new(v::vminu::Vminu),
+            Box::new(v::vmnand::Vmnand),
+            Box::new(v::vmnor::Vmnor),
+            Box::new(v::vmor::Vmor),
+            Box::new(v::vmorn::Vmorn),
+            Box::new(v::vmsbc::Vmsbc),
+            Box::new(v::vmsbf::Vmsbf),
+            Box::new(v::vmseq::Vmseq),
+            Box::new(v::vmsgt::Vmsgt),
+            Box::new(v::vmsgtu::Vmsgtu),
+            Box::new(v::vmsif::Vmsif),
+            Box::new(v::vmsle::Vmsle),
+            Box::new(v::vmsleu::Vmsleu),
+            Box::new(v::vmslt::Vmslt),
+            Box::new(v::vmsltu::Vmsltu),
+            Box::new(v::vmsne::Vmsne),
+            Box::new(v::vmsof::Vmsof),
+            Box::new(v::vmul::Vmul),
+            Box::new(v::vmulh::Vmulh),
+            Box::new(v::vmulhsu::Vmulhsu),
+            Box::new(v::vmulhu::Vmulhu),
+            Box::new(v::vmv_nr_r::VmvNrR),
+            Box::new(v::vmv_s_x::VmvSX),
+            Box::new(v::vmv_v::VmvV),
+            Box::new(v::vmv_x_s::VmvXS),
+            Box::new(v::vmxnor::Vmxnor),
+            Box::new(v::vmxor::Vmxor),
+            Box::new(v::vnclip::Vnclip),
+            Box::new(v::vnclipu::Vnclipu),
+            Box::new(v::vnmsac::Vnmsac),
+            Box::new(v::vnmsub::Vnmsub),
+            Box::new(v::vnsra::Vnsra),
+            Box::new(v::vnsrl::Vnsrl),
+            Box::new(v::vor::Vor),
+            Box::new(v::vredand::Vredand),
+            Box::new(v::vredmax::Vredmax),
+            Box::new(v::vredmaxu::Vredmaxu),
+            Box::new(v::vredmin::Vredmin),
+            Box::new(v::vredminu::Vredminu),
+            Box::new(v::vredor::Vredor),
+            Box::new(v::vredsum::Vredsum),
+            Box::new(v::vredxor::Vredxor),
+            Box::new(v::vrem::Vrem),
+            Box::new(v::vremu::Vremu),
+            Box::new(v::vrgather::Vrgather),
+            Box::new(v::vrgatherei16::Vrgatherei16),
+            Box::new(v::vrsub::Vrsub),
+            Box::new(v::vsadd::Vsadd),
+            Box::new(v::vsaddu::Vsaddu),
+            Box::new(v::vsbc::Vsbc),
+            Box::new(v::vse::Vse),
+            Box::new(v::vsetivli::Vsetivli),
+            Box::new(v::vsetvl::Vsetvl),
+            Box::new(v::vsetvli::Vsetvli),
+            Box::new(v::vsext::Vsext),
+            Box::new(v::vslide1down::Vslide1down),
+            Box::new(v::vslide1up::Vslide1up),
+            Box::new(v::vslidedown::Vslidedown),
+            Box::new(v::vslideup::Vslideup),
+            Box::new(v::vsll::Vsll),
+            Box::new(v::vsm::Vsm),
+            Box::new(v::vsmul::Vsmul),
+            Box::new(v::vsoxei::Vsoxei),
+            Box::new(v::vsr::Vsr),
+            Box::new(v::vsra::Vsra),
+            Box::new(v::vsrl::Vsrl),
+            Box::new(v::vsse::Vsse),
+            Box::new(v::vssra::Vssra),
+            Box::new(v::vssrl::Vssrl),
+            Box::new(v::vssub::Vssub),
+            Box::new(v::vssubu::Vssubu),
+            Box::new(v::vsub::Vsub),
+            Box::new(v::vsuxei::Vsuxei),
+            Box::new(v::vwadd::Vwadd),
+            Box::new(v::vwadd_w::VwaddW),
+            Box::new(v::vwaddu::Vwaddu),
+            Box::new(v::vwaddu_w::VwadduW),
+            Box::new(v::vwmacc::Vwmacc),
+            Box::new(v::vwmaccsu::Vwmaccsu),
+            Box::new(v::vwmaccu::Vwmaccu),
+            Box::new(v::vwmaccus::Vwmaccus),
+            Box::new(v::vwmul::Vwmul),
+            Box::new(v::vwmulsu::Vwmulsu),
+            Box::new(v::vwmulu::Vwmulu),
+            Box::new(v::vwredsum::Vwredsum),
+            Box::new(v::vwredsumu::Vwredsumu),
+            Box::new(v::vwsub::Vwsub),
+            Box::new(v::vwsub_w::VwsubW),
+            Box::new(v::vwsubu::Vwsubu),
+            Box::new(v::vwsubu_w::VwsubuW),
+            Box::new(v::vxor::Vxor),
+            Box::new(v::vzext::Vzext),
+        ]
+    }
+}
+
+/// The RV64 instruction set.
+impl Default for InstrTable {
+    fn default() -> Self {
+        let mut table: Vec<Box<dyn InstrExec>> = vec![
+            Box::new(i::addiw::Addiw),
+            Box::new(i::addw::Addw),
+            Box::new(i::ld::Ld),
+            Box::new(i::lwu::Lwu),
+            Box::new(i::sd::Sd),
+            Box::new(i::slliw::Slliw),
+            Box::new(i::sllw::Sllw),
+            Box::new(i::sraiw::Sraiw),
+            Box::new(i::sraw::Sraw),
+            Box::new(i::srliw::Srliw),
+            Box::new(i::srlw::Srlw),
+            Box::new(i::subw::Subw),
+            Box::new(m::divuw::Divuw),
+            Box::new(m::divw::Divw),
+            Box::new(m::mulw::Mulw),
+            Box::new(m::remuw::Remuw),
+            Box::new(m::remw::Remw),
+            Box::new(h::hlv_d::HlvD),
+            Box::new(h::hlv_wu::HlvWu),
+            Box::new(h::hsv_d::HsvD),
+            Box::new(f::fcvt_l_s::FcvtLS),
+            Box::new(f::fcvt_lu_s::FcvtLuS),
+            Box::new(f::fcvt_s_l::FcvtSL),
+            Box::new(f::fcvt_s_lu::FcvtSLu),
+            Box::new(d::fcvt_d_l::FcvtDL),
+            Box::new(d::fcvt_d_lu::FcvtDLu),
+            Box::new(d::fcvt_l_d::FcvtLD),
+            Box::new(d::fcvt_lu_d::FcvtLuD),
+            Box::new(d::fmv_d_x::FmvDX),
+            Box::new(d::fmv_x_d::FmvXD),
+            Box::new(zfh::fcvt_h_l::FcvtHL),
+            Box::new(zfh::fcvt_h_lu::FcvtHLu),
+            Box::new(zfh::fcvt_l_h::FcvtLH),
+            Box::new(zfh::fcvt_lu_h::FcvtLuH),
+            Box::new(b::add_uw::AddUw),
+            Box::new(b::clzw::Clzw),
+            Box::new(b::cpopw::Cpopw),
+            Box::new(b::ctzw::Ctzw),
+            Box::new(b::rev8::Rev8),
+            Box::new(b::rolw::Rolw),
+            Box::new(b::roriw::Roriw),
+            Box::new(b::rorw::Rorw),
+            Box::new(b::sh1add_uw::Sh1addUw),
+            Box::new(b::sh2add_uw::Sh2addUw),
+            Box::new(b::sh3add_uw::Sh3addUw),
+            Box::new(b::slli_uw::SlliUw),
+            Box::new(b::zext_h::ZextH),
+            Box::new(k::aes64ds::Aes64ds),
+            Box::new(k::aes64dsm::Aes64dsm),
+            Box::new(k::aes64es::Aes64es),
+            Box::new(k::aes64esm::Aes64esm),
+            Box::new(k::aes64im::Aes64im),
+            Box::new(k::aes64ks1i::Aes64ks1i),
+            Box::new(k::aes64ks2::Aes64ks2),
+            Box::new(k::packw::Packw),
+            Box::new(k::sha512sig0::Sha512sig0),
+            Box::new(k::sha512sig1::Sha512sig1),
+            Box::new(k::sha512sum0::Sha512sum0),
+            Box::new(k::sha512sum1::Sha512sum1),
+            Box::new(a::amoadd_d::AmoaddD),
+            Box::new(a::amoand_d::AmoandD),
+            Box::new(a::amomax_d::AmomaxD),
+            Box::new(a::amomaxu_d::AmomaxuD),
+            Box::new(a::amomin_d::AmominD),
+            Box::new(a::amominu_d::AmominuD),
+            Box::new(a::amoor_d::AmoorD),
+            Box::new(a::amoswap_d::AmoswapD),
+            Box::new(a::amoxor_d::AmoxorD),
+            Box::new(a::lr_d::LrD),
+            Box::new(a::sc_d::ScD),
+        ];
+        table.extend(Self::common());
+
+        Self(table.into_boxed_slice())
+    }
+}
+
+#[derive(Debug)]
+pub struct ExpandTable(Box<[Box<dyn InstrExpand>]>);
+
+impl ExpandTable {
+    /// Returns the 32-bit equivalent of `inst`, or `None` for reserved
+    /// encodings.
+    pub fn expand(&self, inst: u16) -> Option<u32> {
+        for exp in self.0.iter() {
+            if exp.matches(inst) {
+                return Some(exp.expand(inst));
+            }
+        }
+        None
+    }
+
+    /// The RV32 compressed instructions: C.JAL and the single-precision
+    /// loads and stores take the encodings RV64 uses for C.ADDIW and the
+    /// doubleword loads and stores.
+    pub fn rv32() -> Self {
+        let mut table: Vec<Box<dyn InstrExpand>> = vec![
+            Box::new(c::c_flw::CFlw),
+            Box::new(c::c_flwsp::CFlwsp),
+            Box::new(c::c_fsw::CFsw),
+            Box::new(c::c_fswsp::CFswsp),
+            Box::new(c::c_jal::CJal),
+        ];
+        table.extend(Self::common());
+
+        Self(table.into_boxed_slice())
+    }
+
+    fn common() -> Vec<Box<dyn InstrExpand>> {
+        vec![
+            Box::new(c::c_add::CAdd),
+            Box::new(c::c_addi::CAddi),
+            Box::new(c::c_addi16sp::CAddi16sp),
+            Box::new(c::c_addi4spn::CAddi4spn),
+            Box::new(c::c_and::CAnd),
+            Box::new(c::c_andi::CAndi),
+            Box::new(c::c_beqz::CBeqz),
+            Box::new(c::c_bnez::CBnez),
+            Box::new(c::c_ebreak::CEbreak),
+            Box::new(c::c_fld::CFld),
+            Box::new(c::c_fldsp::CFldsp),
+            Box::new(c::c_fsd::CFsd),
+            Box::new(c::c_fsdsp::CFsdsp),
+            Box::new(c::c_j::CJ),
+            Box::new(c::c_jalr::CJalr),
+            Box::new(c::c_jr::CJr),
+            Box::new(c::c_li::CLi),
+            Box::new(c::c_lui::CLui),
+            Box::new(c::c_lw::CLw),
+            Box::new(c::c_lwsp::CLwsp),
+            Box::new(c::c_mop::CMop),
+            Box::new(c::c_mv::CMv),
+            Box::new(c::c_nop::CNop),
+            Box::new(c::c_or::COr),
+            Box::new(c::c_slli::CSlli),
+            Box::new(c::c_srai::CSrai),
+            Box::new(c::c_srli::CSrli),
+            Box::new(c::c_sub::CSub),
+            Box::new(c::c_sw::CSw),
+            Box::new(c::c_swsp::CSwsp),
+            Box::new(c::c_xor::CXor),
+        ]
+    }
+}
+
+/// The RV64 compressed instructions.
+impl Default for ExpandTable {
+    fn default() -> Self {
+        let mut table: Vec<Box<dyn InstrExpand>> = vec![
+            Box::new(c::c_addiw::CAddiw),
+            Box::new(c::c_addw::CAddw),
+            Box::new(c::c_ld::CLd),
+            Box::new(c::c_ldsp::CLdsp),
+            Box::new(c::c_sd::CSd),
+            Box::new(c::c_sdsp::CSdsp),
+            Box::new(c::c_subw::CSubw),
         ];
+        table.extend(Self::common());
 
         Self(table.into_boxed_slice())
     }
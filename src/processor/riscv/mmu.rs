@@ -0,0 +1,987 @@
+use crate::{
+    memory::{Bus, exception::Trap as MemoryTrap},
+    processor::riscv::{
+        csr,
+        exception::Trap,
+        hart::Privilege,
+        tlb::{Leaf, Tlb, TlbStats},
+    },
+};
+
+pub const SATP_MODE_BARE: u64 = 0;
+/// Sv32, as encoded in the RV64 layout of `satp` that RV32 harts widen their
+/// `satp` to; see [`Context::satp`].
+pub const SATP_MODE_SV32: u64 = 1;
+pub const SATP_MODE_SV39: u64 = 8;
+pub const SATP_MODE_SV48: u64 = 9;
+pub const SATP_MODE_SV57: u64 = 10;
+
+pub const PTE_V: u64 = 1 << 0;
+pub const PTE_R: u64 = 1 << 1;
+pub const PTE_W: u64 = 1 << 2;
+pub const PTE_X: u64 = 1 << 3;
+pub const PTE_U: u64 = 1 << 4;
+pub const PTE_G: u64 = 1 << 5;
+pub const PTE_A: u64 = 1 << 6;
+pub const PTE_D: u64 = 1 << 7;
+
+pub const PAGE_SIZE: u64 = 1 << PAGE_SHIFT;
+
+const PAGE_SHIFT: u32 = 12;
+const PTE_PPN_MASK: u64 = (1 << 44) - 1;
+/// Bits 63:54 hold N, PBMT and reserved fields; none of the extensions that
+/// give them meaning are implemented, so they must be zero.
+const PTE_RESERVED: u64 = 0x3ff << 54;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+    Fetch,
+    Load,
+    Store,
+}
+
+impl Access {
+    const fn page_fault(self, addr: u64) -> Trap {
+        match self {
+            Self::Fetch => Trap::FetchPageFault { addr },
+            Self::Load => Trap::LoadPageFault { addr },
+            Self::Store => Trap::StorePageFault { addr },
+        }
+    }
+
+    pub(crate) const fn access_fault(self, addr: u64) -> MemoryTrap {
+        match self {
+            Self::Fetch => MemoryTrap::FetchAccessFault { addr },
+            Self::Load => MemoryTrap::LoadAccessFault { addr },
+            Self::Store => MemoryTrap::StoreAccessFault { addr },
+        }
+    }
+}
+
+/// The translation-relevant state of the hart at the time of an access.
+#[derive(Clone, Copy, Debug)]
+pub struct Context {
+    /// `satp`, or `vsatp` for a guest access, in the RV64 layout. RV32
+    /// harts move the mode, ASID and PPN fields of their `satp` into place.
+    pub satp: u64,
+    /// Effective privilege of the access, after `mstatus.MPRV` is applied.
+    pub privilege: Privilege,
+    /// `mstatus.SUM`, or `vsstatus.SUM` for a guest access.
+    pub sum: bool,
+    /// `mstatus.MXR`, which applies to both stages of a guest access.
+    pub mxr: bool,
+    /// `vsstatus.MXR`, which applies to the VS-stage only.
+    pub vmxr: bool,
+    /// `hgatp` for a guest access, which is translated in two stages; `None`
+    /// for a host access.
+    pub hgatp: Option<u64>,
+    /// Set for HLVX, whose loads need execute rather than read permission.
+    pub hlvx: bool,
+}
+
+impl Context {
+    const fn mode(&self) -> u64 {
+        self.satp >> 60
+    }
+
+    const fn asid(&self) -> u16 {
+        (self.satp >> 44) as u16
+    }
+
+    const fn levels(&self) -> Option<u32> {
+        match self.mode() {
+            SATP_MODE_SV32 => Some(2),
+            SATP_MODE_SV39 => Some(3),
+            SATP_MODE_SV48 => Some(4),
+            SATP_MODE_SV57 => Some(5),
+            _ => None,
+        }
+    }
+
+    /// VPN bits each level of the page table resolves.
+    const fn vpn_bits(&self) -> u32 {
+        if self.mode() == SATP_MODE_SV32 { 10 } else { 9 }
+    }
+
+    /// Size in bytes of the PTEs of the page table.
+    const fn pte_size(&self) -> u64 {
+        if self.mode() == SATP_MODE_SV32 { 4 } else { 8 }
+    }
+}
+
+/// The access a translation serves, which decides the exceptions that any
+/// stage of it reports.
+#[derive(Clone, Copy, Debug)]
+struct Origin {
+    vaddr: u64,
+    access: Access,
+}
+
+impl Origin {
+    const fn page_fault(self) -> Trap {
+        self.access.page_fault(self.vaddr)
+    }
+
+    const fn guest_page_fault(self, gpa: u64) -> Trap {
+        let addr = self.vaddr;
+        match self.access {
+            Access::Fetch => Trap::FetchGuestPageFault { addr, gpa },
+            Access::Load => Trap::LoadGuestPageFault { addr, gpa },
+            Access::Store => Trap::StoreGuestPageFault { addr, gpa },
+        }
+    }
+
+    const fn access_fault(self) -> MemoryTrap {
+        self.access.access_fault(self.vaddr)
+    }
+}
+
+/// One stage of translation: the page table to walk and the exception it
+/// raises.
+#[derive(Clone, Copy, Debug)]
+struct Stage {
+    root: u64,
+    levels: u32,
+    /// VPN bits each level resolves, ten for Sv32 and nine otherwise.
+    vpn_bits: u32,
+    /// Size in bytes of each PTE, four for Sv32 and eight otherwise.
+    pte_size: u64,
+    /// Extra VPN bits of the root table, two for the x4 G-stage modes.
+    root_bits: u32,
+    fault: Trap,
+}
+
+/// Stand-in leaf for a stage that is Bare, which permits everything.
+const BARE_PTE: u64 = PTE_V | PTE_R | PTE_W | PTE_X | PTE_U | PTE_A | PTE_D;
+
+#[derive(Debug, Default)]
+pub struct Mmu {
+    itlb: Tlb,
+    dtlb: Tlb,
+}
+
+impl Mmu {
+    /// Translates `vaddr` to a physical address. Cached translations are
+    /// used when they permit the access; otherwise the page table rooted at
+    /// `satp` is walked, updating the A/D bits of the leaf PTE in place.
+    /// Guest accesses are translated to a guest physical address first and
+    /// then through the G-stage table rooted at `hgatp`, which also
+    /// translates every VS-stage page-table access. Every PTE access made by
+    /// the walks must first pass `pmp`.
+    pub fn translate(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        access: Access,
+        ctx: Context,
+        pmp: impl Fn(u64, Access) -> bool,
+    ) -> anyhow::Result<u64> {
+        if ctx.privilege == Privilege::Machine {
+            return Ok(vaddr);
+        }
+        if let Some(hgatp) = ctx.hgatp {
+            return self.translate_guest(bus, vaddr, access, ctx, hgatp, pmp);
+        }
+        let Some(levels) = ctx.levels() else {
+            return Ok(vaddr);
+        };
+
+        let origin = Origin { vaddr, access };
+        // Sv32 translates every bit of an RV32 address.
+        if ctx.mode() != SATP_MODE_SV32 && !Self::canonical(vaddr, levels) {
+            return Err(origin.page_fault().into());
+        }
+
+        let asid = ctx.asid();
+        let tlb = self.tlb(access);
+        if let Some(leaf) = tlb.lookup(vaddr, asid, None)
+            && Self::usable(leaf.pte, access)
+            && Self::permitted(leaf.pte, access, ctx)
+        {
+            return Ok(Self::paddr(leaf.ppn, vaddr));
+        }
+
+        let stage = Stage {
+            root: (ctx.satp & PTE_PPN_MASK) << PAGE_SHIFT,
+            levels,
+            vpn_bits: ctx.vpn_bits(),
+            pte_size: ctx.pte_size(),
+            root_bits: 0,
+            fault: origin.page_fault(),
+        };
+        let locate = |_: &mut dyn Bus, paddr, access| {
+            if pmp(paddr, access) {
+                Ok(paddr)
+            } else {
+                Err(origin.access_fault().into())
+            }
+        };
+        let (paddr, pte, span) = Self::walk(
+            bus,
+            vaddr,
+            access,
+            origin,
+            stage,
+            |pte| Self::permitted(pte, access, ctx),
+            locate,
+        )?;
+        tlb.insert(
+            vaddr,
+            asid,
+            None,
+            Leaf {
+                pte,
+                gpte: 0,
+                span,
+                ppn: paddr >> PAGE_SHIFT,
+            },
+        );
+        Ok(paddr)
+    }
+
+    /// Translates a guest access through the VS-stage and then the G-stage.
+    fn translate_guest(
+        &mut self,
+        bus: &mut dyn Bus,
+        vaddr: u64,
+        access: Access,
+        ctx: Context,
+        hgatp: u64,
+        pmp: impl Fn(u64, Access) -> bool,
+    ) -> anyhow::Result<u64> {
+        let vs_levels = ctx.levels();
+        if vs_levels.is_none() && hgatp >> 60 == csr::HGATP_MODE_BARE {
+            return Ok(vaddr);
+        }
+
+        let origin = Origin { vaddr, access };
+        if vs_levels.is_some_and(|levels| !Self::canonical(vaddr, levels)) {
+            return Err(origin.page_fault().into());
+        }
+
+        let asid = if vs_levels.is_some() { ctx.asid() } else { 0 };
+        let vmid = Some(((hgatp & csr::HGATP_VMID) >> 44) as u16);
+        let tlb = self.tlb(access);
+        if let Some(leaf) = tlb.lookup(vaddr, asid, vmid)
+            && Self::usable(leaf.pte, access)
+            && Self::usable(leaf.gpte, access)
+            && Self::permitted(leaf.pte, access, ctx)
+            && Self::g_permitted(leaf.gpte, access, ctx)
+        {
+            return Ok(Self::paddr(leaf.ppn, vaddr));
+        }
+
+        let (gpa, pte, span) = match vs_levels {
+            Some(levels) => {
+                let stage = Stage {
+                    root: (ctx.satp & PTE_PPN_MASK) << PAGE_SHIFT,
+                    levels,
+                    vpn_bits: 9,
+                    pte_size: 8,
+                    root_bits: 0,
+                    fault: origin.page_fault(),
+                };
+                // VS-stage page tables live in guest physical memory. Reading
+                // them is a plain load even for HLVX, whose need for execute
+                // permission only applies to the final translation.
+                let pte_ctx = Context { hlvx: false, ..ctx };
+                let locate = |bus: &mut dyn Bus, gpa, access| {
+                    let (paddr, _, _) =
+                        Self::g_stage(bus, gpa, access, origin, pte_ctx, hgatp, &pmp)?;
+                    if pmp(paddr, access) {
+                        Ok(paddr)
+                    } else {
+                        Err(origin.access_fault().into())
+                    }
+                };
+                Self::walk(
+                    bus,
+                    vaddr,
+                    access,
+                    origin,
+                    stage,
+                    |pte| Self::permitted(pte, access, ctx),
+                    locate,
+                )?
+            }
+            None => (vaddr, BARE_PTE, 0),
+        };
+        let (paddr, gpte, _) = Self::g_stage(bus, gpa, access, origin, ctx, hgatp, &pmp)?;
+
+        self.tlb(access).insert(
+            vaddr,
+            asid,
+            vmid,
+            Leaf {
+                pte,
+                gpte,
+                span,
+                ppn: paddr >> PAGE_SHIFT,
+            },
+        );
+        Ok(paddr)
+    }
+
+    /// Translates the guest physical address `gpa` through the G-stage
+    /// table, checking it for an `access` made on behalf of `origin`.
+    #[allow(clippy::too_many_arguments)]
+    fn g_stage(
+        bus: &mut dyn Bus,
+        gpa: u64,
+        access: Access,
+        origin: Origin,
+        ctx: Context,
+        hgatp: u64,
+        pmp: &impl Fn(u64, Access) -> bool,
+    ) -> anyhow::Result<(u64, u64, u32)> {
+        let levels = match hgatp >> 60 {
+            csr::HGATP_MODE_SV39X4 => 3,
+            csr::HGATP_MODE_SV48X4 => 4,
+            csr::HGATP_MODE_SV57X4 => 5,
+            _ => return Ok((gpa, BARE_PTE, 0)),
+        };
+
+        let fault = origin.guest_page_fault(gpa);
+        if gpa >> (PAGE_SHIFT + 9 * levels + 2) != 0 {
+            return Err(fault.into());
+        }
+
+        let stage = Stage {
+            root: (hgatp & csr::HGATP_PPN) << PAGE_SHIFT,
+            levels,
+            vpn_bits: 9,
+            pte_size: 8,
+            root_bits: 2,
+            fault,
+        };
+        let locate = |_: &mut dyn Bus, paddr, access| {
+            if pmp(paddr, access) {
+                Ok(paddr)
+            } else {
+                Err(origin.access_fault().into())
+            }
+        };
+        Self::walk(
+            bus,
+            gpa,
+            access,
+            origin,
+            stage,
+            |pte| Self::g_permitted(pte, access, ctx),
+            locate,
+        )
+    }
+
+    /// Drops cached host translations; see [`Tlb::flush`].
+    pub fn flush(&mut self, vaddr: Option<u64>, asid: Option<u16>) {
+        self.itlb.flush(vaddr, asid);
+        self.dtlb.flush(vaddr, asid);
+    }
+
+    /// Drops cached guest translations; see [`Tlb::flush_guest`].
+    pub fn flush_guest(&mut self, vmid: Option<u16>, vaddr: Option<u64>, asid: Option<u16>) {
+        self.itlb.flush_guest(vmid, vaddr, asid);
+        self.dtlb.flush_guest(vmid, vaddr, asid);
+    }
+
+    pub const fn itlb_stats(&self) -> TlbStats {
+        self.itlb.stats()
+    }
+
+    pub const fn dtlb_stats(&self) -> TlbStats {
+        self.dtlb.stats()
+    }
+
+    const fn tlb(&mut self, access: Access) -> &mut Tlb {
+        match access {
+            Access::Fetch => &mut self.itlb,
+            Access::Load | Access::Store => &mut self.dtlb,
+        }
+    }
+
+    const fn canonical(vaddr: u64, levels: u32) -> bool {
+        let va_bits = PAGE_SHIFT + 9 * levels;
+        ((vaddr as i64) << (64 - va_bits) >> (64 - va_bits)) as u64 == vaddr
+    }
+
+    /// Whether a cached leaf serves `access` as is. A store through a clean
+    /// page falls back to the walk so that the D bit gets set in memory.
+    const fn usable(pte: u64, access: Access) -> bool {
+        !matches!(access, Access::Store) || pte & PTE_D != 0
+    }
+
+    const fn paddr(ppn: u64, vaddr: u64) -> u64 {
+        (ppn << PAGE_SHIFT) | (vaddr & ((1 << PAGE_SHIFT) - 1))
+    }
+
+    /// Walks the page table of `stage` for an `access` to `addr`, returning
+    /// the physical address together with the updated leaf PTE and its span,
+    /// the VPN bits it maps beyond a 4 KiB page. `locate` turns the address
+    /// of a PTE into the physical address to access it at.
+    ///
+    /// Only a store sets the D bit: a G-stage walk for a VS-stage PTE
+    /// translates the implicit access to the PTE, not the one of `origin`.
+    fn walk(
+        bus: &mut dyn Bus,
+        addr: u64,
+        access: Access,
+        origin: Origin,
+        stage: Stage,
+        permitted: impl Fn(u64) -> bool,
+        mut locate: impl FnMut(&mut dyn Bus, u64, Access) -> anyhow::Result<u64>,
+    ) -> anyhow::Result<(u64, u64, u32)> {
+        let mut table = stage.root;
+        for level in (0..stage.levels).rev() {
+            let span = stage.vpn_bits * level;
+            let vpn_bits = if level == stage.levels - 1 {
+                stage.vpn_bits + stage.root_bits
+            } else {
+                stage.vpn_bits
+            };
+            let vpn = (addr >> (PAGE_SHIFT + span)) & ((1 << vpn_bits) - 1);
+            let pte_addr = table + vpn * stage.pte_size;
+            let pte_paddr = locate(bus, pte_addr, Access::Load)?;
+            let pte = if stage.pte_size == 4 {
+                bus.read32(pte_paddr).map(u64::from)
+            } else {
+                bus.read64(pte_paddr)
+            }
+            .map_err(|_| origin.access_fault())?;
+
+            let write_only = pte & PTE_R == 0 && pte & PTE_W != 0;
+            if pte & PTE_V == 0 || write_only || pte & PTE_RESERVED != 0 {
+                return Err(stage.fault.into());
+            }
+
+            let ppn = (pte >> 10) & PTE_PPN_MASK;
+            if pte & (PTE_R | PTE_X) == 0 {
+                table = ppn << PAGE_SHIFT;
+                continue;
+            }
+
+            if !permitted(pte) {
+                return Err(stage.fault.into());
+            }
+
+            let page_bits = PAGE_SHIFT + span;
+            let superpage_mask = (1 << span) - 1;
+            if ppn & superpage_mask != 0 {
+                return Err(stage.fault.into());
+            }
+
+            let mut updated = pte | PTE_A;
+            if access == Access::Store {
+                updated |= PTE_D;
+            }
+            if updated != pte {
+                let pte_paddr = locate(bus, pte_addr, Access::Store)?;
+                if stage.pte_size == 4 {
+                    bus.write32(pte_paddr, updated as u32)
+                } else {
+                    bus.write64(pte_paddr, updated)
+                }
+                .map_err(|_| origin.access_fault())?;
+            }
+
+            let offset_mask = (1 << page_bits) - 1;
+            let paddr = (ppn << PAGE_SHIFT) & !offset_mask | (addr & offset_mask);
+            return Ok((paddr, updated, span));
+        }
+
+        Err(stage.fault.into())
+    }
+
+    /// Checks a host or VS-stage leaf.
+    const fn permitted(pte: u64, access: Access, ctx: Context) -> bool {
+        let user_page = pte & PTE_U != 0;
+        let allowed = match ctx.privilege {
+            Privilege::User => user_page,
+            Privilege::Supervisor => !user_page || (ctx.sum && !matches!(access, Access::Fetch)),
+            Privilege::Machine => true,
+        };
+        if !allowed {
+            return false;
+        }
+
+        match access {
+            Access::Fetch => pte & PTE_X != 0,
+            Access::Load if ctx.hlvx => pte & PTE_X != 0,
+            Access::Load => pte & PTE_R != 0 || ((ctx.mxr || ctx.vmxr) && pte & PTE_X != 0),
+            Access::Store => pte & PTE_W != 0,
+        }
+    }
+
+    /// Checks a G-stage leaf. Every G-stage access counts as a U-mode one.
+    const fn g_permitted(pte: u64, access: Access, ctx: Context) -> bool {
+        if pte & PTE_U == 0 {
+            return false;
+        }
+
+        match access {
+            Access::Fetch => pte & PTE_X != 0,
+            Access::Load if ctx.hlvx => pte & PTE_X != 0,
+            Access::Load => pte & PTE_R != 0 || (ctx.mxr && pte & PTE_X != 0),
+            Access::Store => pte & PTE_W != 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::mmap::Mmap;
+
+    const ROOT: u64 = 0x1000;
+
+    fn ctx(mode: u64, privilege: Privilege) -> Context {
+        Context {
+            satp: (mode << 60) | (ROOT >> PAGE_SHIFT),
+            privilege,
+            sum: false,
+            mxr: false,
+            vmxr: false,
+            hgatp: None,
+            hlvx: false,
+        }
+    }
+
+    fn pte(paddr: u64, flags: u64) -> u64 {
+        ((paddr >> PAGE_SHIFT) << 10) | flags | PTE_V
+    }
+
+    /// Maps the 4 KiB page at `vaddr` to `paddr` through a three-level Sv39
+    /// table whose intermediate tables live at 0x2000 and 0x3000.
+    fn map_sv39(bus: &mut Mmap, vaddr: u64, paddr: u64, flags: u64) {
+        let vpn = |level: u32| (vaddr >> (PAGE_SHIFT + 9 * level)) & 0x1ff;
+        bus.write64(ROOT + vpn(2) * 8, pte(0x2000, 0)).unwrap();
+        bus.write64(0x2000 + vpn(1) * 8, pte(0x3000, 0)).unwrap();
+        bus.write64(0x3000 + vpn(0) * 8, pte(paddr, flags)).unwrap();
+    }
+
+    const G_ROOT: u64 = 0x10000;
+
+    /// A guest access under `mode` whose G-stage is Sv39x4 rooted at 0x10000.
+    fn guest(mode: u64, privilege: Privilege) -> Context {
+        Context {
+            hgatp: Some((csr::HGATP_MODE_SV39X4 << 60) | (G_ROOT >> PAGE_SHIFT)),
+            ..ctx(mode, privilege)
+        }
+    }
+
+    /// Maps the 4 KiB guest physical page at `gpa` to `paddr` through an
+    /// Sv39x4 table whose intermediate tables live at 0x14000 and 0x15000.
+    fn map_g(bus: &mut Mmap, gpa: u64, paddr: u64, flags: u64) {
+        let vpn = |level: u32| (gpa >> (PAGE_SHIFT + 9 * level)) & 0x1ff;
+        bus.write64(G_ROOT + (gpa >> 30) * 8, pte(0x14000, 0))
+            .unwrap();
+        bus.write64(0x14000 + vpn(1) * 8, pte(0x15000, 0)).unwrap();
+        bus.write64(0x15000 + vpn(0) * 8, pte(paddr, flags))
+            .unwrap();
+    }
+
+    fn translate(bus: &mut Mmap, vaddr: u64, access: Access, ctx: Context) -> anyhow::Result<u64> {
+        Mmu::default().translate(bus, vaddr, access, ctx, |_, _| true)
+    }
+
+    fn page_fault(result: anyhow::Result<u64>) -> Trap {
+        result.unwrap_err().downcast::<Trap>().unwrap()
+    }
+
+    fn setup() -> Mmap {
+        Mmap::new(0x0, 0x10_0000)
+    }
+
+    #[test]
+    fn mmu_bare_is_identity() {
+        let mut bus = setup();
+        let ctx = ctx(SATP_MODE_BARE, Privilege::Supervisor);
+
+        assert_eq!(
+            translate(&mut bus, 0x1234, Access::Load, ctx).unwrap(),
+            0x1234
+        );
+    }
+
+    #[test]
+    fn mmu_machine_mode_is_identity() {
+        let mut bus = setup();
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Machine);
+
+        assert_eq!(
+            translate(&mut bus, 0x1234, Access::Load, ctx).unwrap(),
+            0x1234
+        );
+    }
+
+    #[test]
+    fn mmu_sv39_leaf() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x4000_5000, 0x8000, PTE_R | PTE_W);
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert_eq!(
+            translate(&mut bus, 0x4000_5123, Access::Load, ctx).unwrap(),
+            0x8123
+        );
+    }
+
+    #[test]
+    fn mmu_sv39_gigapage() {
+        let mut bus = setup();
+        bus.write64(ROOT + 8, pte(0x4000_0000, PTE_R)).unwrap();
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert_eq!(
+            translate(&mut bus, 0x4012_3456, Access::Load, ctx).unwrap(),
+            0x4012_3456
+        );
+    }
+
+    #[test]
+    fn mmu_misaligned_superpage_faults() {
+        let mut bus = setup();
+        bus.write64(ROOT + 8, pte(0x4000_1000, PTE_R)).unwrap();
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert_eq!(
+            page_fault(translate(&mut bus, 0x4000_0000, Access::Load, ctx)),
+            Trap::LoadPageFault { addr: 0x4000_0000 }
+        );
+    }
+
+    #[test]
+    fn mmu_sv48_and_sv57_walk_extra_levels() {
+        for (mode, levels) in [(SATP_MODE_SV48, 4u32), (SATP_MODE_SV57, 5)] {
+            let mut bus = setup();
+            let mut table = ROOT;
+            for level in (1..levels).rev() {
+                let next = 0x2000 + 0x1000 * u64::from(level);
+                bus.write64(table, pte(next, 0)).unwrap();
+                table = next;
+            }
+            bus.write64(table, pte(0x9000, PTE_X)).unwrap();
+            let ctx = ctx(mode, Privilege::Supervisor);
+
+            assert_eq!(
+                translate(&mut bus, 0x42, Access::Fetch, ctx).unwrap(),
+                0x9042
+            );
+        }
+    }
+
+    #[test]
+    fn mmu_sv32_leaf_and_megapage() {
+        let mut bus = setup();
+        // 0xc040_1123: VPN[1] = 0x301, VPN[0] = 0x001.
+        bus.write32(ROOT + 0x301 * 4, pte(0x2000, 0) as u32)
+            .unwrap();
+        bus.write32(0x2000 + 4, pte(0x8000, PTE_R) as u32).unwrap();
+        bus.write32(ROOT + 4, pte(0x40_0000, PTE_X) as u32).unwrap();
+        let ctx = ctx(SATP_MODE_SV32, Privilege::Supervisor);
+
+        assert_eq!(
+            translate(&mut bus, 0xc040_1123, Access::Load, ctx).unwrap(),
+            0x8123
+        );
+        assert_eq!(
+            translate(&mut bus, 0x4a_bcde, Access::Fetch, ctx).unwrap(),
+            0x4a_bcde
+        );
+        assert_ne!(bus.read32(0x2000 + 4).unwrap() as u64 & PTE_A, 0);
+        assert_eq!(bus.read32(0x2000 + 8).unwrap(), 0);
+    }
+
+    #[test]
+    fn mmu_non_canonical_address_faults() {
+        let mut bus = setup();
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert_eq!(
+            page_fault(translate(&mut bus, 1 << 40, Access::Store, ctx)),
+            Trap::StorePageFault { addr: 1 << 40 }
+        );
+    }
+
+    #[test]
+    fn mmu_invalid_pte_faults() {
+        let mut bus = setup();
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert_eq!(
+            page_fault(translate(&mut bus, 0x5000, Access::Fetch, ctx)),
+            Trap::FetchPageFault { addr: 0x5000 }
+        );
+    }
+
+    #[test]
+    fn mmu_write_only_pte_faults() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_W);
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert!(translate(&mut bus, 0x5000, Access::Store, ctx).is_err());
+    }
+
+    #[test]
+    fn mmu_enforces_rwx() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_R);
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert!(translate(&mut bus, 0x5000, Access::Load, ctx).is_ok());
+        assert_eq!(
+            page_fault(translate(&mut bus, 0x5000, Access::Store, ctx)),
+            Trap::StorePageFault { addr: 0x5000 }
+        );
+        assert_eq!(
+            page_fault(translate(&mut bus, 0x5000, Access::Fetch, ctx)),
+            Trap::FetchPageFault { addr: 0x5000 }
+        );
+    }
+
+    #[test]
+    fn mmu_mxr_makes_executable_readable() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_X);
+        let mut ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert!(translate(&mut bus, 0x5000, Access::Load, ctx).is_err());
+
+        ctx.mxr = true;
+        assert!(translate(&mut bus, 0x5000, Access::Load, ctx).is_ok());
+    }
+
+    #[test]
+    fn mmu_user_page_rules() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_R | PTE_X | PTE_U);
+        let mut ctx = ctx(SATP_MODE_SV39, Privilege::User);
+
+        assert!(translate(&mut bus, 0x5000, Access::Load, ctx).is_ok());
+
+        ctx.privilege = Privilege::Supervisor;
+        assert!(translate(&mut bus, 0x5000, Access::Load, ctx).is_err());
+
+        ctx.sum = true;
+        assert!(translate(&mut bus, 0x5000, Access::Load, ctx).is_ok());
+        assert!(translate(&mut bus, 0x5000, Access::Fetch, ctx).is_err());
+    }
+
+    #[test]
+    fn mmu_user_cannot_access_supervisor_page() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_R);
+        let ctx = ctx(SATP_MODE_SV39, Privilege::User);
+
+        assert_eq!(
+            page_fault(translate(&mut bus, 0x5000, Access::Load, ctx)),
+            Trap::LoadPageFault { addr: 0x5000 }
+        );
+    }
+
+    #[test]
+    fn mmu_sets_accessed_and_dirty() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_R | PTE_W);
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+        let leaf = 0x3000 + 5 * 8;
+
+        translate(&mut bus, 0x5000, Access::Load, ctx).unwrap();
+        assert_eq!(bus.read64(leaf).unwrap() & (PTE_A | PTE_D), PTE_A);
+
+        translate(&mut bus, 0x5000, Access::Store, ctx).unwrap();
+        assert_eq!(bus.read64(leaf).unwrap() & (PTE_A | PTE_D), PTE_A | PTE_D);
+    }
+
+    #[test]
+    fn mmu_cached_clean_page_store_sets_dirty() {
+        let mut bus = setup();
+        let mut mmu = Mmu::default();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_R | PTE_W);
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+        let leaf = 0x3000 + 5 * 8;
+
+        mmu.translate(&mut bus, 0x5000, Access::Load, ctx, |_, _| true)
+            .unwrap();
+        mmu.translate(&mut bus, 0x5000, Access::Store, ctx, |_, _| true)
+            .unwrap();
+
+        assert_eq!(bus.read64(leaf).unwrap() & PTE_D, PTE_D);
+        assert_eq!(mmu.dtlb_stats().misses, 1);
+    }
+
+    #[test]
+    fn mmu_cached_translation_rechecks_permissions() {
+        let mut bus = setup();
+        let mut mmu = Mmu::default();
+        map_sv39(&mut bus, 0x5000, 0x8000, PTE_R | PTE_U);
+        let user = ctx(SATP_MODE_SV39, Privilege::User);
+        let supervisor = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        mmu.translate(&mut bus, 0x5000, Access::Load, user, |_, _| true)
+            .unwrap();
+        let result = mmu.translate(&mut bus, 0x5000, Access::Load, supervisor, |_, _| true);
+
+        assert_eq!(page_fault(result), Trap::LoadPageFault { addr: 0x5000 });
+    }
+
+    #[test]
+    fn mmu_pte_fetch_outside_memory_is_access_fault() {
+        let mut bus = Mmap::new(0x8000_0000, 0x1000);
+        let ctx = ctx(SATP_MODE_SV39, Privilege::Supervisor);
+
+        let err = translate(&mut bus, 0x5000, Access::Load, ctx).unwrap_err();
+
+        assert_eq!(
+            err.downcast::<MemoryTrap>().unwrap(),
+            MemoryTrap::LoadAccessFault { addr: 0x5000 }
+        );
+    }
+
+    #[test]
+    fn mmu_g_stage_translates_guest_physical_addresses() {
+        let mut bus = setup();
+        map_g(&mut bus, 0x5000, 0x8000, PTE_R | PTE_W | PTE_U);
+        let ctx = guest(SATP_MODE_BARE, Privilege::Supervisor);
+
+        assert_eq!(
+            translate(&mut bus, 0x5123, Access::Load, ctx).unwrap(),
+            0x8123
+        );
+    }
+
+    #[test]
+    fn mmu_g_stage_requires_user_pages() {
+        let mut bus = setup();
+        map_g(&mut bus, 0x5000, 0x8000, PTE_R | PTE_W);
+        let ctx = guest(SATP_MODE_BARE, Privilege::Supervisor);
+
+        let result = translate(&mut bus, 0x5000, Access::Store, ctx);
+
+        assert_eq!(
+            page_fault(result),
+            Trap::StoreGuestPageFault {
+                addr: 0x5000,
+                gpa: 0x5000
+            }
+        );
+    }
+
+    #[test]
+    fn mmu_g_stage_limits_guest_physical_range() {
+        let mut bus = setup();
+        let ctx = guest(SATP_MODE_BARE, Privilege::Supervisor);
+
+        let result = translate(&mut bus, 1 << 41, Access::Load, ctx);
+
+        assert_eq!(
+            page_fault(result),
+            Trap::LoadGuestPageFault {
+                addr: 1 << 41,
+                gpa: 1 << 41
+            }
+        );
+    }
+
+    #[test]
+    fn mmu_two_stage_translation() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x4000_5000, 0x8000, PTE_R | PTE_W);
+        for table in [ROOT, 0x2000, 0x3000] {
+            map_g(&mut bus, table, table, PTE_R | PTE_W | PTE_U);
+        }
+        map_g(&mut bus, 0x8000, 0x9000, PTE_R | PTE_W | PTE_U);
+        let ctx = guest(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert_eq!(
+            translate(&mut bus, 0x4000_5123, Access::Load, ctx).unwrap(),
+            0x9123
+        );
+    }
+
+    #[test]
+    fn mmu_vs_page_table_fault_reports_pte_address() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x4000_5000, 0x8000, PTE_R | PTE_W);
+        map_g(&mut bus, ROOT, ROOT, PTE_R | PTE_W | PTE_U);
+        let ctx = guest(SATP_MODE_SV39, Privilege::Supervisor);
+
+        let result = translate(&mut bus, 0x4000_5000, Access::Load, ctx);
+
+        assert_eq!(
+            page_fault(result),
+            Trap::LoadGuestPageFault {
+                addr: 0x4000_5000,
+                gpa: 0x2000
+            }
+        );
+    }
+
+    #[test]
+    fn mmu_vs_mxr_applies_to_vs_stage_only() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x4000_5000, 0x8000, PTE_X);
+        for table in [ROOT, 0x2000, 0x3000] {
+            map_g(&mut bus, table, table, PTE_R | PTE_W | PTE_U);
+        }
+        map_g(&mut bus, 0x8000, 0x8000, PTE_X | PTE_U);
+        let vmxr = Context {
+            vmxr: true,
+            ..guest(SATP_MODE_SV39, Privilege::Supervisor)
+        };
+        let mxr = Context { mxr: true, ..vmxr };
+
+        let result = translate(&mut bus, 0x4000_5000, Access::Load, vmxr);
+        assert_eq!(
+            page_fault(result),
+            Trap::LoadGuestPageFault {
+                addr: 0x4000_5000,
+                gpa: 0x8000
+            }
+        );
+        assert_eq!(
+            translate(&mut bus, 0x4000_5000, Access::Load, mxr).unwrap(),
+            0x8000
+        );
+    }
+
+    #[test]
+    fn mmu_hlvx_reads_vs_page_tables_without_execute() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x4000_5000, 0x8000, PTE_X);
+        for table in [ROOT, 0x2000, 0x3000] {
+            map_g(&mut bus, table, table, PTE_R | PTE_W | PTE_U);
+        }
+        map_g(&mut bus, 0x8000, 0x9000, PTE_X | PTE_U);
+        let hlvx = Context {
+            hlvx: true,
+            ..guest(SATP_MODE_SV39, Privilege::Supervisor)
+        };
+
+        assert_eq!(
+            translate(&mut bus, 0x4000_5120, Access::Load, hlvx).unwrap(),
+            0x9120
+        );
+    }
+
+    #[test]
+    fn mmu_guest_store_leaves_g_stage_of_vs_tables_clean() {
+        let mut bus = setup();
+        map_sv39(&mut bus, 0x4000_5000, 0x8000, PTE_R | PTE_W | PTE_A | PTE_D);
+        for table in [ROOT, 0x2000, 0x3000] {
+            map_g(&mut bus, table, table, PTE_R | PTE_W | PTE_U | PTE_A);
+        }
+        map_g(&mut bus, 0x8000, 0x8000, PTE_R | PTE_W | PTE_U);
+        let ctx = guest(SATP_MODE_SV39, Privilege::Supervisor);
+
+        assert_eq!(
+            translate(&mut bus, 0x4000_5000, Access::Store, ctx).unwrap(),
+            0x8000
+        );
+        for table in [ROOT, 0x2000, 0x3000] {
+            let gpte = bus.read64(0x15000 + (table >> PAGE_SHIFT) * 8).unwrap();
+            assert_eq!(gpte & PTE_D, 0);
+        }
+        assert_eq!(bus.read64(0x15000 + 8 * 8).unwrap() & PTE_D, PTE_D);
+    }
+}
@@ -1,4 +1,12 @@
+pub mod crypto;
+pub mod csr;
 pub mod exception;
 pub mod extensions;
 pub mod hart;
 pub mod instruction;
+pub mod mmu;
+pub mod pmp;
+pub mod profile;
+pub mod softfloat;
+pub mod tlb;
+pub mod vector;
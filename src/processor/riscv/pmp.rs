@@ -0,0 +1,322 @@
+use crate::processor::riscv::{
+    csr::{CsrFile, PMPADDR0, PMPCFG0},
+    hart::Privilege,
+    mmu::Access,
+};
+
+pub const PMP_R: u8 = 1 << 0;
+pub const PMP_W: u8 = 1 << 1;
+pub const PMP_X: u8 = 1 << 2;
+pub const PMP_A: u8 = 0b11 << 3;
+pub const PMP_L: u8 = 1 << 7;
+
+pub const PMP_A_OFF: u8 = 0 << 3;
+pub const PMP_A_TOR: u8 = 1 << 3;
+pub const PMP_A_NA4: u8 = 2 << 3;
+pub const PMP_A_NAPOT: u8 = 3 << 3;
+
+/// `pmpaddr` holds bits 55:2 of a 56-bit physical address.
+pub const PMPADDR_MASK: u64 = (1 << 54) - 1;
+
+/// Returns the configuration byte of PMP entry `index`. On RV64 only the
+/// even-numbered `pmpcfg` registers exist, each packing eight entries.
+#[inline(always)]
+pub fn cfg(csrs: &CsrFile, index: usize) -> u8 {
+    let reg = PMPCFG0 + (index / 8 * 2) as u16;
+    (csrs.get(reg) >> (8 * (index % 8))) as u8
+}
+
+#[inline(always)]
+fn addr(csrs: &CsrFile, index: usize) -> u64 {
+    csrs.get(PMPADDR0 + index as u16)
+}
+
+/// Legalizes a write to a `pmpcfg` register: locked entries keep their
+/// configuration and the reserved R=0/W=1 combination drops W.
+pub(crate) fn legalize_cfg(old: u64, new: u64) -> u64 {
+    let mut val = 0;
+    for byte in 0..8 {
+        let shift = 8 * byte;
+        let prev = (old >> shift) as u8;
+        let mut next = (new >> shift) as u8;
+        if prev & PMP_L != 0 {
+            next = prev;
+        } else if next & (PMP_R | PMP_W) == PMP_W {
+            next &= !PMP_W;
+        }
+        val |= u64::from(next) << shift;
+    }
+    val
+}
+
+/// Tells whether `pmpaddr` of entry `index` ignores writes, either because
+/// the entry is locked or because it is the bottom of a locked TOR range.
+pub(crate) fn addr_locked(csrs: &CsrFile, index: usize) -> bool {
+    if cfg(csrs, index) & PMP_L != 0 {
+        return true;
+    }
+    index + 1 < Pmp::MAX_ENTRIES && {
+        let above = cfg(csrs, index + 1);
+        above & PMP_L != 0 && above & PMP_A == PMP_A_TOR
+    }
+}
+
+/// Physical memory protection, evaluated against the `pmpcfg`/`pmpaddr`
+/// state held in the CSR file.
+#[derive(Clone, Copy, Debug)]
+pub struct Pmp {
+    entries: usize,
+}
+
+impl Pmp {
+    pub const DEFAULT_ENTRIES: usize = 16;
+    pub const MAX_ENTRIES: usize = 64;
+
+    pub const fn new(entries: usize) -> Self {
+        assert!(entries <= Self::MAX_ENTRIES);
+        Self { entries }
+    }
+
+    pub const fn entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Checks an access of `size` bytes at `paddr` made with `privilege`.
+    /// The lowest-numbered entry matching any byte decides; an access that
+    /// only partially falls inside it fails.
+    pub fn check(
+        &self,
+        csrs: &CsrFile,
+        paddr: u64,
+        size: u64,
+        access: Access,
+        privilege: Privilege,
+    ) -> bool {
+        let start = u128::from(paddr);
+        let end = start + u128::from(size);
+
+        for index in 0..self.entries {
+            let cfg = cfg(csrs, index);
+            let Some((lo, hi)) = self.range(csrs, index, cfg) else {
+                continue;
+            };
+            if start >= hi || end <= lo {
+                continue;
+            }
+            if start < lo || end > hi {
+                return false;
+            }
+            if privilege == Privilege::Machine && cfg & PMP_L == 0 {
+                return true;
+            }
+            let bit = match access {
+                Access::Fetch => PMP_X,
+                Access::Load => PMP_R,
+                Access::Store => PMP_W,
+            };
+            return cfg & bit != 0;
+        }
+
+        privilege == Privilege::Machine || self.entries == 0
+    }
+
+    /// Returns the byte range `[lo, hi)` matched by entry `index`.
+    fn range(&self, csrs: &CsrFile, index: usize, cfg: u8) -> Option<(u128, u128)> {
+        let addr = u128::from(addr(csrs, index) & PMPADDR_MASK);
+        match cfg & PMP_A {
+            PMP_A_TOR => {
+                let lo = match index {
+                    0 => 0,
+                    _ => u128::from(self::addr(csrs, index - 1) & PMPADDR_MASK) << 2,
+                };
+                let hi = addr << 2;
+                (lo < hi).then_some((lo, hi))
+            }
+            PMP_A_NA4 => Some((addr << 2, (addr << 2) + 4)),
+            PMP_A_NAPOT => {
+                let ones = addr.trailing_ones();
+                let base = (addr & !((1 << ones) - 1)) << 2;
+                Some((base, base + (1 << (ones + 3))))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for Pmp {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_ENTRIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (CsrFile, Pmp) {
+        (CsrFile::new(0), Pmp::default())
+    }
+
+    fn set_entry(csrs: &mut CsrFile, index: usize, cfg: u8, addr: u64) {
+        csrs.write(PMPADDR0 + index as u16, addr, Privilege::Machine)
+            .unwrap();
+        let reg = PMPCFG0 + (index / 8 * 2) as u16;
+        let shift = 8 * (index % 8);
+        let val = (csrs.get(reg) & !(0xff << shift)) | (u64::from(cfg) << shift);
+        csrs.write(reg, val, Privilege::Machine).unwrap();
+    }
+
+    fn napot(base: u64, size: u64) -> u64 {
+        (base | (size / 2 - 1)) >> 2
+    }
+
+    #[test]
+    fn pmp_no_match_allows_machine_only() {
+        let (csrs, pmp) = setup();
+
+        assert!(pmp.check(&csrs, 0x1000, 4, Access::Load, Privilege::Machine));
+        assert!(!pmp.check(&csrs, 0x1000, 4, Access::Load, Privilege::Supervisor));
+        assert!(!pmp.check(&csrs, 0x1000, 4, Access::Load, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_no_entries_allows_everything() {
+        let csrs = CsrFile::with_pmp_entries(0, 0);
+        let pmp = Pmp::new(0);
+
+        assert!(pmp.check(&csrs, 0x1000, 4, Access::Store, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_tor_range() {
+        let (mut csrs, pmp) = setup();
+        set_entry(&mut csrs, 0, PMP_A_OFF, 0x1000 >> 2);
+        set_entry(&mut csrs, 1, PMP_A_TOR | PMP_R, 0x2000 >> 2);
+
+        assert!(pmp.check(&csrs, 0x1000, 8, Access::Load, Privilege::User));
+        assert!(pmp.check(&csrs, 0x1ff8, 8, Access::Load, Privilege::User));
+        assert!(!pmp.check(&csrs, 0x2000, 8, Access::Load, Privilege::User));
+        assert!(!pmp.check(&csrs, 0xff8, 8, Access::Load, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_tor_first_entry_starts_at_zero() {
+        let (mut csrs, pmp) = setup();
+        set_entry(&mut csrs, 0, PMP_A_TOR | PMP_X, 0x1000 >> 2);
+
+        assert!(pmp.check(&csrs, 0x0, 4, Access::Fetch, Privilege::User));
+        assert!(!pmp.check(&csrs, 0x0, 4, Access::Load, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_na4() {
+        let (mut csrs, pmp) = setup();
+        set_entry(&mut csrs, 0, PMP_A_NA4 | PMP_R, 0x1004 >> 2);
+
+        assert!(pmp.check(&csrs, 0x1004, 4, Access::Load, Privilege::User));
+        assert!(!pmp.check(&csrs, 0x1008, 4, Access::Load, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_napot() {
+        let (mut csrs, pmp) = setup();
+        set_entry(
+            &mut csrs,
+            0,
+            PMP_A_NAPOT | PMP_R | PMP_W,
+            napot(0x8000, 0x1000),
+        );
+
+        assert!(pmp.check(&csrs, 0x8000, 8, Access::Store, Privilege::User));
+        assert!(pmp.check(&csrs, 0x8ff8, 8, Access::Store, Privilege::User));
+        assert!(!pmp.check(&csrs, 0x9000, 8, Access::Store, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_napot_whole_address_space() {
+        let (mut csrs, pmp) = setup();
+        set_entry(&mut csrs, 0, PMP_A_NAPOT | PMP_R, PMPADDR_MASK);
+
+        assert!(pmp.check(&csrs, 0, 8, Access::Load, Privilege::User));
+        assert!(pmp.check(&csrs, (1 << 56) - 8, 8, Access::Load, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_partial_match_fails() {
+        let (mut csrs, pmp) = setup();
+        set_entry(&mut csrs, 0, PMP_A_NA4 | PMP_R, 0x1000 >> 2);
+
+        assert!(!pmp.check(&csrs, 0x1000, 8, Access::Load, Privilege::User));
+        assert!(!pmp.check(&csrs, 0x1000, 8, Access::Load, Privilege::Machine));
+    }
+
+    #[test]
+    fn pmp_lowest_entry_wins() {
+        let (mut csrs, pmp) = setup();
+        set_entry(&mut csrs, 0, PMP_A_NA4, 0x1000 >> 2);
+        set_entry(&mut csrs, 1, PMP_A_NAPOT | PMP_R, napot(0x0, 0x10000));
+
+        assert!(!pmp.check(&csrs, 0x1000, 4, Access::Load, Privilege::User));
+        assert!(pmp.check(&csrs, 0x1004, 4, Access::Load, Privilege::User));
+    }
+
+    #[test]
+    fn pmp_machine_mode_bound_only_by_locked_entries() {
+        let (mut csrs, pmp) = setup();
+        set_entry(&mut csrs, 0, PMP_A_NA4, 0x1000 >> 2);
+        set_entry(&mut csrs, 1, PMP_A_NA4 | PMP_L, 0x2000 >> 2);
+
+        assert!(pmp.check(&csrs, 0x1000, 4, Access::Store, Privilege::Machine));
+        assert!(!pmp.check(&csrs, 0x2000, 4, Access::Store, Privilege::Machine));
+    }
+
+    #[test]
+    fn pmp_locked_entry_ignores_writes() {
+        let (mut csrs, _) = setup();
+        set_entry(&mut csrs, 0, PMP_A_NA4 | PMP_L | PMP_R, 0x1000 >> 2);
+
+        set_entry(&mut csrs, 0, PMP_A_NAPOT | PMP_W | PMP_R, 0x2000 >> 2);
+
+        assert_eq!(cfg(&csrs, 0), PMP_A_NA4 | PMP_L | PMP_R);
+        assert_eq!(csrs.get(PMPADDR0), 0x1000 >> 2);
+    }
+
+    #[test]
+    fn pmp_locked_tor_locks_address_below() {
+        let (mut csrs, _) = setup();
+        set_entry(&mut csrs, 1, PMP_A_TOR | PMP_L, 0x2000 >> 2);
+
+        csrs.write(PMPADDR0, 0x1000 >> 2, Privilege::Machine)
+            .unwrap();
+
+        assert_eq!(csrs.get(PMPADDR0), 0);
+    }
+
+    #[test]
+    fn pmp_write_only_is_reserved() {
+        let (mut csrs, _) = setup();
+        set_entry(&mut csrs, 0, PMP_A_NA4 | PMP_W, 0);
+
+        assert_eq!(cfg(&csrs, 0), PMP_A_NA4);
+    }
+
+    #[test]
+    fn pmp_unimplemented_entries_read_zero() {
+        let (mut csrs, _) = setup();
+
+        csrs.write(PMPADDR0 + 16, 0x1234, Privilege::Machine)
+            .unwrap();
+
+        assert_eq!(csrs.read(PMPADDR0 + 16, Privilege::Machine), Some(0));
+        assert_eq!(csrs.read(PMPCFG0 + 1, Privilege::Machine), None);
+    }
+
+    #[test]
+    fn pmp_sixty_four_entries() {
+        let mut csrs = CsrFile::with_pmp_entries(0, 64);
+        let pmp = Pmp::new(64);
+        set_entry(&mut csrs, 63, PMP_A_NA4 | PMP_R, 0x1000 >> 2);
+
+        assert!(pmp.check(&csrs, 0x1000, 4, Access::Load, Privilege::User));
+    }
+}
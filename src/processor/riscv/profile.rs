@@ -0,0 +1,357 @@
+//! RISC-V profiles, which fix the set of extensions that software built for
+//! them may rely on. Selecting a profile configures a hart with its mandatory
+//! extensions and no optional ones, apart from Zifencei and Zihpm, which
+//! every hart implements; it fails when a mandatory one is not implemented.
+
+use std::str::FromStr;
+
+use crate::processor::riscv::hart::{Extension, Extensions, Hart, HartConfig, Misaligned};
+
+/// Extensions that only constrain the execution environment, which every
+/// hart satisfies: main memory supports instruction fetch, LR/SC and AMOs,
+/// and reservation sets and cache blocks are at most 64 bytes.
+const PROPERTIES: [&str; 7] = [
+    "ziccif", "ziccrse", "ziccamoa", "za64rs", "za128rs", "zic64b", "zkt",
+];
+
+const RVA20U64: &[&str] = &[
+    "i", "m", "a", "f", "d", "c", "zicsr", "zicntr", "ziccif", "ziccrse", "ziccamoa", "za128rs",
+    "zicclsm",
+];
+
+const RVA22U64: &[&str] = &[
+    "i",
+    "m",
+    "a",
+    "f",
+    "d",
+    "c",
+    "zicsr",
+    "zicntr",
+    "zihpm",
+    "ziccif",
+    "ziccrse",
+    "ziccamoa",
+    "zicclsm",
+    "za64rs",
+    "zihintpause",
+    "zba",
+    "zbb",
+    "zbs",
+    "zic64b",
+    "zicbom",
+    "zicbop",
+    "zicboz",
+    "zfhmin",
+    "zkt",
+];
+
+const RVA23U64: &[&str] = &[
+    "i",
+    "m",
+    "a",
+    "f",
+    "d",
+    "c",
+    "v",
+    "zicsr",
+    "zicntr",
+    "zihpm",
+    "ziccif",
+    "ziccrse",
+    "ziccamoa",
+    "zicclsm",
+    "za64rs",
+    "zihintpause",
+    "zba",
+    "zbb",
+    "zbs",
+    "zic64b",
+    "zicbom",
+    "zicbop",
+    "zicboz",
+    "zfhmin",
+    "zkt",
+    "zvfhmin",
+    "zvbb",
+    "zvkt",
+    "zihintntl",
+    "zicond",
+    "zimop",
+    "zcmop",
+    "zcb",
+    "zfa",
+    "zawrs",
+    "supm",
+];
+
+const RVA23S64: &[&str] = &[
+    "i",
+    "m",
+    "a",
+    "f",
+    "d",
+    "c",
+    "v",
+    "h",
+    "zicsr",
+    "zicntr",
+    "zihpm",
+    "ziccif",
+    "ziccrse",
+    "ziccamoa",
+    "zicclsm",
+    "za64rs",
+    "zihintpause",
+    "zba",
+    "zbb",
+    "zbs",
+    "zic64b",
+    "zicbom",
+    "zicbop",
+    "zicboz",
+    "zfhmin",
+    "zkt",
+    "zvfhmin",
+    "zvbb",
+    "zvkt",
+    "zihintntl",
+    "zicond",
+    "zimop",
+    "zcmop",
+    "zcb",
+    "zfa",
+    "zawrs",
+    "zifencei",
+    "ss1p13",
+    "svbare",
+    "sv39",
+    "svade",
+    "ssccptr",
+    "sstvecd",
+    "sstvala",
+    "sscounterenw",
+    "svpbmt",
+    "svinval",
+    "svnapot",
+    "sstc",
+    "sscofpmf",
+    "ssnpm",
+    "ssu64xl",
+    "ssstateen",
+    "shcounterenw",
+    "shvstvala",
+    "shtvala",
+    "shvstvecd",
+    "shvsatpa",
+    "shgatpa",
+    "sha",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Profile {
+    Rva20u64,
+    Rva22u64,
+    Rva23u64,
+    Rva23s64,
+}
+
+impl Profile {
+    pub const ALL: [Self; 4] = [
+        Self::Rva20u64,
+        Self::Rva22u64,
+        Self::Rva23u64,
+        Self::Rva23s64,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Rva20u64 => "RVA20U64",
+            Self::Rva22u64 => "RVA22U64",
+            Self::Rva23u64 => "RVA23U64",
+            Self::Rva23s64 => "RVA23S64",
+        }
+    }
+
+    /// Mandatory extensions, named as in ISA strings.
+    pub const fn mandatory(self) -> &'static [&'static str] {
+        match self {
+            Self::Rva20u64 => RVA20U64,
+            Self::Rva22u64 => RVA22U64,
+            Self::Rva23u64 => RVA23U64,
+            Self::Rva23s64 => RVA23S64,
+        }
+    }
+
+    /// Builds on `base` a configuration implementing the mandatory
+    /// extensions, leaving out every optional one that a hart can do
+    /// without. Zicclsm, which all of them require, makes misaligned
+    /// accesses transparent.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `base` is not RV64, which every profile targets, and,
+    /// naming them all, if any mandatory extension is not implemented.
+    pub fn config(self, base: HartConfig) -> anyhow::Result<HartConfig> {
+        anyhow::ensure!(base.xlen == 64, "profile {} requires RV64", self.name());
+        let mut config = HartConfig {
+            compressed: false,
+            vector: false,
+            hypervisor: false,
+            extensions: Extensions::default(),
+            ..base
+        };
+        let mut missing = Vec::new();
+
+        for &name in self.mandatory() {
+            match name {
+                // The base ISA and the M, A, F and D extensions are always
+                // present.
+                "i" | "m" | "a" | "f" | "d" => {}
+                "c" => config.compressed = true,
+                "v" => config.vector = true,
+                "h" => config.hypervisor = true,
+                // Misaligned loads and stores to main memory must not trap.
+                "zicclsm" => config.misaligned = Misaligned::Transparent,
+                _ if Hart::BASE_EXTENSIONS.contains(&name) || PROPERTIES.contains(&name) => {}
+                _ => match name.parse::<Extension>() {
+                    Ok(ext) => config.extensions = config.extensions.with(ext),
+                    Err(_) => missing.push(name),
+                },
+            }
+        }
+
+        anyhow::ensure!(
+            missing.is_empty(),
+            "profile {} requires unimplemented extensions: {}",
+            self.name(),
+            missing.join(", ")
+        );
+        Ok(config)
+    }
+}
+
+impl FromStr for Profile {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|profile| profile.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| anyhow::anyhow!("unknown profile '{s}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        memory::{Bus, mmap::Mmap},
+        processor::{Cpu, riscv::csr},
+    };
+
+    #[test]
+    fn profile_parses_case_insensitively() {
+        assert_eq!("rva22u64".parse::<Profile>().unwrap(), Profile::Rva22u64);
+        assert_eq!("RVA23S64".parse::<Profile>().unwrap(), Profile::Rva23s64);
+        assert!("rva99u64".parse::<Profile>().is_err());
+    }
+
+    #[test]
+    fn rva20u64_leaves_out_optional_extensions() {
+        let config = Profile::Rva20u64.config(HartConfig::default()).unwrap();
+        let hart = Hart::with_config(0, config);
+
+        assert!(config.compressed);
+        assert!(!config.vector);
+        assert_eq!(config.extensions, Extensions::default());
+        assert_eq!(hart.csrs().get(csr::MISA) & csr::misa_ext(b'V'), 0);
+        assert_eq!(hart.isa_string(), "rv64imafdc_zicntr_zicsr_zifencei_zihpm");
+    }
+
+    #[test]
+    fn rva20u64_leaves_out_half_precision() {
+        let config = Profile::Rva20u64.config(HartConfig::default()).unwrap();
+        let isa = Hart::with_config(0, config).isa_string();
+
+        assert!(!config.extensions.contains(Extension::Zfh));
+        assert!(!config.extensions.contains(Extension::Zfhmin));
+        assert!(!isa.contains("zfh"));
+    }
+
+    #[test]
+    fn rva22u64_selects_its_extensions() {
+        let config = Profile::Rva22u64.config(HartConfig::default()).unwrap();
+        let hart = Hart::with_config(0, config);
+
+        assert_eq!(
+            hart.isa_string(),
+            "rv64imafdc_zicbom_zicbop_zicboz_zicntr_zicsr_zifencei_zihintpause_zihpm_zfhmin_zba_zbb_zbs"
+        );
+        assert_ne!(hart.csrs().get(csr::MISA) & csr::misa_ext(b'C'), 0);
+    }
+
+    #[test]
+    fn profile_performs_misaligned_accesses() {
+        let config = Profile::Rva20u64.config(HartConfig::default()).unwrap();
+        let mut hart = Hart::with_config(0x1000, config);
+        let mut bus = Mmap::new(0x0, 0x10_0000);
+        // ld a0, 3(a1)
+        bus.write32(0x1000, 0x0035_b503).unwrap();
+        bus.write64(0x100, 0x0123_4567_89ab_cdef).unwrap();
+        bus.write64(0x108, 0xffff_ffff_ffff_ffff).unwrap();
+        hart.set_xreg(11, 0x100);
+
+        hart.step(&mut bus).unwrap();
+
+        assert_eq!(config.misaligned, Misaligned::Transparent);
+        assert_eq!(hart.xreg(10), 0xffff_ff01_2345_6789);
+        assert_eq!(hart.pc(), 0x1004);
+    }
+
+    #[test]
+    fn profile_keeps_base_parameters() {
+        let base = HartConfig {
+            pmp_entries: 4,
+            vlen: 256,
+            ..HartConfig::default()
+        };
+        let config = Profile::Rva22u64.config(base).unwrap();
+
+        assert_eq!(config.pmp_entries, 4);
+        assert_eq!(config.vlen, 256);
+    }
+
+    #[test]
+    fn profile_rejects_rv32() {
+        let base = HartConfig {
+            xlen: 32,
+            ..HartConfig::default()
+        };
+        let err = Profile::Rva20u64.config(base).unwrap_err().to_string();
+
+        assert_eq!(err, "profile RVA20U64 requires RV64");
+    }
+
+    #[test]
+    fn rva23_fails_on_unimplemented_extensions() {
+        let err = Profile::Rva23u64
+            .config(HartConfig::default())
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.starts_with("profile RVA23U64 requires unimplemented extensions: "));
+        assert!(err.contains("zfa"));
+        assert!(err.contains("zcb"));
+        assert!(!err.contains("zicond"));
+        assert!(!err.contains("zawrs"));
+
+        let err = Profile::Rva23s64
+            .config(HartConfig::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("svinval"));
+        assert!(!err.contains(" h,"));
+        assert!(!err.contains("zifencei"));
+    }
+}
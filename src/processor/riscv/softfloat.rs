@@ -0,0 +1,1181 @@
+//! IEEE 754 binary floating point implemented on integers, so that results and
+//! exception flags do not depend on the host FPU. NaN results are always the
+//! canonical NaN and tininess is detected after rounding, as RISC-V requires.
+
+/// Inexact.
+pub const FLAG_NX: u8 = 1 << 0;
+/// Underflow.
+pub const FLAG_UF: u8 = 1 << 1;
+/// Overflow.
+pub const FLAG_OF: u8 = 1 << 2;
+/// Divide by zero.
+pub const FLAG_DZ: u8 = 1 << 3;
+/// Invalid operation.
+pub const FLAG_NV: u8 = 1 << 4;
+
+pub const F16: Format = Format::new(5, 10);
+pub const F32: Format = Format::new(8, 23);
+pub const F64: Format = Format::new(11, 52);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    NearestEven = 0,
+    TowardZero = 1,
+    Down = 2,
+    Up = 3,
+    NearestMaxMagnitude = 4,
+    /// Round to odd, which no `rm` encoding selects; VFNCVT.ROD.F.F.W uses it
+    /// to narrow without double rounding.
+    Odd,
+}
+
+impl RoundingMode {
+    /// Decodes an `rm` or `frm` field, returning `None` for the reserved
+    /// encodings and for the dynamic mode, which the caller resolves.
+    pub const fn from_bits(bits: u64) -> Option<Self> {
+        match bits {
+            0 => Some(Self::NearestEven),
+            1 => Some(Self::TowardZero),
+            2 => Some(Self::Down),
+            3 => Some(Self::Up),
+            4 => Some(Self::NearestMaxMagnitude),
+            _ => None,
+        }
+    }
+}
+
+/// A binary interchange format. Values of every format travel as the low bits
+/// of a `u64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Format {
+    exp_bits: u32,
+    frac_bits: u32,
+}
+
+impl Format {
+    const fn new(exp_bits: u32, frac_bits: u32) -> Self {
+        Self {
+            exp_bits,
+            frac_bits,
+        }
+    }
+
+    pub const fn bits(self) -> u32 {
+        1 + self.exp_bits + self.frac_bits
+    }
+
+    /// Mask of the bits a value of this format occupies.
+    pub const fn mask(self) -> u64 {
+        u64::MAX >> (64 - self.bits())
+    }
+
+    pub const fn sign(self) -> u64 {
+        1 << (self.bits() - 1)
+    }
+
+    pub const fn canonical_nan(self) -> u64 {
+        (self.exp_max() << self.frac_bits) | (1 << (self.frac_bits - 1))
+    }
+
+    pub const fn is_nan(self, a: u64) -> bool {
+        (a >> self.frac_bits) & self.exp_max() == self.exp_max() && a & self.frac_mask() != 0
+    }
+
+    pub const fn is_signaling_nan(self, a: u64) -> bool {
+        self.is_nan(a) && a & (1 << (self.frac_bits - 1)) == 0
+    }
+
+    const fn exp_max(self) -> u64 {
+        (1 << self.exp_bits) - 1
+    }
+
+    const fn frac_mask(self) -> u64 {
+        (1 << self.frac_bits) - 1
+    }
+
+    const fn bias(self) -> i32 {
+        (1 << (self.exp_bits - 1)) - 1
+    }
+
+    /// Exponent of the least significant bit of a subnormal.
+    const fn exp_min(self) -> i32 {
+        1 - self.bias() - self.frac_bits as i32
+    }
+
+    const fn signed(self, sign: bool, magnitude: u64) -> u64 {
+        if sign {
+            self.sign() | magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    const fn zero(self, sign: bool) -> u64 {
+        self.signed(sign, 0)
+    }
+
+    const fn inf(self, sign: bool) -> u64 {
+        self.signed(sign, self.exp_max() << self.frac_bits)
+    }
+
+    const fn max_finite(self, sign: bool) -> u64 {
+        self.signed(
+            sign,
+            ((self.exp_max() - 1) << self.frac_bits) | self.frac_mask(),
+        )
+    }
+
+    /// Biased exponent and fraction of a nonzero finite magnitude, with
+    /// subnormals normalized: the exponent goes to zero or below and the
+    /// leading one is shifted out of the fraction.
+    const fn normalize(self, a: u64) -> (i64, u64) {
+        let exp = (a >> self.frac_bits) & self.exp_max();
+        let frac = a & self.frac_mask();
+        if exp != 0 {
+            return (exp as i64, frac);
+        }
+        let zeros = frac.leading_zeros() - (64 - self.frac_bits);
+        (-(zeros as i64), (frac << (zeros + 1)) & self.frac_mask())
+    }
+
+    fn unpack(self, a: u64) -> (bool, Value) {
+        let sign = a & self.sign() != 0;
+        let exp = (a >> self.frac_bits) & self.exp_max();
+        let frac = a & self.frac_mask();
+
+        let value = if exp == self.exp_max() {
+            if frac == 0 { Value::Inf } else { Value::Nan }
+        } else if exp == 0 {
+            Value::Finite {
+                exp: self.exp_min(),
+                sig: u128::from(frac),
+            }
+        } else {
+            Value::Finite {
+                exp: self.exp_min() + exp as i32 - 1,
+                sig: u128::from(frac | (1 << self.frac_bits)),
+            }
+        };
+        (sign, value)
+    }
+}
+
+/// An unpacked operand. Finite values, zero included, are `sig * 2^exp`.
+#[derive(Clone, Copy, Debug)]
+enum Value {
+    Nan,
+    Inf,
+    Finite { exp: i32, sig: u128 },
+}
+
+/// Exponent of the most significant bit of `sig * 2^exp`.
+const fn top(exp: i32, sig: u128) -> i32 {
+    exp + 127 - sig.leading_zeros() as i32
+}
+
+/// Shifts right, ORing everything shifted out into the least significant bit
+/// so that rounding still sees the value as inexact.
+const fn shr_jam(sig: u128, shift: u32) -> u128 {
+    if shift == 0 {
+        sig
+    } else if shift >= 128 {
+        (sig != 0) as u128
+    } else {
+        (sig >> shift) | ((sig & ((1 << shift) - 1) != 0) as u128)
+    }
+}
+
+/// Integer square root and whether it was exact.
+const fn isqrt(mut n: u128) -> (u128, bool) {
+    let mut root = 0;
+    let mut bit = 1 << 126;
+    while bit > n {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if n >= root + bit {
+            n -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    (root, n == 0)
+}
+
+/// Rounding state of one operation: the rounding mode it uses and the
+/// exception flags it raised, laid out as in `fflags`.
+#[derive(Clone, Copy, Debug)]
+pub struct SoftFloat {
+    rm: RoundingMode,
+    flags: u8,
+}
+
+impl SoftFloat {
+    pub const fn new(rm: RoundingMode) -> Self {
+        Self { rm, flags: 0 }
+    }
+
+    pub const fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Returns the canonical NaN, raising invalid when asked to or when any
+    /// of `operands` is a signaling NaN.
+    fn nan(&mut self, fmt: Format, invalid: bool, operands: &[u64]) -> u64 {
+        if invalid || operands.iter().any(|&a| fmt.is_signaling_nan(a)) {
+            self.flags |= FLAG_NV;
+        }
+        fmt.canonical_nan()
+    }
+
+    /// Rounds `sig` shifted right by `shift` to an integer, returning it with
+    /// whether any bits were lost.
+    fn round(&self, sign: bool, sig: u128, shift: i32) -> (u128, bool) {
+        if shift <= 0 {
+            return (sig << -shift, false);
+        }
+        let shift = shift as u32;
+        let kept = sig.checked_shr(shift).unwrap_or(0);
+        let half = sig.checked_shr(shift - 1).unwrap_or(0) & 1 != 0;
+        let below_half = match shift - 1 {
+            0 => 0,
+            low @ 1..128 => (1 << low) - 1,
+            _ => u128::MAX,
+        };
+        let sticky = sig & below_half != 0;
+        let inexact = half || sticky;
+
+        let up = match self.rm {
+            RoundingMode::NearestEven => half && (sticky || kept & 1 != 0),
+            RoundingMode::TowardZero => false,
+            RoundingMode::Down => sign && inexact,
+            RoundingMode::Up => !sign && inexact,
+            RoundingMode::NearestMaxMagnitude => half,
+            RoundingMode::Odd => inexact && kept & 1 == 0,
+        };
+        (kept + u128::from(up), inexact)
+    }
+
+    /// Rounds `(-1)^sign * sig * 2^exp` to `fmt`, raising the flags that the
+    /// rounding calls for.
+    fn round_pack(&mut self, fmt: Format, sign: bool, exp: i32, sig: u128) -> u64 {
+        if sig == 0 {
+            return fmt.zero(sign);
+        }
+        let precision = fmt.frac_bits as i32 + 1;
+        let top = top(exp, sig);
+
+        // Tininess after rounding: round to full precision with an unbounded
+        // exponent and see whether the result is still below the normal range.
+        let (unbounded, _) = self.round(sign, sig, top - precision + 1 - exp);
+        let carry = (unbounded >> precision) as i32;
+        let tiny = top + carry < 1 - fmt.bias();
+
+        let mut lsb = (top - precision + 1).max(fmt.exp_min());
+        let (mut kept, inexact) = self.round(sign, sig, lsb - exp);
+        if kept >> precision != 0 {
+            kept >>= 1;
+            lsb += 1;
+        }
+        if inexact {
+            self.flags |= FLAG_NX;
+            if tiny {
+                self.flags |= FLAG_UF;
+            }
+        }
+
+        if kept >> fmt.frac_bits == 0 {
+            return fmt.signed(sign, kept as u64);
+        }
+        let biased = (lsb - fmt.exp_min() + 1) as u64;
+        if biased >= fmt.exp_max() {
+            self.flags |= FLAG_OF | FLAG_NX;
+            return match (self.rm, sign) {
+                (RoundingMode::NearestEven | RoundingMode::NearestMaxMagnitude, _)
+                | (RoundingMode::Down, true)
+                | (RoundingMode::Up, false) => fmt.inf(sign),
+                _ => fmt.max_finite(sign),
+            };
+        }
+        fmt.signed(
+            sign,
+            (biased << fmt.frac_bits) | (kept as u64 & fmt.frac_mask()),
+        )
+    }
+
+    /// Adds two exact finite values and rounds the sum once.
+    #[allow(clippy::too_many_arguments)]
+    fn sum(
+        &mut self,
+        fmt: Format,
+        sa: bool,
+        ea: i32,
+        ma: u128,
+        sb: bool,
+        eb: i32,
+        mb: u128,
+    ) -> u64 {
+        if ma == 0 && mb == 0 {
+            return fmt.zero(if sa == sb {
+                sa
+            } else {
+                self.rm == RoundingMode::Down
+            });
+        }
+        let ((sa, ea, ma), (sb, eb, mb)) = if mb == 0 || (ma != 0 && top(ea, ma) >= top(eb, mb)) {
+            ((sa, ea, ma), (sb, eb, mb))
+        } else {
+            ((sb, eb, mb), (sa, ea, ma))
+        };
+        if mb == 0 {
+            return self.round_pack(fmt, sa, ea, ma);
+        }
+
+        // Align on the smaller exponent. When that would overflow, the smaller
+        // operand sits far below the rounding position and only its stickiness
+        // matters.
+        let (exp, ma, mb) = if eb >= ea {
+            (ea, ma, mb << (eb - ea))
+        } else {
+            let diff = (ea - eb) as u32;
+            let room = (ma.leading_zeros() - 2).min(diff);
+            (ea - room as i32, ma << room, shr_jam(mb, diff - room))
+        };
+
+        let (sign, sig) = if sa == sb {
+            (sa, ma + mb)
+        } else if ma >= mb {
+            (sa, ma - mb)
+        } else {
+            (sb, mb - ma)
+        };
+        if sig == 0 {
+            return fmt.zero(self.rm == RoundingMode::Down);
+        }
+        self.round_pack(fmt, sign, exp, sig)
+    }
+
+    pub fn add(&mut self, fmt: Format, a: u64, b: u64) -> u64 {
+        let (sa, va) = fmt.unpack(a);
+        let (sb, vb) = fmt.unpack(b);
+        match (va, vb) {
+            (Value::Nan, _) | (_, Value::Nan) => self.nan(fmt, false, &[a, b]),
+            (Value::Inf, Value::Inf) if sa != sb => self.nan(fmt, true, &[]),
+            (Value::Inf, _) => fmt.inf(sa),
+            (_, Value::Inf) => fmt.inf(sb),
+            (Value::Finite { exp: ea, sig: ma }, Value::Finite { exp: eb, sig: mb }) => {
+                self.sum(fmt, sa, ea, ma, sb, eb, mb)
+            }
+        }
+    }
+
+    pub fn sub(&mut self, fmt: Format, a: u64, b: u64) -> u64 {
+        self.add(fmt, a, b ^ fmt.sign())
+    }
+
+    pub fn mul(&mut self, fmt: Format, a: u64, b: u64) -> u64 {
+        let (sa, va) = fmt.unpack(a);
+        let (sb, vb) = fmt.unpack(b);
+        let sign = sa != sb;
+        match (va, vb) {
+            (Value::Nan, _) | (_, Value::Nan) => self.nan(fmt, false, &[a, b]),
+            (Value::Inf, Value::Finite { sig: 0, .. })
+            | (Value::Finite { sig: 0, .. }, Value::Inf) => self.nan(fmt, true, &[]),
+            (Value::Inf, _) | (_, Value::Inf) => fmt.inf(sign),
+            (Value::Finite { exp: ea, sig: ma }, Value::Finite { exp: eb, sig: mb }) => {
+                self.round_pack(fmt, sign, ea + eb, ma * mb)
+            }
+        }
+    }
+
+    pub fn div(&mut self, fmt: Format, a: u64, b: u64) -> u64 {
+        let (sa, va) = fmt.unpack(a);
+        let (sb, vb) = fmt.unpack(b);
+        let sign = sa != sb;
+        match (va, vb) {
+            (Value::Nan, _) | (_, Value::Nan) => self.nan(fmt, false, &[a, b]),
+            (Value::Inf, Value::Inf) => self.nan(fmt, true, &[]),
+            (Value::Inf, _) => fmt.inf(sign),
+            (_, Value::Inf) => fmt.zero(sign),
+            (Value::Finite { sig: 0, .. }, Value::Finite { sig: 0, .. }) => {
+                self.nan(fmt, true, &[])
+            }
+            (_, Value::Finite { sig: 0, .. }) => {
+                self.flags |= FLAG_DZ;
+                fmt.inf(sign)
+            }
+            (Value::Finite { exp: ea, sig: ma }, Value::Finite { exp: eb, sig: mb }) => {
+                // With the dividend at the top of a u128 the quotient keeps far
+                // more bits than any format needs, and the remainder is sticky.
+                let shift = ma.leading_zeros() - 1;
+                let num = ma << shift;
+                let quot = num / mb;
+                let sig = quot | u128::from(num % mb != 0);
+                self.round_pack(fmt, sign, ea - shift as i32 - eb, sig)
+            }
+        }
+    }
+
+    pub fn sqrt(&mut self, fmt: Format, a: u64) -> u64 {
+        match fmt.unpack(a) {
+            (_, Value::Nan) => self.nan(fmt, false, &[a]),
+            (_, Value::Finite { sig: 0, .. }) => a,
+            (true, _) => self.nan(fmt, true, &[]),
+            (false, Value::Inf) => a,
+            (false, Value::Finite { exp, sig }) => {
+                let mut shift = sig.leading_zeros() as i32 - 2;
+                if (exp - shift) & 1 != 0 {
+                    shift += 1;
+                }
+                let (root, exact) = isqrt(sig << shift);
+                self.round_pack(fmt, false, (exp - shift) / 2, root | u128::from(!exact))
+            }
+        }
+    }
+
+    /// Computes `a * b + c` with a single rounding. Negated forms are obtained
+    /// by flipping the sign of `a` and `c`, which is exact.
+    pub fn mul_add(&mut self, fmt: Format, a: u64, b: u64, c: u64) -> u64 {
+        let (sa, va) = fmt.unpack(a);
+        let (sb, vb) = fmt.unpack(b);
+        let (sc, vc) = fmt.unpack(c);
+        let sign = sa != sb;
+
+        // Infinity times zero is invalid even when the addend is a quiet NaN.
+        let invalid = matches!(
+            (va, vb),
+            (Value::Inf, Value::Finite { sig: 0, .. }) | (Value::Finite { sig: 0, .. }, Value::Inf)
+        );
+        match (va, vb, vc) {
+            (Value::Nan, _, _) | (_, Value::Nan, _) | (_, _, Value::Nan) => {
+                self.nan(fmt, invalid, &[a, b, c])
+            }
+            _ if invalid => self.nan(fmt, true, &[]),
+            (Value::Inf, _, Value::Inf) | (_, Value::Inf, Value::Inf) if sign != sc => {
+                self.nan(fmt, true, &[])
+            }
+            (Value::Inf, _, _) | (_, Value::Inf, _) => fmt.inf(sign),
+            (_, _, Value::Inf) => fmt.inf(sc),
+            (
+                Value::Finite { exp: ea, sig: ma },
+                Value::Finite { exp: eb, sig: mb },
+                Value::Finite { exp: ec, sig: mc },
+            ) => self.sum(fmt, sign, ea + eb, ma * mb, sc, ec, mc),
+        }
+    }
+
+    /// IEEE 754-2019 minimumNumber: NaNs are ignored unless both operands are
+    /// NaN, and -0 is below +0.
+    pub fn min(&mut self, fmt: Format, a: u64, b: u64) -> u64 {
+        self.min_max(fmt, a, b, true)
+    }
+
+    /// IEEE 754-2019 maximumNumber, the counterpart of [`Self::min`].
+    pub fn max(&mut self, fmt: Format, a: u64, b: u64) -> u64 {
+        self.min_max(fmt, a, b, false)
+    }
+
+    fn min_max(&mut self, fmt: Format, a: u64, b: u64, min: bool) -> u64 {
+        if fmt.is_signaling_nan(a) || fmt.is_signaling_nan(b) {
+            self.flags |= FLAG_NV;
+        }
+        match (fmt.is_nan(a), fmt.is_nan(b)) {
+            (true, true) => fmt.canonical_nan(),
+            (true, false) => b,
+            (false, true) => a,
+            (false, false) => {
+                let a_below = Self::key(fmt, a) < Self::key(fmt, b)
+                    || (a & fmt.sign() != 0 && b & fmt.sign() == 0);
+                if a_below == min { a } else { b }
+            }
+        }
+    }
+
+    /// Orders non-NaN values, with both zeros equal.
+    fn key(fmt: Format, a: u64) -> i64 {
+        let magnitude = (a & !fmt.sign()) as i64;
+        if a & fmt.sign() != 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Quiet equality: only signaling NaNs are invalid.
+    pub fn eq(&mut self, fmt: Format, a: u64, b: u64) -> bool {
+        if fmt.is_nan(a) || fmt.is_nan(b) {
+            self.nan(fmt, false, &[a, b]);
+            return false;
+        }
+        Self::key(fmt, a) == Self::key(fmt, b)
+    }
+
+    /// Signaling less-than: any NaN is invalid.
+    pub fn lt(&mut self, fmt: Format, a: u64, b: u64) -> bool {
+        if fmt.is_nan(a) || fmt.is_nan(b) {
+            self.flags |= FLAG_NV;
+            return false;
+        }
+        Self::key(fmt, a) < Self::key(fmt, b)
+    }
+
+    /// Signaling less-than-or-equal: any NaN is invalid.
+    pub fn le(&mut self, fmt: Format, a: u64, b: u64) -> bool {
+        if fmt.is_nan(a) || fmt.is_nan(b) {
+            self.flags |= FLAG_NV;
+            return false;
+        }
+        Self::key(fmt, a) <= Self::key(fmt, b)
+    }
+
+    /// Returns the one-hot class mask written by FCLASS.
+    pub fn classify(fmt: Format, a: u64) -> u64 {
+        let (sign, value) = fmt.unpack(a);
+        let bit = match value {
+            Value::Nan if fmt.is_signaling_nan(a) => 8,
+            Value::Nan => 9,
+            Value::Inf => 0,
+            Value::Finite { sig: 0, .. } => 3,
+            Value::Finite { sig, .. } if sig >> fmt.frac_bits == 0 => 2,
+            Value::Finite { .. } => 1,
+        };
+        match (value, sign) {
+            (Value::Nan, _) | (_, true) => 1 << bit,
+            (_, false) => 1 << (7 - bit),
+        }
+    }
+
+    /// Converts between formats, rounding when narrowing.
+    pub fn convert(&mut self, from: Format, to: Format, a: u64) -> u64 {
+        match from.unpack(a) {
+            (_, Value::Nan) => {
+                self.nan(from, false, &[a]);
+                to.canonical_nan()
+            }
+            (sign, Value::Inf) => to.inf(sign),
+            (sign, Value::Finite { exp, sig }) => self.round_pack(to, sign, exp, sig),
+        }
+    }
+
+    /// Converts to a `width`-bit integer, saturating out-of-range values and
+    /// NaN as RISC-V does. 32-bit results come back sign-extended.
+    pub fn to_int(&mut self, fmt: Format, a: u64, width: u32, signed: bool) -> u64 {
+        let (min, max): (i128, i128) = if signed {
+            (-(1 << (width - 1)), (1 << (width - 1)) - 1)
+        } else {
+            (0, (1 << width) - 1)
+        };
+
+        let val = match fmt.unpack(a) {
+            (_, Value::Nan) => {
+                self.flags |= FLAG_NV;
+                max
+            }
+            (sign, Value::Inf) => {
+                self.flags |= FLAG_NV;
+                if sign { min } else { max }
+            }
+            (sign, Value::Finite { exp, sig }) => {
+                let (magnitude, inexact) = if exp > 64 {
+                    (u128::MAX >> 1, false)
+                } else {
+                    self.round(sign, sig, -exp)
+                };
+                let val = if sign {
+                    -(magnitude as i128)
+                } else {
+                    magnitude as i128
+                };
+                if val < min || val > max {
+                    self.flags |= FLAG_NV;
+                    if sign { min } else { max }
+                } else {
+                    if inexact {
+                        self.flags |= FLAG_NX;
+                    }
+                    val
+                }
+            }
+        };
+
+        if width == 32 {
+            val as u32 as i32 as u64
+        } else {
+            val as u64
+        }
+    }
+
+    /// Converts the low `width` bits of `val`, taken as signed or unsigned.
+    pub fn from_int(&mut self, fmt: Format, val: u64, width: u32, signed: bool) -> u64 {
+        let shift = 64 - width;
+        let (sign, magnitude) = if signed {
+            let val = ((val << shift) as i64) >> shift;
+            (val < 0, val.unsigned_abs())
+        } else {
+            (false, (val << shift) >> shift)
+        };
+        self.round_pack(fmt, sign, 0, u128::from(magnitude))
+    }
+
+    /// Estimates `1 / a` to 7 bits, as VFREC7 does. Inputs whose reciprocal
+    /// overflows round to infinity or to the largest finite value depending
+    /// on the rounding mode.
+    pub fn rec7(&mut self, fmt: Format, a: u64) -> u64 {
+        let (sign, value) = fmt.unpack(a);
+        match value {
+            Value::Nan => self.nan(fmt, false, &[a]),
+            Value::Inf => fmt.zero(sign),
+            Value::Finite { sig: 0, .. } => {
+                self.flags |= FLAG_DZ;
+                fmt.inf(sign)
+            }
+            Value::Finite { .. } => {
+                let (exp, frac) = fmt.normalize(a);
+                let mut out_exp = 2 * i64::from(fmt.bias()) - 1 - exp;
+                if out_exp >= fmt.exp_max() as i64 {
+                    self.flags |= FLAG_OF | FLAG_NX;
+                    return match (self.rm, sign) {
+                        (RoundingMode::TowardZero, _)
+                        | (RoundingMode::Down, false)
+                        | (RoundingMode::Up, true) => fmt.max_finite(sign),
+                        _ => fmt.inf(sign),
+                    };
+                }
+
+                let index = (frac >> (fmt.frac_bits - 7)) as usize;
+                let mut out_frac = u64::from(REC7[index]) << (fmt.frac_bits - 7);
+                if out_exp <= 0 {
+                    out_frac = (out_frac >> 1) | (1 << (fmt.frac_bits - 1));
+                    if out_exp < 0 {
+                        out_frac >>= 1;
+                    }
+                    out_exp = 0;
+                }
+                fmt.signed(sign, ((out_exp as u64) << fmt.frac_bits) | out_frac)
+            }
+        }
+    }
+
+    /// Estimates `1 / sqrt(a)` to 7 bits, as VFRSQRT7 does.
+    pub fn rsqrt7(&mut self, fmt: Format, a: u64) -> u64 {
+        match fmt.unpack(a) {
+            (_, Value::Nan) => self.nan(fmt, false, &[a]),
+            (sign, Value::Finite { sig: 0, .. }) => {
+                self.flags |= FLAG_DZ;
+                fmt.inf(sign)
+            }
+            (true, _) => self.nan(fmt, true, &[a]),
+            (false, Value::Inf) => fmt.zero(false),
+            (false, Value::Finite { .. }) => {
+                let (exp, frac) = fmt.normalize(a);
+                let index = (((exp & 1) as u64) << 6) | (frac >> (fmt.frac_bits - 6));
+                let out_exp = (3 * i64::from(fmt.bias()) - 1 - exp) / 2;
+                let out_frac = u64::from(RSQRT7[index as usize]) << (fmt.frac_bits - 7);
+                ((out_exp as u64) << fmt.frac_bits) | out_frac
+            }
+        }
+    }
+}
+
+/// VFREC7 significands, indexed by the top seven fraction bits of the input.
+#[rustfmt::skip]
+const REC7: [u8; 128] = [
+    127, 125, 123, 121, 119, 117, 116, 114, 112, 110, 109, 107, 105, 104, 102, 100,
+    99, 97, 96, 94, 93, 91, 90, 88, 87, 85, 84, 83, 81, 80, 79, 77,
+    76, 75, 74, 72, 71, 70, 69, 68, 66, 65, 64, 63, 62, 61, 60, 59,
+    58, 57, 56, 55, 54, 53, 52, 51, 50, 49, 48, 47, 46, 45, 44, 43,
+    42, 41, 40, 40, 39, 38, 37, 36, 35, 35, 34, 33, 32, 31, 31, 30,
+    29, 28, 28, 27, 26, 25, 25, 24, 23, 23, 22, 21, 21, 20, 19, 19,
+    18, 17, 17, 16, 15, 15, 14, 14, 13, 12, 12, 11, 11, 10, 9, 9,
+    8, 8, 7, 7, 6, 5, 5, 4, 4, 3, 3, 2, 2, 1, 1, 0,
+];
+
+/// VFRSQRT7 significands, indexed by the exponent's low bit and the top six
+/// fraction bits of the input.
+#[rustfmt::skip]
+const RSQRT7: [u8; 128] = [
+    52, 51, 50, 48, 47, 46, 44, 43, 42, 41, 40, 39, 38, 36, 35, 34,
+    33, 32, 31, 30, 30, 29, 28, 27, 26, 25, 24, 23, 23, 22, 21, 20,
+    19, 19, 18, 17, 16, 16, 15, 14, 14, 13, 12, 12, 11, 10, 10, 9,
+    9, 8, 7, 7, 6, 6, 5, 4, 4, 3, 3, 2, 2, 1, 1, 0,
+    127, 125, 123, 121, 119, 118, 116, 114, 113, 111, 109, 108, 106, 105, 103, 102,
+    100, 99, 97, 96, 95, 93, 92, 91, 90, 88, 87, 86, 85, 84, 83, 82,
+    80, 79, 78, 77, 76, 75, 74, 73, 72, 71, 70, 70, 69, 68, 67, 66,
+    65, 64, 63, 63, 62, 61, 60, 59, 59, 58, 57, 56, 56, 55, 54, 53,
+];
+
+impl Default for SoftFloat {
+    /// Operations that do not round, such as comparisons, only use the flags.
+    fn default() -> Self {
+        Self::new(RoundingMode::NearestEven)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [RoundingMode; 5] = [
+        RoundingMode::NearestEven,
+        RoundingMode::TowardZero,
+        RoundingMode::Down,
+        RoundingMode::Up,
+        RoundingMode::NearestMaxMagnitude,
+    ];
+
+    fn rne() -> SoftFloat {
+        SoftFloat::new(RoundingMode::NearestEven)
+    }
+
+    fn s(val: f32) -> u64 {
+        u64::from(val.to_bits())
+    }
+
+    fn d(val: f64) -> u64 {
+        val.to_bits()
+    }
+
+    /// A small xorshift generator biased towards interesting bit patterns:
+    /// extreme exponents and all-ones or all-zeros fractions.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn value(&mut self, fmt: Format) -> u64 {
+            let raw = self.next();
+            let spread = fmt.bias().min(32) as u64;
+            let frac = match raw >> 60 {
+                0 => 0,
+                1 => fmt.frac_mask(),
+                _ => raw & fmt.frac_mask(),
+            };
+            let exp = match (raw >> 56) & 0xf {
+                0 => 0,
+                1 => 1,
+                2 => fmt.exp_max() - 1,
+                3 => fmt.exp_max(),
+                _ => fmt.bias() as u64 + (raw >> 40) % (2 * spread) - spread,
+            };
+            ((raw >> 55) & 1) << (fmt.bits() - 1) | (exp << fmt.frac_bits) | frac
+        }
+    }
+
+    /// Compares against the host for round-to-nearest-even, where Rust float
+    /// arithmetic is exactly IEEE 754. NaN payloads differ, so only NaN-ness
+    /// is compared for those.
+    fn same(fmt: Format, ours: u64, host: u64) -> bool {
+        if fmt.is_nan(host) {
+            ours == fmt.canonical_nan()
+        } else {
+            ours == host
+        }
+    }
+
+    #[test]
+    fn softfloat_matches_host_f32() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..200_000 {
+            let (a, b, c) = (rng.value(F32), rng.value(F32), rng.value(F32));
+            let (fa, fb, fc) = (
+                f32::from_bits(a as u32),
+                f32::from_bits(b as u32),
+                f32::from_bits(c as u32),
+            );
+            assert!(same(F32, rne().add(F32, a, b), s(fa + fb)), "{a:x} + {b:x}");
+            assert!(same(F32, rne().sub(F32, a, b), s(fa - fb)), "{a:x} - {b:x}");
+            assert!(same(F32, rne().mul(F32, a, b), s(fa * fb)), "{a:x} * {b:x}");
+            assert!(same(F32, rne().div(F32, a, b), s(fa / fb)), "{a:x} / {b:x}");
+            assert!(same(F32, rne().sqrt(F32, a), s(fa.sqrt())), "sqrt {a:x}");
+            assert!(
+                same(F32, rne().mul_add(F32, a, b, c), s(fa.mul_add(fb, fc))),
+                "{a:x} * {b:x} + {c:x}"
+            );
+            assert!(
+                same(F64, rne().convert(F32, F64, a), d(f64::from(fa))),
+                "{a:x} to f64"
+            );
+        }
+    }
+
+    #[test]
+    fn softfloat_matches_host_f64() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..200_000 {
+            let (a, b, c) = (rng.value(F64), rng.value(F64), rng.value(F64));
+            let (fa, fb, fc) = (f64::from_bits(a), f64::from_bits(b), f64::from_bits(c));
+            assert!(same(F64, rne().add(F64, a, b), d(fa + fb)), "{a:x} + {b:x}");
+            assert!(same(F64, rne().mul(F64, a, b), d(fa * fb)), "{a:x} * {b:x}");
+            assert!(same(F64, rne().div(F64, a, b), d(fa / fb)), "{a:x} / {b:x}");
+            assert!(same(F64, rne().sqrt(F64, a), d(fa.sqrt())), "sqrt {a:x}");
+            assert!(
+                same(F64, rne().mul_add(F64, a, b, c), d(fa.mul_add(fb, fc))),
+                "{a:x} * {b:x} + {c:x}"
+            );
+            assert!(
+                same(F32, rne().convert(F64, F32, a), s(fa as f32)),
+                "{a:x} to f32"
+            );
+            assert_eq!(rne().from_int(F64, a, 64, true), d(a as i64 as f64));
+            assert_eq!(rne().from_int(F64, a, 64, false), d(a as f64));
+            assert_eq!(rne().from_int(F32, a, 64, true), s(a as i64 as f32));
+        }
+    }
+
+    /// There is no host half-precision type, so operands are widened to f32
+    /// and the host result narrowed again. f32 carries more than twice the
+    /// precision of f16 plus two bits, so that double rounding is exact for
+    /// the basic operations (but not for fused multiply-add).
+    #[test]
+    fn softfloat_matches_host_f16_through_f32() {
+        let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+        let widen = |h: u64| f32::from_bits(rne().convert(F16, F32, h) as u32);
+        let narrow = |f: f32| rne().convert(F32, F16, s(f));
+        for _ in 0..200_000 {
+            let (a, b) = (rng.value(F16), rng.value(F16));
+            let (fa, fb) = (widen(a), widen(b));
+            assert!(
+                same(F16, rne().add(F16, a, b), narrow(fa + fb)),
+                "{a:x} + {b:x}"
+            );
+            assert!(
+                same(F16, rne().sub(F16, a, b), narrow(fa - fb)),
+                "{a:x} - {b:x}"
+            );
+            assert!(
+                same(F16, rne().mul(F16, a, b), narrow(fa * fb)),
+                "{a:x} * {b:x}"
+            );
+            assert!(
+                same(F16, rne().div(F16, a, b), narrow(fa / fb)),
+                "{a:x} / {b:x}"
+            );
+            assert!(
+                same(F16, rne().sqrt(F16, a), narrow(fa.sqrt())),
+                "sqrt {a:x}"
+            );
+            assert!(
+                same(F64, rne().convert(F16, F64, a), d(f64::from(fa))),
+                "{a:x} to f64"
+            );
+        }
+    }
+
+    #[test]
+    fn softfloat_f16_edge_cases() {
+        // 65520 is halfway between the largest finite value and 2^16.
+        let mut sf = rne();
+        assert_eq!(sf.convert(F32, F16, s(65_520.0)), 0x7c00);
+        assert_eq!(sf.flags(), FLAG_OF | FLAG_NX);
+
+        let mut sf = rne();
+        assert_eq!(sf.convert(F32, F16, s(65_519.0)), 0x7bff);
+        assert_eq!(sf.flags(), FLAG_NX);
+
+        let mut sf = SoftFloat::new(RoundingMode::TowardZero);
+        assert_eq!(sf.mul(F16, 0x7bff, 0x4000), 0x7bff);
+        assert_eq!(sf.flags(), FLAG_OF | FLAG_NX);
+
+        // Half the smallest subnormal ties to even, i.e. zero.
+        let mut sf = rne();
+        assert_eq!(sf.convert(F32, F16, s(2f32.powi(-25))), 0x0000);
+        assert_eq!(sf.flags(), FLAG_UF | FLAG_NX);
+
+        let mut sf = rne();
+        assert_eq!(sf.convert(F32, F16, s(3.0 * 2f32.powi(-26))), 0x0001);
+        assert_eq!(sf.flags(), FLAG_UF | FLAG_NX);
+
+        let mut sf = rne();
+        assert_eq!(sf.convert(F32, F16, s(2f32.powi(-24))), 0x0001);
+        assert_eq!(sf.add(F16, 0x03ff, 0x0001), 0x0400);
+        assert_eq!(sf.flags(), 0);
+
+        let mut sf = rne();
+        assert_eq!(sf.sqrt(F16, 0xbc00), F16.canonical_nan());
+        assert_eq!(sf.convert(F16, F32, 0x7c01), F32.canonical_nan());
+        assert_eq!(sf.flags(), FLAG_NV);
+
+        let mut sf = rne();
+        assert_eq!(sf.to_int(F16, 0xfbff, 32, true), -65_504i64 as u64);
+        assert_eq!(sf.from_int(F16, 2049, 64, false), 0x6800);
+        assert_eq!(sf.flags(), FLAG_NX);
+    }
+
+    #[test]
+    fn softfloat_nan_results_are_canonical() {
+        let mut sf = rne();
+
+        assert_eq!(sf.add(F32, 0x7fc0_1234, s(1.0)), 0x7fc0_0000);
+        assert_eq!(sf.flags(), 0);
+        assert_eq!(
+            sf.add(F64, d(f64::INFINITY), d(f64::NEG_INFINITY)),
+            0x7ff8_0000_0000_0000
+        );
+        assert_eq!(sf.flags(), FLAG_NV);
+    }
+
+    #[test]
+    fn softfloat_signaling_nan_is_invalid() {
+        let mut sf = rne();
+
+        assert_eq!(sf.mul(F32, 0x7f80_0001, s(2.0)), 0x7fc0_0000);
+        assert_eq!(sf.flags(), FLAG_NV);
+    }
+
+    #[test]
+    fn softfloat_inexact_and_overflow() {
+        let mut sf = rne();
+        assert_eq!(sf.add(F32, s(1.0), s(f32::EPSILON / 4.0)), s(1.0));
+        assert_eq!(sf.flags(), FLAG_NX);
+
+        let mut sf = rne();
+        assert_eq!(sf.mul(F32, s(f32::MAX), s(2.0)), s(f32::INFINITY));
+        assert_eq!(sf.flags(), FLAG_OF | FLAG_NX);
+    }
+
+    #[test]
+    fn softfloat_overflow_depends_on_rounding_mode() {
+        let expected = [
+            f32::NEG_INFINITY,
+            -f32::MAX,
+            f32::NEG_INFINITY,
+            -f32::MAX,
+            f32::NEG_INFINITY,
+        ];
+        for (rm, expected) in ALL.into_iter().zip(expected) {
+            let mut sf = SoftFloat::new(rm);
+            assert_eq!(sf.mul(F32, s(-f32::MAX), s(2.0)), s(expected), "{rm:?}");
+        }
+    }
+
+    #[test]
+    fn softfloat_rounding_modes_on_ties() {
+        // 2^24 + 1 lies halfway between two floats; so does -(2^24 + 3).
+        let cases = [
+            (
+                16_777_217,
+                [
+                    16_777_216.0,
+                    16_777_216.0,
+                    16_777_216.0,
+                    16_777_218.0,
+                    16_777_218.0,
+                ],
+            ),
+            (
+                -16_777_219,
+                [
+                    -16_777_220.0,
+                    -16_777_218.0,
+                    -16_777_220.0,
+                    -16_777_218.0,
+                    -16_777_220.0,
+                ],
+            ),
+        ];
+        for (val, expected) in cases {
+            for (rm, expected) in ALL.into_iter().zip(expected) {
+                let mut sf = SoftFloat::new(rm);
+                assert_eq!(
+                    sf.from_int(F32, val as u64, 64, true),
+                    s(expected),
+                    "{val} {rm:?}"
+                );
+                assert_eq!(sf.flags(), FLAG_NX);
+            }
+        }
+    }
+
+    #[test]
+    fn softfloat_tininess_detected_after_rounding() {
+        // Just below the smallest normal: tiny before rounding, but it rounds
+        // up to it at full precision, so it does not underflow.
+        let min = f32::MIN_POSITIVE;
+        let mut sf = rne();
+        let below = f64::from(min) * (1.0 - 2f64.powi(-25));
+        assert_eq!(sf.convert(F64, F32, d(below)), s(min));
+        assert_eq!(sf.flags(), FLAG_NX);
+
+        // Further below, it stays tiny even at full precision.
+        let mut sf = rne();
+        let below = f64::from(min) * (1.0 - 2f64.powi(-24) - 2f64.powi(-30));
+        assert_eq!(sf.convert(F64, F32, d(below)), s(min) - 1);
+        assert_eq!(sf.flags(), FLAG_UF | FLAG_NX);
+
+        let mut sf = rne();
+        assert_eq!(sf.mul(F32, s(min), s(0.75)), s(min * 0.75));
+        assert_eq!(sf.flags(), 0);
+
+        let mut sf = rne();
+        sf.mul(F32, s(min), s(1.0 / 3.0));
+        assert_eq!(sf.flags(), FLAG_UF | FLAG_NX);
+    }
+
+    #[test]
+    fn softfloat_exact_zero_sign() {
+        assert_eq!(rne().add(F32, s(1.0), s(-1.0)), s(0.0));
+        assert_eq!(
+            SoftFloat::new(RoundingMode::Down).add(F32, s(1.0), s(-1.0)),
+            s(-0.0)
+        );
+        assert_eq!(rne().add(F32, s(-0.0), s(-0.0)), s(-0.0));
+        assert_eq!(rne().mul_add(F32, s(-0.0), s(1.0), s(-0.0)), s(-0.0));
+    }
+
+    #[test]
+    fn softfloat_divide_by_zero() {
+        let mut sf = rne();
+
+        assert_eq!(sf.div(F64, d(-1.0), d(0.0)), d(f64::NEG_INFINITY));
+        assert_eq!(sf.flags(), FLAG_DZ);
+
+        let mut sf = rne();
+        assert_eq!(sf.div(F64, d(0.0), d(0.0)), F64.canonical_nan());
+        assert_eq!(sf.flags(), FLAG_NV);
+    }
+
+    #[test]
+    fn softfloat_sqrt_of_negative_is_invalid() {
+        let mut sf = rne();
+
+        assert_eq!(sf.sqrt(F32, s(-0.0)), s(-0.0));
+        assert_eq!(sf.flags(), 0);
+        assert_eq!(sf.sqrt(F32, s(-1.0)), F32.canonical_nan());
+        assert_eq!(sf.flags(), FLAG_NV);
+    }
+
+    #[test]
+    fn softfloat_mul_add_infinity_times_zero_with_quiet_nan() {
+        let mut sf = rne();
+
+        assert_eq!(
+            sf.mul_add(F32, s(f32::INFINITY), s(0.0), F32.canonical_nan()),
+            F32.canonical_nan()
+        );
+        assert_eq!(sf.flags(), FLAG_NV);
+    }
+
+    #[test]
+    fn softfloat_min_max() {
+        let mut sf = rne();
+
+        assert_eq!(sf.min(F32, s(-0.0), s(0.0)), s(-0.0));
+        assert_eq!(sf.max(F32, s(-0.0), s(0.0)), s(0.0));
+        assert_eq!(sf.min(F32, F32.canonical_nan(), s(3.0)), s(3.0));
+        assert_eq!(sf.flags(), 0);
+        assert_eq!(sf.max(F32, 0x7f80_0001, 0x7fc0_0000), F32.canonical_nan());
+        assert_eq!(sf.flags(), FLAG_NV);
+    }
+
+    #[test]
+    fn softfloat_compare_flags() {
+        let mut sf = rne();
+
+        assert!(sf.eq(F32, s(-0.0), s(0.0)));
+        assert!(!sf.eq(F32, F32.canonical_nan(), s(0.0)));
+        assert_eq!(sf.flags(), 0);
+        assert!(!sf.lt(F32, F32.canonical_nan(), s(0.0)));
+        assert_eq!(sf.flags(), FLAG_NV);
+        assert!(sf.le(F32, s(-2.0), s(-1.0)));
+    }
+
+    #[test]
+    fn softfloat_classify() {
+        let cases = [
+            (s(f32::NEG_INFINITY), 1 << 0),
+            (s(-1.0), 1 << 1),
+            (s(-f32::MIN_POSITIVE / 2.0), 1 << 2),
+            (s(-0.0), 1 << 3),
+            (s(0.0), 1 << 4),
+            (s(f32::MIN_POSITIVE / 2.0), 1 << 5),
+            (s(1.0), 1 << 6),
+            (s(f32::INFINITY), 1 << 7),
+            (0xff80_0001, 1 << 8),
+            (0xffc0_0000, 1 << 9),
+        ];
+        for (val, class) in cases {
+            assert_eq!(SoftFloat::classify(F32, val), class, "{val:x}");
+        }
+    }
+
+    #[test]
+    fn softfloat_to_int_saturates() {
+        let mut sf = rne();
+        assert_eq!(sf.to_int(F32, s(3e9), 32, true), 0x7fff_ffff);
+        assert_eq!(sf.to_int(F32, s(-3e9), 32, true), 0xffff_ffff_8000_0000);
+        assert_eq!(
+            sf.to_int(F32, F32.canonical_nan(), 64, true),
+            i64::MAX as u64
+        );
+        assert_eq!(sf.to_int(F64, d(-1.0), 64, false), 0);
+        assert_eq!(sf.to_int(F64, d(f64::INFINITY), 32, false), u64::MAX);
+        assert_eq!(sf.flags(), FLAG_NV);
+    }
+
+    #[test]
+    fn softfloat_to_int_rounds() {
+        let mut sf = SoftFloat::new(RoundingMode::TowardZero);
+        assert_eq!(sf.to_int(F64, d(-2.5), 32, true), -2i64 as u64);
+        assert_eq!(sf.to_int(F64, d(-0.5), 32, false), 0);
+        assert_eq!(sf.flags(), FLAG_NX);
+
+        let mut sf = rne();
+        assert_eq!(sf.to_int(F64, d(2.5), 64, true), 2);
+        assert_eq!(sf.to_int(F64, d(4_294_967_295.0), 32, false), u64::MAX);
+        assert_eq!(sf.flags(), FLAG_NX);
+    }
+
+    #[test]
+    fn softfloat_round_to_odd_sets_lsb_when_inexact() {
+        let mut sf = SoftFloat::new(RoundingMode::Odd);
+        assert_eq!(sf.convert(F64, F32, d(1.0 + 2f64.powi(-30))), s(1.0) | 1);
+        assert_eq!(sf.convert(F64, F32, d(1.5)), s(1.5));
+        assert_eq!(sf.convert(F64, F32, d(f64::MAX)), s(f32::MAX));
+        assert_eq!(sf.flags(), FLAG_NX | FLAG_OF);
+    }
+
+    #[test]
+    fn softfloat_from_int_narrow_widths() {
+        let mut sf = rne();
+        assert_eq!(sf.from_int(F32, 0xffff, 16, true), s(-1.0));
+        assert_eq!(sf.from_int(F32, 0xffff, 16, false), s(65535.0));
+        assert_eq!(sf.from_int(F64, 0x8000_0000, 32, true), d(-2_147_483_648.0));
+        assert_eq!(sf.flags(), 0);
+    }
+
+    #[test]
+    fn softfloat_rec7_estimates() {
+        let mut sf = rne();
+        assert_eq!(sf.rec7(F32, s(1.0)), s(0.996_093_75));
+        assert_eq!(sf.rec7(F32, s(-2.0)), s(-255.0 / 512.0));
+        assert_eq!(sf.rec7(F32, s(f32::MAX)), 0x0020_0000);
+        assert_eq!(sf.rec7(F32, s(f32::INFINITY)), 0);
+        assert_eq!(sf.flags(), 0);
+
+        assert_eq!(sf.rec7(F32, 1), s(f32::INFINITY));
+        assert_eq!(sf.flags(), FLAG_OF | FLAG_NX);
+        let mut sf = SoftFloat::new(RoundingMode::TowardZero);
+        assert_eq!(sf.rec7(F32, 1), s(f32::MAX));
+    }
+
+    #[test]
+    fn softfloat_rsqrt7_estimates() {
+        let mut sf = rne();
+        assert_eq!(sf.rsqrt7(F32, s(1.0)), s(0.996_093_75));
+        assert_eq!(sf.rsqrt7(F64, d(4.0)), d(0.498_046_875));
+        assert_eq!(sf.rsqrt7(F32, s(f32::INFINITY)), 0);
+        assert_eq!(sf.flags(), 0);
+
+        assert_eq!(sf.rsqrt7(F32, s(-0.0)), s(f32::NEG_INFINITY));
+        assert_eq!(sf.flags(), FLAG_DZ);
+        assert_eq!(sf.rsqrt7(F32, s(-1.0)), F32.canonical_nan());
+        assert_eq!(sf.flags(), FLAG_DZ | FLAG_NV);
+    }
+}
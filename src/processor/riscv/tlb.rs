@@ -0,0 +1,272 @@
+use crate::processor::riscv::mmu::PTE_G;
+
+const PAGE_SHIFT: u32 = 12;
+
+/// A translation as produced by a page walk.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Leaf {
+    /// Leaf PTE as it was after the walk, including its A/D bits.
+    pub pte: u64,
+    /// G-stage leaf PTE of a guest translation, which must permit the access
+    /// as well; zero for host translations.
+    pub gpte: u64,
+    /// VPN bits the leaf PTE maps beyond a 4 KiB page, so that flushing one
+    /// address of a superpage drops every cached page of that superpage.
+    pub span: u32,
+    /// Physical page number backing the page.
+    pub ppn: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct TlbEntry {
+    valid: bool,
+    /// Virtual page number of the 4 KiB page this entry translates. Superpage
+    /// translations are cached one 4 KiB page at a time.
+    vpn: u64,
+    asid: u16,
+    /// VMID of the guest a two-stage translation belongs to, `None` for host
+    /// translations.
+    vmid: Option<u16>,
+    leaf: Leaf,
+}
+
+impl TlbEntry {
+    const fn global(&self) -> bool {
+        self.leaf.pte & PTE_G != 0
+    }
+
+    const fn covers(&self, vaddr: u64) -> bool {
+        let shift = self.leaf.span;
+        self.vpn >> shift == (vaddr >> PAGE_SHIFT) >> shift
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TlbStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// A direct-mapped translation cache, tagged with ASID and VMID.
+#[derive(Debug)]
+pub struct Tlb {
+    entries: Box<[TlbEntry]>,
+    stats: TlbStats,
+}
+
+impl Tlb {
+    pub const DEFAULT_ENTRIES: usize = 256;
+
+    pub fn new(entries: usize) -> Self {
+        debug_assert!(entries.is_power_of_two());
+        Self {
+            entries: vec![TlbEntry::default(); entries].into_boxed_slice(),
+            stats: TlbStats::default(),
+        }
+    }
+
+    #[inline(always)]
+    fn index(&self, vpn: u64) -> usize {
+        vpn as usize & (self.entries.len() - 1)
+    }
+
+    /// Looks up the translation caching `vaddr` in the address space `asid`
+    /// of the host, or of the guest `vmid`.
+    #[inline(always)]
+    pub fn lookup(&mut self, vaddr: u64, asid: u16, vmid: Option<u16>) -> Option<Leaf> {
+        let vpn = vaddr >> PAGE_SHIFT;
+        let entry = &self.entries[self.index(vpn)];
+        if entry.valid
+            && entry.vpn == vpn
+            && entry.vmid == vmid
+            && (entry.global() || entry.asid == asid)
+        {
+            self.stats.hits += 1;
+            Some(entry.leaf)
+        } else {
+            self.stats.misses += 1;
+            None
+        }
+    }
+
+    pub fn insert(&mut self, vaddr: u64, asid: u16, vmid: Option<u16>, leaf: Leaf) {
+        let vpn = vaddr >> PAGE_SHIFT;
+        let index = self.index(vpn);
+        self.entries[index] = TlbEntry {
+            valid: true,
+            vpn,
+            asid,
+            vmid,
+            leaf,
+        };
+    }
+
+    /// Drops cached host translations following SFENCE.VMA semantics:
+    /// `vaddr` restricts the flush to one page and `asid` to one non-global
+    /// address space; `None` means every page or every address space
+    /// respectively.
+    pub fn flush(&mut self, vaddr: Option<u64>, asid: Option<u16>) {
+        self.invalidate(|entry| entry.vmid.is_none(), vaddr, asid);
+    }
+
+    /// Drops cached guest translations of `vmid`, or of every guest when
+    /// `None`, following HFENCE.VVMA semantics for `vaddr` and `asid`.
+    pub fn flush_guest(&mut self, vmid: Option<u16>, vaddr: Option<u64>, asid: Option<u16>) {
+        self.invalidate(
+            |entry| entry.vmid.is_some() && vmid.is_none_or(|vmid| entry.vmid == Some(vmid)),
+            vaddr,
+            asid,
+        );
+    }
+
+    fn invalidate(
+        &mut self,
+        owner: impl Fn(&TlbEntry) -> bool,
+        vaddr: Option<u64>,
+        asid: Option<u16>,
+    ) {
+        for entry in &mut self.entries {
+            let page = vaddr.is_none_or(|vaddr| entry.covers(vaddr));
+            let space = asid.is_none_or(|asid| !entry.global() && entry.asid == asid);
+            if owner(entry) && page && space {
+                entry.valid = false;
+            }
+        }
+    }
+
+    pub const fn stats(&self) -> TlbStats {
+        self.stats
+    }
+}
+
+impl Default for Tlb {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_ENTRIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(pte: u64, span: u32, ppn: u64) -> Leaf {
+        Leaf {
+            pte,
+            gpte: 0,
+            span,
+            ppn,
+        }
+    }
+
+    fn setup() -> Tlb {
+        let mut tlb = Tlb::new(16);
+        tlb.insert(0x1000, 1, None, leaf(0, 0, 0x80));
+        tlb.insert(0x2000, 2, None, leaf(0, 0, 0x81));
+        tlb.insert(0x3000, 1, None, leaf(PTE_G, 0, 0x82));
+        tlb
+    }
+
+    #[test]
+    fn tlb_hit_and_miss_counted() {
+        let mut tlb = setup();
+
+        assert_eq!(tlb.lookup(0x1234, 1, None), Some(leaf(0, 0, 0x80)));
+        assert_eq!(tlb.lookup(0x4000, 1, None), None);
+        assert_eq!(tlb.stats(), TlbStats { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn tlb_asid_tagged() {
+        let mut tlb = setup();
+
+        assert_eq!(tlb.lookup(0x1000, 2, None), None);
+        assert_eq!(tlb.lookup(0x2000, 2, None), Some(leaf(0, 0, 0x81)));
+    }
+
+    #[test]
+    fn tlb_global_matches_any_asid() {
+        let mut tlb = setup();
+
+        assert_eq!(tlb.lookup(0x3000, 7, None), Some(leaf(PTE_G, 0, 0x82)));
+    }
+
+    #[test]
+    fn tlb_flush_all() {
+        let mut tlb = setup();
+
+        tlb.flush(None, None);
+
+        assert_eq!(tlb.lookup(0x1000, 1, None), None);
+        assert_eq!(tlb.lookup(0x3000, 1, None), None);
+    }
+
+    #[test]
+    fn tlb_flush_asid_keeps_global() {
+        let mut tlb = setup();
+
+        tlb.flush(None, Some(1));
+
+        assert_eq!(tlb.lookup(0x1000, 1, None), None);
+        assert_eq!(tlb.lookup(0x2000, 2, None), Some(leaf(0, 0, 0x81)));
+        assert_eq!(tlb.lookup(0x3000, 1, None), Some(leaf(PTE_G, 0, 0x82)));
+    }
+
+    #[test]
+    fn tlb_flush_address_hits_every_asid() {
+        let mut tlb = setup();
+
+        tlb.flush(Some(0x3abc), None);
+
+        assert_eq!(tlb.lookup(0x3000, 1, None), None);
+        assert_eq!(tlb.lookup(0x1000, 1, None), Some(leaf(0, 0, 0x80)));
+    }
+
+    #[test]
+    fn tlb_flush_address_and_asid() {
+        let mut tlb = setup();
+
+        tlb.flush(Some(0x1000), Some(2));
+        assert_eq!(tlb.lookup(0x1000, 1, None), Some(leaf(0, 0, 0x80)));
+
+        tlb.flush(Some(0x1000), Some(1));
+        assert_eq!(tlb.lookup(0x1000, 1, None), None);
+    }
+
+    #[test]
+    fn tlb_flush_address_covers_superpage() {
+        let mut tlb = Tlb::new(16);
+        tlb.insert(0x20_3000, 1, None, leaf(0, 9, 0x403));
+
+        tlb.flush(Some(0x20_0000), None);
+
+        assert_eq!(tlb.lookup(0x20_3000, 1, None), None);
+    }
+
+    #[test]
+    fn tlb_guest_entries_tagged_by_vmid() {
+        let mut tlb = setup();
+        tlb.insert(0x5000, 1, Some(7), leaf(0, 0, 0x90));
+
+        assert_eq!(tlb.lookup(0x5000, 1, Some(7)), Some(leaf(0, 0, 0x90)));
+        assert_eq!(tlb.lookup(0x5000, 1, Some(8)), None);
+        assert_eq!(tlb.lookup(0x5000, 1, None), None);
+        assert_eq!(tlb.lookup(0x1000, 1, Some(7)), None);
+    }
+
+    #[test]
+    fn tlb_flush_leaves_other_side_alone() {
+        let mut tlb = setup();
+        tlb.insert(0x5000, 1, Some(7), leaf(0, 0, 0x90));
+        tlb.insert(0x6000, 1, Some(8), leaf(0, 0, 0x91));
+
+        tlb.flush(None, None);
+        assert_eq!(tlb.lookup(0x5000, 1, Some(7)), Some(leaf(0, 0, 0x90)));
+
+        tlb.flush_guest(Some(7), None, None);
+        assert_eq!(tlb.lookup(0x5000, 1, Some(7)), None);
+        assert_eq!(tlb.lookup(0x6000, 1, Some(8)), Some(leaf(0, 0, 0x91)));
+
+        tlb.flush_guest(None, None, None);
+        assert_eq!(tlb.lookup(0x6000, 1, Some(8)), None);
+    }
+}
@@ -0,0 +1,233 @@
+//! State of the V extension: the vector register file and the `vtype`
+//! encoding. Registers are kept as one byte array so that a register group is
+//! simply a run of consecutive registers, with element `i` of the group at
+//! byte `i * EEW / 8` from the start of its first register.
+
+/// Set in `vtype` when the requested configuration is unsupported.
+pub const VTYPE_VILL: u64 = 1 << 63;
+
+/// Bits of `vtype` other than `vill` that a configuration may set.
+const VTYPE_MASK: u64 = 0xff;
+
+/// A decoded `vtype` value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Vtype(u64);
+
+impl Vtype {
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    pub const fn vill(self) -> bool {
+        self.0 & VTYPE_VILL != 0
+    }
+
+    /// Selected element width in bits.
+    pub const fn sew(self) -> u32 {
+        8 << ((self.0 >> 3) & 0b111)
+    }
+
+    /// Base-2 logarithm of the register group multiplier, from -3 for 1/8 to
+    /// 3 for 8. The reserved encoding reads as -4.
+    pub const fn lmul(self) -> i32 {
+        ((self.0 as i32) << 29) >> 29
+    }
+
+    /// Tail agnostic.
+    pub const fn vta(self) -> bool {
+        self.0 & (1 << 6) != 0
+    }
+
+    /// Mask agnostic.
+    pub const fn vma(self) -> bool {
+        self.0 & (1 << 7) != 0
+    }
+}
+
+/// The 32 vector registers of a hart with a `vlen`-bit register width and
+/// elements of at most `elen` bits.
+#[derive(Clone, Debug)]
+pub struct VectorRegs {
+    vlen: u32,
+    elen: u32,
+    data: Box<[u8]>,
+}
+
+impl VectorRegs {
+    pub const DEFAULT_VLEN: u32 = 128;
+    pub const DEFAULT_ELEN: u32 = 64;
+    pub const MAX_VLEN: u32 = 1 << 16;
+
+    /// Creates a zeroed register file. `vlen` must be a power of two no
+    /// smaller than `elen`, which must be 32 or 64.
+    pub fn new(vlen: u32, elen: u32) -> Self {
+        assert!(vlen.is_power_of_two() && vlen <= Self::MAX_VLEN);
+        assert!(matches!(elen, 32 | 64) && elen <= vlen);
+
+        Self {
+            vlen,
+            elen,
+            data: vec![0; 32 * (vlen as usize / 8)].into_boxed_slice(),
+        }
+    }
+
+    pub const fn vlen(&self) -> u32 {
+        self.vlen
+    }
+
+    pub const fn elen(&self) -> u32 {
+        self.elen
+    }
+
+    /// Register width in bytes, as reported by `vlenb`.
+    pub const fn vlenb(&self) -> usize {
+        self.vlen as usize / 8
+    }
+
+    /// Returns the largest `vl` for `vtype`, or `None` when the configuration
+    /// is unsupported: reserved bits, a reserved LMUL, an element wider than
+    /// ELEN, or a fractional LMUL too small for the element width.
+    pub const fn vlmax(&self, vtype: Vtype) -> Option<usize> {
+        let lmul = vtype.lmul();
+        let sew = vtype.sew();
+        if vtype.bits() & !VTYPE_MASK != 0 || lmul == -4 || sew > self.elen {
+            return None;
+        }
+        if lmul < 0 && sew > self.elen >> -lmul {
+            return None;
+        }
+        Some(Self::elements(self.vlen, sew, lmul))
+    }
+
+    /// Number of `eew`-bit elements in a group of `2^emul` registers.
+    pub const fn elements(vlen: u32, eew: u32, emul: i32) -> usize {
+        let per_reg = (vlen / eew) as usize;
+        if emul >= 0 {
+            per_reg << emul
+        } else {
+            per_reg >> -emul
+        }
+    }
+
+    #[inline(always)]
+    const fn offset(&self, reg: usize, i: usize, eew: u32) -> usize {
+        reg * self.vlenb() + i * (eew as usize / 8)
+    }
+
+    /// Reads element `i` of the `eew`-bit group starting at `reg`,
+    /// zero-extended.
+    #[inline(always)]
+    pub fn get(&self, reg: usize, i: usize, eew: u32) -> u64 {
+        let at = self.offset(reg, i, eew);
+        let bytes = &self.data[at..at + eew as usize / 8];
+        bytes
+            .iter()
+            .rev()
+            .fold(0, |val, &byte| (val << 8) | u64::from(byte))
+    }
+
+    /// Writes the low `eew` bits of `val` to element `i` of the group
+    /// starting at `reg`.
+    #[inline(always)]
+    pub fn set(&mut self, reg: usize, i: usize, eew: u32, val: u64) {
+        let at = self.offset(reg, i, eew);
+        let bytes = &mut self.data[at..at + eew as usize / 8];
+        for (n, byte) in bytes.iter_mut().enumerate() {
+            *byte = (val >> (8 * n)) as u8;
+        }
+    }
+
+    /// Reads bit `i` of mask register `reg`.
+    #[inline(always)]
+    pub fn mask(&self, reg: usize, i: usize) -> bool {
+        (self.data[reg * self.vlenb() + i / 8] >> (i % 8)) & 1 != 0
+    }
+
+    #[inline(always)]
+    pub fn set_mask(&mut self, reg: usize, i: usize, bit: bool) {
+        let byte = &mut self.data[reg * self.vlenb() + i / 8];
+        if bit {
+            *byte |= 1 << (i % 8);
+        } else {
+            *byte &= !(1 << (i % 8));
+        }
+    }
+}
+
+impl Default for VectorRegs {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_VLEN, Self::DEFAULT_ELEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn vtype(vsew: u64, vlmul: u64) -> Vtype {
+        Vtype::from_bits((vsew << 3) | vlmul)
+    }
+
+    #[test]
+    fn vtype_decodes_fields() {
+        let vtype = Vtype::from_bits(0b1101_0111);
+
+        assert_eq!(vtype.sew(), 32);
+        assert_eq!(vtype.lmul(), -1);
+        assert!(vtype.vta());
+        assert!(vtype.vma());
+        assert!(!vtype.vill());
+    }
+
+    #[test]
+    fn vlmax_scales_with_lmul() {
+        let regs = VectorRegs::new(128, 64);
+
+        assert_eq!(regs.vlmax(vtype(0, 0)), Some(16));
+        assert_eq!(regs.vlmax(vtype(0, 3)), Some(128));
+        assert_eq!(regs.vlmax(vtype(3, 0)), Some(2));
+        assert_eq!(regs.vlmax(vtype(2, 0b111)), Some(2));
+        assert_eq!(regs.vlmax(vtype(0, 0b101)), Some(2));
+    }
+
+    #[test]
+    fn vlmax_rejects_unsupported_configurations() {
+        let regs = VectorRegs::new(128, 32);
+
+        assert_eq!(regs.vlmax(vtype(3, 0)), None);
+        assert_eq!(regs.vlmax(vtype(0, 0b100)), None);
+        assert_eq!(regs.vlmax(vtype(2, 0b111)), None);
+        assert_eq!(regs.vlmax(vtype(4, 0)), None);
+        assert_eq!(regs.vlmax(Vtype::from_bits(1 << 8)), None);
+    }
+
+    #[test]
+    fn elements_span_register_groups() {
+        let mut regs = VectorRegs::new(128, 64);
+
+        regs.set(2, 3, 32, 0x1122_3344);
+        regs.set(2, 5, 32, 0xdead_beef);
+
+        assert_eq!(regs.get(2, 3, 32), 0x1122_3344);
+        assert_eq!(regs.get(3, 1, 32), 0xdead_beef);
+        assert_eq!(regs.get(2, 6, 16), 0x3344);
+        assert_eq!(regs.get(2, 3, 64), 0);
+    }
+
+    #[test]
+    fn mask_bits_are_packed_from_bit_zero() {
+        let mut regs = VectorRegs::new(128, 64);
+
+        regs.set_mask(0, 0, true);
+        regs.set_mask(0, 9, true);
+        regs.set_mask(0, 0, false);
+
+        assert_eq!(regs.get(0, 0, 16), 1 << 9);
+        assert!(regs.mask(0, 9));
+        assert!(!regs.mask(0, 0));
+    }
+}